@@ -13,7 +13,7 @@ impl RectStyle {
         back_border_radius: 2.0,
         back_border_color: colors::BORDER,
         filled_color: colors::FILLED,
-        handle_width: 4,
+        handle_width: 4.0,
         handle_color: colors::HANDLE,
         handle_filled_gap: 1.0,
     };
@@ -26,7 +26,7 @@ impl h_slider::StyleSheet for RectStyle {
     fn hovered(&self) -> h_slider::Style {
         h_slider::Style::Rect(h_slider::RectStyle {
             filled_color: colors::FILLED_HOVER,
-            handle_width: 5,
+            handle_width: 5.0,
             ..Self::ACTIVE_RECT_STYLE
         })
     }
@@ -63,7 +63,7 @@ impl RectBipolarStyle {
             back_border_color: colors::BORDER,
             left_filled_color: colors::FILLED,
             right_filled_color: Color::from_rgb(0.0, 0.605, 0.0),
-            handle_width: 4,
+            handle_width: 4.0,
             handle_left_color: colors::HANDLE,
             handle_right_color: Color::from_rgb(0.0, 0.9, 0.0),
             handle_center_color: Color::from_rgb(0.7, 0.7, 0.7),
@@ -79,7 +79,7 @@ impl h_slider::StyleSheet for RectBipolarStyle {
         h_slider::Style::RectBipolar(h_slider::RectBipolarStyle {
             left_filled_color: colors::FILLED_HOVER,
             right_filled_color: Color::from_rgb(0.0, 0.64, 0.0),
-            handle_width: 5,
+            handle_width: 5.0,
             ..Self::ACTIVE_RECT_STYLE
         })
     }
@@ -102,10 +102,14 @@ impl h_slider::StyleSheet for TextureStyle {
                 ),
                 rail_widths: (1.0, 2.0),
                 rail_padding: 14.0,
+                rail_image: None,
             },
-            handle_width: 38,
+            handle_width: 38.0,
             image_handle: self.0.clone(),
             image_bounds: self.1,
+            image_region: None,
+            hovered_image: None,
+            dragging_image: None,
         })
     }
 
@@ -1,5 +1,5 @@
 use iced::Color;
-use iced_audio::{knob, text_marks};
+use iced_audio::{knob, text_marks, Normal};
 
 use super::colors;
 
@@ -186,6 +186,7 @@ impl knob::StyleSheet for CustomArcBipolar {
             empty_color: colors::KNOB_ARC_EMPTY,
             left_filled_color: colors::KNOB_ARC,
             right_filled_color: colors::KNOB_ARC_RIGHT,
+            center: Normal::center(),
             notch_center: knob::NotchShape::Line(Self::NOTCH_CENTER),
             notch_left_right: Some((
                 knob::NotchShape::Line(knob::LineNotch {
@@ -13,7 +13,7 @@ impl RectStyle {
         back_border_radius: 2.0,
         back_border_color: colors::BORDER,
         filled_color: colors::FILLED,
-        handle_height: 4,
+        handle_height: 4.0,
         handle_color: colors::HANDLE,
         handle_filled_gap: 1.0,
     };
@@ -26,7 +26,7 @@ impl v_slider::StyleSheet for RectStyle {
     fn hovered(&self) -> v_slider::Style {
         v_slider::Style::Rect(v_slider::RectStyle {
             filled_color: colors::FILLED_HOVER,
-            handle_height: 5,
+            handle_height: 5.0,
             ..Self::ACTIVE_RECT_STYLE
         })
     }
@@ -72,7 +72,7 @@ impl RectBipolarStyle {
             back_border_color: colors::BORDER,
             top_filled_color: colors::FILLED,
             bottom_filled_color: Color::from_rgb(0.0, 0.605, 0.0),
-            handle_height: 4,
+            handle_height: 4.0,
             handle_top_color: colors::HANDLE,
             handle_bottom_color: Color::from_rgb(0.0, 0.9, 0.0),
             handle_center_color: Color::from_rgb(0.7, 0.7, 0.7),
@@ -88,7 +88,7 @@ impl v_slider::StyleSheet for RectBipolarStyle {
         v_slider::Style::RectBipolar(v_slider::RectBipolarStyle {
             top_filled_color: colors::FILLED_HOVER,
             bottom_filled_color: Color::from_rgb(0.0, 0.64, 0.0),
-            handle_height: 5,
+            handle_height: 5.0,
             ..Self::ACTIVE_RECT_STYLE
         })
     }
@@ -111,10 +111,14 @@ impl v_slider::StyleSheet for TextureStyle {
                 ),
                 rail_widths: (1.0, 2.0),
                 rail_padding: 14.0,
+                rail_image: None,
             },
-            handle_height: 38,
+            handle_height: 38.0,
             image_handle: self.0.clone(),
             image_bounds: self.1,
+            image_region: None,
+            hovered_image: None,
+            dragging_image: None,
         })
     }
 
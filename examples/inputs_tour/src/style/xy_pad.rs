@@ -9,7 +9,7 @@ pub struct CustomStyle;
 impl CustomStyle {
     const ACTIVE_HANDLE: xy_pad::HandleSquare = xy_pad::HandleSquare {
         color: colors::FILLED,
-        size: 10,
+        size: 10.0,
         border_width: 1.0,
         border_radius: 2.0,
         border_color: colors::HANDLE,
@@ -29,6 +29,7 @@ impl CustomStyle {
             b: 0.2,
             a: 0.7,
         },
+        texture: None,
     };
 }
 impl xy_pad::StyleSheet for CustomStyle {
@@ -40,7 +41,7 @@ impl xy_pad::StyleSheet for CustomStyle {
         xy_pad::Style {
             handle: xy_pad::HandleShape::Square(xy_pad::HandleSquare {
                 color: colors::FILLED_HOVER,
-                size: 12,
+                size: 12.0,
                 ..Self::ACTIVE_HANDLE
             }),
             ..Self::ACTIVE_STYLE
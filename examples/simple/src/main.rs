@@ -33,7 +33,7 @@ pub struct App {
     // * LogDBRange - a logarithmic range of decibel values. Values around 0 dB
     // will increment slower than values farther away from 0 dB.
     // * FreqRange - a logarithmic range of frequency values. Each octave in
-    // the 10 octave spectrum (from 20 Hz to 20480 Hz) is spaced evenly.
+    // the range is spaced evenly.
     //
     float_range: FloatRange,
     int_range: IntRange,
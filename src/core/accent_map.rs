@@ -0,0 +1,78 @@
+//! Accent colors assigned to groups of parameters
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use iced_native::Color;
+
+/// Maps keys (such as parameter IDs or group names) to accent colors.
+///
+/// Pass the result of [`get`] to the `accent()` method of a widget to
+/// recolor the filled portions of its style at draw time, without writing a
+/// separate stylesheet for every widget.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::AccentMap;
+/// use iced_native::Color;
+///
+/// let accents = AccentMap::new()
+///     .with("Filter", Color::from_rgb(0.9, 0.5, 0.1))
+///     .with("LFO", Color::from_rgb(0.2, 0.6, 0.9));
+///
+/// assert_eq!(accents.get("LFO"), Some(Color::from_rgb(0.2, 0.6, 0.9)));
+/// assert_eq!(accents.get("Amp"), None);
+/// ```
+///
+/// [`get`]: #method.get
+#[derive(Debug, Clone)]
+pub struct AccentMap<K: Hash + Eq> {
+    map: HashMap<K, Color>,
+}
+
+impl<K: Hash + Eq> AccentMap<K> {
+    /// Creates a new empty `AccentMap`
+    pub fn new() -> Self {
+        Self {
+            map: HashMap::new(),
+        }
+    }
+
+    /// Assigns an accent `color` to `key`, and returns the `AccentMap`
+    pub fn with(mut self, key: K, color: Color) -> Self {
+        let _ = self.map.insert(key, color);
+        self
+    }
+
+    /// Assigns an accent `color` to `key`. Returns the color previously
+    /// assigned to `key`, if any.
+    pub fn insert(&mut self, key: K, color: Color) -> Option<Color> {
+        self.map.insert(key, color)
+    }
+
+    /// Removes the accent color of `key`. Returns the removed color, if any.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<Color>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.remove(key)
+    }
+
+    /// Returns the accent color assigned to `key`, if any.
+    pub fn get<Q>(&self, key: &Q) -> Option<Color>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(key).copied()
+    }
+}
+
+impl<K: Hash + Eq> Default for AccentMap<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,140 @@
+//! Stepping through the values of a discrete parameter
+
+use super::normal::Normal;
+use super::range::IntRange;
+
+/// Evenly spaced stops (detents) that a widget steps through when its
+/// parameter is discrete, such as one mapped by an [`IntRange`].
+///
+/// With detents, a widget moves exactly one step per scroll wheel notch,
+/// and a drag accumulates movement until it reaches the next detent
+/// instead of rounding to the nearest one. This avoids skipped or
+/// flickering values when the range is small.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{Detents, IntRange, Normal};
+///
+/// let range = IntRange::new(0, 4);
+/// let detents = Detents::from(range);
+///
+/// let value = detents.step(range.map_to_normal(1), 1);
+/// assert_eq!(range.unmap_to_value(value), 2);
+///
+/// // A drag that has not reached the next detent yet keeps the value.
+/// let value = detents.drag(range.map_to_normal(2), 0.7);
+/// assert_eq!(range.unmap_to_value(value), 2);
+///
+/// let value = detents.drag(range.map_to_normal(2), 0.75);
+/// assert_eq!(range.unmap_to_value(value), 3);
+/// ```
+///
+/// [`IntRange`]: ../range/struct.IntRange.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Detents {
+    steps: u32,
+}
+
+impl Detents {
+    /// Creates new [`Detents`] that divide the range into `steps` steps,
+    /// so there are `steps + 1` detents including `0.0` and `1.0`.
+    ///
+    /// `steps` is constrained to be at least `1`.
+    ///
+    /// [`Detents`]: struct.Detents.html
+    pub fn new(steps: u32) -> Self {
+        Self {
+            steps: steps.max(1),
+        }
+    }
+
+    /// Returns the number of steps between `0.0` and `1.0`.
+    pub fn steps(&self) -> u32 {
+        self.steps
+    }
+
+    /// Returns the detent closest to `normal`.
+    pub fn snapped(&self, normal: Normal) -> Normal {
+        self.normal(self.index(normal.as_f32()))
+    }
+
+    /// Moves `steps` detents away from the detent closest to `normal`.
+    /// Positive `steps` move up, and negative `steps` move down.
+    pub fn step(&self, normal: Normal, steps: i32) -> Normal {
+        self.normal(self.index(normal.as_f32()) + i64::from(steps))
+    }
+
+    /// Returns the new value of a drag, where `value` is the current value
+    /// and `continuous` is the accumulated (unsnapped) position of the drag.
+    ///
+    /// The value only changes once `continuous` reaches the next detent in
+    /// the direction of the drag.
+    pub fn drag(&self, value: Normal, continuous: f32) -> Normal {
+        let current = self.index(value.as_f32());
+        let steps = self.steps as f32;
+
+        let index = if continuous * steps >= (current + 1) as f32 {
+            (continuous * steps).floor() as i64
+        } else if continuous * steps <= (current - 1) as f32 {
+            (continuous * steps).ceil() as i64
+        } else {
+            current
+        };
+
+        self.normal(index)
+    }
+
+    fn index(&self, normal: f32) -> i64 {
+        (normal * self.steps as f32).round() as i64
+    }
+
+    fn normal(&self, index: i64) -> Normal {
+        let index = index.max(0).min(i64::from(self.steps));
+        (index as f32 / self.steps as f32).into()
+    }
+}
+
+/// Creates [`Detents`] with one step per integer in the [`IntRange`]. An
+/// inverted range has as many steps as the same range the right way round.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{Detents, IntRange};
+///
+/// assert_eq!(Detents::from(IntRange::new(-2, 6)).steps(), 8);
+/// assert_eq!(Detents::from(IntRange::new(6, -2)).steps(), 8);
+///
+/// let inverted = IntRange::new(4, 0);
+/// let detents = Detents::from(inverted);
+///
+/// let value = detents.step(inverted.map_to_normal(3), 1);
+/// assert_eq!(inverted.unmap_to_value(value), 2);
+/// ```
+///
+/// [`Detents`]: struct.Detents.html
+/// [`IntRange`]: ../range/struct.IntRange.html
+impl From<IntRange> for Detents {
+    fn from(range: IntRange) -> Self {
+        Self::new((range.max() - range.min()).unsigned_abs())
+    }
+}
+
+/// Returns the number of detents to step for a scroll of `lines`, which is
+/// at least one step in the direction of the scroll.
+pub(crate) fn wheel_steps(lines: f32) -> i32 {
+    if lines > 0.0 {
+        lines.round().max(1.0) as i32
+    } else if lines < 0.0 {
+        lines.round().min(-1.0) as i32
+    } else {
+        0
+    }
+}
+
+/// Returns the number of detents to step for a page of `page_step` normals,
+/// which is at least one step.
+pub(crate) fn page_steps(detents: Detents, page_step: f32) -> i32 {
+    ((page_step * detents.steps as f32).round() as i32).max(1)
+}
@@ -0,0 +1,162 @@
+//! Discrete parameters whose steps are named options
+
+use std::borrow::Cow;
+
+use crate::core::{Normal, NormalParam};
+
+/// A discrete parameter where each step is a named option, such as the
+/// type of a filter.
+///
+/// Values are the indexes of the options. Like an [`IntRange`], this will
+/// cause the widget to "step" when moved.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::EnumParam;
+///
+/// let filter_type = EnumParam::new(vec!["Low Pass", "Band Pass", "High Pass"]);
+///
+/// let param = filter_type.normal_param(2, 0);
+///
+/// assert_eq!(filter_type.unmap_to_value(param.value), 2);
+/// assert_eq!(filter_type.label_for_normal(param.value), "High Pass");
+/// assert_eq!(filter_type.index_of("Band Pass"), Some(1));
+/// ```
+///
+/// [`IntRange`]: ../range/struct.IntRange.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumParam {
+    labels: Vec<Cow<'static, str>>,
+    span: f32,
+    span_recip: f32,
+}
+
+impl EnumParam {
+    /// Creates a new `EnumParam`
+    ///
+    /// # Arguments
+    ///
+    /// * `labels` - the label of each option, in order
+    ///
+    /// # Panics
+    ///
+    /// This will panic if there are less than two labels
+    pub fn new<I, L>(labels: I) -> Self
+    where
+        I: IntoIterator<Item = L>,
+        L: Into<Cow<'static, str>>,
+    {
+        let labels: Vec<Cow<'static, str>> =
+            labels.into_iter().map(Into::into).collect();
+
+        assert!(labels.len() >= 2);
+
+        let span = (labels.len() - 1) as f32;
+
+        Self {
+            labels,
+            span,
+            span_recip: span.recip(),
+        }
+    }
+
+    /// Returns the number of options
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    /// Returns `true` if there are no options. This is never the case,
+    /// since an `EnumParam` has at least two options
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    /// Returns the labels of the options, in order
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.labels.iter().map(|label| label.as_ref())
+    }
+
+    /// Returns the label of the option at `index`, if any
+    pub fn label(&self, index: usize) -> Option<&str> {
+        self.labels.get(index).map(|label| label.as_ref())
+    }
+
+    /// Returns the label of the option selected by the supplied
+    /// [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn label_for_normal(&self, normal: Normal) -> &str {
+        &self.labels[self.unmap_to_value(normal)]
+    }
+
+    /// Returns the index of the option with the supplied label, if any
+    pub fn index_of(&self, label: &str) -> Option<usize> {
+        self.labels.iter().position(|l| l == label)
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this parameter.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The index of the inital option of the parameter.
+    /// * `default_value` - The index of the default option of the
+    ///   parameter.
+    pub fn normal_param(&self, value: usize, default: usize) -> NormalParam {
+        NormalParam {
+            value: self.map_to_normal(value),
+            default: self.map_to_normal(default),
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this parameter where `value` and `default_value` is the first
+    /// option.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn default_normal_param(&self) -> NormalParam {
+        self.normal_param(0, 0)
+    }
+
+    /// Returns a [`Normal`] that is snapped to the closest option.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn snapped(&self, normal: Normal) -> Normal {
+        self.map_to_normal(self.unmap_to_value(normal))
+    }
+
+    /// Returns the [`Normal`] of the option after the one selected by
+    /// `normal`, or of the last option
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn increment(&self, normal: Normal) -> Normal {
+        self.map_to_normal(self.unmap_to_value(normal) + 1)
+    }
+
+    /// Returns the [`Normal`] of the option before the one selected by
+    /// `normal`, or of the first option
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn decrement(&self, normal: Normal) -> Normal {
+        self.map_to_normal(self.unmap_to_value(normal).saturating_sub(1))
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied index. Indexes
+    /// past the last option map to the last option.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_to_normal(&self, value: usize) -> Normal {
+        let value = value.min(self.labels.len() - 1);
+
+        (value as f32 * self.span_recip).into()
+    }
+
+    /// Returns the corresponding index from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_value(&self, normal: Normal) -> usize {
+        ((normal.as_f32() * self.span).round() as usize)
+            .min(self.labels.len() - 1)
+    }
+}
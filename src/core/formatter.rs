@@ -0,0 +1,331 @@
+//! Display and parse parameter values as human-readable text
+
+/// Converts the values of a parameter to and from human-readable text, for
+/// readouts, tooltips and text entry.
+///
+/// Each range has a default formatter, returned by its `formatter()`
+/// method.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{Formatter, FreqRange};
+///
+/// let formatter = FreqRange::default().formatter();
+///
+/// assert_eq!(formatter.to_text(440.0), "440 Hz");
+/// assert_eq!(formatter.to_text(2500.0), "2.50 kHz");
+/// assert_eq!(formatter.parse("2.5k"), Some(2500.0));
+/// ```
+pub trait Formatter {
+    /// The type of the values
+    type Value;
+
+    /// Returns the text that represents `value`.
+    fn to_text(&self, value: Self::Value) -> String;
+
+    /// Parses text entered by the user. Returns `None` if the text is not
+    /// a valid value.
+    fn parse(&self, text: &str) -> Option<Self::Value>;
+}
+
+/// Formats plain `f32` values, with an optional unit.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FloatFormatter {
+    /// The number of decimal places to show
+    pub decimals: usize,
+    /// The unit shown after the value, such as `"st"` for semitones
+    pub unit: &'static str,
+}
+
+impl Default for FloatFormatter {
+    fn default() -> Self {
+        Self {
+            decimals: 2,
+            unit: "",
+        }
+    }
+}
+
+impl Formatter for FloatFormatter {
+    type Value = f32;
+
+    fn to_text(&self, value: f32) -> String {
+        with_unit(format!("{:.*}", self.decimals, value), self.unit)
+    }
+
+    fn parse(&self, text: &str) -> Option<f32> {
+        parse_f32(strip_unit(text, self.unit))
+    }
+}
+
+/// Formats `i32` values, with an optional unit.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct IntFormatter {
+    /// The unit shown after the value, such as `"voices"`
+    pub unit: &'static str,
+}
+
+impl Formatter for IntFormatter {
+    type Value = i32;
+
+    fn to_text(&self, value: i32) -> String {
+        with_unit(value.to_string(), self.unit)
+    }
+
+    fn parse(&self, text: &str) -> Option<i32> {
+        let text = strip_unit(text, self.unit);
+
+        text.parse::<i32>()
+            .ok()
+            .or_else(|| parse_f32(text).map(|value| value.round() as i32))
+    }
+}
+
+/// Formats decibel values, such as `"-6.0 dB"`.
+///
+/// Values at or below `-f32::MAX` are shown as `"-inf dB"`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DBFormatter {
+    /// The number of decimal places to show
+    pub decimals: usize,
+}
+
+impl Default for DBFormatter {
+    fn default() -> Self {
+        Self { decimals: 1 }
+    }
+}
+
+impl Formatter for DBFormatter {
+    type Value = f32;
+
+    fn to_text(&self, value: f32) -> String {
+        if value <= -f32::MAX {
+            String::from("-inf dB")
+        } else {
+            format!("{:.*} dB", self.decimals, value)
+        }
+    }
+
+    fn parse(&self, text: &str) -> Option<f32> {
+        let text = strip_unit(text, "dB");
+
+        if text.eq_ignore_ascii_case("-inf") {
+            Some(f32::NEG_INFINITY)
+        } else {
+            parse_f32(text)
+        }
+    }
+}
+
+/// Formats frequencies, such as `"440 Hz"` or `"2.50 kHz"`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FreqFormatter {
+    /// The number of decimal places to show for values in kHz
+    pub khz_decimals: usize,
+}
+
+impl Default for FreqFormatter {
+    fn default() -> Self {
+        Self { khz_decimals: 2 }
+    }
+}
+
+impl Formatter for FreqFormatter {
+    type Value = f32;
+
+    fn to_text(&self, value: f32) -> String {
+        if value.abs() >= 1000.0 {
+            format!("{:.*} kHz", self.khz_decimals, value / 1000.0)
+        } else {
+            format!("{:.0} Hz", value)
+        }
+    }
+
+    fn parse(&self, text: &str) -> Option<f32> {
+        let text = strip_unit(text, "Hz");
+
+        match text.strip_suffix(|c| c == 'k' || c == 'K') {
+            Some(khz) => parse_f32(khz).map(|value| value * 1000.0),
+            None => parse_f32(text),
+        }
+    }
+}
+
+/// Formats times in milliseconds, such as `"12.5 ms"` or `"1.25 s"`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct MillisFormatter {
+    /// The number of decimal places to show
+    pub decimals: usize,
+}
+
+impl Default for MillisFormatter {
+    fn default() -> Self {
+        Self { decimals: 1 }
+    }
+}
+
+impl Formatter for MillisFormatter {
+    type Value = f32;
+
+    fn to_text(&self, value: f32) -> String {
+        if value.abs() >= 1000.0 {
+            format!("{:.*} s", self.decimals + 1, value / 1000.0)
+        } else {
+            format!("{:.*} ms", self.decimals, value)
+        }
+    }
+
+    fn parse(&self, text: &str) -> Option<f32> {
+        let text = text.trim();
+
+        if let Some(ms) = strip_suffix_ignore_case(text, "ms") {
+            parse_f32(ms)
+        } else if let Some(s) = strip_suffix_ignore_case(text, "s") {
+            parse_f32(s).map(|value| value * 1000.0)
+        } else {
+            parse_f32(text)
+        }
+    }
+}
+
+/// Formats fractions as percentages, so `0.5` is shown as `"50%"`.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct PercentFormatter {
+    /// The number of decimal places to show
+    pub decimals: usize,
+}
+
+impl Formatter for PercentFormatter {
+    type Value = f32;
+
+    fn to_text(&self, value: f32) -> String {
+        format!("{:.*}%", self.decimals, value * 100.0)
+    }
+
+    fn parse(&self, text: &str) -> Option<f32> {
+        parse_f32(strip_unit(text, "%")).map(|value| value / 100.0)
+    }
+}
+
+/// Formats pan positions from `-1.0` (hard left) to `1.0` (hard right) as
+/// percentages to either side, such as `"L50"`, `"C"` or `"R50"`.
+///
+/// Parsing also accepts plain numbers, where negative percentages are to
+/// the left.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{Formatter, PanFormatter};
+///
+/// let formatter = PanFormatter::default();
+///
+/// assert_eq!(formatter.to_text(-0.5), "L50");
+/// assert_eq!(formatter.to_text(0.0), "C");
+/// assert_eq!(formatter.to_text(0.25), "R25");
+/// assert_eq!(formatter.parse("r50"), Some(0.5));
+/// assert_eq!(formatter.parse("-100"), Some(-1.0));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct PanFormatter {
+    /// The number of decimal places to show
+    pub decimals: usize,
+}
+
+impl Formatter for PanFormatter {
+    type Value = f32;
+
+    fn to_text(&self, value: f32) -> String {
+        let percent = value.clamp(-1.0, 1.0) * 100.0;
+        let text = format!("{:.*}", self.decimals, percent.abs());
+
+        if text.parse::<f32>().map_or(true, |percent| percent == 0.0) {
+            String::from("C")
+        } else if percent < 0.0 {
+            format!("L{}", text)
+        } else {
+            format!("R{}", text)
+        }
+    }
+
+    fn parse(&self, text: &str) -> Option<f32> {
+        let text = text.trim();
+
+        if text.eq_ignore_ascii_case("c") {
+            return Some(0.0);
+        }
+
+        let percent = if let Some(rest) = strip_prefix_ignore_case(text, "l") {
+            -parse_f32(rest)?
+        } else if let Some(rest) = strip_prefix_ignore_case(text, "r") {
+            parse_f32(rest)?
+        } else {
+            parse_f32(text)?
+        };
+
+        Some(percent / 100.0)
+    }
+}
+
+fn with_unit(text: String, unit: &str) -> String {
+    if unit.is_empty() {
+        text
+    } else {
+        format!("{} {}", text, unit)
+    }
+}
+
+fn strip_unit<'a>(text: &'a str, unit: &str) -> &'a str {
+    let text = text.trim();
+
+    if unit.is_empty() {
+        return text;
+    }
+
+    strip_suffix_ignore_case(text, unit).unwrap_or(text)
+}
+
+fn strip_suffix_ignore_case<'a>(
+    text: &'a str,
+    suffix: &str,
+) -> Option<&'a str> {
+    if text.len() < suffix.len()
+        || !text.is_char_boundary(text.len() - suffix.len())
+    {
+        return None;
+    }
+
+    let (rest, end) = text.split_at(text.len() - suffix.len());
+
+    if end.eq_ignore_ascii_case(suffix) {
+        Some(rest.trim_end())
+    } else {
+        None
+    }
+}
+
+fn strip_prefix_ignore_case<'a>(
+    text: &'a str,
+    prefix: &str,
+) -> Option<&'a str> {
+    if text.len() < prefix.len() || !text.is_char_boundary(prefix.len()) {
+        return None;
+    }
+
+    let (start, rest) = text.split_at(prefix.len());
+
+    if start.eq_ignore_ascii_case(prefix) {
+        Some(rest.trim_start())
+    } else {
+        None
+    }
+}
+
+fn parse_f32(text: &str) -> Option<f32> {
+    text.trim()
+        .parse::<f32>()
+        .ok()
+        .filter(|value| value.is_finite())
+}
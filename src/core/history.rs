@@ -0,0 +1,284 @@
+//! Undo and redo edits of parameters
+
+use crate::core::Normal;
+
+static DEFAULT_LIMIT: usize = 100;
+
+/// A single change of a parameter recorded by an [`EditHistory`].
+///
+/// [`EditHistory`]: struct.EditHistory.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Edit<K> {
+    /// The id of the parameter
+    pub id: K,
+    /// The value before the change
+    pub old: Normal,
+    /// The value after the change
+    pub new: Normal,
+}
+
+/// Records the edits of parameters so they can be undone and redone.
+///
+/// Edits are grouped into transactions. Call [`begin`] when a widget is
+/// grabbed, [`record`] for every change while it is held, and [`end`] when
+/// it is released, so a whole drag is undone in one step. Changes of the
+/// same parameter within a transaction are merged into a single [`Edit`].
+///
+/// [`undo`] and [`redo`] return the changes to apply to the parameters
+/// and widgets, but do not record them again.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{EditHistory, Normal};
+///
+/// let mut history = EditHistory::new();
+///
+/// // The user drags the cutoff knob from 0.5 to 0.8.
+/// history.begin();
+/// history.record("Cutoff", Normal::from(0.5), Normal::from(0.6));
+/// history.record("Cutoff", Normal::from(0.6), Normal::from(0.8));
+/// history.end();
+///
+/// assert_eq!(history.undo(), Some(vec![("Cutoff", Normal::from(0.5))]));
+/// assert_eq!(history.redo(), Some(vec![("Cutoff", Normal::from(0.8))]));
+/// ```
+///
+/// [`begin`]: #method.begin
+/// [`record`]: #method.record
+/// [`end`]: #method.end
+/// [`undo`]: #method.undo
+/// [`redo`]: #method.redo
+/// [`Edit`]: struct.Edit.html
+#[derive(Debug, Clone)]
+pub struct EditHistory<K: Clone + PartialEq> {
+    undo_stack: Vec<Vec<Edit<K>>>,
+    redo_stack: Vec<Vec<Edit<K>>>,
+    open: Option<Vec<Edit<K>>>,
+    limit: usize,
+}
+
+impl<K: Clone + PartialEq> EditHistory<K> {
+    /// Creates a new empty `EditHistory` that keeps the last 100
+    /// transactions.
+    pub fn new() -> Self {
+        Self {
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            open: None,
+            limit: DEFAULT_LIMIT,
+        }
+    }
+
+    /// Sets the number of transactions that are kept. The oldest
+    /// transactions are discarded first.
+    ///
+    /// `limit` is constrained to be at least `1`.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit.max(1);
+        self.trim();
+        self
+    }
+
+    /// Starts a transaction, such as when a widget is grabbed. Any open
+    /// transaction is ended first.
+    pub fn begin(&mut self) {
+        self.end();
+        self.open = Some(Vec::new());
+    }
+
+    /// Returns `true` if a transaction is open.
+    pub fn is_open(&self) -> bool {
+        self.open.is_some()
+    }
+
+    /// Records that the parameter `id` changed from `old` to `new`.
+    ///
+    /// Inside a transaction, the change is merged with earlier changes of
+    /// the same parameter. Outside of one, it is recorded as a transaction
+    /// of its own, such as for a key press or a scroll wheel notch.
+    pub fn record(&mut self, id: K, old: Normal, new: Normal) {
+        match &mut self.open {
+            Some(edits) => {
+                if let Some(edit) = edits.iter_mut().find(|e| e.id == id) {
+                    edit.new = new;
+                } else {
+                    edits.push(Edit { id, old, new });
+                }
+            }
+            None => {
+                self.push(vec![Edit { id, old, new }]);
+            }
+        }
+    }
+
+    /// Ends the open transaction, such as when a widget is released.
+    /// Transactions that did not change any value are discarded.
+    pub fn end(&mut self) {
+        if let Some(edits) = self.open.take() {
+            self.push(edits);
+        }
+    }
+
+    /// Discards the open transaction without recording it.
+    pub fn cancel(&mut self) {
+        self.open = None;
+    }
+
+    /// Returns `true` if there is a transaction to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    /// Returns `true` if there is a transaction to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    /// Undoes the last transaction. Any open transaction is ended first.
+    ///
+    /// Returns the old value of each parameter in the transaction, or
+    /// `None` if there is nothing to undo.
+    pub fn undo(&mut self) -> Option<Vec<(K, Normal)>> {
+        self.end();
+
+        let edits = self.undo_stack.pop()?;
+
+        let changes = edits
+            .iter()
+            .rev()
+            .map(|edit| (edit.id.clone(), edit.old))
+            .collect();
+
+        self.redo_stack.push(edits);
+
+        Some(changes)
+    }
+
+    /// Redoes the last undone transaction.
+    ///
+    /// Returns the new value of each parameter in the transaction, or
+    /// `None` if there is nothing to redo.
+    pub fn redo(&mut self) -> Option<Vec<(K, Normal)>> {
+        self.end();
+
+        let edits = self.redo_stack.pop()?;
+
+        let changes = edits
+            .iter()
+            .map(|edit| (edit.id.clone(), edit.new))
+            .collect();
+
+        self.undo_stack.push(edits);
+
+        Some(changes)
+    }
+
+    /// Discards every transaction.
+    pub fn clear(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.open = None;
+    }
+
+    fn push(&mut self, mut edits: Vec<Edit<K>>) {
+        edits.retain(|edit| edit.old != edit.new);
+
+        if edits.is_empty() {
+            return;
+        }
+
+        self.undo_stack.push(edits);
+        self.redo_stack.clear();
+        self.trim();
+    }
+
+    fn trim(&mut self) {
+        if self.undo_stack.len() > self.limit {
+            let excess = self.undo_stack.len() - self.limit;
+            let _ = self.undo_stack.drain(..excess);
+        }
+    }
+}
+
+impl<K: Clone + PartialEq> Default for EditHistory<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn n(value: f32) -> Normal {
+        Normal::from(value)
+    }
+
+    #[test]
+    fn undo_and_redo_at_capacity() {
+        let mut history = EditHistory::new().limit(2);
+
+        history.record("a", n(0.0), n(0.1));
+        history.record("b", n(0.0), n(0.2));
+        history.record("c", n(0.0), n(0.3));
+
+        assert_eq!(history.undo(), Some(vec![("c", n(0.0))]));
+        assert_eq!(history.undo(), Some(vec![("b", n(0.0))]));
+        // The oldest transaction was discarded.
+        assert_eq!(history.undo(), None);
+        assert!(!history.can_undo());
+
+        assert_eq!(history.redo(), Some(vec![("b", n(0.2))]));
+        assert_eq!(history.redo(), Some(vec![("c", n(0.3))]));
+        assert_eq!(history.redo(), None);
+
+        // Undoing again after redoing stays within the limit.
+        assert_eq!(history.undo(), Some(vec![("c", n(0.0))]));
+        assert_eq!(history.undo(), Some(vec![("b", n(0.0))]));
+        assert_eq!(history.undo(), None);
+    }
+
+    #[test]
+    fn new_edit_discards_redo() {
+        let mut history = EditHistory::new();
+
+        history.record("a", n(0.0), n(0.5));
+        assert_eq!(history.undo(), Some(vec![("a", n(0.0))]));
+        assert!(history.can_redo());
+
+        history.record("b", n(0.0), n(0.7));
+        assert!(!history.can_redo());
+        assert_eq!(history.redo(), None);
+
+        assert_eq!(history.undo(), Some(vec![("b", n(0.0))]));
+        assert_eq!(history.undo(), None);
+    }
+
+    #[test]
+    fn transaction_merges_edits() {
+        let mut history = EditHistory::new();
+
+        history.begin();
+        history.record("a", n(0.1), n(0.2));
+        history.record("b", n(0.5), n(0.6));
+        history.record("a", n(0.2), n(0.3));
+        history.end();
+
+        assert_eq!(history.undo(), Some(vec![("b", n(0.5)), ("a", n(0.1))]));
+        assert_eq!(history.undo(), None);
+        assert_eq!(history.redo(), Some(vec![("a", n(0.3)), ("b", n(0.6))]));
+    }
+
+    #[test]
+    fn unchanged_transaction_is_discarded() {
+        let mut history = EditHistory::new();
+
+        history.begin();
+        history.record("a", n(0.4), n(0.6));
+        history.record("a", n(0.6), n(0.4));
+        history.end();
+
+        assert!(!history.can_undo());
+    }
+}
@@ -0,0 +1,323 @@
+//! Mouse button bindings shared by all interactive widgets
+
+use std::cell::Cell;
+
+use iced_native::{keyboard, mouse, Point, Rectangle};
+
+thread_local! {
+    static GLOBAL_INPUT_CONFIG: Cell<InputConfig> =
+        const { Cell::new(InputConfig::DEFAULT) };
+}
+
+/// Defines which mouse buttons control the interactive widgets.
+///
+/// Every widget is created with the global [`InputConfig`], so a host can
+/// honor user preferences (such as left-handed mouse usage) by calling
+/// [`InputConfig::set_global`] once at startup. A single widget can still
+/// override it with its own `input_config()` method.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{InputConfig, ResetGesture};
+/// use iced_native::{mouse, Point};
+///
+/// InputConfig::set_global(InputConfig {
+///     drag_button: mouse::Button::Right,
+///     reset: ResetGesture::Button(mouse::Button::Middle),
+///     menu_button: Some(mouse::Button::Left),
+///     ..InputConfig::DEFAULT
+/// });
+///
+/// assert_eq!(InputConfig::global().drag_button, mouse::Button::Right);
+/// ```
+///
+/// [`InputConfig`]: struct.InputConfig.html
+/// [`InputConfig::set_global`]: struct.InputConfig.html#method.set_global
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct InputConfig {
+    /// The mouse button that drags the widget.
+    ///
+    /// The default is `mouse::Button::Left`.
+    pub drag_button: mouse::Button,
+    /// The gesture that resets the widget to its default value.
+    ///
+    /// The default is [`ResetGesture::DoubleClick`].
+    ///
+    /// [`ResetGesture::DoubleClick`]: enum.ResetGesture.html#variant.DoubleClick
+    pub reset: ResetGesture,
+    /// The mouse button that requests a context menu from the widget.
+    /// Set this to `None` to disable menu requests.
+    ///
+    /// The default is `None`.
+    pub menu_button: Option<mouse::Button>,
+    /// The distance in pixels the cursor must move after pressing the
+    /// `drag_button` before the widget starts changing its value. This keeps
+    /// tiny accidental movements during a click from nudging the value.
+    ///
+    /// Once the threshold is crossed, the full movement from the point where
+    /// the button was pressed is applied, so no movement is lost.
+    ///
+    /// The default is `2.0`.
+    pub drag_threshold: f32,
+    /// How much larger than a widget's bounds the area is that responds to
+    /// presses and the scroll wheel.
+    ///
+    /// Every side of the bounds is pushed out by `(hit_target_scale - 1.0)`
+    /// times half of the shorter side, so thin sliders grow as much as
+    /// square knobs do. Only hit testing is affected; the layout size seen
+    /// by neighboring widgets stays the same. Values below `1.0` are
+    /// treated as `1.0`.
+    ///
+    /// The default is `1.0`.
+    pub hit_target_scale: f32,
+}
+
+impl InputConfig {
+    /// The default [`InputConfig`], where the left mouse button drags and
+    /// double-clicking it resets.
+    ///
+    /// [`InputConfig`]: struct.InputConfig.html
+    pub const DEFAULT: InputConfig = InputConfig {
+        drag_button: mouse::Button::Left,
+        reset: ResetGesture::DoubleClick,
+        menu_button: None,
+        drag_threshold: 2.0,
+        hit_target_scale: 1.0,
+    };
+
+    /// An [`InputConfig`] for touchscreens, with larger hit targets and a
+    /// larger drag threshold to ignore the jitter of a resting finger.
+    ///
+    /// [`InputConfig`]: struct.InputConfig.html
+    pub const TOUCH: InputConfig = InputConfig {
+        drag_button: mouse::Button::Left,
+        reset: ResetGesture::DoubleClick,
+        menu_button: None,
+        drag_threshold: 6.0,
+        hit_target_scale: 2.0,
+    };
+
+    /// Returns this [`InputConfig`] with the hit target scale and drag
+    /// threshold of [`InputConfig::TOUCH`] if `enabled` is `true`, or of
+    /// [`InputConfig::DEFAULT`] otherwise. The button bindings are kept.
+    ///
+    /// [`InputConfig`]: struct.InputConfig.html
+    /// [`InputConfig::TOUCH`]: struct.InputConfig.html#associatedconstant.TOUCH
+    /// [`InputConfig::DEFAULT`]: struct.InputConfig.html#associatedconstant.DEFAULT
+    pub fn touch_mode(self, enabled: bool) -> Self {
+        let preset = if enabled {
+            InputConfig::TOUCH
+        } else {
+            InputConfig::DEFAULT
+        };
+
+        InputConfig {
+            drag_threshold: preset.drag_threshold,
+            hit_target_scale: preset.hit_target_scale,
+            ..self
+        }
+    }
+
+    /// Sets the [`InputConfig`] that newly created widgets will use.
+    ///
+    /// Widgets are expected to live on the GUI thread, so this setting is
+    /// local to the calling thread.
+    ///
+    /// [`InputConfig`]: struct.InputConfig.html
+    pub fn set_global(config: InputConfig) {
+        GLOBAL_INPUT_CONFIG.with(|global| global.set(config));
+    }
+
+    /// Returns the [`InputConfig`] that newly created widgets will use.
+    ///
+    /// [`InputConfig`]: struct.InputConfig.html
+    pub fn global() -> InputConfig {
+        GLOBAL_INPUT_CONFIG.with(|global| global.get())
+    }
+
+    /// Returns the area of a widget with the given `bounds` that responds to
+    /// presses and the scroll wheel.
+    pub(crate) fn hit_bounds(&self, bounds: Rectangle) -> Rectangle {
+        let grow = (self.hit_target_scale.max(1.0) - 1.0)
+            * bounds.width.min(bounds.height)
+            / 2.0;
+
+        Rectangle {
+            x: bounds.x - grow,
+            y: bounds.y - grow,
+            width: bounds.width + grow * 2.0,
+            height: bounds.height + grow * 2.0,
+        }
+    }
+
+    /// Returns the radius around a point-like target (such as a draggable
+    /// node) that responds to presses.
+    pub(crate) fn hit_radius(&self, radius: f32) -> f32 {
+        radius * self.hit_target_scale.max(1.0)
+    }
+
+    /// Returns `true` if the cursor has moved far enough from `drag_start`
+    /// to begin a drag.
+    pub(crate) fn drag_threshold_crossed(
+        &self,
+        drag_start: Point,
+        cursor_position: Point,
+    ) -> bool {
+        drag_start.distance(cursor_position) >= self.drag_threshold
+    }
+
+    /// Returns the [`InputAction`] for a pressed mouse `button`.
+    ///
+    /// * `click_kind` - the kind of click, only used when the `button` is
+    ///   the `drag_button`
+    /// * `modifiers` - the modifier keys held down during the click
+    ///
+    /// [`InputAction`]: enum.InputAction.html
+    pub(crate) fn action(
+        &self,
+        button: mouse::Button,
+        click_kind: mouse::click::Kind,
+        modifiers: keyboard::Modifiers,
+    ) -> InputAction {
+        if self.menu_button == Some(button) {
+            return InputAction::Menu;
+        }
+
+        let is_reset = match self.reset {
+            ResetGesture::DoubleClick => {
+                button == self.drag_button
+                    && !matches!(click_kind, mouse::click::Kind::Single)
+            }
+            ResetGesture::ModifierClick(reset_modifiers) => {
+                button == self.drag_button && modifiers.matches(reset_modifiers)
+            }
+            ResetGesture::Button(reset_button) => button == reset_button,
+            ResetGesture::Disabled => false,
+        };
+
+        if is_reset {
+            InputAction::Reset
+        } else if button == self.drag_button {
+            InputAction::Drag
+        } else {
+            InputAction::None
+        }
+    }
+}
+
+impl Default for InputConfig {
+    fn default() -> Self {
+        InputConfig::DEFAULT
+    }
+}
+
+/// The gesture that resets a widget to its default value.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum ResetGesture {
+    /// Double-clicking the `drag_button` of the [`InputConfig`].
+    ///
+    /// [`InputConfig`]: struct.InputConfig.html
+    #[default]
+    DoubleClick,
+    /// Clicking the `drag_button` of the [`InputConfig`] while holding
+    /// down the modifier keys, such as `Ctrl`-click.
+    ///
+    /// Choose keys other than the fine adjustment modifier keys of the
+    /// widget, which are `Ctrl` by default.
+    ///
+    /// [`InputConfig`]: struct.InputConfig.html
+    ModifierClick(keyboard::Modifiers),
+    /// Clicking a mouse button, such as the middle button. This should not
+    /// be the `drag_button` of the [`InputConfig`], or the widget can not be
+    /// dragged.
+    ///
+    /// [`InputConfig`]: struct.InputConfig.html
+    Button(mouse::Button),
+    /// The widget can not be reset with the mouse.
+    Disabled,
+}
+
+/// The action a widget should take when a mouse button is pressed.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum InputAction {
+    /// Start dragging the widget.
+    Drag,
+    /// Reset the widget to its default value.
+    Reset,
+    /// Request a context menu.
+    Menu,
+    /// The button is not bound to anything.
+    None,
+}
+
+/// Tracks a drag along one axis from an anchored origin.
+///
+/// The value is computed from the total movement since the origin instead of
+/// adding up the movement of every cursor event, so long drags do not drift.
+/// The origin only moves when the scalar changes (such as when the fine
+/// adjustment modifier is pressed), when the value hits `0.0` or `1.0`, or
+/// when the value is changed by something other than the drag.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub(crate) struct DragAnchor {
+    origin: f32,
+    origin_normal: f32,
+    last_position: f32,
+    scalar: f32,
+}
+
+impl DragAnchor {
+    /// Starts a drag at the cursor `position` with the current `normal`.
+    pub fn new(position: f32, normal: f32) -> Self {
+        Self {
+            origin: position,
+            origin_normal: normal,
+            last_position: position,
+            scalar: 0.0,
+        }
+    }
+
+    /// Returns the normal for the cursor `position`, clamped to the range
+    /// `[0.0, 1.0]`.
+    ///
+    /// * `scalar` - how much the normal changes per unit of movement
+    pub fn drag(&mut self, position: f32, scalar: f32) -> f32 {
+        let normal = self.drag_unbounded(position, scalar);
+
+        if (0.0..=1.0).contains(&normal) {
+            normal
+        } else {
+            // Re-anchor at the edge so that reversing the drag takes effect
+            // immediately.
+            let normal = if normal < 0.0 { 0.0 } else { 1.0 };
+            self.rebase(normal);
+            normal
+        }
+    }
+
+    /// Returns the normal for the cursor `position`, which keeps tracking
+    /// the cursor past `0.0` and `1.0`.
+    ///
+    /// * `scalar` - how much the normal changes per unit of movement
+    pub fn drag_unbounded(&mut self, position: f32, scalar: f32) -> f32 {
+        if scalar != self.scalar {
+            self.origin_normal = self.normal_at(self.last_position);
+            self.origin = self.last_position;
+            self.scalar = scalar;
+        }
+
+        self.last_position = position;
+
+        self.normal_at(position)
+    }
+
+    /// Re-anchors the drag at the last cursor position with a new `normal`.
+    pub fn rebase(&mut self, normal: f32) {
+        self.origin = self.last_position;
+        self.origin_normal = normal;
+    }
+
+    fn normal_at(&self, position: f32) -> f32 {
+        self.origin_normal + (position - self.origin) * self.scalar
+    }
+}
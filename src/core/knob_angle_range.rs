@@ -14,11 +14,14 @@ pub static DEFAULT_ANGLE_MAX: f32 = (360.0 - 30.0) * PI_OVER_180;
 /// `0.0` radians points straight down at the bottom of the knob, with the
 /// angles rotating clockwise towards `TWO_PI` (`2*PI`).
 ///
-/// Values < `0.0` and >= `TWO_PI` are not allowed.
+/// Values < `0.0` and >= `TWO_PI` are not allowed, except for the maximum
+/// of a [`full_circle`] range.
 ///
 /// The default minimum (converted to degrees) is `30` degrees, and the default
 /// maximum is `330` degrees, giving a span of `300` degrees, and a halfway
 /// point pointing strait up.
+///
+/// [`full_circle`]: #method.full_circle
 #[derive(Debug, Clone)]
 pub struct KnobAngleRange {
     min: f32,
@@ -88,6 +91,23 @@ impl KnobAngleRange {
         Self { min, max }
     }
 
+    /// A range that spans the whole circle, starting and ending at the
+    /// bottom of the knob. This suits a [`Knob`] that wraps around, such
+    /// as one that controls a phase.
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    pub fn full_circle() -> Self {
+        Self {
+            min: 0.0,
+            max: TWO_PI,
+        }
+    }
+
+    /// Returns `true` if the range spans the whole circle.
+    pub fn is_full_circle(&self) -> bool {
+        self.max - self.min >= TWO_PI
+    }
+
     /// returns the minimum angle (between `0.0` and `TWO_PI` in radians)
     pub fn min(&self) -> f32 {
         self.min
@@ -31,3 +31,54 @@ pub fn amplitude_to_db_f32(amp: f32) -> f32 {
 pub fn amplitdue_to_db_f64(amp: f64) -> f64 {
     20.0f64 * amp.log10()
 }
+
+/// The longest time step in seconds that widgets advance by in one update.
+/// Longer steps, such as after the computer wakes from sleep or the clock
+/// jumps, are shortened to this.
+pub const MAX_DT: f32 = 1.0;
+
+/// Returns `dt` if it is a finite, non-negative time step in seconds, or
+/// `0.0` otherwise, so a glitch in the caller's clock can't stall or corrupt
+/// the state of a widget. Steps longer than [`MAX_DT`] are shortened to
+/// [`MAX_DT`].
+///
+/// [`MAX_DT`]: constant.MAX_DT.html
+#[inline]
+pub(crate) fn sanitize_dt(dt: f32) -> f32 {
+    if !dt.is_finite() {
+        log::debug!("ignoring non-finite time step {}", dt);
+        0.0
+    } else if dt > MAX_DT {
+        log::debug!("shortening time step of {} s to {} s", dt, MAX_DT);
+        MAX_DT
+    } else {
+        dt.max(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanitize_dt_passes_normal_steps() {
+        assert_eq!(sanitize_dt(0.0), 0.0);
+        assert_eq!(sanitize_dt(0.016), 0.016);
+        assert_eq!(sanitize_dt(MAX_DT), MAX_DT);
+    }
+
+    #[test]
+    fn sanitize_dt_rejects_invalid_steps() {
+        assert_eq!(sanitize_dt(-0.5), 0.0);
+        assert_eq!(sanitize_dt(f32::NAN), 0.0);
+        assert_eq!(sanitize_dt(f32::INFINITY), 0.0);
+        assert_eq!(sanitize_dt(f32::NEG_INFINITY), 0.0);
+    }
+
+    #[test]
+    fn sanitize_dt_clamps_long_steps() {
+        assert_eq!(sanitize_dt(1.5), MAX_DT);
+        assert_eq!(sanitize_dt(3600.0), MAX_DT);
+        assert_eq!(sanitize_dt(f32::MAX), MAX_DT);
+    }
+}
@@ -0,0 +1,135 @@
+//! Reference level and calibration offset for meter scales
+
+use super::normal::Normal;
+use super::range::LogDBRange;
+
+/// Converts between raw levels in dBFS and the units shown on the scale of
+/// a meter, such as VU or the K-system.
+///
+/// A raw level equal to the `reference_level` reads as `0` on the scale. The
+/// `offset` is added on top, to trim a meter so it matches an external
+/// one.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::MeterCalibration;
+///
+/// // 0 VU = -18 dBFS
+/// let vu = MeterCalibration::new(-18.0);
+///
+/// assert_eq!(vu.to_scale(-18.0), 0.0);
+/// assert_eq!(vu.to_scale(-12.0), 6.0);
+/// assert_eq!(vu.to_dbfs(-2.0), -20.0);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct MeterCalibration {
+    /// The raw level in dBFS that reads as `0` on the scale.
+    pub reference_level: f32,
+    /// The amount in dB added to every reading.
+    pub offset: f32,
+}
+
+impl MeterCalibration {
+    /// A [`MeterCalibration`] that shows raw levels in dBFS unchanged.
+    ///
+    /// [`MeterCalibration`]: struct.MeterCalibration.html
+    pub const DBFS: MeterCalibration = MeterCalibration {
+        reference_level: 0.0,
+        offset: 0.0,
+    };
+
+    /// Creates a new [`MeterCalibration`] with no offset.
+    ///
+    /// * `reference_level` - the raw level in dBFS that reads as `0` on
+    ///   the scale
+    ///
+    /// [`MeterCalibration`]: struct.MeterCalibration.html
+    pub fn new(reference_level: f32) -> Self {
+        Self {
+            reference_level,
+            offset: 0.0,
+        }
+    }
+
+    /// Sets the amount in dB added to every reading.
+    pub fn offset(mut self, offset: f32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Converts a raw level in dBFS to a reading on the scale.
+    pub fn to_scale(&self, dbfs: f32) -> f32 {
+        dbfs - self.reference_level + self.offset
+    }
+
+    /// Converts a reading on the scale to a raw level in dBFS.
+    pub fn to_dbfs(&self, scale: f32) -> f32 {
+        scale + self.reference_level - self.offset
+    }
+}
+
+impl Default for MeterCalibration {
+    fn default() -> Self {
+        MeterCalibration::DBFS
+    }
+}
+
+/// The K-system metering scales, where `0` on the scale sits a fixed amount
+/// of headroom below full scale.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::KSystem;
+///
+/// let k14 = KSystem::K14;
+///
+/// assert_eq!(k14.calibration().to_scale(-14.0), 0.0);
+/// assert_eq!(k14.calibration().to_scale(0.0), 14.0);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum KSystem {
+    /// 12 dB of headroom, for broadcast
+    K12,
+    /// 14 dB of headroom, for pop and rock mastering
+    K14,
+    /// 20 dB of headroom, for film and wide dynamic range music
+    K20,
+}
+
+impl KSystem {
+    /// Returns the headroom in dB between `0` on the scale and full scale.
+    pub fn headroom(&self) -> f32 {
+        match self {
+            KSystem::K12 => 12.0,
+            KSystem::K14 => 14.0,
+            KSystem::K20 => 20.0,
+        }
+    }
+
+    /// Returns the [`MeterCalibration`] of the scale, where `0` sits at
+    /// `-headroom` dBFS.
+    ///
+    /// [`MeterCalibration`]: struct.MeterCalibration.html
+    pub fn calibration(&self) -> MeterCalibration {
+        MeterCalibration::new(-self.headroom())
+    }
+
+    /// Returns a [`LogDBRange`] in dBFS that spans from 40 dB below `0` on
+    /// the scale up to full scale.
+    ///
+    /// [`LogDBRange`]: ../range/struct.LogDBRange.html
+    pub fn range(&self) -> LogDBRange {
+        LogDBRange::new(-self.headroom() - 40.0, 0.0, Normal::max())
+    }
+
+    /// Returns the raw levels in dBFS where the colored zones of the scale
+    /// start: the `0` point where the green zone ends and the yellow zone
+    /// begins, and `+4` on the scale where the red zone begins.
+    pub fn zone_starts(&self) -> [f32; 2] {
+        let calibration = self.calibration();
+
+        [calibration.to_dbfs(0.0), calibration.to_dbfs(4.0)]
+    }
+}
@@ -0,0 +1,148 @@
+//! Helpers for laying out the segments and zones of a meter
+
+use super::meter_calibration::MeterCalibration;
+use super::normal::Normal;
+use super::range::LogDBRange;
+
+/// A segment of a meter, such as a single LED or a colored zone.
+///
+/// `start` and `end` are positions along the meter, where `0.0.into()` is
+/// the bottom (or left) of the meter and `1.0.into()` is the top (or right).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct MeterSegment {
+    /// Where the segment starts.
+    pub start: Normal,
+    /// Where the segment ends.
+    pub end: Normal,
+}
+
+impl MeterSegment {
+    /// Returns the length of the segment as a fraction of the whole meter.
+    pub fn len(&self) -> f32 {
+        self.end.as_f32() - self.start.as_f32()
+    }
+
+    /// Returns `true` if the segment has zero length.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0.0
+    }
+}
+
+/// Returns the zones of a meter between consecutive decibel `boundaries`,
+/// positioned with the same mapping as the given [`LogDBRange`].
+///
+/// This places the zones exactly where the matching values sit on a slider
+/// or knob that uses the same range. For example, the boundaries
+/// `&[-90.0, -12.0, -6.0, 0.0]` produce three zones.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{meter_layout, LogDBRange, Normal};
+///
+/// let range = LogDBRange::new(-90.0, 0.0, Normal::max());
+///
+/// let zones = meter_layout::zones(&range, &[-90.0, -12.0, 0.0]);
+///
+/// assert_eq!(zones.len(), 2);
+/// assert_eq!(zones[1].start, range.map_to_normal(-12.0));
+/// assert_eq!(zones[1].end, Normal::max());
+/// ```
+///
+/// [`LogDBRange`]: ../range/struct.LogDBRange.html
+pub fn zones(range: &LogDBRange, boundaries: &[f32]) -> Vec<MeterSegment> {
+    boundaries
+        .windows(2)
+        .map(|bounds| MeterSegment {
+            start: range.map_to_normal(bounds[0]),
+            end: range.map_to_normal(bounds[1]),
+        })
+        .collect()
+}
+
+/// Returns the segments of a segmented (LED style) meter, where each segment
+/// spans `step_db` decibels, positioned with the same mapping as the given
+/// [`LogDBRange`].
+///
+/// The segments are counted down from the maximum of the range, so the top
+/// segment always ends exactly at the maximum. The segments are returned in
+/// order from bottom to top.
+///
+/// # Panics
+///
+/// This will panic if `step_db` <= `0.0`
+///
+/// [`LogDBRange`]: ../range/struct.LogDBRange.html
+pub fn segments(range: &LogDBRange, step_db: f32) -> Vec<MeterSegment> {
+    assert!(step_db > 0.0, "step_db must be greater than 0.0");
+
+    let min = range.min();
+    let mut boundaries = vec![range.max()];
+
+    loop {
+        let next = boundaries[boundaries.len() - 1] - step_db;
+        if next <= min {
+            boundaries.push(min);
+            break;
+        }
+        boundaries.push(next);
+    }
+
+    boundaries.reverse();
+
+    zones(range, &boundaries)
+}
+
+/// Returns a mark for every multiple of `step` on the calibrated scale of a
+/// meter, positioned with the same mapping as the given [`LogDBRange`].
+///
+/// The range is in dBFS, while the returned values are readings on the
+/// scale of the [`MeterCalibration`]. This keeps labels such as `0 VU`
+/// exactly where the matching raw level sits on the meter. The marks are
+/// returned in order from bottom to top.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{meter_layout, LogDBRange, MeterCalibration, Normal};
+///
+/// let range = LogDBRange::new(-60.0, 0.0, Normal::max());
+/// let calibration = MeterCalibration::new(-18.0);
+///
+/// let marks = meter_layout::scale_marks(&range, &calibration, 6.0);
+///
+/// assert_eq!(marks.first().unwrap().1, -42.0);
+/// assert_eq!(marks.last().unwrap().1, 18.0);
+/// assert!(marks.contains(&(range.map_to_normal(-18.0), 0.0)));
+/// ```
+///
+/// # Panics
+///
+/// This will panic if `step` <= `0.0`
+///
+/// [`LogDBRange`]: ../range/struct.LogDBRange.html
+/// [`MeterCalibration`]: ../meter_calibration/struct.MeterCalibration.html
+pub fn scale_marks(
+    range: &LogDBRange,
+    calibration: &MeterCalibration,
+    step: f32,
+) -> Vec<(Normal, f32)> {
+    assert!(step > 0.0, "step must be greater than 0.0");
+
+    // Allow for rounding errors so the ends of the range are not missed.
+    let first = (calibration.to_scale(range.min()) / step - 0.0001).ceil();
+    let last = (calibration.to_scale(range.max()) / step + 0.0001).floor();
+
+    if last < first {
+        return Vec::new();
+    }
+
+    (first as i32..=last as i32)
+        .map(|i| {
+            let value = i as f32 * step;
+            let position = range.map_to_normal(calibration.to_dbfs(value));
+
+            (position, value)
+        })
+        .collect()
+}
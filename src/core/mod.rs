@@ -3,17 +3,57 @@
 //! This module holds basic types that can be reused and re-exported in
 //! different runtime implementations.
 
+pub mod accent_map;
+pub mod detents;
+pub mod enum_param;
+pub mod formatter;
+pub mod history;
+pub mod input_config;
 pub mod knob_angle_range;
 pub mod math;
+pub mod meter_calibration;
+pub mod meter_layout;
 pub mod modulation_range;
+pub mod motor;
 pub mod normal;
 pub mod normal_param;
 pub mod offset;
+pub mod orientation;
+pub mod param_bank;
+pub mod param_link;
+pub mod param_listener;
 pub mod range;
+pub mod shared_param;
+pub mod shortcut_map;
+pub mod smoothed_normal;
+pub mod tempo_sync;
+pub mod transition;
+pub mod viewport;
 
+pub use accent_map::AccentMap;
+pub use detents::Detents;
+pub use enum_param::EnumParam;
+pub use formatter::{
+    DBFormatter, FloatFormatter, Formatter, FreqFormatter, IntFormatter,
+    MillisFormatter, PanFormatter, PercentFormatter,
+};
+pub use history::{Edit, EditHistory};
+pub use input_config::{InputConfig, ResetGesture};
 pub use knob_angle_range::*;
+pub use meter_calibration::{KSystem, MeterCalibration};
 pub use modulation_range::ModulationRange;
-pub use normal::Normal;
-pub use normal_param::NormalParam;
+pub use motor::Motor;
+pub use normal::{Normal, NormalF64};
+pub use normal_param::{NormalParam, SavedParam};
 pub use offset::Offset;
+pub use orientation::Orientation;
+pub use param_bank::{BankParam, ParamBank, ParamRange};
+pub use param_link::{LinkMode, ParamLink};
+pub use param_listener::{ParamListener, ParamNotifier};
 pub use range::*;
+pub use shared_param::SharedParam;
+pub use shortcut_map::{Shortcut, ShortcutMap};
+pub use smoothed_normal::{SmoothedNormal, Smoothing};
+pub use tempo_sync::{NoteDivision, TempoSyncParam};
+pub use transition::Transition;
+pub use viewport::{Viewport, ViewportGestures};
@@ -0,0 +1,105 @@
+//! Glide a control toward a value set from outside the GUI
+
+use crate::core::math::sanitize_dt;
+use crate::core::Normal;
+
+static DEFAULT_SPEED: f32 = 4.0;
+
+/// Emulates a motorized fader: when a value is set from outside the GUI
+/// (such as by host automation), the control glides toward it at a fixed
+/// speed instead of jumping.
+///
+/// Grabbing the control stops the motor, so the user never has to fight
+/// it.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{Motor, Normal};
+///
+/// // Travel the whole range in half a second.
+/// let mut motor = Motor::new(2.0);
+///
+/// motor.set_target(Normal::max());
+///
+/// let normal = motor.step(Normal::min(), 0.125).unwrap();
+/// assert_eq!(normal.as_f32(), 0.25);
+///
+/// // The motor stops once it reaches the target.
+/// let normal = motor.step(Normal::from(0.9), 0.125).unwrap();
+/// assert_eq!(normal, Normal::max());
+/// assert!(!motor.is_running());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Motor {
+    speed: f32,
+    target: Option<Normal>,
+}
+
+impl Motor {
+    /// Creates a new `Motor` that travels at `speed` normals per second.
+    /// A speed of `2.0` travels the whole range in half a second.
+    pub fn new(speed: f32) -> Self {
+        Self {
+            speed: speed.max(0.0),
+            target: None,
+        }
+    }
+
+    /// Returns the speed in normals per second.
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Sets the speed in normals per second.
+    pub fn set_speed(&mut self, speed: f32) {
+        self.speed = speed.max(0.0);
+    }
+
+    /// Returns the value the motor is travelling to, if it is running.
+    pub fn target(&self) -> Option<Normal> {
+        self.target
+    }
+
+    /// Starts travelling to `target`.
+    pub fn set_target(&mut self, target: Normal) {
+        self.target = Some(target);
+    }
+
+    /// Returns `true` while the motor is travelling to a target.
+    pub fn is_running(&self) -> bool {
+        self.target.is_some()
+    }
+
+    /// Stops the motor where it is.
+    pub fn stop(&mut self) {
+        self.target = None;
+    }
+
+    /// Moves `current` toward the target.
+    ///
+    /// * `current` - the value currently shown by the control
+    /// * `dt` - the time in seconds since the last step
+    ///
+    /// Returns the new value, or `None` if the motor is not running.
+    pub fn step(&mut self, current: Normal, dt: f32) -> Option<Normal> {
+        let target = self.target?;
+
+        let max_travel = self.speed * sanitize_dt(dt);
+        let distance = target.as_f32() - current.as_f32();
+
+        if distance.abs() <= max_travel {
+            self.target = None;
+
+            Some(target)
+        } else {
+            Some(current.saturating_add(max_travel.copysign(distance)))
+        }
+    }
+}
+
+impl Default for Motor {
+    fn default() -> Self {
+        Motor::new(DEFAULT_SPEED)
+    }
+}
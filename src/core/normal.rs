@@ -2,6 +2,26 @@
 //!
 //! `0.0 >= value <= 1.0`
 
+use std::ops::Mul;
+
+macro_rules! constrain_fn {
+    ($name:ident, $float:ty) => {
+        /// Returns `value` clamped to the range `[0.0, 1.0]`, or `None` if
+        /// it is `NaN`.
+        #[inline]
+        fn $name(value: $float) -> Option<$float> {
+            if value.is_nan() {
+                None
+            } else {
+                Some(value.clamp(0.0, 1.0))
+            }
+        }
+    };
+}
+
+constrain_fn!(constrain_f32, f32);
+constrain_fn!(constrain_f64, f64);
+
 /// An `f32` value that is gauranteed to be constrained to the range of
 ///
 /// `0.0 >= value <= 1.0`
@@ -19,8 +39,18 @@
 ///
 /// normal.set(0.5);
 /// assert_eq!(normal.as_f32(), 0.5);
+///
+/// // Non-finite values can never leave a `Normal` out of range.
+/// normal.set(f32::NAN);
+/// assert_eq!(normal.as_f32(), 0.5);
+/// assert_eq!(Normal::new(f32::INFINITY).as_f32(), 1.0);
 /// ```
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "f32", into = "f32")
+)]
 pub struct Normal {
     value: f32,
 }
@@ -42,33 +72,31 @@ impl Normal {
     ///
     /// else if `value > 1.0`, then `normal.value` is set to `1.0`
     ///
+    /// else if `value` is `NaN`, then `normal.value` is set to `0.0`
+    ///
     /// else `normal.value` is set to `value`
     pub fn new(value: f32) -> Self {
-        Self {
-            value: {
-                if value < 0.0 {
-                    0.0
-                } else if value > 1.0 {
-                    1.0
-                } else {
-                    value
-                }
-            },
+        match constrain_f32(value) {
+            Some(value) => Self { value },
+            None => {
+                log::debug!("Normal::new received NaN, using 0.0 instead");
+                Self::min()
+            }
         }
     }
 
     /// Returns a `Normal` with the value `0.0`.
-    pub fn min() -> Self {
+    pub const fn min() -> Self {
         Self { value: 0.0 }
     }
 
     /// Returns a `Normal` with the value `1.0`.
-    pub fn max() -> Self {
+    pub const fn max() -> Self {
         Self { value: 1.0 }
     }
 
     /// Returns a `Normal` with the value `0.5`.
-    pub fn center() -> Self {
+    pub const fn center() -> Self {
         Self { value: 0.5 }
     }
 
@@ -82,16 +110,15 @@ impl Normal {
     ///
     /// else if `value > 1.0`, then `normal.value` is set to `1.0`
     ///
+    /// else if `value` is `NaN`, then `normal.value` is left unchanged
+    ///
     /// else `normal.value` is set to `value`
     #[inline]
     pub fn set(&mut self, value: f32) {
-        self.value = {
-            if value < 0.0 {
-                0.0
-            } else if value > 1.0 {
-                1.0
-            } else {
-                value
+        match constrain_f32(value) {
+            Some(value) => self.value = value,
+            None => {
+                log::debug!("Normal::set received NaN, keeping {}", self.value)
             }
         }
     }
@@ -120,6 +147,64 @@ impl Normal {
     pub fn scale_inv(&self, scalar: f32) -> f32 {
         (1.0 - self.value) * scalar
     }
+
+    /// Returns the inverse (`1.0 - value`) of the `Normal`
+    #[inline]
+    pub fn inverse(&self) -> Normal {
+        Self {
+            value: 1.0 - self.value,
+        }
+    }
+
+    /// Returns the `Normal` plus `delta`, clamped to the range of a
+    /// `Normal`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::Normal;
+    ///
+    /// assert_eq!(Normal::from(0.75).saturating_add(0.5), Normal::max());
+    /// assert_eq!(Normal::from(0.25).saturating_sub(0.5), Normal::min());
+    /// ```
+    #[inline]
+    pub fn saturating_add(&self, delta: f32) -> Normal {
+        Normal::new(self.value + delta)
+    }
+
+    /// Returns the `Normal` minus `delta`, clamped to the range of a
+    /// `Normal`
+    #[inline]
+    pub fn saturating_sub(&self, delta: f32) -> Normal {
+        Normal::new(self.value - delta)
+    }
+
+    /// Returns the value between `start` and `end` at the position of the
+    /// `Normal`, such as the position of a tick mark in pixels
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::Normal;
+    ///
+    /// assert_eq!(Normal::from(0.25).lerp(100.0, 200.0), 125.0);
+    /// assert_eq!(Normal::from(0.25).lerp(200.0, 100.0), 175.0);
+    /// ```
+    #[inline]
+    pub fn lerp(&self, start: f32, end: f32) -> f32 {
+        start + (end - start) * self.value
+    }
+}
+
+impl Mul<f32> for Normal {
+    type Output = f32;
+
+    /// Returns the value of the `Normal` times `rhs`, the same as
+    /// [`scale`](#method.scale)
+    #[inline]
+    fn mul(self, rhs: f32) -> f32 {
+        self.value * rhs
+    }
 }
 
 impl From<f32> for Normal {
@@ -133,3 +218,163 @@ impl From<Normal> for f32 {
         normal.value
     }
 }
+
+/// An `f64` value that is gauranteed to be constrained to the range of
+///
+/// `0.0 >= value <= 1.0`
+///
+/// Widgets work with [`Normal`]s, but applications that keep the state of
+/// their parameters in double precision can store a `NormalF64` instead.
+/// Use [`updated_from`] to apply the values sent by a widget, so values
+/// the widget did not change keep their full precision.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{Normal, NormalF64};
+///
+/// let stored = NormalF64::new(0.1);
+///
+/// // Sync the widget to the stored value.
+/// let normal = stored.as_normal();
+///
+/// // Only replace the stored value when the widget has moved.
+/// let moved = Normal::from(0.25);
+/// let stored = stored.updated_from(moved);
+/// assert_eq!(stored.as_f64(), 0.25);
+///
+/// let stored = NormalF64::new(0.1).updated_from(normal);
+/// assert_eq!(stored.as_f64(), 0.1);
+/// ```
+///
+/// [`Normal`]: struct.Normal.html
+/// [`updated_from`]: #method.updated_from
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "f64", into = "f64")
+)]
+pub struct NormalF64 {
+    value: f64,
+}
+
+impl Default for NormalF64 {
+    fn default() -> Self {
+        Self { value: 0.0 }
+    }
+}
+
+impl NormalF64 {
+    /// Creates a new `NormalF64`. The value is constrained the same way as
+    /// [`Normal::new`].
+    ///
+    /// [`Normal::new`]: struct.Normal.html#method.new
+    pub fn new(value: f64) -> Self {
+        match constrain_f64(value) {
+            Some(value) => Self { value },
+            None => {
+                log::debug!("NormalF64::new received NaN, using 0.0 instead");
+                Self::min()
+            }
+        }
+    }
+
+    /// Returns a `NormalF64` with the value `0.0`.
+    pub const fn min() -> Self {
+        Self { value: 0.0 }
+    }
+
+    /// Returns a `NormalF64` with the value `1.0`.
+    pub const fn max() -> Self {
+        Self { value: 1.0 }
+    }
+
+    /// Returns a `NormalF64` with the value `0.5`.
+    pub const fn center() -> Self {
+        Self { value: 0.5 }
+    }
+
+    /// Set a value for the `NormalF64`. The value is constrained the same
+    /// way as [`Normal::set`].
+    ///
+    /// [`Normal::set`]: struct.Normal.html#method.set
+    #[inline]
+    pub fn set(&mut self, value: f64) {
+        match constrain_f64(value) {
+            Some(value) => self.value = value,
+            None => log::debug!(
+                "NormalF64::set received NaN, keeping {}",
+                self.value
+            ),
+        }
+    }
+
+    /// Returns the value of the `NormalF64` as an `f64`
+    #[inline]
+    pub fn as_f64(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the value of the `NormalF64` as a [`Normal`] for widgets
+    ///
+    /// [`Normal`]: struct.Normal.html
+    #[inline]
+    pub fn as_normal(&self) -> Normal {
+        Normal::new(self.value as f32)
+    }
+
+    /// Returns the `NormalF64` with the value of `normal` from a widget,
+    /// or `self` unchanged if `normal` is what [`as_normal`] returns.
+    ///
+    /// [`as_normal`]: #method.as_normal
+    #[inline]
+    pub fn updated_from(&self, normal: Normal) -> Self {
+        if normal == self.as_normal() {
+            *self
+        } else {
+            normal.into()
+        }
+    }
+
+    /// Returns the inverse (`1.0 - value`) of the `NormalF64`
+    #[inline]
+    pub fn inverse(&self) -> NormalF64 {
+        Self {
+            value: 1.0 - self.value,
+        }
+    }
+
+    /// Returns the value between `start` and `end` at the position of the
+    /// `NormalF64`
+    #[inline]
+    pub fn lerp(&self, start: f64, end: f64) -> f64 {
+        start + (end - start) * self.value
+    }
+}
+
+impl From<f64> for NormalF64 {
+    fn from(value: f64) -> Self {
+        NormalF64::new(value)
+    }
+}
+
+impl From<NormalF64> for f64 {
+    fn from(normal: NormalF64) -> f64 {
+        normal.value
+    }
+}
+
+impl From<Normal> for NormalF64 {
+    fn from(normal: Normal) -> Self {
+        Self {
+            value: f64::from(normal.value),
+        }
+    }
+}
+
+impl From<NormalF64> for Normal {
+    fn from(normal: NormalF64) -> Self {
+        normal.as_normal()
+    }
+}
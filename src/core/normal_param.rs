@@ -12,6 +12,7 @@ use std::fmt::Debug;
 ///
 /// [`Normal`]: ../struct.Normal.html
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NormalParam {
     /// The value of the parameter represented as a [`Normal`]
     ///
@@ -32,3 +33,38 @@ impl Default for NormalParam {
         }
     }
 }
+
+/// The value and default value of a parameter in the units of its range,
+/// such as dB or Hz, for storing in presets and plugin state.
+///
+/// Unlike a [`NormalParam`], a `SavedParam` keeps its meaning when the
+/// range of the parameter changes in a later version of the application.
+/// Create one with the `save()` method of a range, and turn it back into a
+/// [`NormalParam`] with the `restore()` method.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{FreqRange, SavedParam};
+///
+/// let range = FreqRange::new(20.0, 20000.0);
+/// let param = range.normal_param(1000.0, 1000.0);
+///
+/// let saved: SavedParam<f32> = range.save(&param);
+///
+/// // A wider range still restores the same frequency.
+/// let new_range = FreqRange::new(20.0, 20480.0);
+/// let restored = new_range.restore(&saved);
+///
+/// assert!((new_range.unmap_to_value(restored.value) - 1000.0).abs() < 0.1);
+/// ```
+///
+/// [`NormalParam`]: struct.NormalParam.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SavedParam<T> {
+    /// The value of the parameter
+    pub value: T,
+    /// The default value of the parameter
+    pub default: T,
+}
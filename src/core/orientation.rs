@@ -0,0 +1,11 @@
+//! The direction a widget is laid out in
+
+/// The direction a widget such as a meter is laid out in.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Orientation {
+    /// The minimum is at the bottom and the maximum is at the top.
+    #[default]
+    Vertical,
+    /// The minimum is at the left and the maximum is at the right.
+    Horizontal,
+}
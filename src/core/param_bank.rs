@@ -0,0 +1,507 @@
+//! A collection of parameters keyed by an id
+
+use crate::core::range::{
+    ExpRange, FloatRange, FreqRange, IntRange, LogDBRange,
+};
+use crate::core::{
+    Normal, NormalParam, ParamListener, SavedParam, SharedParam,
+};
+
+/// Any of the built-in ranges, so parameters with different kinds of
+/// ranges can be stored together in a [`ParamBank`].
+///
+/// Values are `f32`, so the values of an [`IntRange`] are converted.
+///
+/// [`ParamBank`]: struct.ParamBank.html
+/// [`IntRange`]: ../range/struct.IntRange.html
+#[derive(Debug, Copy, Clone)]
+pub enum ParamRange {
+    /// A [`FloatRange`](../range/struct.FloatRange.html)
+    Float(FloatRange),
+    /// An [`ExpRange`](../range/struct.ExpRange.html)
+    Exp(ExpRange),
+    /// An [`IntRange`](../range/struct.IntRange.html)
+    Int(IntRange),
+    /// A [`LogDBRange`](../range/struct.LogDBRange.html)
+    LogDB(LogDBRange),
+    /// A [`FreqRange`](../range/struct.FreqRange.html)
+    Freq(FreqRange),
+}
+
+impl ParamRange {
+    /// Returns the corresponding [`Normal`] from the supplied value
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_to_normal(&self, value: f32) -> Normal {
+        match self {
+            ParamRange::Float(range) => range.map_to_normal(value),
+            ParamRange::Exp(range) => range.map_to_normal(value),
+            ParamRange::Int(range) => range.map_to_normal(value.round() as i32),
+            ParamRange::LogDB(range) => range.map_to_normal(value),
+            ParamRange::Freq(range) => range.map_to_normal(value),
+        }
+    }
+
+    /// Returns the corresponding value from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_value(&self, normal: Normal) -> f32 {
+        match self {
+            ParamRange::Float(range) => range.unmap_to_value(normal),
+            ParamRange::Exp(range) => range.unmap_to_value(normal),
+            ParamRange::Int(range) => range.unmap_to_value(normal) as f32,
+            ParamRange::LogDB(range) => range.unmap_to_value(normal),
+            ParamRange::Freq(range) => range.unmap_to_value(normal),
+        }
+    }
+
+    /// Returns the [`Normal`] of the value one step above the value of
+    /// `normal`
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn increment(&self, normal: Normal) -> Normal {
+        match self {
+            ParamRange::Float(range) => range.increment(normal),
+            ParamRange::Exp(range) => range.increment(normal),
+            ParamRange::Int(range) => range.increment(normal),
+            ParamRange::LogDB(range) => range.increment(normal),
+            ParamRange::Freq(range) => range.increment(normal),
+        }
+    }
+
+    /// Returns the [`Normal`] of the value one step below the value of
+    /// `normal`
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn decrement(&self, normal: Normal) -> Normal {
+        match self {
+            ParamRange::Float(range) => range.decrement(normal),
+            ParamRange::Exp(range) => range.decrement(normal),
+            ParamRange::Int(range) => range.decrement(normal),
+            ParamRange::LogDB(range) => range.decrement(normal),
+            ParamRange::Freq(range) => range.decrement(normal),
+        }
+    }
+
+    /// Returns `normal` snapped to the closest value of the range. Only
+    /// an [`IntRange`] is snapped.
+    ///
+    /// [`IntRange`]: ../range/struct.IntRange.html
+    pub fn snapped(&self, normal: Normal) -> Normal {
+        match self {
+            ParamRange::Int(range) => range.snapped(normal),
+            _ => normal,
+        }
+    }
+}
+
+impl From<FloatRange> for ParamRange {
+    fn from(range: FloatRange) -> Self {
+        ParamRange::Float(range)
+    }
+}
+
+impl From<ExpRange> for ParamRange {
+    fn from(range: ExpRange) -> Self {
+        ParamRange::Exp(range)
+    }
+}
+
+impl From<IntRange> for ParamRange {
+    fn from(range: IntRange) -> Self {
+        ParamRange::Int(range)
+    }
+}
+
+impl From<LogDBRange> for ParamRange {
+    fn from(range: LogDBRange) -> Self {
+        ParamRange::LogDB(range)
+    }
+}
+
+impl From<FreqRange> for ParamRange {
+    fn from(range: FreqRange) -> Self {
+        ParamRange::Freq(range)
+    }
+}
+
+/// A parameter in a [`ParamBank`]: its range and its current and default
+/// values.
+///
+/// [`ParamBank`]: struct.ParamBank.html
+#[derive(Debug, Clone)]
+pub struct BankParam {
+    /// The range of the parameter
+    pub range: ParamRange,
+    /// The value and default value of the parameter
+    pub normal_param: NormalParam,
+    shared: Option<SharedParam>,
+}
+
+impl BankParam {
+    /// Returns the [`SharedParam`] of the parameter, if one was created
+    /// with [`ParamBank::shared`].
+    ///
+    /// [`SharedParam`]: ../shared_param/struct.SharedParam.html
+    /// [`ParamBank::shared`]: struct.ParamBank.html#method.shared
+    pub fn shared(&self) -> Option<&SharedParam> {
+        self.shared.as_ref()
+    }
+
+    fn set_normal(&mut self, normal: Normal) {
+        self.normal_param.value = normal;
+
+        if let Some(shared) = &self.shared {
+            shared.set(normal);
+        }
+    }
+
+    /// Returns the value of the parameter in the units of its range
+    pub fn value(&self) -> f32 {
+        self.range.unmap_to_value(self.normal_param.value)
+    }
+
+    /// Returns the default value of the parameter in the units of its
+    /// range
+    pub fn default_value(&self) -> f32 {
+        self.range.unmap_to_value(self.normal_param.default)
+    }
+}
+
+/// Owns the parameters of an application, each keyed by an id.
+///
+/// Widgets send the [`Normal`] of the parameter they control, so a single
+/// message such as `ParamChanged(id, normal)` and a call to
+/// [`set_from_normal`] can replace a `match` over every parameter in
+/// `update()`.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{FreqRange, LogDBRange, Normal, ParamBank};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum ParamId {
+///     Cutoff,
+///     Gain,
+/// }
+///
+/// let mut bank = ParamBank::new();
+/// bank.insert(ParamId::Cutoff, FreqRange::default(), 1000.0, 1000.0);
+/// bank.insert(ParamId::Gain, LogDBRange::default(), 0.0, 0.0);
+///
+/// // A message sent by the gain knob.
+/// bank.set_from_normal(&ParamId::Gain, Normal::max());
+/// assert_eq!(bank.value(&ParamId::Gain), Some(12.0));
+///
+/// bank.reset_all();
+/// assert_eq!(bank.value(&ParamId::Gain), Some(0.0));
+/// ```
+///
+/// [`Normal`]: ../struct.Normal.html
+/// [`set_from_normal`]: #method.set_from_normal
+#[derive(Debug, Clone)]
+pub struct ParamBank<K: PartialEq> {
+    params: Vec<(K, BankParam)>,
+}
+
+impl<K: PartialEq> ParamBank<K> {
+    /// Creates a new empty `ParamBank`.
+    pub fn new() -> Self {
+        Self { params: Vec::new() }
+    }
+
+    /// Adds the parameter `id` with the supplied range, value and default
+    /// value. This replaces any parameter with the same id.
+    pub fn insert(
+        &mut self,
+        id: K,
+        range: impl Into<ParamRange>,
+        value: f32,
+        default: f32,
+    ) {
+        let range = range.into();
+
+        let mut param = BankParam {
+            range,
+            normal_param: NormalParam {
+                value: range.map_to_normal(value),
+                default: range.map_to_normal(default),
+            },
+            shared: None,
+        };
+
+        if let Some(entry) = self.params.iter_mut().find(|(k, _)| *k == id) {
+            param.shared = entry.1.shared.take();
+            param.set_normal(param.normal_param.value);
+
+            entry.1 = param;
+        } else {
+            self.params.push((id, param));
+        }
+    }
+
+    /// Removes the parameter `id`, returning it if it was in the bank.
+    pub fn remove(&mut self, id: &K) -> Option<BankParam> {
+        let index = self.params.iter().position(|(k, _)| k == id)?;
+
+        Some(self.params.remove(index).1)
+    }
+
+    /// Returns the parameter `id`, if any.
+    pub fn get(&self, id: &K) -> Option<&BankParam> {
+        self.params
+            .iter()
+            .find(|(k, _)| k == id)
+            .map(|(_, param)| param)
+    }
+
+    /// Returns the parameter `id` mutably, if any.
+    ///
+    /// Changes made to the `normal_param` of the parameter directly are not
+    /// sent to its [`SharedParam`].
+    ///
+    /// [`SharedParam`]: ../shared_param/struct.SharedParam.html
+    pub fn get_mut(&mut self, id: &K) -> Option<&mut BankParam> {
+        self.params
+            .iter_mut()
+            .find(|(k, _)| k == id)
+            .map(|(_, param)| param)
+    }
+
+    /// Returns the [`NormalParam`] of the parameter `id` to sync a widget
+    /// with, if any.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn normal_param(&self, id: &K) -> Option<NormalParam> {
+        self.get(id).map(|param| param.normal_param)
+    }
+
+    /// Returns the value of the parameter `id` in the units of its range,
+    /// if any.
+    pub fn value(&self, id: &K) -> Option<f32> {
+        self.get(id).map(BankParam::value)
+    }
+
+    /// Sets the parameter `id` from the [`Normal`] sent by a widget.
+    ///
+    /// Returns the new value in the units of its range, or `None` if there
+    /// is no parameter `id`.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn set_from_normal(&mut self, id: &K, normal: Normal) -> Option<f32> {
+        let param = self.get_mut(id)?;
+
+        param.set_normal(param.range.snapped(normal));
+
+        Some(param.value())
+    }
+
+    /// Sets the parameter `id` to `value` in the units of its range.
+    ///
+    /// Returns the new [`Normal`], or `None` if there is no parameter `id`.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn set_value(&mut self, id: &K, value: f32) -> Option<Normal> {
+        let param = self.get_mut(id)?;
+
+        param.set_normal(param.range.map_to_normal(value));
+
+        Some(param.normal_param.value)
+    }
+
+    /// Resets the parameter `id` to its default value.
+    ///
+    /// Returns the new [`Normal`], or `None` if there is no parameter `id`.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn reset(&mut self, id: &K) -> Option<Normal> {
+        let param = self.get_mut(id)?;
+
+        param.set_normal(param.normal_param.default);
+
+        Some(param.normal_param.value)
+    }
+
+    /// Resets every parameter to its default value.
+    pub fn reset_all(&mut self) {
+        for (_, param) in self.params.iter_mut() {
+            param.set_normal(param.normal_param.default);
+        }
+    }
+
+    /// Returns a [`SharedParam`] that follows the value of the parameter
+    /// `id`, for reading it from the audio thread. Every change made
+    /// through the bank is stored in it.
+    ///
+    /// Every call returns a clone of the same [`SharedParam`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::{FloatRange, Normal, ParamBank};
+    ///
+    /// let mut bank = ParamBank::new();
+    /// bank.insert("Mix", FloatRange::default(), 0.5, 0.5);
+    ///
+    /// // Moved to the audio thread.
+    /// let mix = bank.shared(&"Mix").unwrap();
+    ///
+    /// bank.set_from_normal(&"Mix", Normal::max());
+    /// assert_eq!(mix.get(), Normal::max());
+    /// ```
+    ///
+    /// [`SharedParam`]: ../shared_param/struct.SharedParam.html
+    pub fn shared(&mut self, id: &K) -> Option<SharedParam> {
+        let param = self.get_mut(id)?;
+        let value = param.normal_param.value;
+
+        Some(
+            param
+                .shared
+                .get_or_insert_with(|| SharedParam::new(value))
+                .clone(),
+        )
+    }
+
+    /// Returns the value and default value of every parameter in the units
+    /// of its range, for storing in presets and plugin state.
+    pub fn save(&self) -> Vec<(&K, SavedParam<f32>)> {
+        self.params
+            .iter()
+            .map(|(id, param)| {
+                (
+                    id,
+                    SavedParam {
+                        value: param.value(),
+                        default: param.default_value(),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Restores the value of the parameter `id` from a value created with
+    /// [`save`]. The default value is kept, so a newer version of the
+    /// application can change it.
+    ///
+    /// [`save`]: #method.save
+    pub fn restore(
+        &mut self,
+        id: &K,
+        saved: &SavedParam<f32>,
+    ) -> Option<Normal> {
+        self.set_value(id, saved.value)
+    }
+
+    /// Returns an iterator over the ids and parameters, in the order they
+    /// were inserted.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &BankParam)> {
+        self.params.iter().map(|(id, param)| (id, param))
+    }
+
+    /// Returns an iterator over the ids and parameters that allows
+    /// modifying the parameters.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&K, &mut BankParam)> {
+        self.params.iter_mut().map(|(id, param)| (&*id, param))
+    }
+
+    /// Returns the number of parameters.
+    pub fn len(&self) -> usize {
+        self.params.len()
+    }
+
+    /// Returns `true` if there are no parameters.
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+}
+
+/// Applies the changes collected by a [`ParamNotifier`], so
+/// `notifier.dispatch(&mut bank)` syncs the bank with the host.
+///
+/// [`ParamNotifier`]: ../param_listener/struct.ParamNotifier.html
+impl<K: PartialEq> ParamListener<K> for ParamBank<K> {
+    fn param_changed(&mut self, id: &K, normal: Normal) {
+        let _ = self.set_from_normal(id, normal);
+    }
+}
+
+impl<K: PartialEq> Default for ParamBank<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_replaces_param_and_keeps_shared() {
+        let mut bank = ParamBank::new();
+        bank.insert("a", FloatRange::new(0.0, 10.0), 2.0, 5.0);
+
+        let shared = bank.shared(&"a").unwrap();
+        assert_eq!(shared.get(), Normal::from(0.2));
+
+        bank.insert("a", FloatRange::new(0.0, 10.0), 8.0, 5.0);
+        assert_eq!(bank.len(), 1);
+        assert_eq!(bank.value(&"a"), Some(8.0));
+        assert_eq!(shared.get(), Normal::from(0.8));
+    }
+
+    #[test]
+    fn set_from_normal_snaps_int_ranges() {
+        let mut bank = ParamBank::new();
+        bank.insert("steps", IntRange::new(0, 4), 0.0, 0.0);
+
+        assert_eq!(
+            bank.set_from_normal(&"steps", Normal::from(0.3)),
+            Some(1.0)
+        );
+        assert_eq!(
+            bank.normal_param(&"steps").map(|param| param.value),
+            Some(Normal::from(0.25))
+        );
+    }
+
+    #[test]
+    fn reset_and_restore() {
+        let mut bank = ParamBank::new();
+        bank.insert("a", FloatRange::new(0.0, 10.0), 2.0, 5.0);
+        bank.insert("b", FloatRange::new(0.0, 10.0), 7.0, 1.0);
+
+        let saved: Vec<(&str, SavedParam<f32>)> = bank
+            .save()
+            .into_iter()
+            .map(|(id, saved)| (*id, saved))
+            .collect();
+
+        bank.reset_all();
+        assert_eq!(bank.value(&"a"), Some(5.0));
+        assert_eq!(bank.value(&"b"), Some(1.0));
+
+        for (id, saved) in &saved {
+            let _ = bank.restore(id, saved);
+        }
+        assert_eq!(bank.value(&"a"), Some(2.0));
+        assert_eq!(bank.value(&"b"), Some(7.0));
+        assert_eq!(bank.get(&"b").map(BankParam::default_value), Some(1.0));
+    }
+
+    #[test]
+    fn missing_param() {
+        let mut bank = ParamBank::new();
+        bank.insert("a", FloatRange::default(), 0.5, 0.5);
+
+        assert_eq!(bank.value(&"b"), None);
+        assert_eq!(bank.set_from_normal(&"b", Normal::max()), None);
+        assert_eq!(bank.set_value(&"b", 0.5), None);
+        assert_eq!(bank.reset(&"b"), None);
+        assert!(bank.shared(&"b").is_none());
+
+        assert!(bank.remove(&"a").is_some());
+        assert!(bank.is_empty());
+    }
+}
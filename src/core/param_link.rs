@@ -0,0 +1,258 @@
+//! Gang parameters together, such as the gains of a stereo pair
+
+use crate::core::{Normal, ParamBank};
+
+/// How the parameters of a [`ParamLink`] follow the one that was changed.
+///
+/// [`ParamLink`]: struct.ParamLink.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum LinkMode {
+    /// The linked parameters are set to the same value.
+    #[default]
+    Absolute,
+    /// The linked parameters move by the same amount, keeping their
+    /// offsets from each other.
+    Relative,
+    /// The linked parameters are set to the inverse (`1.0 - value`) of the
+    /// value, such as for a crossfade.
+    Inverted,
+}
+
+/// A group of parameters that are ganged together, so changing one of them
+/// changes the others.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{LinkMode, Normal, ParamLink};
+///
+/// let link = ParamLink::new(vec!["Gain L", "Gain R"], LinkMode::Relative);
+///
+/// // Gain L is moved up by 0.25 while Gain R is at 0.25.
+/// let changes = link.linked_changes(
+///     &"Gain L",
+///     Normal::from(0.5),
+///     Normal::from(0.75),
+///     |_| Normal::from(0.25),
+/// );
+///
+/// assert_eq!(changes, vec![("Gain R", Normal::from(0.5))]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ParamLink<K: Clone + PartialEq> {
+    members: Vec<K>,
+    mode: LinkMode,
+    enabled: bool,
+}
+
+impl<K: Clone + PartialEq> ParamLink<K> {
+    /// Creates a new enabled `ParamLink` of the parameters `members`.
+    pub fn new(members: Vec<K>, mode: LinkMode) -> Self {
+        Self {
+            members,
+            mode,
+            enabled: true,
+        }
+    }
+
+    /// Returns the ids of the linked parameters.
+    pub fn members(&self) -> &[K] {
+        &self.members
+    }
+
+    /// Returns `true` if the parameter `id` is in the link.
+    pub fn contains(&self, id: &K) -> bool {
+        self.members.contains(id)
+    }
+
+    /// Returns how the linked parameters follow the one that was changed.
+    pub fn mode(&self) -> LinkMode {
+        self.mode
+    }
+
+    /// Sets how the linked parameters follow the one that was changed.
+    pub fn set_mode(&mut self, mode: LinkMode) {
+        self.mode = mode;
+    }
+
+    /// Returns `true` if the link is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Enables or disables the link, such as with a "stereo link" button.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// Returns the changes of the other parameters in the link when the
+    /// parameter `id` changes from `old` to `new`.
+    ///
+    /// `value_of` returns the current value of a parameter. Returns no
+    /// changes if the link is disabled or `id` is not in the link.
+    pub fn linked_changes<F>(
+        &self,
+        id: &K,
+        old: Normal,
+        new: Normal,
+        value_of: F,
+    ) -> Vec<(K, Normal)>
+    where
+        F: Fn(&K) -> Normal,
+    {
+        if !self.enabled || !self.contains(id) {
+            return Vec::new();
+        }
+
+        self.members
+            .iter()
+            .filter(|member| *member != id)
+            .map(|member| {
+                let normal = match self.mode {
+                    LinkMode::Absolute => new,
+                    LinkMode::Relative => value_of(member)
+                        .saturating_add(new.as_f32() - old.as_f32()),
+                    LinkMode::Inverted => new.inverse(),
+                };
+
+                (member.clone(), normal)
+            })
+            .collect()
+    }
+
+    /// Sets the parameter `id` in `bank` from the [`Normal`] sent by a
+    /// widget, and sets the other parameters in the link to follow it.
+    ///
+    /// Returns every change made, starting with `id`, so the widgets of
+    /// the linked parameters can be updated.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn apply(
+        &self,
+        bank: &mut ParamBank<K>,
+        id: &K,
+        normal: Normal,
+    ) -> Vec<(K, Normal)> {
+        let old = match bank.normal_param(id) {
+            Some(param) => param.value,
+            None => return Vec::new(),
+        };
+
+        let _ = bank.set_from_normal(id, normal);
+
+        let new = match bank.normal_param(id) {
+            Some(param) => param.value,
+            None => normal,
+        };
+
+        let linked = self.linked_changes(id, old, new, |member| {
+            match bank.normal_param(member) {
+                Some(param) => param.value,
+                None => old,
+            }
+        });
+
+        let mut changes = vec![(id.clone(), new)];
+
+        for (member, normal) in linked {
+            let _ = bank.set_from_normal(&member, normal);
+
+            if let Some(param) = bank.normal_param(&member) {
+                changes.push((member, param.value));
+            }
+        }
+
+        changes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::range::FloatRange;
+
+    fn changes(
+        mode: LinkMode,
+        old: f32,
+        new: f32,
+        other: f32,
+    ) -> Vec<(&'static str, Normal)> {
+        ParamLink::new(vec!["a", "b"], mode).linked_changes(
+            &"a",
+            Normal::from(old),
+            Normal::from(new),
+            |_| Normal::from(other),
+        )
+    }
+
+    #[test]
+    fn absolute_at_range_ends() {
+        assert_eq!(
+            changes(LinkMode::Absolute, 0.5, 0.0, 0.3),
+            vec![("b", Normal::min())]
+        );
+        assert_eq!(
+            changes(LinkMode::Absolute, 0.5, 1.0, 0.3),
+            vec![("b", Normal::max())]
+        );
+    }
+
+    #[test]
+    fn relative_saturates_at_range_ends() {
+        assert_eq!(
+            changes(LinkMode::Relative, 0.5, 0.75, 0.9),
+            vec![("b", Normal::max())]
+        );
+        assert_eq!(
+            changes(LinkMode::Relative, 0.5, 0.25, 0.1),
+            vec![("b", Normal::min())]
+        );
+        assert_eq!(
+            changes(LinkMode::Relative, 1.0, 0.0, 1.0),
+            vec![("b", Normal::min())]
+        );
+    }
+
+    #[test]
+    fn inverted_at_range_ends() {
+        assert_eq!(
+            changes(LinkMode::Inverted, 0.5, 0.0, 0.3),
+            vec![("b", Normal::max())]
+        );
+        assert_eq!(
+            changes(LinkMode::Inverted, 0.5, 1.0, 0.3),
+            vec![("b", Normal::min())]
+        );
+    }
+
+    #[test]
+    fn disabled_or_unlinked_has_no_changes() {
+        let mut link = ParamLink::new(vec!["a", "b"], LinkMode::Absolute);
+
+        let unlinked =
+            link.linked_changes(&"c", Normal::min(), Normal::max(), |_| {
+                Normal::min()
+            });
+        assert!(unlinked.is_empty());
+
+        link.set_enabled(false);
+        let disabled =
+            link.linked_changes(&"a", Normal::min(), Normal::max(), |_| {
+                Normal::min()
+            });
+        assert!(disabled.is_empty());
+    }
+
+    #[test]
+    fn apply_updates_bank() {
+        let mut bank = ParamBank::new();
+        bank.insert("a", FloatRange::default(), 0.5, 0.5);
+        bank.insert("b", FloatRange::default(), 0.8, 0.5);
+
+        let link = ParamLink::new(vec!["a", "b"], LinkMode::Relative);
+        let changes = link.apply(&mut bank, &"a", Normal::max());
+
+        assert_eq!(changes, vec![("a", Normal::max()), ("b", Normal::max())]);
+        assert_eq!(bank.normal_param(&"b").unwrap().value, Normal::max());
+    }
+}
@@ -0,0 +1,125 @@
+//! Notifications of parameter changes that did not come from the GUI
+
+use crate::core::Normal;
+
+/// Receives the changes of parameters that were made programmatically, such
+/// as by host automation or by loading a preset.
+///
+/// This is implemented for every `FnMut(&K, Normal)`, so a closure that
+/// updates the matching widget state can be used as a listener.
+pub trait ParamListener<K> {
+    /// Called when the parameter `id` was changed to `normal`.
+    fn param_changed(&mut self, id: &K, normal: Normal);
+}
+
+impl<K, F> ParamListener<K> for F
+where
+    F: FnMut(&K, Normal),
+{
+    fn param_changed(&mut self, id: &K, normal: Normal) {
+        (self)(id, normal)
+    }
+}
+
+/// Collects programmatic parameter changes until the GUI is ready to apply
+/// them, and then hands them to a [`ParamListener`].
+///
+/// Call [`notify`] whenever a parameter changes outside of the GUI. Changes
+/// to the same parameter are coalesced, so only the latest value of each
+/// parameter is delivered. Call [`dispatch`] from the `update()` of the
+/// application to sync the widgets in one place.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{Normal, ParamNotifier};
+///
+/// let mut notifier = ParamNotifier::new();
+///
+/// // Host automation moves the cutoff twice before the GUI updates.
+/// notifier.notify("Cutoff", Normal::from(0.25));
+/// notifier.notify("Gain", Normal::from(0.5));
+/// notifier.notify("Cutoff", Normal::from(0.75));
+///
+/// let mut changes = Vec::new();
+/// notifier.dispatch(&mut |id: &&'static str, normal: Normal| {
+///     changes.push((*id, normal.as_f32()));
+/// });
+///
+/// assert_eq!(changes, vec![("Cutoff", 0.75), ("Gain", 0.5)]);
+/// assert!(notifier.is_empty());
+/// ```
+///
+/// [`ParamListener`]: trait.ParamListener.html
+/// [`notify`]: #method.notify
+/// [`dispatch`]: #method.dispatch
+#[derive(Debug, Clone)]
+pub struct ParamNotifier<K: PartialEq> {
+    pending: Vec<(K, Normal)>,
+}
+
+impl<K: PartialEq> ParamNotifier<K> {
+    /// Creates a new `ParamNotifier` with no pending changes.
+    pub fn new() -> Self {
+        Self {
+            pending: Vec::new(),
+        }
+    }
+
+    /// Records that the parameter `id` was changed to `normal`. This
+    /// replaces any pending change of the same parameter.
+    pub fn notify(&mut self, id: K, normal: Normal) {
+        if let Some(change) = self.pending.iter_mut().find(|(k, _)| *k == id) {
+            change.1 = normal;
+        } else {
+            self.pending.push((id, normal));
+        }
+    }
+
+    /// Returns the pending value of the parameter `id`, if it has changed
+    /// since the last dispatch.
+    pub fn pending(&self, id: &K) -> Option<Normal> {
+        self.pending
+            .iter()
+            .find(|(k, _)| k == id)
+            .map(|(_, normal)| *normal)
+    }
+
+    /// Returns `true` if there are no pending changes.
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Returns the number of parameters with pending changes.
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Hands every pending change to `listener`, in the order the
+    /// parameters first changed, and clears them.
+    pub fn dispatch<L>(&mut self, listener: &mut L)
+    where
+        L: ParamListener<K> + ?Sized,
+    {
+        for (id, normal) in self.pending.drain(..) {
+            listener.param_changed(&id, normal);
+        }
+    }
+
+    /// Removes and returns every pending change, in the order the
+    /// parameters first changed.
+    pub fn drain(&mut self) -> std::vec::Drain<'_, (K, Normal)> {
+        self.pending.drain(..)
+    }
+
+    /// Discards every pending change.
+    pub fn clear(&mut self) {
+        self.pending.clear();
+    }
+}
+
+impl<K: PartialEq> Default for ParamNotifier<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,19 +1,40 @@
 //! Ranges of parameter values that map to a [`Normal`]
-use crate::core::normal_param::NormalParam;
+use crate::core::formatter::{
+    DBFormatter, FloatFormatter, FreqFormatter, IntFormatter, PercentFormatter,
+};
+use crate::core::normal_param::{NormalParam, SavedParam};
 ///
 /// [`Normal`]: ../struct.Normal.html
 use crate::core::Normal;
 
 use std::fmt::Debug;
 
+/// The default step size of continuous linear ranges, as a fraction of the
+/// span of the range
+static DEFAULT_STEP_FRACTION: f32 = 0.01;
+
 /// A range that maps a continuous linear range of `f32` values
 /// to a [`Normal`]
 ///
+/// The range may be inverted, so that increasing the [`Normal`] decreases
+/// the value:
+///
+/// ```
+/// use iced_audio::FloatRange;
+///
+/// // An attenuation control from 0 dB down to -60 dB.
+/// let range = FloatRange::new(0.0, -60.0);
+///
+/// assert_eq!(range.unmap_to_value(1.0.into()), -60.0);
+/// assert_eq!(range.map_to_normal(-15.0).as_f32(), 0.25);
+/// ```
+///
 /// [`Normal`]: ../struct.Normal.html
 #[derive(Debug, Copy, Clone)]
 pub struct FloatRange {
     min: f32,
     max: f32,
+    step: f32,
     span: f32,
     span_recip: f32,
 }
@@ -23,14 +44,22 @@ impl FloatRange {
     ///
     /// # Arguments
     ///
-    /// * `min` - the minimum of the range (inclusive)
-    /// * `max` - the maximum of the range (inclusive)
+    /// * `min` - the value at a [`Normal`] of `0.0` (inclusive)
+    /// * `max` - the value at a [`Normal`] of `1.0` (inclusive)
+    ///
+    /// If `min` > `max`, the range is inverted.
     ///
     /// # Panics
     ///
-    /// This will panic if `max` <= `min`
+    /// This will panic if `max` == `min`, or if either is `NaN`
+    ///
+    /// [`Normal`]: ../struct.Normal.html
     pub fn new(min: f32, max: f32) -> Self {
-        assert!(max > min);
+        assert!(
+            !min.is_nan() && !max.is_nan(),
+            "min and max must not be NaN"
+        );
+        assert!(max != min, "max must not equal min");
 
         let span = max - min;
         let span_recip = span.recip();
@@ -38,6 +67,7 @@ impl FloatRange {
         Self {
             min,
             max,
+            step: span.abs() * DEFAULT_STEP_FRACTION,
             span,
             span_recip,
         }
@@ -51,11 +81,79 @@ impl FloatRange {
         FloatRange::new(-1.0, 1.0)
     }
 
+    /// Returns the value at a [`Normal`] of `0.0`
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn min(&self) -> f32 {
+        self.min
+    }
+
+    /// Returns the value at a [`Normal`] of `1.0`
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn max(&self) -> f32 {
+        self.max
+    }
+
+    /// Returns this range with `min` and `max` swapped, so that increasing
+    /// the [`Normal`] decreases the value
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn inverted(self) -> Self {
+        FloatRange::new(self.max, self.min).with_step_size(self.step)
+    }
+
+    /// Changes the range to `min..max`, for ranges that depend on the value
+    /// of another parameter.
+    ///
+    /// `param` is a parameter mapped with this range. Its value and default
+    /// value are clamped to the new range, and their normals are updated so
+    /// they keep their values. The step size is kept.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `max` == `min`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::FloatRange;
+    ///
+    /// // A high-cut that can't go below the low-cut.
+    /// let mut high_cut_range = FloatRange::new(20.0, 20000.0);
+    /// let mut high_cut = high_cut_range.normal_param(1000.0, 20000.0);
+    ///
+    /// high_cut_range.set_range(2000.0, 20000.0, &mut high_cut);
+    ///
+    /// assert_eq!(high_cut_range.unmap_to_value(high_cut.value), 2000.0);
+    /// assert_eq!(high_cut_range.unmap_to_value(high_cut.default), 20000.0);
+    /// ```
+    pub fn set_range(&mut self, min: f32, max: f32, param: &mut NormalParam) {
+        let saved = self.save(param);
+
+        *self = FloatRange::new(min, max).with_step_size(self.step);
+
+        *param = self.restore(&saved);
+    }
+
+    /// Returns `true` if increasing the [`Normal`] decreases the value
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn is_inverted(&self) -> bool {
+        self.min > self.max
+    }
+
     fn constrain(&self, value: f32) -> f32 {
-        if value <= self.min {
-            self.min
-        } else if value >= self.max {
-            self.max
+        let (low, high) = if self.is_inverted() {
+            (self.max, self.min)
+        } else {
+            (self.min, self.max)
+        };
+
+        if value <= low {
+            low
+        } else if value >= high {
+            high
         } else {
             value
         }
@@ -100,6 +198,82 @@ impl FloatRange {
     pub fn unmap_to_value(&self, normal: Normal) -> f32 {
         (normal.as_f32() * self.span) + self.min
     }
+
+    /// Returns the default [`FloatFormatter`] for values of this range
+    ///
+    /// [`FloatFormatter`]: ../formatter/struct.FloatFormatter.html
+    pub fn formatter(&self) -> FloatFormatter {
+        FloatFormatter::default()
+    }
+
+    /// Returns this range with the amount that [`increment`] and
+    /// [`decrement`] move the value by, in the units of the
+    /// range
+    ///
+    /// The default is 1% of the span of the range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::FloatRange;
+    ///
+    /// let range = FloatRange::new(0.0, 10.0).with_step_size(0.5);
+    ///
+    /// let normal = range.increment(range.map_to_normal(2.0));
+    /// assert_eq!(range.unmap_to_value(normal), 2.5);
+    /// ```
+    ///
+    /// [`increment`]: #method.increment
+    /// [`decrement`]: #method.decrement
+    pub fn with_step_size(mut self, step: f32) -> Self {
+        self.step = step.abs();
+        self
+    }
+
+    /// Returns the amount that [`increment`] and [`decrement`] move the
+    /// value by
+    ///
+    /// [`increment`]: #method.increment
+    /// [`decrement`]: #method.decrement
+    pub fn step_size(&self) -> f32 {
+        self.step
+    }
+
+    /// Returns the [`Normal`] of the value one step above the value of
+    /// `normal`, such as for a key press or a scroll wheel notch
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn increment(&self, normal: Normal) -> Normal {
+        let value = self.unmap_to_value(normal);
+        self.map_to_normal(value + self.step)
+    }
+
+    /// Returns the [`Normal`] of the value one step below the value of
+    /// `normal`
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn decrement(&self, normal: Normal) -> Normal {
+        let value = self.unmap_to_value(normal);
+        self.map_to_normal(value - self.step)
+    }
+
+    /// Returns the value and default value of `param` in the units of this
+    /// range, for storing in presets and plugin state.
+    pub fn save(&self, param: &NormalParam) -> SavedParam<f32> {
+        SavedParam {
+            value: self.unmap_to_value(param.value),
+            default: self.unmap_to_value(param.default),
+        }
+    }
+
+    /// Creates a new [`NormalParam`] from values created with [`save`].
+    /// Values outside of this range are clamped.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    /// [`save`]: #method.save
+    pub fn restore(&self, saved: &SavedParam<f32>) -> NormalParam {
+        self.normal_param(saved.value, saved.default)
+    }
 }
 
 impl Default for FloatRange {
@@ -108,11 +282,221 @@ impl Default for FloatRange {
     }
 }
 
+/// A range that maps a continuous range of `f32` values to a [`Normal`]
+/// along a power curve
+///
+/// An `exponent` greater than `1.0` gives more resolution at the low end of
+/// the range, which suits parameters such as attack times and ratios. An
+/// `exponent` between `0.0` and `1.0` gives more resolution at the high end,
+/// and an `exponent` of `1.0` is the same as a [`FloatRange`].
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::ExpRange;
+///
+/// // Attack time in milliseconds.
+/// let range = ExpRange::new(0.0, 1000.0, 2.0);
+///
+/// // The first half of the knob covers the first quarter of the range.
+/// assert_eq!(range.unmap_to_value(0.5.into()), 250.0);
+/// assert_eq!(range.map_to_normal(250.0).as_f32(), 0.5);
+/// ```
+///
+/// [`Normal`]: ../struct.Normal.html
+/// [`FloatRange`]: struct.FloatRange.html
+#[derive(Debug, Copy, Clone)]
+pub struct ExpRange {
+    min: f32,
+    max: f32,
+    exponent: f32,
+    step: f32,
+    span: f32,
+    span_recip: f32,
+    exponent_recip: f32,
+}
+
+impl ExpRange {
+    /// Creates a new `ExpRange`
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - the minimum of the range (inclusive)
+    /// * `max` - the maximum of the range (inclusive)
+    /// * `exponent` - the exponent of the curve
+    ///
+    /// # Panics
+    ///
+    /// This will panic if
+    /// * `max` <= `min`
+    /// * `exponent` <= `0.0`
+    pub fn new(min: f32, max: f32, exponent: f32) -> Self {
+        assert!(max > min, "max must be greater than min");
+        assert!(exponent > 0.0, "exponent must be positive");
+
+        let span = max - min;
+
+        Self {
+            min,
+            max,
+            exponent,
+            step: span * DEFAULT_STEP_FRACTION,
+            span,
+            span_recip: span.recip(),
+            exponent_recip: exponent.recip(),
+        }
+    }
+
+    /// Returns the minimum of the range
+    pub fn min(&self) -> f32 {
+        self.min
+    }
+
+    /// Returns the maximum of the range
+    pub fn max(&self) -> f32 {
+        self.max
+    }
+
+    /// Returns the exponent of the curve
+    pub fn exponent(&self) -> f32 {
+        self.exponent
+    }
+
+    fn constrain(&self, value: f32) -> f32 {
+        if value <= self.min {
+            self.min
+        } else if value >= self.max {
+            self.max
+        } else {
+            value
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The inital value of the parameter.
+    /// * `default_value` - The default value of the parameter.
+    pub fn normal_param(&self, value: f32, default: f32) -> NormalParam {
+        NormalParam {
+            value: self.map_to_normal(value),
+            default: self.map_to_normal(default),
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range where `value` and `default_value` is `0.0`.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn default_normal_param(&self) -> NormalParam {
+        NormalParam {
+            value: self.map_to_normal(0.0),
+            default: self.map_to_normal(0.0),
+        }
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied value
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_to_normal(&self, value: f32) -> Normal {
+        let value = self.constrain(value);
+        ((value - self.min) * self.span_recip)
+            .powf(self.exponent_recip)
+            .into()
+    }
+
+    /// Returns the corresponding value from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_value(&self, normal: Normal) -> f32 {
+        (normal.as_f32().powf(self.exponent) * self.span) + self.min
+    }
+
+    /// Returns the default [`FloatFormatter`] for values of this range
+    ///
+    /// [`FloatFormatter`]: ../formatter/struct.FloatFormatter.html
+    pub fn formatter(&self) -> FloatFormatter {
+        FloatFormatter::default()
+    }
+
+    /// Returns this range with the amount that [`increment`] and
+    /// [`decrement`] move the value by, in the units of the
+    /// range
+    ///
+    /// The default is 1% of the span of the range.
+    ///
+    /// [`increment`]: #method.increment
+    /// [`decrement`]: #method.decrement
+    pub fn with_step_size(mut self, step: f32) -> Self {
+        self.step = step.abs();
+        self
+    }
+
+    /// Returns the amount that [`increment`] and [`decrement`] move the
+    /// value by
+    ///
+    /// [`increment`]: #method.increment
+    /// [`decrement`]: #method.decrement
+    pub fn step_size(&self) -> f32 {
+        self.step
+    }
+
+    /// Returns the [`Normal`] of the value one step above the value of
+    /// `normal`, such as for a key press or a scroll wheel notch
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn increment(&self, normal: Normal) -> Normal {
+        let value = self.unmap_to_value(normal);
+        self.map_to_normal(value + self.step)
+    }
+
+    /// Returns the [`Normal`] of the value one step below the value of
+    /// `normal`
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn decrement(&self, normal: Normal) -> Normal {
+        let value = self.unmap_to_value(normal);
+        self.map_to_normal(value - self.step)
+    }
+
+    /// Returns the value and default value of `param` in the units of this
+    /// range, for storing in presets and plugin state.
+    pub fn save(&self, param: &NormalParam) -> SavedParam<f32> {
+        SavedParam {
+            value: self.unmap_to_value(param.value),
+            default: self.unmap_to_value(param.default),
+        }
+    }
+
+    /// Creates a new [`NormalParam`] from values created with [`save`].
+    /// Values outside of this range are clamped.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    /// [`save`]: #method.save
+    pub fn restore(&self, saved: &SavedParam<f32>) -> NormalParam {
+        self.normal_param(saved.value, saved.default)
+    }
+}
+
+impl Default for ExpRange {
+    fn default() -> Self {
+        ExpRange::new(0.0, 1.0, 2.0)
+    }
+}
+
 /// A range that defines a discrete linear range of i32 values
+///
+/// Like a [`FloatRange`], the range may be inverted by passing a `min`
+/// that is greater than `max`.
+///
+/// [`FloatRange`]: struct.FloatRange.html
 #[derive(Debug, Copy, Clone)]
 pub struct IntRange {
     min: i32,
     max: i32,
+    step: i32,
     span: f32,
     span_recip: f32,
 }
@@ -122,14 +506,18 @@ impl IntRange {
     ///
     /// # Arguments
     ///
-    /// * `min` - the minimum of the range (inclusive)
-    /// * `max` - the maximum of the range (inclusive)
+    /// * `min` - the value at a [`Normal`] of `0.0` (inclusive)
+    /// * `max` - the value at a [`Normal`] of `1.0` (inclusive)
+    ///
+    /// If `min` > `max`, the range is inverted.
     ///
     /// # Panics
     ///
-    /// This will panic if `max` <= `min`
+    /// This will panic if `max` == `min`
+    ///
+    /// [`Normal`]: ../struct.Normal.html
     pub fn new(min: i32, max: i32) -> Self {
-        assert!(max > min);
+        assert!(max != min);
 
         let span = (max - min) as f32;
         let span_recip = span.recip();
@@ -137,16 +525,72 @@ impl IntRange {
         Self {
             min,
             max,
+            step: 1,
             span,
             span_recip,
         }
     }
 
+    /// Returns the value at a [`Normal`] of `0.0`. This is the minimum of
+    /// the range unless it is inverted.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn min(&self) -> i32 {
+        self.min
+    }
+
+    /// Returns the value at a [`Normal`] of `1.0`. This is the maximum of
+    /// the range unless it is inverted.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn max(&self) -> i32 {
+        self.max
+    }
+
+    /// Returns this range with `min` and `max` swapped, so that increasing
+    /// the [`Normal`] decreases the value
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn inverted(self) -> Self {
+        IntRange::new(self.max, self.min).with_step_size(self.step)
+    }
+
+    /// Changes the range to `min..max`, for ranges that depend on the value
+    /// of another parameter.
+    ///
+    /// `param` is a parameter mapped with this range. Its value and default
+    /// value are clamped to the new range, and their normals are updated so
+    /// they keep their values. The step size is kept.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `max` == `min`
+    pub fn set_range(&mut self, min: i32, max: i32, param: &mut NormalParam) {
+        let saved = self.save(param);
+
+        *self = IntRange::new(min, max).with_step_size(self.step);
+
+        *param = self.restore(&saved);
+    }
+
+    /// Returns `true` if increasing the [`Normal`] decreases the value
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn is_inverted(&self) -> bool {
+        self.min > self.max
+    }
+
     fn constrain(&self, value: i32) -> i32 {
-        if value <= self.min {
-            self.min
-        } else if value >= self.max {
-            self.max
+        let (low, high) = if self.is_inverted() {
+            (self.max, self.min)
+        } else {
+            (self.min, self.max)
+        };
+
+        if value <= low {
+            low
+        } else if value >= high {
+            high
         } else {
             value
         }
@@ -200,6 +644,71 @@ impl IntRange {
     pub fn unmap_to_value(&self, normal: Normal) -> i32 {
         (normal.as_f32() * self.span).round() as i32 + self.min
     }
+
+    /// Returns the default [`IntFormatter`] for values of this range
+    ///
+    /// [`IntFormatter`]: ../formatter/struct.IntFormatter.html
+    pub fn formatter(&self) -> IntFormatter {
+        IntFormatter::default()
+    }
+
+    /// Returns this range with the amount that [`increment`] and
+    /// [`decrement`] move the value by, in the units of the
+    /// range
+    ///
+    /// The default is `1`.
+    ///
+    /// [`increment`]: #method.increment
+    /// [`decrement`]: #method.decrement
+    pub fn with_step_size(mut self, step: i32) -> Self {
+        self.step = step.abs();
+        self
+    }
+
+    /// Returns the amount that [`increment`] and [`decrement`] move the
+    /// value by
+    ///
+    /// [`increment`]: #method.increment
+    /// [`decrement`]: #method.decrement
+    pub fn step_size(&self) -> i32 {
+        self.step
+    }
+
+    /// Returns the [`Normal`] of the value one step above the value of
+    /// `normal`, such as for a key press or a scroll wheel notch
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn increment(&self, normal: Normal) -> Normal {
+        let value = self.unmap_to_value(normal);
+        self.map_to_normal(value.saturating_add(self.step))
+    }
+
+    /// Returns the [`Normal`] of the value one step below the value of
+    /// `normal`
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn decrement(&self, normal: Normal) -> Normal {
+        let value = self.unmap_to_value(normal);
+        self.map_to_normal(value.saturating_sub(self.step))
+    }
+
+    /// Returns the value and default value of `param` in the units of this
+    /// range, for storing in presets and plugin state.
+    pub fn save(&self, param: &NormalParam) -> SavedParam<i32> {
+        SavedParam {
+            value: self.unmap_to_value(param.value),
+            default: self.unmap_to_value(param.default),
+        }
+    }
+
+    /// Creates a new [`NormalParam`] from values created with [`save`].
+    /// Values outside of this range are clamped.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    /// [`save`]: #method.save
+    pub fn restore(&self, saved: &SavedParam<i32>) -> NormalParam {
+        self.normal_param(saved.value, saved.default)
+    }
 }
 
 impl Default for IntRange {
@@ -218,6 +727,7 @@ pub struct LogDBRange {
     min: f32,
     max: f32,
     zero_position: Normal,
+    step: f32,
     min_recip: f32,
     max_recip: f32,
     zero_pos_recip: f32,
@@ -268,6 +778,7 @@ impl LogDBRange {
             min,
             max,
             zero_position,
+            step: 0.5,
             min_recip,
             max_recip,
             zero_pos_recip,
@@ -275,6 +786,16 @@ impl LogDBRange {
         }
     }
 
+    /// Returns the minimum of the range in dB
+    pub fn min(&self) -> f32 {
+        self.min
+    }
+
+    /// Returns the maximum of the range in dB
+    pub fn max(&self) -> f32 {
+        self.max
+    }
+
     fn constrain(&self, value: f32) -> f32 {
         if value <= self.min {
             self.min
@@ -367,6 +888,70 @@ impl LogDBRange {
             log_normal * self.max
         }
     }
+
+    /// Returns the default [`DBFormatter`] for values of this range
+    ///
+    /// [`DBFormatter`]: ../formatter/struct.DBFormatter.html
+    pub fn formatter(&self) -> DBFormatter {
+        DBFormatter::default()
+    }
+
+    /// Returns this range with the amount that [`increment`] and
+    /// [`decrement`] move the value by, in dB
+    ///
+    /// The default is `0.5` dB.
+    ///
+    /// [`increment`]: #method.increment
+    /// [`decrement`]: #method.decrement
+    pub fn with_step_size(mut self, step: f32) -> Self {
+        self.step = step.abs();
+        self
+    }
+
+    /// Returns the amount that [`increment`] and [`decrement`] move the
+    /// value by
+    ///
+    /// [`increment`]: #method.increment
+    /// [`decrement`]: #method.decrement
+    pub fn step_size(&self) -> f32 {
+        self.step
+    }
+
+    /// Returns the [`Normal`] of the value one step above the value of
+    /// `normal`, such as for a key press or a scroll wheel notch
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn increment(&self, normal: Normal) -> Normal {
+        let value = self.unmap_to_value(normal);
+        self.map_to_normal(value + self.step)
+    }
+
+    /// Returns the [`Normal`] of the value one step below the value of
+    /// `normal`
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn decrement(&self, normal: Normal) -> Normal {
+        let value = self.unmap_to_value(normal);
+        self.map_to_normal(value - self.step)
+    }
+
+    /// Returns the value and default value of `param` in the units of this
+    /// range, for storing in presets and plugin state.
+    pub fn save(&self, param: &NormalParam) -> SavedParam<f32> {
+        SavedParam {
+            value: self.unmap_to_value(param.value),
+            default: self.unmap_to_value(param.default),
+        }
+    }
+
+    /// Creates a new [`NormalParam`] from values created with [`save`].
+    /// Values outside of this range are clamped.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    /// [`save`]: #method.save
+    pub fn restore(&self, saved: &SavedParam<f32>) -> NormalParam {
+        self.normal_param(saved.value, saved.default)
+    }
 }
 
 impl Default for LogDBRange {
@@ -376,64 +961,78 @@ impl Default for LogDBRange {
 }
 
 /// A [`NormalParam`] that defines a continuous logarithmic range of `f32` frequency
-/// values, with each octave in the 10 octave spectrum spaced evenly.
+/// values, with each octave spaced evenly.
 ///
 /// Smaller frequencies will increment slower per slider movement than larger
 /// ones.
+///
+/// The range can be any positive frequencies, such as `0.01 Hz` to `100 Hz`
+/// for the rate of an LFO, or `200 Hz` to `8 kHz` for the band of an
+/// equalizer.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::FreqRange;
+///
+/// let lfo_rate = FreqRange::new(0.01, 100.0);
+///
+/// let param = lfo_rate.normal_param(1.0, 1.0);
+///
+/// // 1 Hz is halfway between 0.01 Hz and 100 Hz in octaves.
+/// assert!((param.value.as_f32() - 0.5).abs() < 0.0001);
+/// ```
 #[derive(Debug, Copy, Clone)]
 pub struct FreqRange {
     min: f32,
     max: f32,
-    spectrum_normal_span: f32,
-    spectrum_normal_span_recip: f32,
-    min_spectrum_normal: Normal,
+    step: f32,
+    min_octave: f32,
+    octave_span: f32,
+    octave_span_recip: f32,
 }
 
 impl FreqRange {
-    /// Creates a new `OctaveNormalParam`
+    /// Creates a new `FreqRange`
     ///
     /// # Arguments
     ///
-    /// * `min` - the minimum of the range in Hz (inclusive), will be
-    /// constrained to `20.0 Hz <= min <= 20480.0 Hz`
-    /// * `max` - the maximum of the range in Hz (inclusive), will be
-    /// constrained to `20.0 Hz <= max <= 20480.0 Hz`
+    /// * `min` - the minimum of the range in Hz (inclusive)
+    /// * `max` - the maximum of the range in Hz (inclusive)
     ///
     /// # Panics
     ///
     /// This will panic if
+    /// * `min` <= `0.0`
     /// * `max` <= `min`
     ///
     pub fn new(min: f32, max: f32) -> Self {
+        assert!(min > 0.0);
         assert!(max > min);
 
-        let mut min = min;
-        if min < 20.0 {
-            min = 20.0;
-        }
-
-        let mut max = max;
-        if max > 20480.0 {
-            max = 20480.0;
-        }
-
-        let min_spectrum_normal = octave_spectrum_map_to_normal(min);
-        let max_spectrum_normal = octave_spectrum_map_to_normal(max);
-
-        let spectrum_normal_span =
-            max_spectrum_normal.as_f32() - min_spectrum_normal.as_f32();
-
-        let spectrum_normal_span_recip = 1.0 / spectrum_normal_span;
+        let min_octave = min.log2();
+        let octave_span = max.log2() - min_octave;
 
         Self {
             min,
             max,
-            spectrum_normal_span,
-            min_spectrum_normal,
-            spectrum_normal_span_recip,
+            step: 1.0,
+            min_octave,
+            octave_span,
+            octave_span_recip: octave_span.recip(),
         }
     }
 
+    /// Returns the minimum of the range in Hz
+    pub fn min(&self) -> f32 {
+        self.min
+    }
+
+    /// Returns the maximum of the range in Hz
+    pub fn max(&self) -> f32 {
+        self.max
+    }
+
     fn constrain(&self, value: f32) -> f32 {
         if value <= self.min {
             self.min
@@ -459,13 +1058,14 @@ impl FreqRange {
     }
 
     /// Creates a new [`NormalParam`] with values mapped
-    /// from this range where `value` and `default_value` is `20480.0`.
+    /// from this range where `value` and `default_value` is the maximum of
+    /// the range.
     ///
     /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
     pub fn default_normal_param(&self) -> NormalParam {
         NormalParam {
-            value: self.map_to_normal(20_480.0),
-            default: self.map_to_normal(20_480.0),
+            value: Normal::max(),
+            default: Normal::max(),
         }
     }
 
@@ -474,22 +1074,91 @@ impl FreqRange {
     /// [`Normal`]: ../struct.Normal.html
     pub fn map_to_normal(&self, value: f32) -> Normal {
         let value = self.constrain(value);
-        let spectrum_normal = octave_spectrum_map_to_normal(value);
-        ((spectrum_normal.as_f32() - self.min_spectrum_normal.as_f32())
-            * self.spectrum_normal_span_recip)
-            .into()
+
+        ((value.log2() - self.min_octave) * self.octave_span_recip).into()
     }
 
     /// Returns the corresponding frequency value from the supplied [`Normal`]
     ///
     /// [`Normal`]: ../struct.Normal.html
     pub fn unmap_to_value(&self, normal: Normal) -> f32 {
-        let spectrum_normal = Normal::new(
-            normal.as_f32() * self.spectrum_normal_span
-                + self.min_spectrum_normal.as_f32(),
-        );
+        (normal.as_f32() * self.octave_span + self.min_octave).exp2()
+    }
+
+    /// Returns the default [`FreqFormatter`] for values of this range
+    ///
+    /// [`FreqFormatter`]: ../formatter/struct.FreqFormatter.html
+    pub fn formatter(&self) -> FreqFormatter {
+        FreqFormatter::default()
+    }
+
+    /// Returns this range with the amount that [`increment`] and
+    /// [`decrement`] move the value by, in semitones
+    ///
+    /// The default is `1.0` semitone.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::FreqRange;
+    ///
+    /// let range = FreqRange::new(20.0, 20000.0).with_step_size(12.0);
+    ///
+    /// // One step is an octave.
+    /// let normal = range.increment(range.map_to_normal(440.0));
+    /// assert!((range.unmap_to_value(normal) - 880.0).abs() < 0.01);
+    /// ```
+    ///
+    /// [`increment`]: #method.increment
+    /// [`decrement`]: #method.decrement
+    pub fn with_step_size(mut self, step: f32) -> Self {
+        self.step = step.abs();
+        self
+    }
+
+    /// Returns the amount that [`increment`] and [`decrement`] move the
+    /// value by
+    ///
+    /// [`increment`]: #method.increment
+    /// [`decrement`]: #method.decrement
+    pub fn step_size(&self) -> f32 {
+        self.step
+    }
+
+    /// Returns the [`Normal`] of the value one step above the value of
+    /// `normal`, such as for a key press or a scroll wheel notch
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn increment(&self, normal: Normal) -> Normal {
+        let value = self.unmap_to_value(normal);
+        self.map_to_normal(value * (self.step / 12.0).exp2())
+    }
+
+    /// Returns the [`Normal`] of the value one step below the value of
+    /// `normal`
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn decrement(&self, normal: Normal) -> Normal {
+        let value = self.unmap_to_value(normal);
+        self.map_to_normal(value / (self.step / 12.0).exp2())
+    }
+
+    /// Returns the value and default value of `param` in the units of this
+    /// range, for storing in presets and plugin state.
+    pub fn save(&self, param: &NormalParam) -> SavedParam<f32> {
+        SavedParam {
+            value: self.unmap_to_value(param.value),
+            default: self.unmap_to_value(param.default),
+        }
+    }
 
-        octave_normal_to_spectrum(spectrum_normal)
+    /// Creates a new [`NormalParam`] from values created with [`save`].
+    /// Values outside of this range are clamped.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    /// [`save`]: #method.save
+    pub fn restore(&self, saved: &SavedParam<f32>) -> NormalParam {
+        self.normal_param(saved.value, saved.default)
     }
 }
 
@@ -499,18 +1168,428 @@ impl Default for FreqRange {
     }
 }
 
-/// Returns the corresponding frequency for the whole 10 octave spectrum
-/// (between 20 Hz and 20480 Hz)
-#[inline]
-fn octave_normal_to_spectrum(value: Normal) -> f32 {
-    40.0 * 2.0_f32.powf((10.0 * value.as_f32()) - 1.0)
-}
-
-/// Returns the corresponding [`Normal`] for a frequency in the whole
-/// 10 octave spectrum (between 20 Hz and 20480 Hz)
+/// A range that maps stereo width values to a [`Normal`]
+///
+/// A width of `0.0` is mono, `1.0` (100%) leaves the stereo image
+/// unchanged, and `2.0` (200%) is twice as wide. The mono width is at a
+/// [`Normal`] of `0.0`.
+///
+/// A width knob can show the width as two notches mirrored around the
+/// center of the knob, which are returned by [`spread`]. The
+/// `StereoWidth` style of a `Knob` draws them this way.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{Formatter, WidthRange};
+///
+/// let range = WidthRange::default();
+///
+/// assert_eq!(range.map_to_normal(1.0).as_f32(), 0.5);
+///
+/// let max = range.unmap_to_value(1.0.into());
+/// assert_eq!(range.formatter().to_text(max), "200%");
+///
+/// // At 100%, the notches are halfway between the center and the ends.
+/// let (left, right) = range.spread(range.map_to_normal(1.0));
+/// assert_eq!((left.as_f32(), right.as_f32()), (0.25, 0.75));
+/// ```
 ///
 /// [`Normal`]: ../struct.Normal.html
-#[inline]
-fn octave_spectrum_map_to_normal(freq: f32) -> Normal {
-    (((freq / 40.0).log2() + 1.0) * 0.1).into()
+/// [`spread`]: #method.spread
+#[derive(Debug, Copy, Clone)]
+pub struct WidthRange {
+    max: f32,
+    step: f32,
+}
+
+impl WidthRange {
+    /// Creates a new `WidthRange`
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - the maximum width, such as `2.0` for 200% (inclusive)
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `max` <= `0.0`
+    pub fn new(max: f32) -> Self {
+        assert!(max > 0.0);
+
+        Self {
+            max,
+            step: max * DEFAULT_STEP_FRACTION,
+        }
+    }
+
+    /// Returns the maximum width
+    pub fn max(&self) -> f32 {
+        self.max
+    }
+
+    /// Returns the [`Normal`] of the mono width, such as for a
+    /// [`soft_detent`] of a `Knob`
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    /// [`soft_detent`]: ../../native/knob/struct.Knob.html#method.soft_detent
+    pub fn mono(&self) -> Normal {
+        Normal::min()
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The inital value of the parameter.
+    /// * `default_value` - The default value of the parameter.
+    pub fn normal_param(&self, value: f32, default: f32) -> NormalParam {
+        NormalParam {
+            value: self.map_to_normal(value),
+            default: self.map_to_normal(default),
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range where `value` and `default_value` is `1.0` (100%).
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn default_normal_param(&self) -> NormalParam {
+        self.normal_param(1.0, 1.0)
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied width
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_to_normal(&self, value: f32) -> Normal {
+        (value.max(0.0).min(self.max) / self.max).into()
+    }
+
+    /// Returns the corresponding width from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_value(&self, normal: Normal) -> f32 {
+        normal.as_f32() * self.max
+    }
+
+    /// Returns the positions of two notches mirrored around the center for
+    /// the width at `normal`. Mono puts both at the center, and the maximum
+    /// width puts them at `0.0` and `1.0`.
+    pub fn spread(&self, normal: Normal) -> (Normal, Normal) {
+        let half = normal.as_f32() / 2.0;
+
+        ((0.5 - half).into(), (0.5 + half).into())
+    }
+
+    /// Returns the default [`PercentFormatter`] for values of this range
+    ///
+    /// [`PercentFormatter`]: ../formatter/struct.PercentFormatter.html
+    pub fn formatter(&self) -> PercentFormatter {
+        PercentFormatter::default()
+    }
+
+    /// Returns this range with the amount that [`increment`] and
+    /// [`decrement`] move the value by
+    ///
+    /// The default is 1% of the maximum width.
+    ///
+    /// [`increment`]: #method.increment
+    /// [`decrement`]: #method.decrement
+    pub fn with_step_size(mut self, step: f32) -> Self {
+        self.step = step.abs();
+        self
+    }
+
+    /// Returns the amount that [`increment`] and [`decrement`] move the
+    /// value by
+    ///
+    /// [`increment`]: #method.increment
+    /// [`decrement`]: #method.decrement
+    pub fn step_size(&self) -> f32 {
+        self.step
+    }
+
+    /// Returns the [`Normal`] of the value one step above the value of
+    /// `normal`, such as for a key press or a scroll wheel notch
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn increment(&self, normal: Normal) -> Normal {
+        let value = self.unmap_to_value(normal);
+        self.map_to_normal(value + self.step)
+    }
+
+    /// Returns the [`Normal`] of the value one step below the value of
+    /// `normal`
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn decrement(&self, normal: Normal) -> Normal {
+        let value = self.unmap_to_value(normal);
+        self.map_to_normal(value - self.step)
+    }
+
+    /// Returns the value and default value of `param` in the units of this
+    /// range, for storing in presets and plugin state.
+    pub fn save(&self, param: &NormalParam) -> SavedParam<f32> {
+        SavedParam {
+            value: self.unmap_to_value(param.value),
+            default: self.unmap_to_value(param.default),
+        }
+    }
+
+    /// Creates a new [`NormalParam`] from values created with [`save`].
+    /// Values outside of this range are clamped.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    /// [`save`]: #method.save
+    pub fn restore(&self, saved: &SavedParam<f32>) -> NormalParam {
+        self.normal_param(saved.value, saved.default)
+    }
+}
+
+impl Default for WidthRange {
+    fn default() -> Self {
+        WidthRange::new(2.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    //! Ranges are stored as the arguments of their constructors, and are
+    //! checked the same way when they are loaded.
+
+    use super::*;
+
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    struct FloatRangeDef {
+        min: f32,
+        max: f32,
+        #[serde(default)]
+        step: Option<f32>,
+    }
+
+    impl Serialize for FloatRange {
+        fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+            FloatRangeDef {
+                min: self.min,
+                max: self.max,
+                step: Some(self.step),
+            }
+            .serialize(s)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for FloatRange {
+        fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+            let def = FloatRangeDef::deserialize(d)?;
+
+            if def.min.is_nan() || def.max.is_nan() || def.max == def.min {
+                return Err(D::Error::custom("max must not equal min"));
+            }
+
+            let range = FloatRange::new(def.min, def.max);
+
+            Ok(match def.step {
+                Some(step) => range.with_step_size(step),
+                None => range,
+            })
+        }
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct ExpRangeDef {
+        min: f32,
+        max: f32,
+        exponent: f32,
+        #[serde(default)]
+        step: Option<f32>,
+    }
+
+    impl Serialize for ExpRange {
+        fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+            ExpRangeDef {
+                min: self.min,
+                max: self.max,
+                exponent: self.exponent,
+                step: Some(self.step),
+            }
+            .serialize(s)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for ExpRange {
+        fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+            let def = ExpRangeDef::deserialize(d)?;
+
+            if def.max.is_nan() || def.max <= def.min {
+                return Err(D::Error::custom("max must be greater than min"));
+            }
+            if def.exponent.is_nan() || def.exponent <= 0.0 {
+                return Err(D::Error::custom("exponent must be positive"));
+            }
+
+            let range = ExpRange::new(def.min, def.max, def.exponent);
+
+            Ok(match def.step {
+                Some(step) => range.with_step_size(step),
+                None => range,
+            })
+        }
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct IntRangeDef {
+        min: i32,
+        max: i32,
+        #[serde(default)]
+        step: Option<i32>,
+    }
+
+    impl Serialize for IntRange {
+        fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+            IntRangeDef {
+                min: self.min,
+                max: self.max,
+                step: Some(self.step),
+            }
+            .serialize(s)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for IntRange {
+        fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+            let def = IntRangeDef::deserialize(d)?;
+
+            if def.max == def.min {
+                return Err(D::Error::custom("max must not equal min"));
+            }
+
+            let range = IntRange::new(def.min, def.max);
+
+            Ok(match def.step {
+                Some(step) => range.with_step_size(step),
+                None => range,
+            })
+        }
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct LogDBRangeDef {
+        min: f32,
+        max: f32,
+        zero_position: Normal,
+        #[serde(default)]
+        step: Option<f32>,
+    }
+
+    impl Serialize for LogDBRange {
+        fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+            LogDBRangeDef {
+                min: self.min,
+                max: self.max,
+                zero_position: self.zero_position,
+                step: Some(self.step),
+            }
+            .serialize(s)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for LogDBRange {
+        fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+            let def = LogDBRangeDef::deserialize(d)?;
+
+            if def.max.is_nan() || def.max <= def.min {
+                return Err(D::Error::custom("max must be greater than min"));
+            }
+            if def.max.is_nan() || def.max < 0.0 {
+                return Err(D::Error::custom("max must be 0.0 or positive"));
+            }
+            if def.min.is_nan() || def.min > 0.0 {
+                return Err(D::Error::custom("min must be 0.0 or negative"));
+            }
+
+            let range = LogDBRange::new(def.min, def.max, def.zero_position);
+
+            Ok(match def.step {
+                Some(step) => range.with_step_size(step),
+                None => range,
+            })
+        }
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct FreqRangeDef {
+        min: f32,
+        max: f32,
+        #[serde(default)]
+        step: Option<f32>,
+    }
+
+    impl Serialize for FreqRange {
+        fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+            FreqRangeDef {
+                min: self.min,
+                max: self.max,
+                step: Some(self.step),
+            }
+            .serialize(s)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for FreqRange {
+        fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+            let def = FreqRangeDef::deserialize(d)?;
+
+            if def.min.is_nan() || def.min <= 0.0 {
+                return Err(D::Error::custom("min must be greater than 0.0"));
+            }
+
+            if def.max.is_nan() || def.max <= def.min {
+                return Err(D::Error::custom("max must be greater than min"));
+            }
+
+            let range = FreqRange::new(def.min, def.max);
+
+            Ok(match def.step {
+                Some(step) => range.with_step_size(step),
+                None => range,
+            })
+        }
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct WidthRangeDef {
+        max: f32,
+        #[serde(default)]
+        step: Option<f32>,
+    }
+
+    impl Serialize for WidthRange {
+        fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+            WidthRangeDef {
+                max: self.max,
+                step: Some(self.step),
+            }
+            .serialize(s)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for WidthRange {
+        fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+            let def = WidthRangeDef::deserialize(d)?;
+
+            if def.max.is_nan() || def.max <= 0.0 {
+                return Err(D::Error::custom("max must be greater than 0.0"));
+            }
+
+            let range = WidthRange::new(def.max);
+
+            Ok(match def.step {
+                Some(step) => range.with_step_size(step),
+                None => range,
+            })
+        }
+    }
 }
@@ -0,0 +1,84 @@
+//! Share the value of a parameter between the GUI and audio threads
+
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+use crate::core::Normal;
+
+/// The [`Normal`] value of a parameter that can be read and written from
+/// any thread without locking, such as by the GUI and the audio thread of
+/// a plugin.
+///
+/// Cloning a `SharedParam` is cheap, and every clone refers to the same
+/// value. The value is stored in an `AtomicU32`, so reading it from a
+/// real-time thread never blocks or allocates.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{Normal, SharedParam};
+///
+/// let gui_side = SharedParam::new(Normal::center());
+/// let audio_side = gui_side.clone();
+///
+/// // The GUI updates the value when a widget is moved...
+/// gui_side.set(Normal::from(0.75));
+///
+/// // ...and the audio thread reads it while processing.
+/// assert_eq!(audio_side.get().as_f32(), 0.75);
+/// ```
+///
+/// [`Normal`]: ../struct.Normal.html
+#[derive(Debug, Clone)]
+pub struct SharedParam {
+    value: Arc<AtomicU32>,
+}
+
+impl SharedParam {
+    /// Creates a new `SharedParam` with the value `normal`.
+    pub fn new(normal: Normal) -> Self {
+        Self {
+            value: Arc::new(AtomicU32::new(normal.as_f32().to_bits())),
+        }
+    }
+
+    /// Returns the current value.
+    #[inline]
+    pub fn get(&self) -> Normal {
+        f32::from_bits(self.value.load(Ordering::Relaxed)).into()
+    }
+
+    /// Sets the value to `normal`.
+    #[inline]
+    pub fn set(&self, normal: Normal) {
+        self.value
+            .store(normal.as_f32().to_bits(), Ordering::Relaxed);
+    }
+
+    /// Sets the value to `normal` and returns the previous value.
+    #[inline]
+    pub fn swap(&self, normal: Normal) -> Normal {
+        f32::from_bits(
+            self.value
+                .swap(normal.as_f32().to_bits(), Ordering::Relaxed),
+        )
+        .into()
+    }
+
+    /// Returns `true` if `self` and `other` refer to the same value.
+    pub fn ptr_eq(&self, other: &SharedParam) -> bool {
+        Arc::ptr_eq(&self.value, &other.value)
+    }
+}
+
+impl Default for SharedParam {
+    fn default() -> Self {
+        SharedParam::new(Normal::min())
+    }
+}
+
+impl From<Normal> for SharedParam {
+    fn from(normal: Normal) -> Self {
+        SharedParam::new(normal)
+    }
+}
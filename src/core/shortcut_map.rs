@@ -0,0 +1,242 @@
+//! Keyboard shortcuts that focus and nudge parameters
+
+use iced_native::keyboard::{self, KeyCode, Modifiers};
+use iced_native::Event;
+
+use crate::core::Normal;
+
+/// A keyboard shortcut: a key pressed while holding down some modifier keys.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Shortcut {
+    /// The key that triggers the shortcut
+    pub key_code: KeyCode,
+    /// The modifier keys that must be held down. Keys that are not set here
+    /// may be held down as well.
+    pub modifiers: Modifiers,
+}
+
+impl From<KeyCode> for Shortcut {
+    fn from(key_code: KeyCode) -> Self {
+        Self {
+            key_code,
+            modifiers: Modifiers::default(),
+        }
+    }
+}
+
+/// Binds keyboard shortcuts to parameter IDs, so power users can adjust
+/// parameters without the mouse.
+///
+/// Pressing a bound shortcut focuses its parameter. While a parameter is
+/// focused, the arrow keys nudge its value up and down, and `Escape` clears
+/// the focus.
+///
+/// Forward every keyboard event of the application (for example from
+/// `iced_native::subscription::events`) to [`on_event`]. It returns the same
+/// `(ID, Normal)` pair that the widget of the parameter would send.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{Normal, ShortcutMap};
+/// use iced_native::keyboard::{self, KeyCode};
+/// use iced_native::Event;
+///
+/// let mut shortcuts = ShortcutMap::new()
+///     .with(KeyCode::G, "Gain")
+///     .with(KeyCode::F, "Cutoff");
+///
+/// let press = |key_code| {
+///     Event::Keyboard(keyboard::Event::KeyPressed {
+///         key_code,
+///         modifiers: Default::default(),
+///     })
+/// };
+///
+/// // "g" focuses the gain parameter.
+/// assert_eq!(shortcuts.on_event(&press(KeyCode::G), |_| Normal::center()), None);
+/// assert_eq!(shortcuts.focused(), Some(&"Gain"));
+///
+/// // The up arrow nudges it.
+/// let (id, normal) = shortcuts
+///     .on_event(&press(KeyCode::Up), |_| Normal::center())
+///     .unwrap();
+///
+/// assert_eq!(id, "Gain");
+/// assert!(normal.as_f32() > 0.5);
+/// ```
+///
+/// [`on_event`]: #method.on_event
+#[derive(Debug, Clone)]
+pub struct ShortcutMap<K: Clone + PartialEq> {
+    bindings: Vec<(Shortcut, K)>,
+    focused: Option<K>,
+    step: f32,
+    fine_step: f32,
+    fine_keys: Modifiers,
+}
+
+impl<K: Clone + PartialEq> ShortcutMap<K> {
+    /// Creates a new empty `ShortcutMap`.
+    ///
+    /// The arrow keys nudge by `0.01` of the range, or by `0.001` while
+    /// `Shift` is held down.
+    pub fn new() -> Self {
+        Self {
+            bindings: Vec::new(),
+            focused: None,
+            step: 0.01,
+            fine_step: 0.001,
+            fine_keys: Modifiers {
+                shift: true,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Binds `shortcut` to the parameter `id`, and returns the
+    /// `ShortcutMap`.
+    pub fn with(mut self, shortcut: impl Into<Shortcut>, id: K) -> Self {
+        let _ = self.bind(shortcut, id);
+        self
+    }
+
+    /// Binds `shortcut` to the parameter `id`. Returns the ID previously
+    /// bound to `shortcut`, if any.
+    pub fn bind(&mut self, shortcut: impl Into<Shortcut>, id: K) -> Option<K> {
+        let shortcut = shortcut.into();
+
+        if let Some(binding) =
+            self.bindings.iter_mut().find(|(s, _)| *s == shortcut)
+        {
+            Some(std::mem::replace(&mut binding.1, id))
+        } else {
+            self.bindings.push((shortcut, id));
+            None
+        }
+    }
+
+    /// Removes the binding of `shortcut`. Returns the ID that was bound to
+    /// it, if any.
+    pub fn unbind(&mut self, shortcut: impl Into<Shortcut>) -> Option<K> {
+        let shortcut = shortcut.into();
+
+        let index = self.bindings.iter().position(|(s, _)| *s == shortcut)?;
+
+        Some(self.bindings.remove(index).1)
+    }
+
+    /// Returns the ID bound to `shortcut`, if any.
+    pub fn get(&self, shortcut: impl Into<Shortcut>) -> Option<&K> {
+        let shortcut = shortcut.into();
+
+        self.bindings
+            .iter()
+            .find(|(s, _)| *s == shortcut)
+            .map(|(_, id)| id)
+    }
+
+    /// Sets how far the arrow keys nudge the focused parameter, as a
+    /// fraction of its range.
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets how far the arrow keys nudge the focused parameter while the
+    /// `fine_keys` are held down, as a fraction of its range.
+    pub fn fine_step(mut self, fine_step: f32) -> Self {
+        self.fine_step = fine_step;
+        self
+    }
+
+    /// Sets the modifier keys that select the `fine_step`.
+    pub fn fine_keys(mut self, fine_keys: Modifiers) -> Self {
+        self.fine_keys = fine_keys;
+        self
+    }
+
+    /// Returns the ID of the focused parameter, if any.
+    pub fn focused(&self) -> Option<&K> {
+        self.focused.as_ref()
+    }
+
+    /// Focuses the parameter `id`, or clears the focus with `None`.
+    pub fn set_focused(&mut self, id: Option<K>) {
+        self.focused = id;
+    }
+
+    /// Handles a keyboard `event`.
+    ///
+    /// * `event` - an event of the application
+    /// * `value_of` - returns the current value of a parameter
+    ///
+    /// Returns the ID and new value of the focused parameter when an arrow
+    /// key nudges it.
+    pub fn on_event<F>(
+        &mut self,
+        event: &Event,
+        value_of: F,
+    ) -> Option<(K, Normal)>
+    where
+        F: FnOnce(&K) -> Normal,
+    {
+        let (key_code, modifiers) = match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) => (*key_code, *modifiers),
+            _ => return None,
+        };
+
+        let direction = match key_code {
+            KeyCode::Up | KeyCode::Right => 1.0,
+            KeyCode::Down | KeyCode::Left => -1.0,
+            KeyCode::Escape => {
+                self.focused = None;
+                return None;
+            }
+            _ => {
+                // The most specific binding wins, so "Shift + G" is not
+                // shadowed by "G".
+                if let Some((_, id)) = self
+                    .bindings
+                    .iter()
+                    .filter(|(s, _)| {
+                        s.key_code == key_code && modifiers.matches(s.modifiers)
+                    })
+                    .max_by_key(|(s, _)| modifier_count(s.modifiers))
+                {
+                    self.focused = Some(id.clone());
+                }
+
+                return None;
+            }
+        };
+
+        let id = self.focused.clone()?;
+
+        let step = if modifiers.matches(self.fine_keys) {
+            self.fine_step
+        } else {
+            self.step
+        };
+
+        let normal = Normal::from(value_of(&id).as_f32() + direction * step);
+
+        Some((id, normal))
+    }
+}
+
+fn modifier_count(modifiers: Modifiers) -> u8 {
+    modifiers.shift as u8
+        + modifiers.control as u8
+        + modifiers.alt as u8
+        + modifiers.logo as u8
+}
+
+impl<K: Clone + PartialEq> Default for ShortcutMap<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,312 @@
+//! De-zipper a parameter that is changed in steps
+
+use crate::core::Normal;
+
+/// The distance to the target at which one-pole smoothing snaps to it.
+static ONE_POLE_SETTLE: f32 = 0.000_01;
+
+/// How a [`SmoothedNormal`] moves toward its target.
+///
+/// [`SmoothedNormal`]: struct.SmoothedNormal.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Smoothing {
+    /// Ramps linearly to the target in exactly `steps` steps.
+    Linear {
+        /// The number of steps (samples or frames) of the ramp
+        steps: u32,
+    },
+    /// Moves toward the target exponentially, covering about 63% of the
+    /// remaining distance every `steps` steps.
+    OnePole {
+        /// The time constant in steps (samples or frames)
+        steps: f32,
+    },
+}
+
+impl Default for Smoothing {
+    fn default() -> Self {
+        Smoothing::Linear { steps: 64 }
+    }
+}
+
+/// A [`Normal`] that glides toward a target over a number of steps, so a
+/// parameter that changes in jumps (such as from a GUI that updates once
+/// per frame) does not cause zipper noise.
+///
+/// A step can be an audio sample, a block of samples, or an animation
+/// frame of a meter.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{Normal, SmoothedNormal, Smoothing};
+///
+/// let mut gain = SmoothedNormal::new(
+///     Normal::min(),
+///     Smoothing::Linear { steps: 4 },
+/// );
+///
+/// gain.set_target(Normal::max());
+///
+/// assert_eq!(gain.step().as_f32(), 0.25);
+/// assert_eq!(gain.step().as_f32(), 0.5);
+///
+/// gain.skip(2);
+/// assert_eq!(gain.current(), Normal::max());
+/// assert!(!gain.is_smoothing());
+/// ```
+///
+/// [`Normal`]: ../struct.Normal.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SmoothedNormal {
+    smoothing: Smoothing,
+    current: f32,
+    target: f32,
+    increment: f32,
+    steps_left: u32,
+    coeff: f32,
+}
+
+impl SmoothedNormal {
+    /// Creates a new `SmoothedNormal` that starts at `value`.
+    pub fn new(value: Normal, smoothing: Smoothing) -> Self {
+        let mut smoothed = Self {
+            smoothing,
+            current: value.as_f32(),
+            target: value.as_f32(),
+            increment: 0.0,
+            steps_left: 0,
+            coeff: 0.0,
+        };
+
+        smoothed.set_smoothing(smoothing);
+
+        smoothed
+    }
+
+    /// Returns how the value moves toward its target.
+    pub fn smoothing(&self) -> Smoothing {
+        self.smoothing
+    }
+
+    /// Sets how the value moves toward its target. A ramp in progress
+    /// continues with the new setting from where it is.
+    pub fn set_smoothing(&mut self, smoothing: Smoothing) {
+        self.smoothing = smoothing;
+
+        self.coeff = match smoothing {
+            Smoothing::OnePole { steps } if steps > 0.0 => {
+                (-steps.recip()).exp()
+            }
+            _ => 0.0,
+        };
+
+        if self.is_smoothing() {
+            self.start_ramp();
+        }
+    }
+
+    /// Returns the current value.
+    pub fn current(&self) -> Normal {
+        self.current.into()
+    }
+
+    /// Returns the value being moved toward.
+    pub fn target(&self) -> Normal {
+        self.target.into()
+    }
+
+    /// Starts moving toward `target`.
+    pub fn set_target(&mut self, target: Normal) {
+        self.target = target.as_f32();
+        self.start_ramp();
+    }
+
+    /// Jumps to `value` without smoothing, such as when playback starts.
+    pub fn reset(&mut self, value: Normal) {
+        self.current = value.as_f32();
+        self.target = value.as_f32();
+        self.steps_left = 0;
+    }
+
+    /// Returns `true` while the value has not reached the target.
+    pub fn is_smoothing(&self) -> bool {
+        self.current != self.target
+    }
+
+    /// Advances by one step and returns the new value.
+    pub fn step(&mut self) -> Normal {
+        if !self.is_smoothing() {
+            return self.current.into();
+        }
+
+        match self.smoothing {
+            Smoothing::Linear { .. } => {
+                if self.steps_left <= 1 {
+                    self.current = self.target;
+                    self.steps_left = 0;
+                } else {
+                    self.current += self.increment;
+                    self.steps_left -= 1;
+                }
+            }
+            Smoothing::OnePole { .. } => {
+                self.current =
+                    self.target + (self.current - self.target) * self.coeff;
+
+                if (self.current - self.target).abs() < ONE_POLE_SETTLE {
+                    self.current = self.target;
+                }
+            }
+        }
+
+        self.current.into()
+    }
+
+    /// Advances by `steps` steps at once, such as for a block of samples
+    /// that does not need per-sample values.
+    pub fn skip(&mut self, steps: u32) -> Normal {
+        match self.smoothing {
+            Smoothing::Linear { .. } => {
+                if steps >= self.steps_left {
+                    self.current = self.target;
+                    self.steps_left = 0;
+                } else {
+                    self.current += self.increment * steps as f32;
+                    self.steps_left -= steps;
+                }
+            }
+            Smoothing::OnePole { .. } => {
+                self.current = self.target
+                    + (self.current - self.target)
+                        * self.coeff.powi(steps.min(i32::MAX as u32) as i32);
+
+                if (self.current - self.target).abs() < ONE_POLE_SETTLE {
+                    self.current = self.target;
+                }
+            }
+        }
+
+        self.current.into()
+    }
+
+    /// Fills `output` with consecutive steps.
+    pub fn fill(&mut self, output: &mut [f32]) {
+        for value in output.iter_mut() {
+            *value = self.step().as_f32();
+        }
+    }
+
+    fn start_ramp(&mut self) {
+        match self.smoothing {
+            Smoothing::Linear { steps } if steps > 0 => {
+                self.steps_left = steps;
+                self.increment = (self.target - self.current) / steps as f32;
+            }
+            Smoothing::Linear { .. } => {
+                self.current = self.target;
+                self.steps_left = 0;
+            }
+            Smoothing::OnePole { .. } => {
+                if self.coeff == 0.0 {
+                    self.current = self.target;
+                }
+            }
+        }
+    }
+}
+
+impl Default for SmoothedNormal {
+    fn default() -> Self {
+        SmoothedNormal::new(Normal::min(), Smoothing::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linear_converges_in_steps() {
+        let mut smoothed =
+            SmoothedNormal::new(Normal::min(), Smoothing::Linear { steps: 8 });
+        smoothed.set_target(Normal::max());
+
+        for _ in 0..7 {
+            let _ = smoothed.step();
+            assert!(smoothed.is_smoothing());
+        }
+
+        assert_eq!(smoothed.step(), Normal::max());
+        assert!(!smoothed.is_smoothing());
+    }
+
+    #[test]
+    fn one_pole_converges() {
+        let mut smoothed = SmoothedNormal::new(
+            Normal::max(),
+            Smoothing::OnePole { steps: 10.0 },
+        );
+        smoothed.set_target(Normal::min());
+
+        let mut last = smoothed.current();
+        let mut steps = 0;
+        while smoothed.is_smoothing() {
+            let current = smoothed.step();
+            assert!(current < last);
+            last = current;
+
+            steps += 1;
+            assert!(steps < 1000, "one-pole smoothing did not settle");
+        }
+
+        assert_eq!(smoothed.current(), Normal::min());
+    }
+
+    #[test]
+    fn one_pole_skip_matches_steps() {
+        let smoothing = Smoothing::OnePole { steps: 10.0 };
+
+        let mut stepped = SmoothedNormal::new(Normal::min(), smoothing);
+        let mut skipped = stepped;
+        stepped.set_target(Normal::max());
+        skipped.set_target(Normal::max());
+
+        for _ in 0..5 {
+            let _ = stepped.step();
+        }
+        let _ = skipped.skip(5);
+
+        let difference =
+            (stepped.current().as_f32() - skipped.current().as_f32()).abs();
+        assert!(difference < 0.0001);
+    }
+
+    #[test]
+    fn zero_steps_jump_to_target() {
+        for smoothing in &[
+            Smoothing::Linear { steps: 0 },
+            Smoothing::OnePole { steps: 0.0 },
+        ] {
+            let mut smoothed = SmoothedNormal::new(Normal::min(), *smoothing);
+            smoothed.set_target(Normal::center());
+
+            assert_eq!(smoothed.current(), Normal::center());
+            assert!(!smoothed.is_smoothing());
+        }
+    }
+
+    #[test]
+    fn skipping_zero_steps_keeps_value() {
+        for smoothing in &[
+            Smoothing::Linear { steps: 4 },
+            Smoothing::OnePole { steps: 4.0 },
+        ] {
+            let mut smoothed = SmoothedNormal::new(Normal::min(), *smoothing);
+            smoothed.set_target(Normal::max());
+
+            assert_eq!(smoothed.skip(0), Normal::min());
+            assert!(smoothed.is_smoothing());
+        }
+    }
+}
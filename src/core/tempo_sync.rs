@@ -0,0 +1,286 @@
+//! Tempo-synced musical note divisions
+
+use std::fmt;
+
+use crate::core::{Normal, NormalParam};
+
+/// A musical note division, such as the time of a tempo-synced delay or
+/// the period of a tempo-synced LFO.
+///
+/// Bars are assumed to be in 4/4 time, so a bar is four beats long.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NoteDivision {
+    /// A thirty-second note
+    ThirtySecond,
+    /// A sixteenth note triplet
+    SixteenthTriplet,
+    /// A sixteenth note
+    Sixteenth,
+    /// A dotted sixteenth note
+    SixteenthDotted,
+    /// An eighth note triplet
+    EighthTriplet,
+    /// An eighth note
+    Eighth,
+    /// A dotted eighth note
+    EighthDotted,
+    /// A quarter note triplet
+    QuarterTriplet,
+    /// A quarter note
+    Quarter,
+    /// A dotted quarter note
+    QuarterDotted,
+    /// A half note triplet
+    HalfTriplet,
+    /// A half note
+    Half,
+    /// A dotted half note
+    HalfDotted,
+    /// One bar
+    Bar,
+    /// Two bars
+    TwoBars,
+    /// Four bars
+    FourBars,
+}
+
+impl NoteDivision {
+    /// All note divisions, from the shortest to the longest.
+    pub const ALL: [NoteDivision; 16] = [
+        NoteDivision::ThirtySecond,
+        NoteDivision::SixteenthTriplet,
+        NoteDivision::Sixteenth,
+        NoteDivision::SixteenthDotted,
+        NoteDivision::EighthTriplet,
+        NoteDivision::Eighth,
+        NoteDivision::EighthDotted,
+        NoteDivision::QuarterTriplet,
+        NoteDivision::Quarter,
+        NoteDivision::QuarterDotted,
+        NoteDivision::HalfTriplet,
+        NoteDivision::Half,
+        NoteDivision::HalfDotted,
+        NoteDivision::Bar,
+        NoteDivision::TwoBars,
+        NoteDivision::FourBars,
+    ];
+
+    /// Returns the length of the division in beats, where a quarter note
+    /// is one beat.
+    pub fn beats(&self) -> f32 {
+        match self {
+            NoteDivision::ThirtySecond => 0.125,
+            NoteDivision::SixteenthTriplet => 1.0 / 6.0,
+            NoteDivision::Sixteenth => 0.25,
+            NoteDivision::SixteenthDotted => 0.375,
+            NoteDivision::EighthTriplet => 1.0 / 3.0,
+            NoteDivision::Eighth => 0.5,
+            NoteDivision::EighthDotted => 0.75,
+            NoteDivision::QuarterTriplet => 2.0 / 3.0,
+            NoteDivision::Quarter => 1.0,
+            NoteDivision::QuarterDotted => 1.5,
+            NoteDivision::HalfTriplet => 4.0 / 3.0,
+            NoteDivision::Half => 2.0,
+            NoteDivision::HalfDotted => 3.0,
+            NoteDivision::Bar => 4.0,
+            NoteDivision::TwoBars => 8.0,
+            NoteDivision::FourBars => 16.0,
+        }
+    }
+
+    /// Returns the length of the division in seconds at `bpm` beats per
+    /// minute.
+    pub fn seconds(&self, bpm: f32) -> f32 {
+        self.beats() * 60.0 / bpm
+    }
+
+    /// Returns the rate of the division in Hz at `bpm` beats per minute,
+    /// such as the frequency of a tempo-synced LFO.
+    pub fn hz(&self, bpm: f32) -> f32 {
+        bpm / (60.0 * self.beats())
+    }
+}
+
+impl fmt::Display for NoteDivision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            NoteDivision::ThirtySecond => "1/32",
+            NoteDivision::SixteenthTriplet => "1/16T",
+            NoteDivision::Sixteenth => "1/16",
+            NoteDivision::SixteenthDotted => "1/16D",
+            NoteDivision::EighthTriplet => "1/8T",
+            NoteDivision::Eighth => "1/8",
+            NoteDivision::EighthDotted => "1/8D",
+            NoteDivision::QuarterTriplet => "1/4T",
+            NoteDivision::Quarter => "1/4",
+            NoteDivision::QuarterDotted => "1/4D",
+            NoteDivision::HalfTriplet => "1/2T",
+            NoteDivision::Half => "1/2",
+            NoteDivision::HalfDotted => "1/2D",
+            NoteDivision::Bar => "1 bar",
+            NoteDivision::TwoBars => "2 bars",
+            NoteDivision::FourBars => "4 bars",
+        };
+
+        f.write_str(label)
+    }
+}
+
+/// A discrete parameter that steps through a list of [`NoteDivision`]s,
+/// for delay times and LFO rates that are synced to the tempo.
+///
+/// Like an [`IntRange`], this will cause the widget to "step" when moved.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{NoteDivision, TempoSyncParam};
+///
+/// let sync = TempoSyncParam::default();
+///
+/// let param = sync.normal_param(NoteDivision::Eighth, NoteDivision::Quarter);
+/// let division = sync.unmap_to_value(param.value);
+///
+/// assert_eq!(division, NoteDivision::Eighth);
+/// assert_eq!(division.beats(), 0.5);
+/// assert_eq!(division.to_string(), "1/8");
+/// ```
+///
+/// [`NoteDivision`]: enum.NoteDivision.html
+/// [`IntRange`]: ../range/struct.IntRange.html
+#[derive(Debug, Copy, Clone)]
+pub struct TempoSyncParam {
+    divisions: &'static [NoteDivision],
+    span: f32,
+    span_recip: f32,
+}
+
+impl TempoSyncParam {
+    /// Creates a new `TempoSyncParam`
+    ///
+    /// # Arguments
+    ///
+    /// * `divisions` - the divisions to step through, from the shortest
+    ///   to the longest
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `divisions` has less than two elements
+    pub fn new(divisions: &'static [NoteDivision]) -> Self {
+        assert!(divisions.len() >= 2);
+
+        let span = (divisions.len() - 1) as f32;
+
+        Self {
+            divisions,
+            span,
+            span_recip: span.recip(),
+        }
+    }
+
+    /// Returns the divisions this parameter steps through
+    pub fn divisions(&self) -> &'static [NoteDivision] {
+        self.divisions
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this parameter.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The inital value of the parameter.
+    /// * `default_value` - The default value of the parameter.
+    pub fn normal_param(
+        &self,
+        value: NoteDivision,
+        default: NoteDivision,
+    ) -> NormalParam {
+        NormalParam {
+            value: self.map_to_normal(value),
+            default: self.map_to_normal(default),
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this parameter where `value` and `default_value` is a quarter
+    /// note (or the closest division to it).
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn default_normal_param(&self) -> NormalParam {
+        self.normal_param(NoteDivision::Quarter, NoteDivision::Quarter)
+    }
+
+    /// Returns a [`Normal`] that is snapped to the closest division.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn snapped(&self, normal: Normal) -> Normal {
+        self.normal_at(self.index_of_normal(normal))
+    }
+
+    /// Returns the [`Normal`] of the next longer division after the one
+    /// selected by `normal`, or of the longest division
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn increment(&self, normal: Normal) -> Normal {
+        let index = self.index_of_normal(normal) + 1;
+        self.normal_at(index.min(self.divisions.len() - 1))
+    }
+
+    /// Returns the [`Normal`] of the next shorter division before the one
+    /// selected by `normal`, or of the shortest division
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn decrement(&self, normal: Normal) -> Normal {
+        self.normal_at(self.index_of_normal(normal).saturating_sub(1))
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied division.
+    /// A division that is not in the list maps to the closest one in
+    /// length.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_to_normal(&self, value: NoteDivision) -> Normal {
+        let index = self
+            .divisions
+            .iter()
+            .position(|d| *d == value)
+            .unwrap_or_else(|| {
+                let beats = value.beats();
+
+                let mut closest = 0;
+                for (i, d) in self.divisions.iter().enumerate() {
+                    if (d.beats() - beats).abs()
+                        < (self.divisions[closest].beats() - beats).abs()
+                    {
+                        closest = i;
+                    }
+                }
+                closest
+            });
+
+        self.normal_at(index)
+    }
+
+    /// Returns the corresponding division from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_value(&self, normal: Normal) -> NoteDivision {
+        self.divisions[self.index_of_normal(normal)]
+    }
+
+    fn normal_at(&self, index: usize) -> Normal {
+        (index as f32 * self.span_recip).into()
+    }
+
+    fn index_of_normal(&self, normal: Normal) -> usize {
+        ((normal.as_f32() * self.span).round() as usize)
+            .min(self.divisions.len() - 1)
+    }
+}
+
+impl Default for TempoSyncParam {
+    fn default() -> Self {
+        TempoSyncParam::new(&NoteDivision::ALL)
+    }
+}
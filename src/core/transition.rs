@@ -0,0 +1,147 @@
+//! Animate a widget between its active, hovered, and dragging styles
+
+use std::time::{Duration, Instant};
+
+static DEFAULT_DURATION: Duration = Duration::from_millis(120);
+
+/// Tracks how far a widget has moved between its active, hovered, and
+/// dragging styles, so the renderer can blend them instead of switching
+/// instantly.
+///
+/// The widget sets whether it is hovered or pressed as events arrive, and
+/// [`tick`] moves both amounts toward their targets. Each amount travels
+/// from `0.0` to `1.0` over the duration of the transition.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::Transition;
+/// use std::time::{Duration, Instant};
+///
+/// let start = Instant::now();
+/// let mut transition = Transition::new(Duration::from_millis(100));
+///
+/// let _ = transition.tick(start);
+/// transition.set_hovered(true);
+///
+/// assert!(transition.tick(start + Duration::from_millis(50)));
+/// assert_eq!(transition.hover_amount(), 0.5);
+///
+/// // The transition stops once it reaches the hovered style.
+/// assert!(!transition.tick(start + Duration::from_millis(150)));
+/// assert_eq!(transition.hover_amount(), 1.0);
+/// ```
+///
+/// [`tick`]: #method.tick
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Transition {
+    duration: Duration,
+    is_hovered: bool,
+    is_pressed: bool,
+    hover_amount: f32,
+    press_amount: f32,
+    last_tick: Option<Instant>,
+}
+
+impl Transition {
+    /// Creates a new `Transition` that takes `duration` to move from one
+    /// style to the next. A zero `duration` switches styles instantly.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            is_hovered: false,
+            is_pressed: false,
+            hover_amount: 0.0,
+            press_amount: 0.0,
+            last_tick: None,
+        }
+    }
+
+    /// Returns the time it takes to move from one style to the next.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Sets the time it takes to move from one style to the next.
+    pub fn set_duration(&mut self, duration: Duration) {
+        self.duration = duration;
+    }
+
+    /// Sets whether the mouse is over the widget.
+    pub fn set_hovered(&mut self, is_hovered: bool) {
+        self.is_hovered = is_hovered;
+    }
+
+    /// Sets whether the widget is being dragged.
+    pub fn set_pressed(&mut self, is_pressed: bool) {
+        self.is_pressed = is_pressed;
+    }
+
+    /// Returns how far the widget is toward its hovered style, from `0.0`
+    /// to `1.0`.
+    pub fn hover_amount(&self) -> f32 {
+        self.hover_amount
+    }
+
+    /// Returns how far the widget is toward its dragging style, from `0.0`
+    /// to `1.0`.
+    pub fn press_amount(&self) -> f32 {
+        self.press_amount
+    }
+
+    /// Returns `true` while either amount has not reached its target.
+    pub fn is_animating(&self) -> bool {
+        self.hover_amount != target(self.is_hovered)
+            || self.press_amount != target(self.is_pressed)
+    }
+
+    /// Advances the transition to the time `now`.
+    ///
+    /// Returns `true` while the transition is still animating. Call this
+    /// periodically (for example from a timer subscription) while it
+    /// returns `true`.
+    pub fn tick(&mut self, now: Instant) -> bool {
+        let seconds = match self.last_tick {
+            Some(last_tick) => {
+                now.saturating_duration_since(last_tick).as_secs_f32()
+            }
+            None => 0.0,
+        };
+        self.last_tick = Some(now);
+
+        let travel = if self.duration == Duration::from_secs(0) {
+            1.0
+        } else {
+            seconds / self.duration.as_secs_f32()
+        };
+
+        self.hover_amount = step(self.hover_amount, self.is_hovered, travel);
+        self.press_amount = step(self.press_amount, self.is_pressed, travel);
+
+        self.is_animating()
+    }
+}
+
+impl Default for Transition {
+    fn default() -> Self {
+        Transition::new(DEFAULT_DURATION)
+    }
+}
+
+#[inline]
+fn target(is_on: bool) -> f32 {
+    if is_on {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+#[inline]
+fn step(amount: f32, is_on: bool, travel: f32) -> f32 {
+    if is_on {
+        (amount + travel).min(1.0)
+    } else {
+        (amount - travel).max(0.0)
+    }
+}
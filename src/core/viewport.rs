@@ -0,0 +1,244 @@
+//! A zoomable and scrollable view into a timeline
+
+use iced_native::{event, keyboard, mouse, Point, Rectangle};
+
+/// Roughly how many pixels a touchpad scrolls per line of a mouse wheel.
+const PIXELS_PER_LINE: f32 = 20.0;
+
+/// The mouse gestures that zoom and scroll a [`Viewport`].
+///
+/// [`Viewport`]: struct.Viewport.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ViewportGestures {
+    /// The modifier keys that make the vertical mouse wheel zoom around the
+    /// cursor instead of scrolling.
+    ///
+    /// The default is `Ctrl`.
+    pub zoom_keys: keyboard::Modifiers,
+    /// How much one line of the mouse wheel zooms in or out.
+    ///
+    /// The default is `1.25`.
+    pub zoom_step: f32,
+    /// How far one line of the mouse wheel scrolls, as a fraction of the
+    /// visible range.
+    ///
+    /// The default is `0.1`.
+    pub scroll_step: f32,
+    /// The mouse button that pans the view while it is held down. Set this
+    /// to `None` to disable panning.
+    ///
+    /// The default is `Some(mouse::Button::Middle)`.
+    pub pan_button: Option<mouse::Button>,
+    /// The maximum zoom, where `1.0` shows the whole timeline and `2.0`
+    /// shows half of it.
+    ///
+    /// The default is `64.0`.
+    pub max_zoom: f32,
+}
+
+impl Default for ViewportGestures {
+    fn default() -> Self {
+        Self {
+            zoom_keys: keyboard::Modifiers {
+                control: true,
+                ..Default::default()
+            },
+            zoom_step: 1.25,
+            scroll_step: 0.1,
+            pan_button: Some(mouse::Button::Middle),
+            max_zoom: 64.0,
+        }
+    }
+}
+
+/// The visible range of a timeline, shared by every widget that can be
+/// zoomed and scrolled.
+///
+/// Positions on the timeline are fractions of its whole length, from `0.0`
+/// to `1.0`.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::Viewport;
+///
+/// let mut viewport = Viewport::new();
+///
+/// // Zoom in 4x around the middle of the view.
+/// viewport.zoom_at(4.0, 0.5);
+///
+/// assert_eq!(viewport.visible_range(), (0.375, 0.625));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Viewport {
+    zoom: f32,
+    offset: f32,
+    pan_origin: Option<(f32, f32)>,
+}
+
+impl Viewport {
+    /// Creates a new [`Viewport`] that shows the whole timeline.
+    ///
+    /// [`Viewport`]: struct.Viewport.html
+    pub fn new() -> Self {
+        Self {
+            zoom: 1.0,
+            offset: 0.0,
+            pan_origin: None,
+        }
+    }
+
+    /// Returns the zoom, where `1.0` shows the whole timeline and `2.0`
+    /// shows half of it.
+    pub fn zoom(&self) -> f32 {
+        self.zoom
+    }
+
+    /// Sets the zoom, keeping the start of the visible range in place. The
+    /// zoom is clamped to be at least `1.0`.
+    pub fn set_zoom(&mut self, zoom: f32) {
+        self.zoom = zoom.max(1.0);
+        self.set_offset(self.offset);
+    }
+
+    /// Returns the start of the visible range.
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+
+    /// Scrolls so that the visible range starts at `offset`. The offset is
+    /// clamped so the visible range stays inside the timeline.
+    pub fn set_offset(&mut self, offset: f32) {
+        let max_offset = 1.0 - self.visible_len();
+
+        self.offset = if offset.is_nan() {
+            0.0
+        } else {
+            offset.max(0.0).min(max_offset)
+        };
+    }
+
+    /// Returns the `(start, end)` of the visible range.
+    pub fn visible_range(&self) -> (f32, f32) {
+        (self.offset, self.offset + self.visible_len())
+    }
+
+    /// Returns the length of the visible range.
+    pub fn visible_len(&self) -> f32 {
+        1.0 / self.zoom
+    }
+
+    /// Zooms by `factor` while keeping the point at `anchor` in place, where
+    /// `anchor` is a position in the view from `0.0` (left) to `1.0` (right).
+    pub fn zoom_at(&mut self, factor: f32, anchor: f32) {
+        let anchor_pos = self.offset + anchor * self.visible_len();
+
+        self.zoom = (self.zoom * factor).max(1.0);
+
+        self.set_offset(anchor_pos - anchor * self.visible_len());
+    }
+
+    /// Scrolls by `amount`, as a fraction of the visible range. Positive
+    /// amounts scroll towards the end of the timeline.
+    pub fn scroll_by(&mut self, amount: f32) {
+        self.set_offset(self.offset + amount * self.visible_len());
+    }
+
+    /// Returns `true` while the view is being panned with the pan button.
+    pub fn is_panning(&self) -> bool {
+        self.pan_origin.is_some()
+    }
+
+    /// Zooms and scrolls the view in response to a mouse `event`.
+    ///
+    /// * `bounds` - the bounds of the widget showing the view
+    /// * `cursor_position` - the current cursor position
+    /// * `gestures` - the [`ViewportGestures`] to respond to
+    /// * `modifiers` - the modifier keys that are currently held down
+    ///
+    /// [`ViewportGestures`]: struct.ViewportGestures.html
+    pub(crate) fn on_mouse_event(
+        &mut self,
+        event: mouse::Event,
+        bounds: Rectangle,
+        cursor_position: Point,
+        gestures: &ViewportGestures,
+        modifiers: keyboard::Modifiers,
+    ) -> event::Status {
+        if bounds.width <= 0.0 {
+            return event::Status::Ignored;
+        }
+
+        match event {
+            mouse::Event::WheelScrolled { delta } => {
+                if !bounds.contains(cursor_position) {
+                    return event::Status::Ignored;
+                }
+
+                let (x, y) = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => (x, y),
+                    mouse::ScrollDelta::Pixels { x, y } => {
+                        (x / PIXELS_PER_LINE, y / PIXELS_PER_LINE)
+                    }
+                };
+
+                if x == 0.0 && y == 0.0 {
+                    return event::Status::Ignored;
+                }
+
+                if y != 0.0 && modifiers.matches(gestures.zoom_keys) {
+                    let anchor = (cursor_position.x - bounds.x) / bounds.width;
+
+                    let zoom = (self.zoom * gestures.zoom_step.powf(y))
+                        .min(gestures.max_zoom.max(1.0));
+
+                    self.zoom_at(zoom / self.zoom, anchor);
+                } else {
+                    // Wheel up scrolls back, and a rightward swipe scrolls
+                    // forward.
+                    self.scroll_by((x - y) * gestures.scroll_step);
+                }
+
+                event::Status::Captured
+            }
+            mouse::Event::ButtonPressed(button)
+                if Some(button) == gestures.pan_button =>
+            {
+                if bounds.contains(cursor_position) {
+                    self.pan_origin = Some((cursor_position.x, self.offset));
+
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            mouse::Event::CursorMoved { .. } => {
+                if let Some((origin_x, origin_offset)) = self.pan_origin {
+                    let moved = (cursor_position.x - origin_x) / bounds.width;
+
+                    // The content follows the cursor.
+                    self.set_offset(origin_offset - moved * self.visible_len());
+
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            mouse::Event::ButtonReleased(button)
+                if Some(button) == gestures.pan_button
+                    && self.pan_origin.is_some() =>
+            {
+                self.pan_origin = None;
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+}
+
+impl Default for Viewport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
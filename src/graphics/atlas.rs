@@ -0,0 +1,40 @@
+//! Draw images from a texture atlas
+
+use iced_graphics::Primitive;
+use iced_native::{image, Rectangle, Vector};
+
+use crate::style::atlas::AtlasRegion;
+
+/// Draws the image `handle` in `bounds`. If `region` is set, only that
+/// region of the image is drawn, stretched to fill `bounds`.
+pub(crate) fn draw_image(
+    handle: image::Handle,
+    region: Option<AtlasRegion>,
+    bounds: Rectangle,
+) -> Primitive {
+    let region = match region {
+        Some(region) if region.rect.width > 0.0 && region.rect.height > 0.0 => {
+            region
+        }
+        _ => return Primitive::Image { handle, bounds },
+    };
+
+    let scale_x = bounds.width / region.rect.width;
+    let scale_y = bounds.height / region.rect.height;
+
+    // The whole atlas is drawn scaled, and the clip leaves only the region
+    // visible.
+    Primitive::Clip {
+        bounds,
+        offset: Vector::new(0, 0),
+        content: Box::new(Primitive::Image {
+            handle,
+            bounds: Rectangle {
+                x: bounds.x - (region.rect.x * scale_x),
+                y: bounds.y - (region.rect.y * scale_y),
+                width: region.atlas_size.width * scale_x,
+                height: region.atlas_size.height * scale_y,
+            },
+        }),
+    }
+}
@@ -0,0 +1,68 @@
+//! Display a clip indicator LED that stays lit until it is clicked.
+
+use crate::native::clip_indicator;
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Color, Rectangle};
+
+pub use crate::native::clip_indicator::State;
+pub use crate::style::clip_indicator::{Style, StyleSheet};
+
+/// A small LED that lights up when the signal clips, and stays lit until
+/// the user clicks it.
+pub type ClipIndicator<'a, Message, Backend> =
+    clip_indicator::ClipIndicator<'a, Message, Renderer<Backend>>;
+
+impl<B: Backend> clip_indicator::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        lit: bool,
+        since_clip: f32,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        let color = if !lit {
+            style.off_color
+        } else if let Some(flash_color) = style.flash_color {
+            let fade = if style.flash_decay > 0.0 {
+                (since_clip / style.flash_decay).min(1.0)
+            } else {
+                1.0
+            };
+
+            mix(flash_color, style.on_color, fade)
+        } else {
+            style.on_color
+        };
+
+        (
+            Primitive::Quad {
+                bounds,
+                background: Background::Color(color),
+                border_radius: style.border_radius,
+                border_width: style.border_width,
+                border_color: style.border_color,
+            },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+fn mix(a: Color, b: Color, amount: f32) -> Color {
+    Color {
+        r: a.r + (b.r - a.r) * amount,
+        g: a.g + (b.g - a.g) * amount,
+        b: a.b + (b.b - a.b) * amount,
+        a: a.a + (b.a - a.a) * amount,
+    }
+}
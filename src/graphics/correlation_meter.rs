@@ -0,0 +1,111 @@
+//! Display a scrolling history of stereo phase correlation values.
+
+use std::collections::VecDeque;
+
+use crate::core::Normal;
+use crate::native::correlation_meter;
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Color, Rectangle};
+
+pub use crate::native::correlation_meter::State;
+pub use crate::style::correlation_meter::{Style, StyleSheet};
+
+/// A meter that displays a scrolling history strip of stereo phase
+/// correlation values.
+pub type CorrelationMeter<'a, Backend> =
+    correlation_meter::CorrelationMeter<'a, Renderer<Backend>>;
+
+impl<B: Backend> correlation_meter::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        history: &VecDeque<Normal>,
+        capacity: usize,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        let mut primitives = Vec::with_capacity(history.len() + 2);
+
+        primitives.push(Primitive::Quad {
+            bounds,
+            background: Background::Color(style.back_color),
+            border_radius: 0.0,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        });
+
+        let inner = Rectangle {
+            x: bounds.x + style.back_border_width,
+            y: bounds.y + style.back_border_width,
+            width: bounds.width - (style.back_border_width * 2.0),
+            height: bounds.height - (style.back_border_width * 2.0),
+        };
+
+        let center_y = inner.y + inner.height / 2.0;
+        let column_width = inner.width / capacity.max(1) as f32;
+
+        // The newest value is at the right edge.
+        let first_x =
+            inner.x + inner.width - history.len() as f32 * column_width;
+
+        for (i, correlation) in history.iter().enumerate() {
+            let value = correlation.as_f32();
+            if value == 0.5 {
+                continue;
+            }
+
+            let x = first_x + i as f32 * column_width;
+            let next_x = first_x + (i + 1) as f32 * column_width;
+            let y = inner.y + (1.0 - value) * inner.height;
+
+            let (top, bottom, color) = if value > 0.5 {
+                (y, center_y, style.positive_color)
+            } else {
+                (center_y, y, style.negative_color)
+            };
+
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: x.floor(),
+                    y: top,
+                    width: next_x.floor() - x.floor(),
+                    height: bottom - top,
+                },
+                background: Background::Color(color),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            });
+        }
+
+        if let Some(color) = style.center_line_color {
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: inner.x,
+                    y: (center_y - style.center_line_width / 2.0).round(),
+                    width: inner.width,
+                    height: style.center_line_width,
+                },
+                background: Background::Color(color),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            });
+        }
+
+        (
+            Primitive::Group { primitives },
+            mouse::Interaction::default(),
+        )
+    }
+}
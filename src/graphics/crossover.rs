@@ -0,0 +1,116 @@
+//! Display a frequency rail with draggable split points, such as the
+//! crossover frequencies of a multiband compressor or EQ.
+
+use crate::core::NormalParam;
+use crate::graphics::pixel_snap::snap_floor;
+use crate::native::crossover;
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Color, Point, Rectangle};
+
+pub use crate::native::crossover::State;
+pub use crate::style::crossover::{HandleStyle, Style, StyleSheet};
+
+/// A rail with draggable split points that divide it into bands.
+pub type Crossover<'a, Message, Backend> =
+    crossover::Crossover<'a, Message, Renderer<Backend>>;
+
+impl<B: Backend> crossover::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        _cursor_position: Point,
+        splits: &[NormalParam],
+        hovered: Option<usize>,
+        dragging: Option<usize>,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+
+        let bounds = Rectangle {
+            x: snap_floor(bounds.x),
+            y: snap_floor(bounds.y),
+            width: snap_floor(bounds.width),
+            height: snap_floor(bounds.height),
+        };
+
+        let back = Primitive::Quad {
+            bounds,
+            background: Background::Color(style.back_color),
+            border_radius: 0.0,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        };
+
+        let split_x =
+            |normal: f32| snap_floor(bounds.x + normal * bounds.width);
+
+        let rail_height = style.rail_height.min(bounds.height);
+        let rail_y = snap_floor(bounds.center_y() - rail_height / 2.0);
+
+        let edges: Vec<f32> = std::iter::once(bounds.x)
+            .chain(splits.iter().map(|split| split_x(split.value.as_f32())))
+            .chain(std::iter::once(bounds.x + bounds.width))
+            .collect();
+
+        let bands = edges
+            .windows(2)
+            .enumerate()
+            .map(|(index, edge)| Primitive::Quad {
+                bounds: Rectangle {
+                    x: edge[0],
+                    y: rail_y,
+                    width: edge[1] - edge[0],
+                    height: rail_height,
+                },
+                background: Background::Color(style.band_color(index)),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            })
+            .collect();
+
+        let handles = splits
+            .iter()
+            .enumerate()
+            .map(|(index, split)| {
+                let handle_style = if Some(index) == dragging {
+                    style_sheet.dragging_handle()
+                } else if Some(index) == hovered {
+                    style_sheet.hovered_handle()
+                } else {
+                    style_sheet.handle()
+                };
+
+                let x = split_x(split.value.as_f32());
+
+                Primitive::Quad {
+                    bounds: Rectangle {
+                        x: x - snap_floor(handle_style.width / 2.0),
+                        y: bounds.y,
+                        width: handle_style.width,
+                        height: bounds.height,
+                    },
+                    background: Background::Color(handle_style.color),
+                    border_radius: handle_style.border_radius,
+                    border_width: handle_style.border_width,
+                    border_color: handle_style.border_color,
+                }
+            })
+            .collect();
+
+        (
+            Primitive::Group {
+                primitives: vec![
+                    back,
+                    Primitive::Group { primitives: bands },
+                    Primitive::Group {
+                        primitives: handles,
+                    },
+                ],
+            },
+            mouse::Interaction::default(),
+        )
+    }
+}
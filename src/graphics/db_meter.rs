@@ -0,0 +1,375 @@
+//! Display a meter of decibel levels with a peak-hold line.
+
+use crate::core::{Normal, Orientation};
+use crate::native::db_meter;
+use iced_graphics::triangle::{Mesh2D, Vertex2D};
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Color, Rectangle, Size, Vector};
+
+pub use crate::native::db_meter::{Channel, State};
+pub use crate::style::db_meter::{Style, StyleSheet};
+
+/// A meter that displays decibel levels, with a peak-hold line.
+pub type DBMeter<'a, Backend> = db_meter::DBMeter<'a, Renderer<Backend>>;
+
+impl<B: Backend> db_meter::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        orientation: Orientation,
+        left: &Channel,
+        right: Option<&Channel>,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        let mut primitives = Vec::with_capacity(12);
+
+        primitives.push(Primitive::Quad {
+            bounds,
+            background: Background::Color(style.back_color),
+            border_radius: 0.0,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        });
+
+        let inner = Rectangle {
+            x: bounds.x + style.back_border_width,
+            y: bounds.y + style.back_border_width,
+            width: bounds.width - (style.back_border_width * 2.0),
+            height: bounds.height - (style.back_border_width * 2.0),
+        };
+
+        let meter = Meter {
+            bounds: inner,
+            orientation,
+        };
+
+        if let Some(right) = right {
+            // The left channel is on the left of a vertical meter, and on
+            // top of a horizontal one.
+            let (left_meter, right_meter) = meter.split(style.channel_gap);
+
+            draw_bar(&mut primitives, &left_meter, left, &style);
+            draw_bar(&mut primitives, &right_meter, right, &style);
+
+            if style.shared_peak_line {
+                let peak = if right.peak().as_f32() > left.peak().as_f32() {
+                    right.peak()
+                } else {
+                    left.peak()
+                };
+
+                draw_peak_line(&mut primitives, &meter, peak, &style);
+            } else {
+                draw_peak_line(
+                    &mut primitives,
+                    &left_meter,
+                    left.peak(),
+                    &style,
+                );
+                draw_peak_line(
+                    &mut primitives,
+                    &right_meter,
+                    right.peak(),
+                    &style,
+                );
+            }
+        } else {
+            draw_bar(&mut primitives, &meter, left, &style);
+            draw_peak_line(&mut primitives, &meter, left.peak(), &style);
+        }
+
+        (
+            Primitive::Group { primitives },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+/// The area of one bar of a meter, and the direction it fills in.
+struct Meter {
+    bounds: Rectangle,
+    orientation: Orientation,
+}
+
+impl Meter {
+    /// Splits the meter across its axis into two bars with a `gap` between
+    /// them.
+    fn split(&self, gap: f32) -> (Meter, Meter) {
+        let bounds = self.bounds;
+
+        let (first, second) = match self.orientation {
+            Orientation::Vertical => {
+                let width = ((bounds.width - gap) / 2.0).floor();
+                (
+                    Rectangle { width, ..bounds },
+                    Rectangle {
+                        x: bounds.x + bounds.width - width,
+                        width,
+                        ..bounds
+                    },
+                )
+            }
+            Orientation::Horizontal => {
+                let height = ((bounds.height - gap) / 2.0).floor();
+                (
+                    Rectangle { height, ..bounds },
+                    Rectangle {
+                        y: bounds.y + bounds.height - height,
+                        height,
+                        ..bounds
+                    },
+                )
+            }
+        };
+
+        (
+            Meter {
+                bounds: first,
+                orientation: self.orientation,
+            },
+            Meter {
+                bounds: second,
+                orientation: self.orientation,
+            },
+        )
+    }
+
+    /// Returns the length of the meter along its axis.
+    fn length(&self) -> f32 {
+        match self.orientation {
+            Orientation::Vertical => self.bounds.height,
+            Orientation::Horizontal => self.bounds.width,
+        }
+    }
+
+    /// Returns the distance in pixels from the start of the meter to
+    /// `position`.
+    fn offset_at(&self, position: f32) -> f32 {
+        (position * self.length()).round()
+    }
+
+    /// Returns the part of the meter from `start` to `end`, or `None` if it
+    /// is empty.
+    fn span(&self, start: f32, end: f32) -> Option<Rectangle> {
+        let start = self.offset_at(start);
+        let end = self.offset_at(end);
+
+        if end <= start {
+            return None;
+        }
+
+        let bounds = self.bounds;
+
+        Some(match self.orientation {
+            Orientation::Vertical => Rectangle {
+                x: bounds.x,
+                y: bounds.y + bounds.height - end,
+                width: bounds.width,
+                height: end - start,
+            },
+            Orientation::Horizontal => Rectangle {
+                x: bounds.x + start,
+                y: bounds.y,
+                width: end - start,
+                height: bounds.height,
+            },
+        })
+    }
+
+    /// Returns a line of `thickness` pixels across the meter, centered on
+    /// `position` but kept inside the meter.
+    fn line(&self, position: f32, thickness: f32) -> Rectangle {
+        let offset = (self.offset_at(position) - thickness / 2.0)
+            .max(0.0)
+            .min(self.length() - thickness);
+
+        let bounds = self.bounds;
+
+        match self.orientation {
+            Orientation::Vertical => Rectangle {
+                x: bounds.x,
+                y: bounds.y + bounds.height - offset - thickness,
+                width: bounds.width,
+                height: thickness,
+            },
+            Orientation::Horizontal => Rectangle {
+                x: bounds.x + offset,
+                y: bounds.y,
+                width: thickness,
+                height: bounds.height,
+            },
+        }
+    }
+}
+
+fn draw_bar(
+    primitives: &mut Vec<Primitive>,
+    meter: &Meter,
+    channel: &Channel,
+    style: &Style,
+) {
+    let normal = if let Some(peak_bar_color) = style.peak_bar_color {
+        if let Some(bounds) = meter.span(0.0, channel.normal().as_f32()) {
+            primitives.push(Primitive::Quad {
+                bounds,
+                background: Background::Color(peak_bar_color),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            });
+        }
+
+        channel.rms()
+    } else {
+        channel.normal()
+    };
+
+    if style.gradient {
+        draw_gradient_bar(primitives, meter, normal, style);
+        return;
+    }
+
+    let value = normal.as_f32();
+
+    let zones = [
+        (0.0, style.med_start, style.low_color),
+        (style.med_start, style.high_start, style.med_color),
+        (style.high_start, style.clip_start, style.high_color),
+        (style.clip_start, 1.0, style.clip_color),
+    ];
+
+    for (start, end, color) in zones.iter() {
+        if let Some(bounds) = meter.span(*start, end.min(value)) {
+            primitives.push(Primitive::Quad {
+                bounds,
+                background: Background::Color(*color),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            });
+        }
+    }
+}
+
+fn draw_gradient_bar(
+    primitives: &mut Vec<Primitive>,
+    meter: &Meter,
+    normal: Normal,
+    style: &Style,
+) {
+    let bounds = meter.bounds;
+
+    let value = normal.as_f32();
+    if value <= 0.0 || bounds.width <= 0.0 || bounds.height <= 0.0 {
+        return;
+    }
+
+    let stops = [
+        (0.0, style.low_color),
+        (style.med_start, style.med_color),
+        (style.high_start, style.high_color),
+        (style.clip_start, style.clip_color),
+        (1.0, style.clip_color),
+    ];
+
+    // Each row is an edge of the strip across the meter, from the start up
+    // to the end of the bar.
+    let mut rows: Vec<(f32, [f32; 4])> = Vec::with_capacity(stops.len() + 1);
+
+    for (i, (position, color)) in stops.iter().enumerate() {
+        if *position >= value {
+            let (prev_position, prev_color) = stops[i - 1];
+            let span = position - prev_position;
+            let amount = if span > 0.0 {
+                (value - prev_position) / span
+            } else {
+                1.0
+            };
+
+            rows.push((value, mix(prev_color, *color, amount)));
+            break;
+        }
+
+        rows.push((*position, color.into_linear()));
+    }
+
+    let mut vertices = Vec::with_capacity(rows.len() * 2);
+    let mut indices = Vec::with_capacity((rows.len() - 1) * 6);
+
+    for (position, color) in rows.iter() {
+        let offset = meter.offset_at(*position);
+
+        let (a, b) = match meter.orientation {
+            Orientation::Vertical => {
+                let y = bounds.height - offset;
+                ([0.0, y], [bounds.width, y])
+            }
+            Orientation::Horizontal => ([offset, 0.0], [offset, bounds.height]),
+        };
+
+        vertices.push(Vertex2D {
+            position: a,
+            color: *color,
+        });
+        vertices.push(Vertex2D {
+            position: b,
+            color: *color,
+        });
+    }
+
+    for row in 0..(rows.len() as u32 - 1) {
+        let i = row * 2;
+        indices.extend_from_slice(&[i, i + 1, i + 2, i + 1, i + 3, i + 2]);
+    }
+
+    primitives.push(Primitive::Translate {
+        translation: Vector::new(bounds.x, bounds.y),
+        content: Box::new(Primitive::Mesh2D {
+            buffers: Mesh2D { vertices, indices },
+            size: Size::new(bounds.width, bounds.height),
+        }),
+    });
+}
+
+/// Blends two colors in linear RGBA.
+fn mix(a: Color, b: Color, amount: f32) -> [f32; 4] {
+    let a = a.into_linear();
+    let b = b.into_linear();
+
+    [
+        a[0] + (b[0] - a[0]) * amount,
+        a[1] + (b[1] - a[1]) * amount,
+        a[2] + (b[2] - a[2]) * amount,
+        a[3] + (b[3] - a[3]) * amount,
+    ]
+}
+
+fn draw_peak_line(
+    primitives: &mut Vec<Primitive>,
+    meter: &Meter,
+    peak: Normal,
+    style: &Style,
+) {
+    if style.peak_line_width <= 0.0 || peak.as_f32() <= 0.0 {
+        return;
+    }
+
+    primitives.push(Primitive::Quad {
+        bounds: meter.line(peak.as_f32(), style.peak_line_width),
+        background: Background::Color(style.peak_line_color),
+        border_radius: 0.0,
+        border_width: 0.0,
+        border_color: Color::TRANSPARENT,
+    });
+}
@@ -0,0 +1,246 @@
+//! A cache that lets a widget skip regenerating its primitives.
+
+use iced_graphics::Primitive;
+use iced_native::{mouse, Color, Rectangle};
+use std::cell::{Cell, RefCell};
+use std::fmt::{self, Write};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use crate::core::{ModulationRange, Normal, Transition};
+use crate::graphics::pixel_snap;
+use crate::native::{text_marks, tick_marks};
+
+#[derive(Clone)]
+struct DrawCacheData {
+    pub cache: Arc<Primitive>,
+    pub interaction: mouse::Interaction,
+
+    pub revision: u64,
+    pub bounds: Rectangle,
+    pub is_mouse_over: bool,
+}
+
+impl std::fmt::Debug for DrawCacheData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "")
+    }
+}
+
+/// A cache for the primitives of a whole widget.
+///
+/// The primitives are only regenerated when the revision of the widget's
+/// state, its bounds, or whether the mouse is over it has changed since the
+/// previous frame.
+///
+/// The cache also records whether the latest draw regenerated the
+/// primitives, and if so the bounds of the widget. This invalidates the
+/// widget as a whole, and can be used to decide which widgets of a window
+/// need to be redrawn. Widgets without a `DrawCache`, such as the meters,
+/// are regenerated on every draw.
+#[derive(Debug, Clone, Default)]
+pub struct DrawCache {
+    data: RefCell<Option<DrawCacheData>>,
+    last_bounds: Cell<Option<Rectangle>>,
+    invalidated_bounds: Cell<Option<Rectangle>>,
+}
+
+impl DrawCache {
+    /// Cache and retrieve the primitives of a widget.
+    ///
+    /// * `revision` - The revision of the widget's state. If this is `None`,
+    ///   then caching is disabled and `builder` is always called.
+    /// * `bounds` - The bounds of the widget.
+    /// * `is_mouse_over` - Whether the mouse is over the widget.
+    /// * `builder` - Generates the primitives of the widget.
+    pub fn cached<F: FnOnce() -> (Primitive, mouse::Interaction)>(
+        &self,
+        revision: Option<u64>,
+        bounds: Rectangle,
+        is_mouse_over: bool,
+        builder: F,
+    ) -> (Primitive, mouse::Interaction) {
+        let revision = if let Some(revision) = revision {
+            revision
+        } else {
+            *self.data.borrow_mut() = None;
+            self.invalidate(bounds);
+            return builder();
+        };
+
+        let mut data = self.data.borrow_mut();
+
+        let is_valid = if let Some(data) = &*data {
+            data.revision == revision
+                && data.bounds == bounds
+                && data.is_mouse_over == is_mouse_over
+        } else {
+            false
+        };
+
+        if is_valid {
+            self.invalidated_bounds.set(None);
+        } else {
+            self.invalidate(bounds);
+
+            let (primitive, interaction) = builder();
+
+            *data = Some(DrawCacheData {
+                cache: Arc::new(primitive),
+                interaction,
+                revision,
+                bounds,
+                is_mouse_over,
+            });
+        }
+
+        let data = data.as_ref().unwrap();
+
+        (
+            Primitive::Cached {
+                cache: Arc::clone(&data.cache),
+            },
+            data.interaction,
+        )
+    }
+
+    /// Returns the bounds of the widget if its primitives were regenerated
+    /// in the latest draw, or `None` if the cached primitives were reused
+    /// unchanged.
+    ///
+    /// This is the whole widget, joined with its old bounds if it has moved
+    /// or been resized. It is always `Some` while caching is disabled.
+    pub fn invalidated_bounds(&self) -> Option<Rectangle> {
+        self.invalidated_bounds.get()
+    }
+
+    fn invalidate(&self, bounds: Rectangle) {
+        let invalidated_bounds = match self.last_bounds.replace(Some(bounds)) {
+            Some(last_bounds) if last_bounds != bounds => {
+                union(last_bounds, bounds)
+            }
+            _ => bounds,
+        };
+
+        self.invalidated_bounds.set(Some(invalidated_bounds));
+    }
+}
+
+/// Returns the smallest rectangle that contains both `a` and `b`.
+fn union(a: Rectangle, b: Rectangle) -> Rectangle {
+    let x = a.x.min(b.x);
+    let y = a.y.min(b.y);
+
+    Rectangle {
+        x,
+        y,
+        width: (a.x + a.width).max(b.x + b.width) - x,
+        height: (a.y + a.height).max(b.y + b.height) - y,
+    }
+}
+
+/// Combines everything that affects the appearance of a widget into a single
+/// revision for a [`DrawCache`].
+///
+/// [`DrawCache`]: struct.DrawCache.html
+pub(crate) struct Revision {
+    hasher: iced_native::Hasher,
+}
+
+impl Revision {
+    /// Starts a new revision from the revision counter of a widget's state.
+    ///
+    /// The scale factor used to snap shapes to physical pixels is included,
+    /// so changing it regenerates the primitives.
+    pub fn new(state_revision: u64) -> Self {
+        let mut hasher = iced_native::Hasher::default();
+        state_revision.hash(&mut hasher);
+        pixel_snap::scale_factor().to_bits().hash(&mut hasher);
+
+        Self { hasher }
+    }
+
+    pub fn normal(mut self, normal: Normal) -> Self {
+        normal.as_f32().to_bits().hash(&mut self.hasher);
+        self
+    }
+
+    pub fn optional_normal(mut self, normal: Option<Normal>) -> Self {
+        normal.map(|n| n.as_f32().to_bits()).hash(&mut self.hasher);
+        self
+    }
+
+    pub fn flag(mut self, flag: bool) -> Self {
+        flag.hash(&mut self.hasher);
+        self
+    }
+
+    pub fn color(mut self, color: Option<Color>) -> Self {
+        if let Some(color) = color {
+            for component in &color.into_linear() {
+                component.to_bits().hash(&mut self.hasher);
+            }
+        } else {
+            0u8.hash(&mut self.hasher);
+        }
+        self
+    }
+
+    pub fn mod_range(mut self, mod_range: Option<&ModulationRange>) -> Self {
+        if let Some(mod_range) = mod_range {
+            mod_range.start.as_f32().to_bits().hash(&mut self.hasher);
+            mod_range.end.as_f32().to_bits().hash(&mut self.hasher);
+            mod_range.filled_visible.hash(&mut self.hasher);
+        } else {
+            0u8.hash(&mut self.hasher);
+        }
+        self
+    }
+
+    pub fn transition(mut self, transition: Option<&Transition>) -> Self {
+        if let Some(transition) = transition {
+            transition.hover_amount().to_bits().hash(&mut self.hasher);
+            transition.press_amount().to_bits().hash(&mut self.hasher);
+        } else {
+            0u8.hash(&mut self.hasher);
+        }
+        self
+    }
+
+    pub fn tick_marks(
+        mut self,
+        tick_marks: Option<&tick_marks::Group>,
+    ) -> Self {
+        tick_marks.map(|t| t.hashed()).hash(&mut self.hasher);
+        self
+    }
+
+    pub fn text_marks(
+        mut self,
+        text_marks: Option<&text_marks::Group>,
+    ) -> Self {
+        text_marks.map(|t| t.hashed()).hash(&mut self.hasher);
+        self
+    }
+
+    /// Hashes a style through its `Debug` output, since the styles contain
+    /// floats and can't implement `Hash`.
+    pub fn style(mut self, style: &impl fmt::Debug) -> Self {
+        let _ = write!(HashWriter(&mut self.hasher), "{:?}", style);
+        self
+    }
+
+    pub fn finish(&self) -> u64 {
+        self.hasher.finish()
+    }
+}
+
+/// Feeds formatted text straight into a hasher without allocating.
+struct HashWriter<'a>(&'a mut iced_native::Hasher);
+
+impl Write for HashWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.write(s.as_bytes());
+        Ok(())
+    }
+}
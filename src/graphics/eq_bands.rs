@@ -0,0 +1,248 @@
+//! Display draggable EQ band nodes and their response curves on top of a
+//! spectrum.
+
+use crate::native::eq_bands;
+use iced_graphics::canvas::{Fill, Frame, Path, Stroke};
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Color, Point, Rectangle, Size, Vector};
+
+pub use crate::native::eq_bands::{Band, BandKind, Ranges, State};
+pub use crate::style::eq_bands::{NodeStyle, Style, StyleSheet};
+
+/// The horizontal distance in pixels between the points of a response curve.
+const CURVE_STEP: f32 = 2.0;
+
+/// An EQ display with a draggable node for each [`Band`].
+///
+/// [`Band`]: struct.Band.html
+pub type EqBands<'a, Message, Backend> =
+    eq_bands::EqBands<'a, Message, Renderer<Backend>>;
+
+impl<B: Backend> eq_bands::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        state: &eq_bands::DrawState<'_>,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let eq_bands::DrawState {
+            bounds,
+            cursor_position: _,
+            bands,
+            ranges,
+            hovered,
+            dragging,
+            spectrum,
+            sample_rate,
+        } = *state;
+
+        let style = style_sheet.style();
+
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        let back = Primitive::Quad {
+            bounds,
+            background: Background::Color(style.back_color),
+            border_radius: 0.0,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        };
+
+        if bounds.width <= 0.0 || bounds.height <= 0.0 {
+            return (back, mouse::Interaction::default());
+        }
+
+        let size = Size::new(bounds.width, bounds.height);
+        let mut frame = Frame::new(size);
+
+        if let (Some(spectrum), Some(color)) = (spectrum, style.spectrum_color)
+        {
+            draw_spectrum(&mut frame, spectrum, ranges, sample_rate, color);
+        }
+
+        let db_to_y = |db: f32| {
+            (1.0 - ranges.gain.map_to_normal(db).as_f32()) * size.height
+        };
+
+        if let Some(color) = style.zero_line_color {
+            let y = db_to_y(0.0);
+            frame.stroke(
+                &Path::line(Point::new(0.0, y), Point::new(size.width, y)),
+                Stroke {
+                    width: style.zero_line_width,
+                    color,
+                    ..Stroke::default()
+                },
+            );
+        }
+
+        let num_points = (size.width / CURVE_STEP).ceil() as usize + 1;
+        let frequencies: Vec<(f32, f32)> = (0..num_points)
+            .map(|i| {
+                let x = (i as f32 * CURVE_STEP).min(size.width);
+                let hz =
+                    ranges.frequency.unmap_to_value((x / size.width).into());
+                (x, hz)
+            })
+            .collect();
+
+        let mut sum_db = vec![0.0; num_points];
+
+        for (index, band) in bands.iter().enumerate() {
+            let response: Vec<f32> = frequencies
+                .iter()
+                .map(|(_, hz)| band.response_db(ranges, *hz))
+                .collect();
+
+            for (sum, db) in sum_db.iter_mut().zip(response.iter()) {
+                *sum += db;
+            }
+
+            if style.band_curve_width > 0.0 {
+                let curve = curve_path(&frequencies, &response, db_to_y);
+                frame.stroke(
+                    &curve,
+                    Stroke {
+                        width: style.band_curve_width,
+                        color: style.band_color(index),
+                        ..Stroke::default()
+                    },
+                );
+            }
+        }
+
+        frame.stroke(
+            &curve_path(&frequencies, &sum_db, db_to_y),
+            Stroke {
+                width: style.sum_curve_width,
+                color: style.sum_curve_color,
+                ..Stroke::default()
+            },
+        );
+
+        for (index, band) in bands.iter().enumerate() {
+            let node_style = if Some(index) == dragging {
+                style_sheet.dragging_node()
+            } else if Some(index) == hovered {
+                style_sheet.hovered_node()
+            } else {
+                style_sheet.node()
+            };
+
+            let (x, y) = band.node_position(ranges);
+            let node = Path::circle(
+                Point::new(
+                    x.as_f32() * size.width,
+                    (1.0 - y.as_f32()) * size.height,
+                ),
+                node_style.diameter / 2.0,
+            );
+
+            frame.fill(
+                &node,
+                Fill {
+                    color: style.band_color(index),
+                    ..Fill::default()
+                },
+            );
+            if node_style.border_width > 0.0 {
+                frame.stroke(
+                    &node,
+                    Stroke {
+                        width: node_style.border_width,
+                        color: node_style.border_color,
+                        ..Stroke::default()
+                    },
+                );
+            }
+        }
+
+        let content = Primitive::Translate {
+            translation: Vector::new(bounds.x, bounds.y),
+            content: Box::new(frame.into_geometry().into_primitive()),
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![back, content],
+            },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+fn curve_path(
+    frequencies: &[(f32, f32)],
+    response: &[f32],
+    db_to_y: impl Fn(f32) -> f32,
+) -> Path {
+    Path::new(|path| {
+        for (i, ((x, _), db)) in frequencies.iter().zip(response).enumerate() {
+            let point = Point::new(*x, db_to_y(*db));
+
+            if i == 0 {
+                path.move_to(point);
+            } else {
+                path.line_to(point);
+            }
+        }
+    })
+}
+
+fn draw_spectrum(
+    frame: &mut Frame,
+    spectrum: &[f32],
+    ranges: &Ranges,
+    sample_rate: f32,
+    color: Color,
+) {
+    let nyquist = sample_rate / 2.0;
+    if spectrum.len() < 2 || nyquist <= 0.0 {
+        return;
+    }
+
+    let size = frame.size();
+    let last = spectrum.len() - 1;
+
+    let path = Path::new(|path| {
+        path.move_to(Point::new(0.0, size.height));
+
+        let mut x = 0.0;
+        while x <= size.width {
+            let hz = ranges.frequency.unmap_to_value((x / size.width).into());
+            let bin = (hz / nyquist * last as f32).max(0.0).min(last as f32);
+
+            let i = (bin.floor() as usize).min(last);
+            let next = (i + 1).min(last);
+            let t = bin - i as f32;
+            let magnitude = spectrum[i] + (spectrum[next] - spectrum[i]) * t;
+
+            let magnitude = if magnitude.is_nan() {
+                0.0
+            } else {
+                magnitude.clamp(0.0, 1.0)
+            };
+
+            path.line_to(Point::new(x, (1.0 - magnitude) * size.height));
+
+            x += CURVE_STEP;
+        }
+
+        path.line_to(Point::new(size.width, size.height));
+        path.close();
+    });
+
+    frame.fill(
+        &path,
+        Fill {
+            color,
+            ..Fill::default()
+        },
+    );
+}
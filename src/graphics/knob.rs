@@ -3,17 +3,21 @@
 //! [`Param`]: ../core/param/struct.Param.html
 
 use crate::core::{ModulationRange, Normal};
+use crate::graphics::atlas::draw_image;
+use crate::graphics::draw_cache::Revision;
+use crate::graphics::pixel_snap::{snap, snap_rect};
 use crate::graphics::{text_marks, tick_marks};
 use crate::native::knob;
-use iced_graphics::canvas::{path::Arc, Frame, Path, Stroke};
+use crate::style::transition::Interpolate;
+use iced_graphics::canvas::{path::Arc, Fill, Frame, Path, Stroke};
 use iced_graphics::{Backend, Primitive, Renderer};
-use iced_native::{mouse, Background, Point, Rectangle, Size, Vector};
+use iced_native::{mouse, Color, Point, Rectangle, Size, Vector};
 
 pub use crate::native::knob::State;
 pub use crate::style::knob::{
     ArcBipolarStyle, ArcStyle, CircleNotch, CircleStyle, LineCap, LineNotch,
-    ModRangeArcStyle, NotchShape, Style, StyleLength, StyleSheet,
-    TextMarksStyle, TickMarksStyle, ValueArcStyle,
+    ModRangeArcStyle, NotchShape, StereoWidthStyle, Style, StyleLength,
+    StyleSheet, TextMarksStyle, TextureStyle, TickMarksStyle, ValueArcStyle,
 };
 
 struct ValueMarkers<'a> {
@@ -46,95 +50,215 @@ pub type Knob<'a, Message, Backend> =
 impl<B: Backend> knob::Renderer for Renderer<B> {
     type Style = Box<dyn StyleSheet>;
 
+    fn mod_ring_width(&self, style_sheet: &Self::Style) -> Option<f32> {
+        let width =
+            |style: Option<ModRangeArcStyle>| style.map(|s| s.offset + s.width);
+
+        match (
+            width(style_sheet.mod_range_arc_style()),
+            width(style_sheet.mod_range_arc_style_2()),
+        ) {
+            (Some(width_1), Some(width_2)) => Some(width_1.max(width_2)),
+            (width_1, width_2) => width_1.or(width_2),
+        }
+    }
+
     fn draw(
         &mut self,
-        bounds: Rectangle,
-        cursor_position: Point,
-        normal: Normal,
-        is_dragging: bool,
-        mod_range_1: Option<&ModulationRange>,
-        mod_range_2: Option<&ModulationRange>,
-        tick_marks: Option<&tick_marks::Group>,
-        text_marks: Option<&text_marks::Group>,
+        state: &knob::DrawState<'_>,
         style_sheet: &Self::Style,
-        tick_marks_cache: &tick_marks::PrimitiveCache,
-        text_marks_cache: &text_marks::PrimitiveCache,
     ) -> Self::Output {
-        let is_mouse_over = bounds.contains(cursor_position);
+        let knob::DrawState {
+            bounds,
+            cursor_position,
+            normal,
+            default_normal,
+            is_dragging,
+            is_focused,
+            transition,
+            mod_range_1,
+            mod_range_2,
+            modulated_value,
+            automation_value,
+            tick_marks,
+            text_marks,
+            accent,
+            warning,
+            tick_marks_cache,
+            text_marks_cache,
+            draw_cache,
+            revision,
+        } = *state;
 
-        let angle_range = style_sheet.angle_range();
+        let is_mouse_over = bounds.contains(cursor_position);
 
-        let style = if is_dragging {
-            style_sheet.dragging()
-        } else if is_mouse_over {
-            style_sheet.hovered()
+        let accent = if warning {
+            Some(style_sheet.warning_color())
         } else {
-            style_sheet.active()
+            accent
         };
 
-        let value_markers = ValueMarkers {
-            tick_marks,
-            text_marks,
-            mod_range_1,
-            mod_range_2,
-            tick_marks_style: style_sheet.tick_marks_style(),
-            text_marks_style: style_sheet.text_marks_style(),
-            value_arc_style: style_sheet.value_arc_style(),
-            mod_range_style_1: style_sheet.mod_range_arc_style(),
-            mod_range_style_2: style_sheet.mod_range_arc_style_2(),
-        };
+        let revision = revision.map(|revision| {
+            Revision::new(revision)
+                .style(&style_sheet.active())
+                .style(&style_sheet.hovered())
+                .style(&style_sheet.dragging())
+                .style(&style_sheet.focused())
+                .style(&style_sheet.angle_range())
+                .style(&style_sheet.tick_marks_style())
+                .style(&style_sheet.text_marks_style())
+                .style(&style_sheet.value_arc_style())
+                .style(&style_sheet.mod_range_arc_style())
+                .style(&style_sheet.mod_range_arc_style_2())
+                .style(&style_sheet.default_notch_style())
+                .style(&style_sheet.automation_value_style())
+                .style(&style_sheet.modulated_value_style())
+                .style(&style_sheet.warning_color())
+                .finish()
+        });
+
+        draw_cache.cached(revision, bounds, is_mouse_over, || {
+            let angle_range = style_sheet.angle_range();
+
+            let style = if let Some(transition) = transition {
+                let resting = if is_focused {
+                    style_sheet.focused()
+                } else {
+                    style_sheet.active()
+                };
 
-        let bounds = {
-            let bounds = Rectangle {
-                x: bounds.x.round(),
-                y: bounds.y.round(),
-                width: bounds.width.round(),
-                height: bounds.height.round(),
+                resting
+                    .interpolate(
+                        &style_sheet.hovered(),
+                        transition.hover_amount(),
+                    )
+                    .interpolate(
+                        &style_sheet.dragging(),
+                        transition.press_amount(),
+                    )
+            } else if is_dragging {
+                style_sheet.dragging()
+            } else if is_mouse_over {
+                style_sheet.hovered()
+            } else if is_focused {
+                style_sheet.focused()
+            } else {
+                style_sheet.active()
             };
 
-            if bounds.width == bounds.height {
-                bounds
-            } else if bounds.width > bounds.height {
-                Rectangle {
-                    x: (bounds.x + (bounds.width - bounds.height) / 2.0)
-                        .round(),
-                    y: bounds.y,
-                    width: bounds.height,
-                    height: bounds.height,
-                }
+            let style = if let Some(accent) = accent {
+                style.with_accent(accent)
             } else {
-                Rectangle {
-                    x: bounds.x,
-                    y: (bounds.y + (bounds.height - bounds.width) / 2.0)
-                        .round(),
-                    width: bounds.width,
-                    height: bounds.width,
+                style
+            };
+
+            let value_markers = ValueMarkers {
+                tick_marks,
+                text_marks,
+                mod_range_1,
+                mod_range_2,
+                tick_marks_style: style_sheet.tick_marks_style(),
+                text_marks_style: style_sheet.text_marks_style(),
+                value_arc_style: style_sheet.value_arc_style().map(|style| {
+                    match accent {
+                        Some(accent) => style.with_accent(accent),
+                        None => style,
+                    }
+                }),
+                mod_range_style_1: style_sheet.mod_range_arc_style(),
+                mod_range_style_2: style_sheet.mod_range_arc_style_2(),
+            };
+
+            let bounds = {
+                let bounds = snap_rect(bounds);
+
+                if bounds.width == bounds.height {
+                    bounds
+                } else if bounds.width > bounds.height {
+                    Rectangle {
+                        x: snap(
+                            bounds.x + (bounds.width - bounds.height) / 2.0,
+                        ),
+                        y: bounds.y,
+                        width: bounds.height,
+                        height: bounds.height,
+                    }
+                } else {
+                    Rectangle {
+                        x: bounds.x,
+                        y: snap(
+                            bounds.y + (bounds.height - bounds.width) / 2.0,
+                        ),
+                        width: bounds.width,
+                        height: bounds.width,
+                    }
                 }
-            }
-        };
+            };
 
-        let radius = bounds.width / 2.0;
+            let radius = bounds.width / 2.0;
 
-        let start_angle =
-            if angle_range.min() >= crate::core::math::THREE_HALVES_PI {
-                angle_range.min() - crate::core::math::THREE_HALVES_PI
-            } else {
-                angle_range.min() + std::f32::consts::FRAC_PI_2
+            let start_angle =
+                if angle_range.min() >= crate::core::math::THREE_HALVES_PI {
+                    angle_range.min() - crate::core::math::THREE_HALVES_PI
+                } else {
+                    angle_range.min() + std::f32::consts::FRAC_PI_2
+                };
+            let angle_span = angle_range.max() - angle_range.min();
+            let value_angle = start_angle + (normal.scale(angle_span));
+
+            let knob_info = KnobInfo {
+                bounds,
+                start_angle,
+                angle_span,
+                radius,
+                value: normal,
+                value_angle,
             };
-        let angle_span = angle_range.max() - angle_range.min();
-        let value_angle = start_angle + (normal.scale(angle_span));
 
-        let knob_info = KnobInfo {
-            bounds,
-            start_angle,
-            angle_span,
-            radius,
-            value: normal,
-            value_angle,
-        };
+            let default_notch = match style_sheet.default_notch_style() {
+                Some(notch) => draw_notch(
+                    &KnobInfo {
+                        value: default_normal,
+                        value_angle: start_angle
+                            + default_normal.scale(angle_span),
+                        ..knob_info
+                    },
+                    &notch,
+                ),
+                None => Primitive::None,
+            };
 
-        (
-            match style {
+            let automation_value = match automation_value {
+                Some(normal) => match style_sheet.automation_value_style() {
+                    Some(notch) => draw_notch(
+                        &KnobInfo {
+                            value: normal,
+                            value_angle: start_angle + normal.scale(angle_span),
+                            ..knob_info
+                        },
+                        &notch,
+                    ),
+                    None => Primitive::None,
+                },
+                None => Primitive::None,
+            };
+
+            let modulated_value = match modulated_value {
+                Some(normal) => match style_sheet.modulated_value_style() {
+                    Some(notch) => draw_notch(
+                        &KnobInfo {
+                            value: normal,
+                            value_angle: start_angle + normal.scale(angle_span),
+                            ..knob_info
+                        },
+                        &notch,
+                    ),
+                    None => Primitive::None,
+                },
+                None => Primitive::None,
+            };
+
+            let primitives = match style {
                 Style::Circle(style) => draw_circle_style(
                     &knob_info,
                     style,
@@ -156,9 +280,34 @@ impl<B: Backend> knob::Renderer for Renderer<B> {
                     tick_marks_cache,
                     text_marks_cache,
                 ),
-            },
-            mouse::Interaction::default(),
-        )
+                Style::StereoWidth(style) => draw_stereo_width_style(
+                    &knob_info,
+                    style,
+                    &value_markers,
+                    tick_marks_cache,
+                    text_marks_cache,
+                ),
+                Style::Texture(style) => draw_texture_style(
+                    &knob_info,
+                    style,
+                    &value_markers,
+                    tick_marks_cache,
+                    text_marks_cache,
+                ),
+            };
+
+            (
+                Primitive::Group {
+                    primitives: vec![
+                        primitives,
+                        default_notch,
+                        automation_value,
+                        modulated_value,
+                    ],
+                },
+                mouse::Interaction::default(),
+            )
+        })
     }
 }
 
@@ -455,6 +604,48 @@ fn draw_mod_range_arc(
     }
 }
 
+/// Draws a circle as a tessellated mesh, which has smoother edges than a
+/// quad with a border radius at small sizes and fractional scale factors.
+fn draw_circle(
+    center: Point,
+    radius: f32,
+    color: Color,
+    border_width: f32,
+    border_color: Color,
+) -> Primitive {
+    let diameter = radius * 2.0;
+
+    let mut frame = Frame::new(Size::new(diameter, diameter));
+
+    frame.fill(
+        &Path::circle(Point::new(radius, radius), radius),
+        Fill {
+            color,
+            ..Fill::default()
+        },
+    );
+
+    // Like the border of a quad, the border is drawn inside the circle.
+    if border_width > 0.0 {
+        frame.stroke(
+            &Path::circle(
+                Point::new(radius, radius),
+                radius - (border_width / 2.0),
+            ),
+            Stroke {
+                width: border_width,
+                color: border_color,
+                ..Stroke::default()
+            },
+        );
+    }
+
+    Primitive::Translate {
+        translation: Vector::new(center.x - radius, center.y - radius),
+        content: Box::new(frame.into_geometry().into_primitive()),
+    }
+}
+
 fn draw_circle_notch(knob_info: &KnobInfo, style: &CircleNotch) -> Primitive {
     let value_angle = knob_info.value_angle + std::f32::consts::FRAC_PI_2;
 
@@ -471,21 +662,16 @@ fn draw_circle_notch(knob_info: &KnobInfo, style: &CircleNotch) -> Primitive {
     let offset_radius = knob_info.radius
         - style.offset.from_knob_diameter(knob_info.bounds.width);
 
-    Primitive::Quad {
-        bounds: Rectangle {
-            x: knob_info.bounds.center_x() + (dx * offset_radius)
-                - notch_radius,
-            y: knob_info.bounds.center_y()
-                - (dy * offset_radius)
-                - notch_radius,
-            width: notch_diameter,
-            height: notch_diameter,
-        },
-        background: Background::Color(style.color),
-        border_radius: notch_radius,
-        border_width: style.border_width,
-        border_color: style.border_color,
-    }
+    draw_circle(
+        Point::new(
+            knob_info.bounds.center_x() + (dx * offset_radius),
+            knob_info.bounds.center_y() - (dy * offset_radius),
+        ),
+        notch_radius,
+        style.color,
+        style.border_width,
+        style.border_color,
+    )
 }
 
 fn draw_line_notch(knob_info: &KnobInfo, style: &LineNotch) -> Primitive {
@@ -546,13 +732,13 @@ fn draw_circle_style<'a>(
             text_marks_cache,
         );
 
-    let knob_back = Primitive::Quad {
-        bounds: knob_info.bounds,
-        background: Background::Color(style.color),
-        border_radius: knob_info.radius,
-        border_width: style.border_width,
-        border_color: style.border_color,
-    };
+    let knob_back = draw_circle(
+        Point::new(knob_info.bounds.center_x(), knob_info.bounds.center_y()),
+        knob_info.radius,
+        style.color,
+        style.border_width,
+        style.border_color,
+    );
 
     let notch = draw_notch(knob_info, &style.notch);
 
@@ -569,6 +755,65 @@ fn draw_circle_style<'a>(
     }
 }
 
+fn draw_texture_style<'a>(
+    knob_info: &KnobInfo,
+    style: TextureStyle,
+    value_markers: &ValueMarkers<'a>,
+    tick_marks_cache: &tick_marks::PrimitiveCache,
+    text_marks_cache: &text_marks::PrimitiveCache,
+) -> Primitive {
+    let (tick_marks, text_marks, value_arc, mod_range_arc_1, mod_range_arc_2) =
+        draw_value_markers(
+            knob_info,
+            value_markers,
+            tick_marks_cache,
+            text_marks_cache,
+        );
+
+    let frames = style.frames.max(1);
+    let frame = knob_info
+        .value
+        .scale(f32::from(frames - 1))
+        .round()
+        .min(f32::from(frames - 1));
+
+    let bounds = knob_info.bounds;
+
+    // Only the current frame of the filmstrip is visible through the clip.
+    let filmstrip = if let Some(region) = style.image_region {
+        draw_image(
+            style.image_handle,
+            Some(region.frame(frame as u16, frames)),
+            bounds,
+        )
+    } else {
+        Primitive::Clip {
+            bounds,
+            offset: Vector::new(0, 0),
+            content: Box::new(Primitive::Image {
+                handle: style.image_handle,
+                bounds: Rectangle {
+                    x: bounds.x,
+                    y: bounds.y - (frame * bounds.height),
+                    width: bounds.width,
+                    height: bounds.height * f32::from(frames),
+                },
+            }),
+        }
+    };
+
+    Primitive::Group {
+        primitives: vec![
+            tick_marks,
+            text_marks,
+            value_arc,
+            mod_range_arc_1,
+            mod_range_arc_2,
+            filmstrip,
+        ],
+    }
+}
+
 fn draw_arc_style<'a>(
     knob_info: &KnobInfo,
     style: ArcStyle,
@@ -659,10 +904,10 @@ enum BipolarState {
 }
 
 impl BipolarState {
-    pub fn from_knob_value(knob_value: Normal) -> Self {
-        if knob_value.as_f32() < 0.499 {
+    pub fn from_knob_value(knob_value: Normal, center: Normal) -> Self {
+        if knob_value.as_f32() < center.as_f32() - 0.001 {
             BipolarState::Left
-        } else if knob_value.as_f32() > 0.501 {
+        } else if knob_value.as_f32() > center.as_f32() + 0.001 {
             BipolarState::Right
         } else {
             BipolarState::Center
@@ -685,7 +930,8 @@ fn draw_arc_bipolar_style<'a>(
             text_marks_cache,
         );
 
-    let bipolar_state = BipolarState::from_knob_value(knob_info.value);
+    let bipolar_state =
+        BipolarState::from_knob_value(knob_info.value, style.center);
 
     let arc: Primitive = {
         let width = style.width.from_knob_diameter(knob_info.bounds.width);
@@ -693,7 +939,8 @@ fn draw_arc_bipolar_style<'a>(
         let center_point = Point::new(knob_info.radius, knob_info.radius);
         let arc_radius = knob_info.radius - (width / 2.0);
 
-        let half_angle = knob_info.start_angle + (knob_info.angle_span / 2.0);
+        let center_angle =
+            knob_info.start_angle + style.center.scale(knob_info.angle_span);
 
         let mut frame = Frame::new(Size::new(
             knob_info.bounds.width,
@@ -731,7 +978,7 @@ fn draw_arc_bipolar_style<'a>(
                     center: center_point,
                     radius: arc_radius,
                     start_angle: knob_info.value_angle,
-                    end_angle: half_angle,
+                    end_angle: center_angle,
                 };
 
                 let filled_path = Path::new(|path| path.arc(filled_arc));
@@ -749,7 +996,7 @@ fn draw_arc_bipolar_style<'a>(
                 let filled_arc = Arc {
                     center: center_point,
                     radius: arc_radius,
-                    start_angle: half_angle,
+                    start_angle: center_angle,
                     end_angle: knob_info.value_angle,
                 };
 
@@ -789,3 +1036,109 @@ fn draw_arc_bipolar_style<'a>(
         ],
     }
 }
+
+fn draw_stereo_width_style<'a>(
+    knob_info: &KnobInfo,
+    style: StereoWidthStyle,
+    value_markers: &ValueMarkers<'a>,
+    tick_marks_cache: &tick_marks::PrimitiveCache,
+    text_marks_cache: &text_marks::PrimitiveCache,
+) -> Primitive {
+    let (tick_marks, text_marks, value_arc, mod_range_arc_1, mod_range_arc_2) =
+        draw_value_markers(
+            knob_info,
+            value_markers,
+            tick_marks_cache,
+            text_marks_cache,
+        );
+
+    // The notches are mirrored around the center of the angle range, so the
+    // full value spreads them to both ends.
+    let center_angle = knob_info.start_angle + (knob_info.angle_span / 2.0);
+    let spread = knob_info.value.scale(knob_info.angle_span / 2.0);
+    let left_angle = center_angle - spread;
+    let right_angle = center_angle + spread;
+
+    let arc: Primitive = {
+        let width = style.width.from_knob_diameter(knob_info.bounds.width);
+
+        let center_point = Point::new(knob_info.radius, knob_info.radius);
+        let arc_radius = knob_info.radius - (width / 2.0);
+
+        let mut frame = Frame::new(Size::new(
+            knob_info.bounds.width,
+            knob_info.bounds.width,
+        ));
+
+        let empty_stroke = Stroke {
+            width,
+            color: style.empty_color,
+            line_cap: style.cap,
+            ..Stroke::default()
+        };
+
+        let empty_arc = Arc {
+            center: center_point,
+            radius: arc_radius,
+            start_angle: knob_info.start_angle,
+            end_angle: knob_info.start_angle + knob_info.angle_span,
+        };
+
+        let empty_path = Path::new(|path| path.arc(empty_arc));
+
+        frame.stroke(&empty_path, empty_stroke);
+
+        if spread > 0.001 {
+            let filled_stroke = Stroke {
+                width,
+                color: style.filled_color,
+                line_cap: style.cap,
+                ..Stroke::default()
+            };
+
+            let filled_arc = Arc {
+                center: center_point,
+                radius: arc_radius,
+                start_angle: left_angle,
+                end_angle: right_angle,
+            };
+
+            let filled_path = Path::new(|path| path.arc(filled_arc));
+
+            frame.stroke(&filled_path, filled_stroke);
+        }
+
+        Primitive::Translate {
+            translation: Vector::new(knob_info.bounds.x, knob_info.bounds.y),
+            content: Box::new(frame.into_geometry().into_primitive()),
+        }
+    };
+
+    let left_notch = draw_notch(
+        &KnobInfo {
+            value_angle: left_angle,
+            ..*knob_info
+        },
+        &style.notch,
+    );
+    let right_notch = draw_notch(
+        &KnobInfo {
+            value_angle: right_angle,
+            ..*knob_info
+        },
+        &style.notch,
+    );
+
+    Primitive::Group {
+        primitives: vec![
+            tick_marks,
+            text_marks,
+            arc,
+            left_notch,
+            right_notch,
+            value_arc,
+            mod_range_arc_1,
+            mod_range_arc_2,
+        ],
+    }
+}
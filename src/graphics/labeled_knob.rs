@@ -0,0 +1,36 @@
+//! Display a [`Knob`] with a text label and an optional value readout.
+//!
+//! [`Knob`]: ../knob/struct.Knob.html
+
+use crate::native::labeled_knob;
+use iced_graphics::{backend, Backend, Primitive, Renderer};
+
+pub use crate::native::labeled_knob::LabelPlacement;
+
+/// A [`Knob`] with a text label and an optional value readout.
+///
+/// [`Knob`]: ../knob/struct.Knob.html
+pub type LabeledKnob<'a, Message, Backend> =
+    labeled_knob::LabeledKnob<'a, Message, Renderer<Backend>>;
+
+impl<B> labeled_knob::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    fn draw(
+        &mut self,
+        knob: Self::Output,
+        label: Self::Output,
+        value: Option<Self::Output>,
+    ) -> Self::Output {
+        let (knob, interaction) = knob;
+
+        let mut primitives = vec![knob, label.0];
+
+        if let Some((value, _)) = value {
+            primitives.push(value);
+        }
+
+        (Primitive::Group { primitives }, interaction)
+    }
+}
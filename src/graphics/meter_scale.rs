@@ -0,0 +1,101 @@
+//! Display a dB scale of tick marks and text labels beside meters.
+
+use crate::core::Orientation;
+use crate::graphics::{text_marks, tick_marks};
+use crate::native::meter_scale;
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Rectangle};
+
+pub use crate::native::meter_scale::State;
+pub use crate::style::meter_scale::{Style, StyleSheet};
+
+/// A ruler of tick marks and dB labels with no meter of its own.
+pub type MeterScale<'a, Backend> =
+    meter_scale::MeterScale<'a, Renderer<Backend>>;
+
+impl<B: Backend> meter_scale::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        state: &meter_scale::DrawState<'_>,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let meter_scale::DrawState {
+            bounds,
+            orientation,
+            tick_marks,
+            text_marks,
+            tick_marks_cache,
+            text_marks_cache,
+        } = *state;
+
+        let (tick_marks, text_marks) = match orientation {
+            Orientation::Vertical => {
+                let style = style_sheet.style();
+
+                let mark_bounds = Rectangle {
+                    x: bounds.x.round(),
+                    y: (bounds.y + style.inset).round(),
+                    width: bounds.width.round(),
+                    height: (bounds.height - (style.inset * 2.0)).round(),
+                };
+
+                (
+                    tick_marks::draw_vertical_tick_marks(
+                        &mark_bounds,
+                        tick_marks,
+                        &style.tick_marks,
+                        &style.tick_marks_placement,
+                        false,
+                        tick_marks_cache,
+                    ),
+                    text_marks::draw_vertical_text_marks(
+                        &mark_bounds,
+                        text_marks,
+                        &style.text_marks,
+                        &style.text_marks_placement,
+                        false,
+                        text_marks_cache,
+                    ),
+                )
+            }
+            Orientation::Horizontal => {
+                let style = style_sheet.horizontal_style();
+
+                let mark_bounds = Rectangle {
+                    x: (bounds.x + style.inset).round(),
+                    y: bounds.y.round(),
+                    width: (bounds.width - (style.inset * 2.0)).round(),
+                    height: bounds.height.round(),
+                };
+
+                (
+                    tick_marks::draw_horizontal_tick_marks(
+                        &mark_bounds,
+                        tick_marks,
+                        &style.tick_marks,
+                        &style.tick_marks_placement,
+                        false,
+                        tick_marks_cache,
+                    ),
+                    text_marks::draw_horizontal_text_marks(
+                        &mark_bounds,
+                        text_marks,
+                        &style.text_marks,
+                        &style.text_marks_placement,
+                        false,
+                        text_marks_cache,
+                    ),
+                )
+            }
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![tick_marks, text_marks],
+            },
+            mouse::Interaction::default(),
+        )
+    }
+}
@@ -1,15 +1,34 @@
 //! A wgpu renderer for Iced Audio widgets
 
+pub mod clip_indicator;
+pub mod correlation_meter;
+pub mod crossover;
+pub mod db_meter;
+pub mod eq_bands;
 pub mod h_slider;
 pub mod knob;
+pub mod labeled_knob;
+pub mod meter_scale;
 pub mod mod_range_input;
+pub mod pan_knob;
 pub mod ramp;
+pub mod reduction_history;
+pub mod spectrogram;
 pub mod v_slider;
+pub mod vu_meter;
+pub mod waveform_view;
 pub mod xy_pad;
 
 pub mod text_marks;
 pub mod tick_marks;
 
-//pub mod db_meter;
+pub mod draw_cache;
+pub mod pixel_snap;
+
+mod atlas;
+
+pub use draw_cache::DrawCache;
+pub use pixel_snap::set_scale_factor;
+
 //pub mod phase_meter;
 //pub mod reduction_meter;
@@ -2,14 +2,19 @@
 //!
 //! [`Param`]: ../core/param/struct.Param.html
 
+use crate::core::Normal;
+use crate::graphics::draw_cache::Revision;
+use crate::graphics::pixel_snap::{snap, snap_floor};
 use crate::native::mod_range_input;
 
+use iced_graphics::canvas::{Fill, Frame, Path};
 use iced_graphics::{Backend, Primitive, Renderer};
-use iced_native::{mouse, Background, Point, Rectangle};
+use iced_native::{mouse, Background, Color, Point, Rectangle, Size, Vector};
 
 pub use crate::native::mod_range_input::State;
 pub use crate::style::mod_range_input::{
-    CircleStyle, DefaultInvisible, SquareStyle, Style, StyleSheet,
+    BipolarStyle, CircleStyle, DefaultInvisible, InvertedFillStyle,
+    SquareStyle, Style, StyleSheet,
 };
 
 /// An interactive dot that controls an [`Param`]
@@ -23,63 +28,256 @@ impl<B: Backend> mod_range_input::Renderer for Renderer<B> {
 
     fn draw(
         &mut self,
-        bounds: Rectangle,
-        cursor_position: Point,
-        is_dragging: bool,
+        state: &mod_range_input::DrawState<'_>,
         style_sheet: &Self::Style,
     ) -> Self::Output {
+        let mod_range_input::DrawState {
+            bounds,
+            cursor_position,
+            normal,
+            default_normal,
+            is_dragging,
+            is_focused,
+            draw_cache,
+            revision,
+        } = *state;
+
         let is_mouse_over = bounds.contains(cursor_position);
 
-        let style = if is_dragging {
-            style_sheet.dragging()
-        } else if is_mouse_over {
-            style_sheet.hovered()
-        } else {
-            style_sheet.active()
-        };
+        let revision = revision.map(|revision| {
+            Revision::new(revision)
+                .style(&style_sheet.active())
+                .style(&style_sheet.hovered())
+                .style(&style_sheet.dragging())
+                .style(&style_sheet.focused())
+                .finish()
+        });
+
+        draw_cache.cached(revision, bounds, is_mouse_over, || {
+            let style = if is_dragging {
+                style_sheet.dragging()
+            } else if is_mouse_over {
+                style_sheet.hovered()
+            } else if is_focused {
+                style_sheet.focused()
+            } else {
+                style_sheet.active()
+            };
+
+            let dot: Primitive = match style {
+                Style::Circle(style) => {
+                    let bounds_x = snap_floor(bounds.x);
+                    let bounds_y = snap_floor(bounds.y);
+                    let bounds_size = snap_floor(bounds.width);
 
-        let dot: Primitive = match style {
-            Style::Circle(style) => {
-                let bounds_x = bounds.x.floor();
-                let bounds_y = bounds.y.floor();
-                let bounds_size = bounds.width.floor();
-
-                let radius = bounds_size / 2.0;
-
-                Primitive::Quad {
-                    bounds: Rectangle {
-                        x: bounds_x,
-                        y: bounds_y,
-                        width: bounds_size,
-                        height: bounds_size,
-                    },
-                    background: Background::Color(style.color),
-                    border_radius: radius,
-                    border_width: style.border_width,
-                    border_color: style.border_color,
+                    let radius = bounds_size / 2.0;
+
+                    Primitive::Quad {
+                        bounds: Rectangle {
+                            x: bounds_x,
+                            y: bounds_y,
+                            width: bounds_size,
+                            height: bounds_size,
+                        },
+                        background: Background::Color(style.color),
+                        border_radius: radius,
+                        border_width: style.border_width,
+                        border_color: style.border_color,
+                    }
+                }
+                Style::Square(style) => {
+                    let bounds_x = snap_floor(bounds.x);
+                    let bounds_y = snap_floor(bounds.y);
+                    let bounds_size = snap_floor(bounds.width);
+
+                    Primitive::Quad {
+                        bounds: Rectangle {
+                            x: bounds_x,
+                            y: bounds_y,
+                            width: bounds_size,
+                            height: bounds_size,
+                        },
+                        background: Background::Color(style.color),
+                        border_radius: style.border_radius,
+                        border_width: style.border_width,
+                        border_color: style.border_color,
+                    }
                 }
-            }
-            Style::Square(style) => {
-                let bounds_x = bounds.x.floor();
-                let bounds_y = bounds.y.floor();
-                let bounds_size = bounds.width.floor();
-
-                Primitive::Quad {
-                    bounds: Rectangle {
-                        x: bounds_x,
-                        y: bounds_y,
-                        width: bounds_size,
-                        height: bounds_size,
-                    },
-                    background: Background::Color(style.color),
-                    border_radius: style.border_radius,
-                    border_width: style.border_width,
-                    border_color: style.border_color,
+                Style::Bipolar(style) => {
+                    draw_bipolar_style(bounds, normal, default_normal, &style)
                 }
-            }
-            Style::Invisible => Primitive::None,
+                Style::InvertedFill(style) => draw_inverted_fill_style(
+                    bounds,
+                    normal,
+                    default_normal,
+                    &style,
+                ),
+                Style::Invisible => Primitive::None,
+            };
+
+            (dot, mouse::Interaction::default())
+        })
+    }
+}
+
+/// Returns the modulation amount of `normal` from `-1.0` to `1.0`, where
+/// `default_normal` is zero.
+fn amount(normal: Normal, default_normal: Normal) -> f32 {
+    let normal = normal.as_f32();
+    let center = default_normal.as_f32();
+
+    if normal > center {
+        (normal - center) / (1.0 - center)
+    } else if normal < center {
+        (normal - center) / center
+    } else {
+        0.0
+    }
+}
+
+fn draw_bipolar_style(
+    bounds: Rectangle,
+    normal: Normal,
+    default_normal: Normal,
+    style: &BipolarStyle,
+) -> Primitive {
+    let bounds_x = snap_floor(bounds.x);
+    let bounds_y = snap_floor(bounds.y);
+    let bounds_size = snap_floor(bounds.width);
+
+    let amount = amount(normal, default_normal);
+
+    let color = if amount > 0.0 {
+        style.positive_color
+    } else if amount < 0.0 {
+        style.negative_color
+    } else {
+        style.center_color
+    };
+
+    let dot = Primitive::Quad {
+        bounds: Rectangle {
+            x: bounds_x,
+            y: bounds_y,
+            width: bounds_size,
+            height: bounds_size,
+        },
+        background: Background::Color(color),
+        border_radius: bounds_size / 2.0,
+        border_width: style.border_width,
+        border_color: style.border_color,
+    };
+
+    let arrow = match style.arrow_color {
+        Some(arrow_color) if amount != 0.0 => draw_arrow(
+            bounds_x,
+            bounds_y,
+            bounds_size,
+            amount > 0.0,
+            arrow_color,
+        ),
+        _ => Primitive::None,
+    };
+
+    Primitive::Group {
+        primitives: vec![dot, arrow],
+    }
+}
+
+/// Draws a triangle in the middle of a dot that points up or down.
+fn draw_arrow(
+    x: f32,
+    y: f32,
+    size: f32,
+    points_up: bool,
+    color: Color,
+) -> Primitive {
+    let half_width = size * 0.25;
+    let half_height = size * 0.2;
+    let center = size / 2.0;
+
+    let (tip_y, base_y) = if points_up {
+        (center - half_height, center + half_height)
+    } else {
+        (center + half_height, center - half_height)
+    };
+
+    let path = Path::new(|path| {
+        path.move_to(Point::new(center, tip_y));
+        path.line_to(Point::new(center + half_width, base_y));
+        path.line_to(Point::new(center - half_width, base_y));
+        path.close();
+    });
+
+    let mut frame = Frame::new(Size::new(size, size));
+
+    frame.fill(
+        &path,
+        Fill {
+            color,
+            ..Fill::default()
+        },
+    );
+
+    Primitive::Translate {
+        translation: Vector::new(x, y),
+        content: Box::new(frame.into_geometry().into_primitive()),
+    }
+}
+
+fn draw_inverted_fill_style(
+    bounds: Rectangle,
+    normal: Normal,
+    default_normal: Normal,
+    style: &InvertedFillStyle,
+) -> Primitive {
+    let bounds_x = snap_floor(bounds.x);
+    let bounds_y = snap_floor(bounds.y);
+    let bounds_size = snap_floor(bounds.width);
+
+    let back = Primitive::Quad {
+        bounds: Rectangle {
+            x: bounds_x,
+            y: bounds_y,
+            width: bounds_size,
+            height: bounds_size,
+        },
+        background: Background::Color(style.back_color),
+        border_radius: style.border_radius,
+        border_width: style.border_width,
+        border_color: style.border_color,
+    };
+
+    let amount = amount(normal, default_normal);
+
+    let fill = if amount != 0.0 {
+        let inner_size = bounds_size - (style.border_width * 2.0);
+        let center_y = bounds_y + (bounds_size / 2.0);
+        let fill_height = snap(amount.abs() * inner_size / 2.0);
+
+        let (y, color) = if amount > 0.0 {
+            (center_y - fill_height, style.positive_color)
+        } else {
+            (center_y, style.negative_color)
         };
 
-        (dot, mouse::Interaction::default())
+        Primitive::Quad {
+            bounds: Rectangle {
+                x: bounds_x + style.border_width,
+                y,
+                width: inner_size,
+                height: fill_height,
+            },
+            background: Background::Color(color),
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        }
+    } else {
+        Primitive::None
+    };
+
+    Primitive::Group {
+        primitives: vec![back, fill],
     }
 }
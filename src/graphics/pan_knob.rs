@@ -0,0 +1,27 @@
+//! Display a [`Knob`] that pans between left and right, with a readout such
+//! as `"L50"`, `"C"` or `"R50"`.
+//!
+//! [`Knob`]: ../knob/struct.Knob.html
+
+use crate::native::pan_knob;
+use crate::style::knob::StyleSheet;
+use iced_graphics::{backend, Backend, Renderer};
+
+pub use crate::native::labeled_knob::LabelPlacement;
+pub use crate::style::pan_knob::default_style_sheet;
+
+/// A [`Knob`] that pans between left and right, with a readout of the pan
+/// position.
+///
+/// [`Knob`]: ../knob/struct.Knob.html
+pub type PanKnob<'a, Message, Backend> =
+    pan_knob::PanKnob<'a, Message, Renderer<Backend>>;
+
+impl<B> pan_knob::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    fn default_pan_knob_style() -> Box<dyn StyleSheet> {
+        default_style_sheet()
+    }
+}
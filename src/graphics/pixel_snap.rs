@@ -0,0 +1,68 @@
+//! Snap the edges of shapes to physical pixels
+//!
+//! Layout happens in logical pixels, so an edge that lands on a whole
+//! logical pixel can still fall between two physical pixels at a scale
+//! factor of 1.5 or 2.0, which makes thin lines look blurry or uneven. The
+//! renderers snap their edges with the scale factor set here.
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use iced_native::Rectangle;
+
+/// The bits of `1.0_f32`
+static SCALE_FACTOR: AtomicU32 = AtomicU32::new(0x3f80_0000);
+
+/// Sets the scale factor used to snap shapes to physical pixels.
+///
+/// This should be the window's scale factor multiplied by the scale factor
+/// of the application, and it should be set again whenever either of them
+/// changes. The default is `1.0`.
+///
+/// Values that are not finite or not greater than `0.0` are ignored.
+pub fn set_scale_factor(scale_factor: f64) {
+    let scale_factor = scale_factor as f32;
+
+    if scale_factor.is_finite() && scale_factor > 0.0 {
+        SCALE_FACTOR.store(scale_factor.to_bits(), Ordering::Relaxed);
+    }
+}
+
+/// Returns the scale factor used to snap shapes to physical pixels.
+pub fn scale_factor() -> f32 {
+    f32::from_bits(SCALE_FACTOR.load(Ordering::Relaxed))
+}
+
+/// Rounds a position or length in logical pixels to the nearest physical
+/// pixel.
+#[inline]
+pub fn snap(value: f32) -> f32 {
+    let scale_factor = scale_factor();
+
+    (value * scale_factor).round() / scale_factor
+}
+
+/// Rounds a position or length in logical pixels down to a physical pixel.
+#[inline]
+pub fn snap_floor(value: f32) -> f32 {
+    let scale_factor = scale_factor();
+
+    (value * scale_factor).floor() / scale_factor
+}
+
+/// Rounds every edge of `rect` to the nearest physical pixel.
+///
+/// The right and bottom edges are snapped on their own rather than through
+/// the size, so they don't drift off the pixel grid when the position is
+/// rounded in the other direction.
+#[inline]
+pub fn snap_rect(rect: Rectangle) -> Rectangle {
+    let x = snap(rect.x);
+    let y = snap(rect.y);
+
+    Rectangle {
+        x,
+        y,
+        width: snap(rect.x + rect.width) - x,
+        height: snap(rect.y + rect.height) - y,
+    }
+}
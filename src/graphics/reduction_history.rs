@@ -0,0 +1,111 @@
+//! Display a scrolling history of gain reduction.
+
+use std::collections::VecDeque;
+
+use crate::core::Normal;
+use crate::native::reduction_history;
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Color, Rectangle};
+
+pub use crate::native::reduction_history::State;
+pub use crate::style::reduction_history::{Style, StyleSheet};
+
+/// A graph that plots the last few seconds of gain reduction as a scrolling
+/// filled area, with a bar showing the current gain reduction.
+pub type ReductionHistory<'a, Backend> =
+    reduction_history::ReductionHistory<'a, Renderer<Backend>>;
+
+impl<B: Backend> reduction_history::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        history: &VecDeque<Normal>,
+        columns: usize,
+        current: Normal,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        let mut primitives = Vec::with_capacity(history.len() + 2);
+
+        primitives.push(Primitive::Quad {
+            bounds,
+            background: Background::Color(style.back_color),
+            border_radius: 0.0,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        });
+
+        let inner = Rectangle {
+            x: bounds.x + style.back_border_width,
+            y: bounds.y + style.back_border_width,
+            width: bounds.width - (style.back_border_width * 2.0),
+            height: bounds.height - (style.back_border_width * 2.0),
+        };
+
+        let bar_width = style.bar_width.max(0.0).min(inner.width).round();
+        let graph_width = inner.width - bar_width;
+
+        let column_width = graph_width / columns.max(1) as f32;
+
+        // The newest value is at the right edge of the graph.
+        let first_x =
+            inner.x + graph_width - history.len() as f32 * column_width;
+
+        for (i, reduction) in history.iter().enumerate() {
+            let height = (reduction.as_f32() * inner.height).round();
+            if height <= 0.0 {
+                continue;
+            }
+
+            let x = (first_x + i as f32 * column_width).floor();
+            let next_x = (first_x + (i + 1) as f32 * column_width).floor();
+
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x,
+                    y: inner.y,
+                    width: next_x - x,
+                    height,
+                },
+                background: Background::Color(style.fill_color),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            });
+        }
+
+        if bar_width > 0.0 {
+            let height = (current.as_f32() * inner.height).round();
+
+            if height > 0.0 {
+                primitives.push(Primitive::Quad {
+                    bounds: Rectangle {
+                        x: inner.x + graph_width,
+                        y: inner.y,
+                        width: bar_width,
+                        height,
+                    },
+                    background: Background::Color(style.bar_color),
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+        }
+
+        (
+            Primitive::Group { primitives },
+            mouse::Interaction::default(),
+        )
+    }
+}
@@ -0,0 +1,269 @@
+//! Display a scrolling spectrogram (waterfall) of magnitude spectra.
+
+use std::cell::RefCell;
+use std::hash::{Hash, Hasher};
+
+use crate::native::spectrogram;
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{image, mouse, Background, Color, Rectangle};
+
+pub use crate::native::spectrogram::{FrequencyAxis, State};
+pub use crate::style::spectrogram::{Colormap, Style, StyleSheet};
+
+/// A scrolling spectrogram (waterfall) display.
+pub type Spectrogram<'a, Backend> =
+    spectrogram::Spectrogram<'a, Renderer<Backend>>;
+
+impl<B: Backend> spectrogram::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        state: &spectrogram::DrawState<'_>,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let spectrogram::DrawState {
+            bounds, texture, ..
+        } = *state;
+
+        let style = style_sheet.style();
+
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        let inner = Rectangle {
+            x: bounds.x + style.back_border_width,
+            y: bounds.y + style.back_border_width,
+            width: bounds.width - (style.back_border_width * 2.0),
+            height: bounds.height - (style.back_border_width * 2.0),
+        };
+
+        let image = if inner.width >= 1.0 && inner.height >= 1.0 {
+            Primitive::Image {
+                handle: texture.update(
+                    state,
+                    &style.colormap,
+                    inner.height as usize,
+                ),
+                bounds: inner,
+            }
+        } else {
+            Primitive::None
+        };
+
+        let border = Primitive::Quad {
+            bounds,
+            background: Background::Color(Color::TRANSPARENT),
+            border_radius: 0.0,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![image, border],
+            },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+struct TextureData {
+    key: u64,
+    width: usize,
+    height: usize,
+    pushed: u64,
+    pixels: Vec<u8>,
+    lut: Vec<[u8; 4]>,
+    handle: image::Handle,
+}
+
+/// The texture cache of a [`Spectrogram`].
+///
+/// Only the columns pushed since the previous frame are colored. The rest of
+/// the texture is scrolled in place.
+///
+/// [`Spectrogram`]: type.Spectrogram.html
+#[derive(Default)]
+pub struct Texture {
+    data: RefCell<Option<TextureData>>,
+}
+
+impl std::fmt::Debug for Texture {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Texture")
+    }
+}
+
+impl Texture {
+    fn update(
+        &self,
+        state: &spectrogram::DrawState<'_>,
+        colormap: &Colormap,
+        height: usize,
+    ) -> image::Handle {
+        let spectrogram::DrawState {
+            columns,
+            window,
+            pushed,
+            frequency_axis,
+            revision,
+            ..
+        } = *state;
+
+        let width = window.max(1);
+        let key =
+            texture_key(width, height, frequency_axis, colormap, revision);
+
+        let mut data = self.data.borrow_mut();
+
+        let new_columns = match &*data {
+            Some(data) if data.key == key => {
+                let new_columns = pushed.wrapping_sub(data.pushed) as usize;
+                if new_columns == 0 {
+                    return data.handle.clone();
+                }
+                new_columns.min(width)
+            }
+            _ => {
+                *data = Some(TextureData {
+                    key,
+                    width,
+                    height,
+                    pushed,
+                    pixels: vec![0; width * height * 4],
+                    lut: (0..=255)
+                        .map(|i| bgra(colormap.color(i as f32 / 255.0)))
+                        .collect(),
+                    handle: image::Handle::from_pixels(0, 0, Vec::new()),
+                });
+                width
+            }
+        };
+
+        let data = data.as_mut().unwrap();
+        let stride = data.width * 4;
+
+        // Scroll the old columns to the left.
+        if new_columns < width {
+            for row in data.pixels.chunks_exact_mut(stride) {
+                row.copy_within(new_columns * 4.., 0);
+            }
+        }
+
+        let rows: Vec<(f32, f32)> = (0..data.height)
+            .map(|row| {
+                let top = 1.0 - row as f32 / data.height as f32;
+                let bottom = 1.0 - (row + 1) as f32 / data.height as f32;
+                (bottom, top)
+            })
+            .collect();
+
+        for x in (width - new_columns)..width {
+            // The newest column is at the right edge.
+            let column = (columns.len() + x)
+                .checked_sub(width)
+                .and_then(|i| columns.get(i));
+
+            for (row, (bottom, top)) in rows.iter().enumerate() {
+                let magnitude = column.map_or(0.0, |column| {
+                    row_magnitude(column, frequency_axis, *bottom, *top)
+                });
+
+                let i = (magnitude.clamp(0.0, 1.0) * 255.0).round() as usize;
+                let pixel = row * stride + x * 4;
+                data.pixels[pixel..pixel + 4].copy_from_slice(&data.lut[i]);
+            }
+        }
+
+        data.pushed = pushed;
+        data.handle = image::Handle::from_pixels(
+            data.width as u32,
+            data.height as u32,
+            data.pixels.clone(),
+        );
+
+        data.handle.clone()
+    }
+}
+
+/// Returns the magnitude of a row spanning the positions `bottom` to `top`.
+///
+/// If the row covers several bins, then their maximum is used so narrow
+/// peaks do not disappear. Otherwise the bins are interpolated.
+fn row_magnitude(
+    column: &[f32],
+    frequency_axis: &FrequencyAxis,
+    bottom: f32,
+    top: f32,
+) -> f32 {
+    if column.is_empty() {
+        return 0.0;
+    }
+
+    let last = column.len() - 1;
+    let low = frequency_axis.bin(bottom, column.len());
+    let high = frequency_axis.bin(top, column.len());
+
+    let magnitude = if high - low > 1.0 {
+        let first = (low.ceil().max(0.0) as usize).min(last);
+        let end = (high.floor().max(0.0) as usize).min(last);
+
+        column[first..=end.max(first)]
+            .iter()
+            .fold(0.0f32, |max, m| max.max(*m))
+    } else {
+        let bin = ((low + high) / 2.0).max(0.0).min(last as f32);
+        let i = (bin.floor() as usize).min(last);
+        let next = (i + 1).min(last);
+        let t = bin - i as f32;
+
+        column[i] + (column[next] - column[i]) * t
+    };
+
+    if magnitude.is_nan() {
+        0.0
+    } else {
+        magnitude
+    }
+}
+
+fn texture_key(
+    width: usize,
+    height: usize,
+    frequency_axis: &FrequencyAxis,
+    colormap: &Colormap,
+    revision: u64,
+) -> u64 {
+    let mut hasher = iced_native::Hasher::default();
+
+    width.hash(&mut hasher);
+    height.hash(&mut hasher);
+    revision.hash(&mut hasher);
+
+    frequency_axis.min_hz.to_bits().hash(&mut hasher);
+    frequency_axis.max_hz.to_bits().hash(&mut hasher);
+    frequency_axis.sample_rate.to_bits().hash(&mut hasher);
+    frequency_axis.log_scale.hash(&mut hasher);
+
+    for i in 0..=255 {
+        bgra(colormap.color(i as f32 / 255.0)).hash(&mut hasher);
+    }
+
+    hasher.finish()
+}
+
+fn bgra(color: Color) -> [u8; 4] {
+    let to_u8 = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+
+    [
+        to_u8(color.b),
+        to_u8(color.g),
+        to_u8(color.r),
+        to_u8(color.a),
+    ]
+}
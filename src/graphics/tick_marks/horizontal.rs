@@ -4,7 +4,9 @@ use super::PrimitiveCache;
 use crate::core::Normal;
 use crate::native::tick_marks;
 use crate::style::tick_marks::{Placement, Shape, Style};
+use iced_graphics::canvas::{Fill, Frame, Path};
 use iced_graphics::{Background, Color, Primitive, Rectangle};
+use iced_native::{Point, Size, Vector};
 
 fn draw_horizontal_lines(
     primitives: &mut Vec<Primitive>,
@@ -15,9 +17,11 @@ fn draw_horizontal_lines(
     width: f32,
     length: f32,
     color: Color,
+    border_radius: f32,
     inverse: bool,
 ) {
     let start_x = bounds_x - (f32::from(width) / 2.0);
+    let border_radius = border_radius.min(width / 2.0).min(length / 2.0);
     let back_color = Background::Color(color);
 
     if inverse {
@@ -30,7 +34,7 @@ fn draw_horizontal_lines(
                     height: f32::from(length),
                 },
                 background: back_color,
-                border_radius: 0.0,
+                border_radius,
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
             });
@@ -45,7 +49,7 @@ fn draw_horizontal_lines(
                     height: f32::from(length),
                 },
                 background: back_color,
-                border_radius: 0.0,
+                border_radius,
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
             });
@@ -53,6 +57,136 @@ fn draw_horizontal_lines(
     }
 }
 
+/// A tier of tick marks, and where it is drawn.
+struct Tier<'a> {
+    tick_marks: &'a [Normal],
+    bounds: &'a Rectangle,
+    y: f32,
+    inverse: bool,
+}
+
+fn draw_horizontal_triangles(
+    primitives: &mut Vec<Primitive>,
+    tier: &Tier<'_>,
+    width: f32,
+    length: f32,
+    color: Color,
+    tip_at_start: bool,
+) {
+    let Tier {
+        tick_marks,
+        bounds,
+        y,
+        inverse,
+    } = *tier;
+    let bounds_x = bounds.x;
+    let bounds_width = bounds.width;
+
+    let half_width = width / 2.0;
+
+    let (base_y, tip_y) = if tip_at_start {
+        (length, 0.0)
+    } else {
+        (0.0, length)
+    };
+
+    let mut frame = Frame::new(Size::new(bounds_width + width, length));
+
+    let fill = Fill {
+        color,
+        ..Fill::default()
+    };
+
+    for tick_mark in tick_marks {
+        let offset = if inverse {
+            tick_mark.scale_inv(bounds_width)
+        } else {
+            tick_mark.scale(bounds_width)
+        };
+
+        let path = Path::new(|p| {
+            p.move_to(Point::new(offset, base_y));
+            p.line_to(Point::new(offset + half_width, tip_y));
+            p.line_to(Point::new(offset + width, base_y));
+            p.close();
+        });
+
+        frame.fill(&path, fill);
+    }
+
+    primitives.push(Primitive::Translate {
+        translation: Vector::new(bounds_x - half_width, y),
+        content: Box::new(frame.into_geometry().into_primitive()),
+    });
+}
+
+/// Draws a tier of `Line`, `RoundedLine`, or `Triangle` tick marks that
+/// start at `y`.
+///
+/// `tip_at_start` is whether the tip of a triangle is at `y` (true), or
+/// at `y + length` (false).
+fn draw_horizontal_line_shape(
+    primitives: &mut Vec<Primitive>,
+    tier: &Tier<'_>,
+    length: f32,
+    shape: &Shape,
+    tip_at_start: bool,
+) {
+    let Tier {
+        tick_marks,
+        bounds,
+        y,
+        inverse,
+    } = *tier;
+
+    match *shape {
+        Shape::Line { width, color, .. } => {
+            draw_horizontal_lines(
+                primitives,
+                tick_marks,
+                bounds.x,
+                bounds.width,
+                y,
+                width,
+                length,
+                color,
+                0.0,
+                inverse,
+            );
+        }
+        Shape::RoundedLine {
+            width,
+            border_radius,
+            color,
+            ..
+        } => {
+            draw_horizontal_lines(
+                primitives,
+                tick_marks,
+                bounds.x,
+                bounds.width,
+                y,
+                width,
+                length,
+                color,
+                border_radius,
+                inverse,
+            );
+        }
+        Shape::Triangle { width, color, .. } => {
+            draw_horizontal_triangles(
+                primitives,
+                tier,
+                width,
+                length,
+                color,
+                tip_at_start,
+            );
+        }
+        Shape::None | Shape::Circle { .. } => {}
+    }
+}
+
 fn draw_horizontal_circles(
     primitives: &mut Vec<Primitive>,
     tick_marks: &[Normal],
@@ -63,42 +197,34 @@ fn draw_horizontal_circles(
     color: Color,
     inverse: bool,
 ) {
-    let diameter = f32::from(diameter);
     let radius = diameter / 2.0;
-    let start_x = bounds_x - f32::from(radius);
-    let back_color = Background::Color(color);
 
-    if inverse {
-        for tick_mark in tick_marks {
-            primitives.push(Primitive::Quad {
-                bounds: Rectangle {
-                    x: (start_x + tick_mark.scale_inv(bounds_width)),
-                    y,
-                    width: diameter,
-                    height: diameter,
-                },
-                background: back_color,
-                border_radius: radius,
-                border_width: 0.0,
-                border_color: Color::TRANSPARENT,
-            });
-        }
-    } else {
-        for tick_mark in tick_marks {
-            primitives.push(Primitive::Quad {
-                bounds: Rectangle {
-                    x: (start_x + tick_mark.scale(bounds_width)),
-                    y,
-                    width: diameter,
-                    height: diameter,
-                },
-                background: back_color,
-                border_radius: radius,
-                border_width: 0.0,
-                border_color: Color::TRANSPARENT,
-            });
-        }
+    // Circles are tessellated into a mesh, which has smoother edges than
+    // quads with a border radius at small sizes.
+    let mut frame = Frame::new(Size::new(bounds_width + diameter, diameter));
+
+    let fill = Fill {
+        color,
+        ..Fill::default()
+    };
+
+    for tick_mark in tick_marks {
+        let offset = if inverse {
+            tick_mark.scale_inv(bounds_width)
+        } else {
+            tick_mark.scale(bounds_width)
+        };
+
+        frame.fill(
+            &Path::circle(Point::new(radius + offset, radius), radius),
+            fill,
+        );
     }
+
+    primitives.push(Primitive::Translate {
+        translation: Vector::new(bounds_x - radius, y),
+        content: Box::new(frame.into_geometry().into_primitive()),
+    });
 }
 
 #[inline]
@@ -113,21 +239,20 @@ fn draw_horizontal_top_aligned_tier(
     if let Some(tick_marks) = tick_marks {
         match shape {
             Shape::None => return,
-            Shape::Line {
-                length,
-                width,
-                color,
-            } => {
-                draw_horizontal_lines(
+            Shape::Line { length, .. }
+            | Shape::RoundedLine { length, .. }
+            | Shape::Triangle { length, .. } => {
+                draw_horizontal_line_shape(
                     primitives,
-                    tick_marks,
-                    bounds.x,
-                    bounds.width,
-                    y,
-                    *width,
+                    &Tier {
+                        tick_marks,
+                        bounds,
+                        y,
+                        inverse,
+                    },
                     *length,
-                    *color,
-                    inverse,
+                    shape,
+                    false,
                 );
             }
             Shape::Circle { diameter, color } => {
@@ -192,21 +317,20 @@ fn draw_horizontal_bottom_aligned_tier(
     if let Some(tick_marks) = tick_marks {
         match shape {
             Shape::None => return,
-            Shape::Line {
-                length,
-                width,
-                color,
-            } => {
-                draw_horizontal_lines(
+            Shape::Line { length, .. }
+            | Shape::RoundedLine { length, .. }
+            | Shape::Triangle { length, .. } => {
+                draw_horizontal_line_shape(
                     primitives,
-                    tick_marks,
-                    bounds.x,
-                    bounds.width,
-                    y - f32::from(*length),
-                    *width,
+                    &Tier {
+                        tick_marks,
+                        bounds,
+                        y: y - f32::from(*length),
+                        inverse,
+                    },
                     *length,
-                    *color,
-                    inverse,
+                    shape,
+                    true,
                 );
             }
             Shape::Circle { diameter, color } => {
@@ -272,11 +396,9 @@ fn draw_horizontal_center_aligned_tier(
     if let Some(tick_marks) = tick_marks {
         match shape {
             Shape::None => return,
-            Shape::Line {
-                length,
-                width,
-                color,
-            } => {
+            Shape::Line { length, .. }
+            | Shape::RoundedLine { length, .. }
+            | Shape::Triangle { length, .. } => {
                 let (y, length) = if fill_length {
                     (
                         bounds.y + f32::from(*length),
@@ -286,16 +408,17 @@ fn draw_horizontal_center_aligned_tier(
                     (y - (*length / 2.0), *length)
                 };
 
-                draw_horizontal_lines(
+                draw_horizontal_line_shape(
                     primitives,
-                    tick_marks,
-                    bounds.x,
-                    bounds.width,
-                    y,
-                    *width,
+                    &Tier {
+                        tick_marks,
+                        bounds,
+                        y,
+                        inverse,
+                    },
                     length,
-                    *color,
-                    inverse,
+                    shape,
+                    false,
                 );
             }
             Shape::Circle { diameter, color } => {
@@ -375,11 +498,9 @@ fn draw_horizontal_center_aligned_split_tier(
     if let Some(tick_marks) = tick_marks {
         match shape {
             Shape::None => return,
-            Shape::Line {
-                length,
-                width,
-                color,
-            } => {
+            Shape::Line { length, .. }
+            | Shape::RoundedLine { length, .. }
+            | Shape::Triangle { length, .. } => {
                 let (left_y, length) = if fill_length {
                     let length =
                         f32::from(*length) + (bounds.height + gap) / 2.0;
@@ -390,27 +511,29 @@ fn draw_horizontal_center_aligned_split_tier(
 
                 let right_y = y + (gap / 2.0);
 
-                draw_horizontal_lines(
+                draw_horizontal_line_shape(
                     primitives,
-                    tick_marks,
-                    bounds.x,
-                    bounds.width,
-                    left_y,
-                    *width,
+                    &Tier {
+                        tick_marks,
+                        bounds,
+                        y: left_y,
+                        inverse,
+                    },
                     length,
-                    *color,
-                    inverse,
+                    shape,
+                    true,
                 );
-                draw_horizontal_lines(
+                draw_horizontal_line_shape(
                     primitives,
-                    tick_marks,
-                    bounds.x,
-                    bounds.width,
-                    right_y,
-                    *width,
+                    &Tier {
+                        tick_marks,
+                        bounds,
+                        y: right_y,
+                        inverse,
+                    },
                     length,
-                    *color,
-                    inverse,
+                    shape,
+                    false,
                 );
             }
             Shape::Circle { diameter, color } => {
@@ -101,6 +101,95 @@ fn draw_radial_lines(
     }
 }
 
+/// Draws a filled `path` for each tick mark, where `path` is a tick mark
+/// pointing straight up from the center.
+fn draw_radial_paths(
+    frame: &mut Frame,
+    start_angle: f32,
+    angle_span: f32,
+    tick_marks: &[Normal],
+    color: Color,
+    path: &Path,
+    inverse: bool,
+) {
+    let fill = Fill {
+        color,
+        ..Fill::default()
+    };
+
+    for tick_mark in tick_marks {
+        let angle = if inverse {
+            start_angle + tick_mark.scale_inv(angle_span)
+        } else {
+            start_angle + tick_mark.scale(angle_span)
+        };
+
+        frame.with_save(|frame| {
+            if angle < -0.001 || angle > 0.001 {
+                frame.rotate(angle);
+            }
+
+            frame.fill(path, fill);
+        });
+    }
+}
+
+fn rounded_line_path(
+    offset_radius: f32,
+    width: f32,
+    length: f32,
+    border_radius: f32,
+) -> Path {
+    let r = border_radius.min(width / 2.0).min(length / 2.0).max(0.0);
+
+    let left = -width / 2.0;
+    let right = width / 2.0;
+    let top = -offset_radius - length;
+    let bottom = -offset_radius;
+
+    Path::new(|p| {
+        p.move_to(Point::new(left + r, top));
+        p.line_to(Point::new(right - r, top));
+        p.quadratic_curve_to(
+            Point::new(right, top),
+            Point::new(right, top + r),
+        );
+        p.line_to(Point::new(right, bottom - r));
+        p.quadratic_curve_to(
+            Point::new(right, bottom),
+            Point::new(right - r, bottom),
+        );
+        p.line_to(Point::new(left + r, bottom));
+        p.quadratic_curve_to(
+            Point::new(left, bottom),
+            Point::new(left, bottom - r),
+        );
+        p.line_to(Point::new(left, top + r));
+        p.quadratic_curve_to(Point::new(left, top), Point::new(left + r, top));
+        p.close();
+    })
+}
+
+fn triangle_path(
+    offset_radius: f32,
+    width: f32,
+    length: f32,
+    tip_outward: bool,
+) -> Path {
+    let (base, tip) = if tip_outward {
+        (-offset_radius, -offset_radius - length)
+    } else {
+        (-offset_radius - length, -offset_radius)
+    };
+
+    Path::new(|p| {
+        p.move_to(Point::new(-width / 2.0, base));
+        p.line_to(Point::new(0.0, tip));
+        p.line_to(Point::new(width / 2.0, base));
+        p.close();
+    })
+}
+
 #[inline]
 fn draw_tier(
     frame: &mut Frame,
@@ -149,6 +238,54 @@ fn draw_tier(
                     );
                 }
             }
+            Shape::RoundedLine {
+                length,
+                width,
+                border_radius,
+                color,
+            } => {
+                let offset_radius = if inside {
+                    offset_radius - length
+                } else {
+                    offset_radius
+                };
+
+                draw_radial_paths(
+                    frame,
+                    start_angle,
+                    angle_span,
+                    tick_marks,
+                    *color,
+                    &rounded_line_path(
+                        offset_radius,
+                        *width,
+                        *length,
+                        *border_radius,
+                    ),
+                    inverse,
+                );
+            }
+            Shape::Triangle {
+                length,
+                width,
+                color,
+            } => {
+                let offset_radius = if inside {
+                    offset_radius - length
+                } else {
+                    offset_radius
+                };
+
+                draw_radial_paths(
+                    frame,
+                    start_angle,
+                    angle_span,
+                    tick_marks,
+                    *color,
+                    &triangle_path(offset_radius, *width, *length, !inside),
+                    inverse,
+                );
+            }
             Shape::Circle { diameter, color } => {
                 let radius = f32::from(*diameter) / 2.0;
 
@@ -183,19 +320,25 @@ fn draw_tier(
 fn max_length(style: &Style) -> f32 {
     let length_1 = match style.tier_1 {
         Shape::None => 0.0,
-        Shape::Line { length, .. } => length,
+        Shape::Line { length, .. }
+        | Shape::RoundedLine { length, .. }
+        | Shape::Triangle { length, .. } => length,
         Shape::Circle { diameter, .. } => diameter,
     };
 
     let length_2 = match style.tier_1 {
         Shape::None => 0.0,
-        Shape::Line { length, .. } => length,
+        Shape::Line { length, .. }
+        | Shape::RoundedLine { length, .. }
+        | Shape::Triangle { length, .. } => length,
         Shape::Circle { diameter, .. } => diameter,
     };
 
     let length_3 = match style.tier_1 {
         Shape::None => 0.0,
-        Shape::Line { length, .. } => length,
+        Shape::Line { length, .. }
+        | Shape::RoundedLine { length, .. }
+        | Shape::Triangle { length, .. } => length,
         Shape::Circle { diameter, .. } => diameter,
     };
 
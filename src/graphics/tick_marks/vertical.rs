@@ -4,7 +4,9 @@ use super::PrimitiveCache;
 use crate::core::Normal;
 use crate::native::tick_marks;
 use crate::style::tick_marks::{Placement, Shape, Style};
+use iced_graphics::canvas::{Fill, Frame, Path};
 use iced_graphics::{Background, Color, Primitive, Rectangle};
+use iced_native::{Point, Size, Vector};
 
 fn draw_vertical_lines(
     primitives: &mut Vec<Primitive>,
@@ -15,9 +17,11 @@ fn draw_vertical_lines(
     width: f32,
     length: f32,
     color: Color,
+    border_radius: f32,
     inverse: bool,
 ) {
     let start_y = bounds_y - (f32::from(width) / 2.0);
+    let border_radius = border_radius.min(width / 2.0).min(length / 2.0);
     let back_color = Background::Color(color);
 
     if inverse {
@@ -30,7 +34,7 @@ fn draw_vertical_lines(
                     height: f32::from(width),
                 },
                 background: back_color,
-                border_radius: 0.0,
+                border_radius,
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
             });
@@ -45,7 +49,7 @@ fn draw_vertical_lines(
                     height: f32::from(width),
                 },
                 background: back_color,
-                border_radius: 0.0,
+                border_radius,
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
             });
@@ -53,6 +57,136 @@ fn draw_vertical_lines(
     }
 }
 
+/// A tier of tick marks, and where it is drawn.
+struct Tier<'a> {
+    tick_marks: &'a [Normal],
+    bounds: &'a Rectangle,
+    x: f32,
+    inverse: bool,
+}
+
+fn draw_vertical_triangles(
+    primitives: &mut Vec<Primitive>,
+    tier: &Tier<'_>,
+    width: f32,
+    length: f32,
+    color: Color,
+    tip_at_start: bool,
+) {
+    let Tier {
+        tick_marks,
+        bounds,
+        x,
+        inverse,
+    } = *tier;
+    let bounds_y = bounds.y;
+    let bounds_height = bounds.height;
+
+    let half_width = width / 2.0;
+
+    let (base_x, tip_x) = if tip_at_start {
+        (length, 0.0)
+    } else {
+        (0.0, length)
+    };
+
+    let mut frame = Frame::new(Size::new(length, bounds_height + width));
+
+    let fill = Fill {
+        color,
+        ..Fill::default()
+    };
+
+    for tick_mark in tick_marks {
+        let offset = if inverse {
+            tick_mark.scale(bounds_height)
+        } else {
+            tick_mark.scale_inv(bounds_height)
+        };
+
+        let path = Path::new(|p| {
+            p.move_to(Point::new(base_x, offset));
+            p.line_to(Point::new(tip_x, offset + half_width));
+            p.line_to(Point::new(base_x, offset + width));
+            p.close();
+        });
+
+        frame.fill(&path, fill);
+    }
+
+    primitives.push(Primitive::Translate {
+        translation: Vector::new(x, bounds_y - half_width),
+        content: Box::new(frame.into_geometry().into_primitive()),
+    });
+}
+
+/// Draws a tier of `Line`, `RoundedLine`, or `Triangle` tick marks that
+/// start at `x`.
+///
+/// `tip_at_start` is whether the tip of a triangle is at `x` (true), or
+/// at `x + length` (false).
+fn draw_vertical_line_shape(
+    primitives: &mut Vec<Primitive>,
+    tier: &Tier<'_>,
+    length: f32,
+    shape: &Shape,
+    tip_at_start: bool,
+) {
+    let Tier {
+        tick_marks,
+        bounds,
+        x,
+        inverse,
+    } = *tier;
+
+    match *shape {
+        Shape::Line { width, color, .. } => {
+            draw_vertical_lines(
+                primitives,
+                tick_marks,
+                bounds.y,
+                bounds.height,
+                x,
+                width,
+                length,
+                color,
+                0.0,
+                inverse,
+            );
+        }
+        Shape::RoundedLine {
+            width,
+            border_radius,
+            color,
+            ..
+        } => {
+            draw_vertical_lines(
+                primitives,
+                tick_marks,
+                bounds.y,
+                bounds.height,
+                x,
+                width,
+                length,
+                color,
+                border_radius,
+                inverse,
+            );
+        }
+        Shape::Triangle { width, color, .. } => {
+            draw_vertical_triangles(
+                primitives,
+                tier,
+                width,
+                length,
+                color,
+                tip_at_start,
+            );
+        }
+        Shape::None | Shape::Circle { .. } => {}
+    }
+}
+
 fn draw_vertical_circles(
     primitives: &mut Vec<Primitive>,
     tick_marks: &[Normal],
@@ -63,42 +197,34 @@ fn draw_vertical_circles(
     color: Color,
     inverse: bool,
 ) {
-    let diameter = f32::from(diameter);
     let radius = diameter / 2.0;
-    let start_y = bounds_y - f32::from(radius);
-    let back_color = Background::Color(color);
 
-    if inverse {
-        for tick_mark in tick_marks {
-            primitives.push(Primitive::Quad {
-                bounds: Rectangle {
-                    x,
-                    y: (start_y + tick_mark.scale(bounds_height)),
-                    width: diameter,
-                    height: diameter,
-                },
-                background: back_color,
-                border_radius: radius,
-                border_width: 0.0,
-                border_color: Color::TRANSPARENT,
-            });
-        }
-    } else {
-        for tick_mark in tick_marks {
-            primitives.push(Primitive::Quad {
-                bounds: Rectangle {
-                    x,
-                    y: (start_y + tick_mark.scale_inv(bounds_height)),
-                    width: diameter,
-                    height: diameter,
-                },
-                background: back_color,
-                border_radius: radius,
-                border_width: 0.0,
-                border_color: Color::TRANSPARENT,
-            });
-        }
+    // Circles are tessellated into a mesh, which has smoother edges than
+    // quads with a border radius at small sizes.
+    let mut frame = Frame::new(Size::new(diameter, bounds_height + diameter));
+
+    let fill = Fill {
+        color,
+        ..Fill::default()
+    };
+
+    for tick_mark in tick_marks {
+        let offset = if inverse {
+            tick_mark.scale(bounds_height)
+        } else {
+            tick_mark.scale_inv(bounds_height)
+        };
+
+        frame.fill(
+            &Path::circle(Point::new(radius, radius + offset), radius),
+            fill,
+        );
     }
+
+    primitives.push(Primitive::Translate {
+        translation: Vector::new(x, bounds_y - radius),
+        content: Box::new(frame.into_geometry().into_primitive()),
+    });
 }
 
 #[inline]
@@ -113,21 +239,20 @@ fn draw_vertical_left_aligned_tier(
     if let Some(tick_marks) = tick_marks {
         match shape {
             Shape::None => return,
-            Shape::Line {
-                length,
-                width,
-                color,
-            } => {
-                draw_vertical_lines(
+            Shape::Line { length, .. }
+            | Shape::RoundedLine { length, .. }
+            | Shape::Triangle { length, .. } => {
+                draw_vertical_line_shape(
                     primitives,
-                    tick_marks,
-                    bounds.y,
-                    bounds.height,
-                    x,
-                    *width,
+                    &Tier {
+                        tick_marks,
+                        bounds,
+                        x,
+                        inverse,
+                    },
                     *length,
-                    *color,
-                    inverse,
+                    shape,
+                    false,
                 );
             }
             Shape::Circle { diameter, color } => {
@@ -192,21 +317,20 @@ fn draw_vertical_right_aligned_tier(
     if let Some(tick_marks) = tick_marks {
         match shape {
             Shape::None => return,
-            Shape::Line {
-                length,
-                width,
-                color,
-            } => {
-                draw_vertical_lines(
+            Shape::Line { length, .. }
+            | Shape::RoundedLine { length, .. }
+            | Shape::Triangle { length, .. } => {
+                draw_vertical_line_shape(
                     primitives,
-                    tick_marks,
-                    bounds.y,
-                    bounds.height,
-                    x - f32::from(*length),
-                    *width,
+                    &Tier {
+                        tick_marks,
+                        bounds,
+                        x: x - f32::from(*length),
+                        inverse,
+                    },
                     *length,
-                    *color,
-                    inverse,
+                    shape,
+                    true,
                 );
             }
             Shape::Circle { diameter, color } => {
@@ -272,11 +396,9 @@ fn draw_vertical_center_aligned_tier(
     if let Some(tick_marks) = tick_marks {
         match shape {
             Shape::None => return,
-            Shape::Line {
-                length,
-                width,
-                color,
-            } => {
+            Shape::Line { length, .. }
+            | Shape::RoundedLine { length, .. }
+            | Shape::Triangle { length, .. } => {
                 let (x, length) = if fill_length {
                     (
                         bounds.x + f32::from(*length),
@@ -286,16 +408,17 @@ fn draw_vertical_center_aligned_tier(
                     (x - (*length / 2.0), *length)
                 };
 
-                draw_vertical_lines(
+                draw_vertical_line_shape(
                     primitives,
-                    tick_marks,
-                    bounds.y,
-                    bounds.height,
-                    x,
-                    *width,
+                    &Tier {
+                        tick_marks,
+                        bounds,
+                        x,
+                        inverse,
+                    },
                     length,
-                    *color,
-                    inverse,
+                    shape,
+                    false,
                 );
             }
             Shape::Circle { diameter, color } => {
@@ -375,11 +498,9 @@ fn draw_vertical_center_aligned_split_tier(
     if let Some(tick_marks) = tick_marks {
         match shape {
             Shape::None => return,
-            Shape::Line {
-                length,
-                width,
-                color,
-            } => {
+            Shape::Line { length, .. }
+            | Shape::RoundedLine { length, .. }
+            | Shape::Triangle { length, .. } => {
                 let (left_x, length) = if fill_length {
                     let length = *length + ((bounds.width + gap) / 2.0);
                     (x - length - (gap / 2.0), length)
@@ -389,27 +510,29 @@ fn draw_vertical_center_aligned_split_tier(
 
                 let right_x = x + (gap / 2.0);
 
-                draw_vertical_lines(
+                draw_vertical_line_shape(
                     primitives,
-                    tick_marks,
-                    bounds.y,
-                    bounds.height,
-                    left_x,
-                    *width,
+                    &Tier {
+                        tick_marks,
+                        bounds,
+                        x: left_x,
+                        inverse,
+                    },
                     length,
-                    *color,
-                    inverse,
+                    shape,
+                    true,
                 );
-                draw_vertical_lines(
+                draw_vertical_line_shape(
                     primitives,
-                    tick_marks,
-                    bounds.y,
-                    bounds.height,
-                    right_x,
-                    *width,
+                    &Tier {
+                        tick_marks,
+                        bounds,
+                        x: right_x,
+                        inverse,
+                    },
                     length,
-                    *color,
-                    inverse,
+                    shape,
+                    false,
                 );
             }
             Shape::Circle { diameter, color } => {
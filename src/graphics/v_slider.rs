@@ -3,16 +3,21 @@
 //! [`Param`]: ../core/param/trait.Param.html
 
 use crate::core::{ModulationRange, Normal};
+use crate::graphics::atlas::draw_image;
+use crate::graphics::draw_cache::Revision;
+use crate::graphics::pixel_snap::{snap, snap_rect};
 use crate::graphics::{text_marks, tick_marks};
 use crate::native::v_slider;
+use crate::style::transition::Interpolate;
 use iced_graphics::{Backend, Primitive, Renderer};
-use iced_native::{mouse, Background, Color, Point, Rectangle};
+use iced_native::{mouse, Background, Color, Rectangle, Vector};
 
 pub use crate::native::v_slider::State;
 pub use crate::style::v_slider::{
-    ClassicHandle, ClassicRail, ClassicStyle, ModRangePlacement, ModRangeStyle,
-    RectBipolarStyle, RectStyle, Style, StyleSheet, TextMarksStyle,
-    TextureStyle, TickMarksStyle,
+    ClassicHandle, ClassicRail, ClassicStyle, DefaultMarkerStyle, HandleImage,
+    ModRangePlacement, ModRangeStyle, ModulatedValueStyle, RailImage,
+    RailImageFill, RectBipolarStyle, RectStyle, Style, StyleSheet,
+    TextMarksStyle, TextureStyle, TickMarksStyle,
 };
 
 struct ValueMarkers<'a> {
@@ -24,6 +29,10 @@ struct ValueMarkers<'a> {
     text_marks_style: Option<TextMarksStyle>,
     mod_range_style_1: Option<ModRangeStyle>,
     mod_range_style_2: Option<ModRangeStyle>,
+    default_normal: Normal,
+    default_marker_style: Option<DefaultMarkerStyle>,
+    tick_marks_cache: &'a tick_marks::PrimitiveCache,
+    text_marks_cache: &'a text_marks::PrimitiveCache,
 }
 
 /// A vertical slider GUI widget that controls a [`Param`]
@@ -40,82 +49,166 @@ impl<B: Backend> v_slider::Renderer for Renderer<B> {
 
     fn draw(
         &mut self,
-        bounds: Rectangle,
-        cursor_position: Point,
-        normal: Normal,
-        is_dragging: bool,
-        mod_range_1: Option<&ModulationRange>,
-        mod_range_2: Option<&ModulationRange>,
-        tick_marks: Option<&tick_marks::Group>,
-        text_marks: Option<&text_marks::Group>,
+        state: &v_slider::DrawState<'_>,
         style_sheet: &Self::Style,
-        tick_marks_cache: &tick_marks::PrimitiveCache,
-        text_marks_cache: &text_marks::PrimitiveCache,
     ) -> Self::Output {
+        let v_slider::DrawState {
+            bounds,
+            cursor_position,
+            normal,
+            default_normal,
+            is_dragging,
+            is_focused,
+            transition,
+            mod_range_1,
+            mod_range_2,
+            modulated_value,
+            automation_value,
+            tick_marks,
+            text_marks,
+            accent,
+            warning,
+            center_fill,
+            tick_marks_cache,
+            text_marks_cache,
+            draw_cache,
+            revision,
+        } = *state;
+
         let is_mouse_over = bounds.contains(cursor_position);
 
-        let style = if is_dragging {
-            style_sheet.dragging()
-        } else if is_mouse_over {
-            style_sheet.hovered()
+        let accent = if warning {
+            Some(style_sheet.warning_color())
         } else {
-            style_sheet.active()
+            accent
         };
 
-        let bounds = Rectangle {
-            x: bounds.x.round(),
-            y: bounds.y.round(),
-            width: bounds.width.round(),
-            height: bounds.height.round(),
-        };
+        let revision = revision.map(|revision| {
+            Revision::new(revision)
+                .style(&style_sheet.active())
+                .style(&style_sheet.hovered())
+                .style(&style_sheet.dragging())
+                .style(&style_sheet.focused())
+                .style(&style_sheet.tick_marks_style())
+                .style(&style_sheet.text_marks_style())
+                .style(&style_sheet.mod_range_style())
+                .style(&style_sheet.mod_range_style_2())
+                .style(&style_sheet.default_marker_style())
+                .style(&style_sheet.automation_value_style())
+                .style(&style_sheet.modulated_value_style())
+                .style(&style_sheet.warning_color())
+                .finish()
+        });
+
+        draw_cache.cached(revision, bounds, is_mouse_over, || {
+            let style = if let Some(transition) = transition {
+                let resting = if is_focused {
+                    style_sheet.focused()
+                } else {
+                    style_sheet.active()
+                };
+
+                resting
+                    .interpolate(
+                        &style_sheet.hovered(),
+                        transition.hover_amount(),
+                    )
+                    .interpolate(
+                        &style_sheet.dragging(),
+                        transition.press_amount(),
+                    )
+            } else if is_dragging {
+                style_sheet.dragging()
+            } else if is_mouse_over {
+                style_sheet.hovered()
+            } else if is_focused {
+                style_sheet.focused()
+            } else {
+                style_sheet.active()
+            };
 
-        let value_markers = ValueMarkers {
-            tick_marks,
-            text_marks,
-            mod_range_1,
-            mod_range_2,
-            tick_marks_style: style_sheet.tick_marks_style(),
-            text_marks_style: style_sheet.text_marks_style(),
-            mod_range_style_1: style_sheet.mod_range_style(),
-            mod_range_style_2: style_sheet.mod_range_style_2(),
-        };
+            let style = if let Some(accent) = accent {
+                style.with_accent(accent)
+            } else {
+                style
+            };
 
-        let primitives = match style {
-            Style::Texture(style) => draw_texture_style(
-                normal,
-                &bounds,
-                style,
-                &value_markers,
-                tick_marks_cache,
-                text_marks_cache,
-            ),
-            Style::Classic(style) => draw_classic_style(
-                normal,
-                &bounds,
-                &style,
-                &value_markers,
+            let bounds = snap_rect(bounds);
+
+            let value_markers = ValueMarkers {
+                tick_marks,
+                text_marks,
+                mod_range_1,
+                mod_range_2,
+                tick_marks_style: style_sheet.tick_marks_style(),
+                text_marks_style: style_sheet.text_marks_style(),
+                mod_range_style_1: style_sheet.mod_range_style(),
+                mod_range_style_2: style_sheet.mod_range_style_2(),
+                default_normal,
+                default_marker_style: style_sheet.default_marker_style(),
                 tick_marks_cache,
                 text_marks_cache,
-            ),
-            Style::Rect(style) => draw_rect_style(
-                normal,
+            };
+
+            let handle_height = match &style {
+                Style::Texture(style) => style.handle_height,
+                Style::Classic(style) => style.handle.height,
+                Style::Rect(style) => style.handle_height,
+                Style::RectBipolar(style) => style.handle_height,
+            };
+
+            let automation_value = draw_modulated_value(
                 &bounds,
-                &style,
-                &value_markers,
-                tick_marks_cache,
-                text_marks_cache,
-            ),
-            Style::RectBipolar(style) => draw_rect_bipolar_style(
-                normal,
+                handle_height,
+                automation_value,
+                &style_sheet.automation_value_style(),
+            );
+
+            let modulated_value = draw_modulated_value(
                 &bounds,
-                &style,
-                &value_markers,
-                tick_marks_cache,
-                text_marks_cache,
-            ),
-        };
+                handle_height,
+                modulated_value,
+                &style_sheet.modulated_value_style(),
+            );
+
+            let primitives = match style {
+                Style::Texture(style) => draw_texture_style(
+                    normal,
+                    &bounds,
+                    style,
+                    is_dragging,
+                    is_mouse_over,
+                    &value_markers,
+                ),
+                Style::Classic(style) => {
+                    draw_classic_style(normal, &bounds, &style, &value_markers)
+                }
+                Style::Rect(style) => draw_rect_style(
+                    normal,
+                    &bounds,
+                    &style,
+                    center_fill,
+                    &value_markers,
+                ),
+                Style::RectBipolar(style) => draw_rect_bipolar_style(
+                    normal,
+                    &bounds,
+                    &style,
+                    &value_markers,
+                ),
+            };
 
-        (primitives, mouse::Interaction::default())
+            (
+                Primitive::Group {
+                    primitives: vec![
+                        primitives,
+                        automation_value,
+                        modulated_value,
+                    ],
+                },
+                mouse::Interaction::default(),
+            )
+        })
     }
 }
 
@@ -123,21 +216,28 @@ fn draw_value_markers<'a>(
     mark_bounds: &Rectangle,
     mod_bounds: &Rectangle,
     value_markers: &ValueMarkers<'a>,
-    tick_marks_cache: &tick_marks::PrimitiveCache,
-    text_marks_cache: &text_marks::PrimitiveCache,
 ) -> (Primitive, Primitive, Primitive, Primitive) {
     (
-        draw_tick_marks(
-            mark_bounds,
-            value_markers.tick_marks,
-            &value_markers.tick_marks_style,
-            tick_marks_cache,
-        ),
+        Primitive::Group {
+            primitives: vec![
+                draw_tick_marks(
+                    mark_bounds,
+                    value_markers.tick_marks,
+                    &value_markers.tick_marks_style,
+                    value_markers.tick_marks_cache,
+                ),
+                draw_default_marker(
+                    mark_bounds,
+                    value_markers.default_normal,
+                    &value_markers.default_marker_style,
+                ),
+            ],
+        },
         draw_text_marks(
             mark_bounds,
             value_markers.text_marks,
             &value_markers.text_marks_style,
-            text_marks_cache,
+            value_markers.text_marks_cache,
         ),
         draw_mod_range(
             mod_bounds,
@@ -152,6 +252,32 @@ fn draw_value_markers<'a>(
     )
 }
 
+fn draw_default_marker(
+    bounds: &Rectangle,
+    normal: Normal,
+    style: &Option<DefaultMarkerStyle>,
+) -> Primitive {
+    if let Some(style) = style {
+        Primitive::Quad {
+            bounds: Rectangle {
+                x: snap(bounds.center_x() + style.offset - (style.width / 2.0)),
+                y: snap(
+                    bounds.y + normal.scale_inv(bounds.height)
+                        - (style.height / 2.0),
+                ),
+                width: style.width,
+                height: style.height,
+            },
+            background: Background::Color(style.color),
+            border_radius: style.border_radius,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        }
+    } else {
+        Primitive::None
+    }
+}
+
 fn draw_tick_marks(
     bounds: &Rectangle,
     tick_marks: Option<&tick_marks::Group>,
@@ -295,43 +421,86 @@ fn draw_mod_range(
     }
 }
 
+fn draw_modulated_value(
+    bounds: &Rectangle,
+    handle_height: f32,
+    modulated_value: Option<Normal>,
+    style: &Option<ModulatedValueStyle>,
+) -> Primitive {
+    if let Some(normal) = modulated_value {
+        if let Some(style) = style {
+            let y = bounds.y
+                + (handle_height / 2.0)
+                + normal.scale_inv(bounds.height - handle_height)
+                - (style.height / 2.0);
+
+            Primitive::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + style.padding,
+                    y: snap(y),
+                    width: bounds.width - (style.padding * 2.0),
+                    height: style.height,
+                },
+                background: Background::Color(style.color),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            }
+        } else {
+            Primitive::None
+        }
+    } else {
+        Primitive::None
+    }
+}
+
 fn draw_texture_style<'a>(
     normal: Normal,
     bounds: &Rectangle,
     style: TextureStyle,
+    is_dragging: bool,
+    is_mouse_over: bool,
     value_markers: &ValueMarkers<'a>,
-    tick_marks_cache: &tick_marks::PrimitiveCache,
-    text_marks_cache: &text_marks::PrimitiveCache,
 ) -> Primitive {
     let value_bounds = Rectangle {
         x: bounds.x,
-        y: (bounds.y + (f32::from(style.handle_height) / 2.0)).round(),
+        y: snap(bounds.y + (style.handle_height / 2.0)),
         width: bounds.width,
-        height: bounds.height - f32::from(style.handle_height),
+        height: bounds.height - style.handle_height,
     };
 
-    let (tick_marks, text_marks, mod_range_1, mod_range_2) = draw_value_markers(
-        &value_bounds,
-        &value_bounds,
-        value_markers,
-        tick_marks_cache,
-        text_marks_cache,
-    );
+    let (tick_marks, text_marks, mod_range_1, mod_range_2) =
+        draw_value_markers(&value_bounds, &value_bounds, value_markers);
 
     let (left_rail, right_rail) = draw_classic_rail(&bounds, &style.rail);
 
-    let handle = Primitive::Image {
-        handle: style.image_handle,
-        bounds: Rectangle {
-            x: (bounds.center_x() + style.image_bounds.x).round(),
-            y: (value_bounds.y
-                + style.image_bounds.y
-                + normal.scale_inv(value_bounds.height))
-            .round(),
+    let state_image = if is_dragging {
+        style.dragging_image
+    } else if is_mouse_over {
+        style.hovered_image
+    } else {
+        None
+    };
+
+    let (image_handle, image_region) = match state_image {
+        Some(image) => (image.image_handle, image.image_region),
+        None => (style.image_handle, style.image_region),
+    };
+
+    let handle = draw_image(
+        image_handle,
+        image_region,
+        Rectangle {
+            x: snap(bounds.center_x() + style.image_bounds.x),
+            y: snap(
+                value_bounds.y
+                    + style.image_bounds.y
+                    + normal.scale_inv(value_bounds.height),
+            ),
             width: style.image_bounds.width,
             height: style.image_bounds.height,
         },
-    };
+    );
 
     Primitive::Group {
         primitives: vec![
@@ -351,30 +520,23 @@ fn draw_classic_style<'a>(
     bounds: &Rectangle,
     style: &ClassicStyle,
     value_markers: &ValueMarkers<'a>,
-    tick_marks_cache: &tick_marks::PrimitiveCache,
-    text_marks_cache: &text_marks::PrimitiveCache,
 ) -> Primitive {
-    let handle_height = f32::from(style.handle.height);
+    let handle_height = style.handle.height;
 
     let value_bounds = Rectangle {
         x: bounds.x,
-        y: (bounds.y + (handle_height / 2.0)).round(),
+        y: snap(bounds.y + (handle_height / 2.0)),
         width: bounds.width,
         height: bounds.height - handle_height,
     };
 
-    let (tick_marks, text_marks, mod_range_1, mod_range_2) = draw_value_markers(
-        &value_bounds,
-        &value_bounds,
-        value_markers,
-        tick_marks_cache,
-        text_marks_cache,
-    );
+    let (tick_marks, text_marks, mod_range_1, mod_range_2) =
+        draw_value_markers(&value_bounds, &value_bounds, value_markers);
 
     let (left_rail, right_rail) = draw_classic_rail(&bounds, &style.rail);
 
     let handle_border_radius = style.handle.border_radius;
-    let handle_offset = normal.scale_inv(value_bounds.height).round();
+    let handle_offset = snap(normal.scale_inv(value_bounds.height));
     let notch_width = f32::from(style.handle.notch_width);
 
     let handle = Primitive::Quad {
@@ -394,9 +556,10 @@ fn draw_classic_style<'a>(
         Primitive::Quad {
             bounds: Rectangle {
                 x: bounds.x,
-                y: (bounds.y + handle_offset + (handle_height / 2.0)
-                    - (notch_width / 2.0))
-                    .round(),
+                y: snap(
+                    bounds.y + handle_offset + (handle_height / 2.0)
+                        - (notch_width / 2.0),
+                ),
                 width: bounds.width,
                 height: notch_width,
             },
@@ -427,29 +590,23 @@ fn draw_rect_style<'a>(
     normal: Normal,
     bounds: &Rectangle,
     style: &RectStyle,
+    center_fill: bool,
     value_markers: &ValueMarkers<'a>,
-    tick_marks_cache: &tick_marks::PrimitiveCache,
-    text_marks_cache: &text_marks::PrimitiveCache,
 ) -> Primitive {
-    let handle_height = f32::from(style.handle_height);
+    let handle_height = style.handle_height;
 
     let border_width = f32::from(style.back_border_width);
     let twice_border_width = border_width * 2.0;
 
     let value_bounds = Rectangle {
         x: bounds.x,
-        y: (bounds.y + (handle_height / 2.0)).round(),
+        y: snap(bounds.y + (handle_height / 2.0)),
         width: bounds.width,
         height: bounds.height - handle_height,
     };
 
-    let (tick_marks, text_marks, mod_range_1, mod_range_2) = draw_value_markers(
-        &value_bounds,
-        &bounds,
-        value_markers,
-        tick_marks_cache,
-        text_marks_cache,
-    );
+    let (tick_marks, text_marks, mod_range_1, mod_range_2) =
+        draw_value_markers(&value_bounds, &bounds, value_markers);
 
     let empty_rect = Primitive::Quad {
         bounds: Rectangle {
@@ -464,23 +621,45 @@ fn draw_rect_style<'a>(
         border_color: style.back_border_color,
     };
 
-    let handle_offset = normal
-        .scale_inv(value_bounds.height - twice_border_width)
-        .round();
+    let handle_offset =
+        snap(normal.scale_inv(value_bounds.height - twice_border_width));
 
-    let filled_offset =
-        handle_offset + handle_height + f32::from(style.handle_filled_gap);
-    let filled_rect = Primitive::Quad {
-        bounds: Rectangle {
-            x: bounds.x,
-            y: bounds.y + filled_offset,
-            width: bounds.width,
-            height: bounds.height - filled_offset,
-        },
-        background: Background::Color(style.filled_color),
-        border_radius: style.back_border_radius,
-        border_width: style.back_border_width,
-        border_color: Color::TRANSPARENT,
+    let filled_gap = f32::from(style.handle_filled_gap);
+
+    let filled_bounds = if !center_fill {
+        let filled_offset = handle_offset + handle_height + filled_gap;
+        Some((filled_offset, bounds.height - filled_offset))
+    } else if normal.as_f32() > 0.499 && normal.as_f32() < 0.501 {
+        None
+    } else if normal.as_f32() > 0.5 {
+        let filled_offset = handle_offset + handle_height + filled_gap;
+        Some((
+            filled_offset,
+            snap((bounds.height / 2.0) - filled_offset + twice_border_width),
+        ))
+    } else {
+        let filled_offset = snap(bounds.height / 2.0) - border_width;
+        Some((
+            filled_offset,
+            handle_offset - filled_offset + twice_border_width - filled_gap,
+        ))
+    };
+
+    let filled_rect = if let Some((y, height)) = filled_bounds {
+        Primitive::Quad {
+            bounds: Rectangle {
+                x: bounds.x,
+                y: bounds.y + y,
+                width: bounds.width,
+                height,
+            },
+            background: Background::Color(style.filled_color),
+            border_radius: style.back_border_radius,
+            border_width: style.back_border_width,
+            border_color: Color::TRANSPARENT,
+        }
+    } else {
+        Primitive::None
     };
 
     let handle = Primitive::Quad {
@@ -514,28 +693,21 @@ fn draw_rect_bipolar_style<'a>(
     bounds: &Rectangle,
     style: &RectBipolarStyle,
     value_markers: &ValueMarkers<'a>,
-    tick_marks_cache: &tick_marks::PrimitiveCache,
-    text_marks_cache: &text_marks::PrimitiveCache,
 ) -> Primitive {
-    let handle_height = f32::from(style.handle_height);
+    let handle_height = style.handle_height;
 
     let border_width = f32::from(style.back_border_width);
     let twice_border_width = border_width * 2.0;
 
     let value_bounds = Rectangle {
         x: bounds.x,
-        y: (bounds.y + (handle_height / 2.0)).round(),
+        y: snap(bounds.y + (handle_height / 2.0)),
         width: bounds.width,
         height: bounds.height - handle_height,
     };
 
-    let (tick_marks, text_marks, mod_range_1, mod_range_2) = draw_value_markers(
-        &value_bounds,
-        &bounds,
-        value_markers,
-        tick_marks_cache,
-        text_marks_cache,
-    );
+    let (tick_marks, text_marks, mod_range_1, mod_range_2) =
+        draw_value_markers(&value_bounds, &bounds, value_markers);
 
     let empty_rect = Primitive::Quad {
         bounds: Rectangle {
@@ -550,9 +722,8 @@ fn draw_rect_bipolar_style<'a>(
         border_color: style.back_border_color,
     };
 
-    let handle_offset = normal
-        .scale_inv(value_bounds.height - twice_border_width)
-        .round();
+    let handle_offset =
+        snap(normal.scale_inv(value_bounds.height - twice_border_width));
 
     let (handle_color, filled_rect) = if normal.as_f32() > 0.499
         && normal.as_f32() < 0.501
@@ -568,9 +739,10 @@ fn draw_rect_bipolar_style<'a>(
                     x: bounds.x,
                     y: bounds.y + filled_rect_offset,
                     width: bounds.width,
-                    height: ((bounds.height / 2.0) - filled_rect_offset
-                        + twice_border_width)
-                        .round(),
+                    height: snap(
+                        (bounds.height / 2.0) - filled_rect_offset
+                            + twice_border_width,
+                    ),
                 },
                 background: Background::Color(style.top_filled_color),
                 border_radius: style.back_border_radius,
@@ -579,7 +751,7 @@ fn draw_rect_bipolar_style<'a>(
             },
         )
     } else {
-        let filled_rect_offset = (bounds.height / 2.0).round() - border_width;
+        let filled_rect_offset = snap(bounds.height / 2.0) - border_width;
         (
             style.handle_bottom_color,
             Primitive::Quad {
@@ -625,10 +797,60 @@ fn draw_rect_bipolar_style<'a>(
     }
 }
 
+fn draw_rail_image(
+    bounds: &Rectangle,
+    style: &ClassicRail,
+    rail_image: &RailImage,
+) -> Primitive {
+    let rail_bounds = Rectangle {
+        y: bounds.y + style.rail_padding,
+        x: snap(bounds.center_x() - (rail_image.thickness / 2.0)),
+        height: bounds.height - (style.rail_padding * 2.0),
+        width: rail_image.thickness,
+    };
+
+    match rail_image.fill {
+        RailImageFill::Stretch => draw_image(
+            rail_image.image_handle.clone(),
+            rail_image.image_region,
+            rail_bounds,
+        ),
+        RailImageFill::Tile { length } => {
+            let length = length.max(1.0);
+            let tiles = (rail_bounds.height / length).ceil() as usize;
+
+            let primitives = (0..tiles)
+                .map(|i| {
+                    draw_image(
+                        rail_image.image_handle.clone(),
+                        rail_image.image_region,
+                        Rectangle {
+                            y: rail_bounds.y + (i as f32 * length),
+                            height: length,
+                            ..rail_bounds
+                        },
+                    )
+                })
+                .collect();
+
+            // The last tile is cut off at the end of the rail.
+            Primitive::Clip {
+                bounds: rail_bounds,
+                offset: Vector::new(0, 0),
+                content: Box::new(Primitive::Group { primitives }),
+            }
+        }
+    }
+}
+
 fn draw_classic_rail(
     bounds: &Rectangle,
     style: &ClassicRail,
 ) -> (Primitive, Primitive) {
+    if let Some(rail_image) = &style.rail_image {
+        return (draw_rail_image(bounds, style, rail_image), Primitive::None);
+    }
+
     let (left_width, right_width) = style.rail_widths;
     let (left_color, right_color) = style.rail_colors;
 
@@ -637,7 +859,7 @@ fn draw_classic_rail(
 
     let full_width = left_width + right_width;
 
-    let start_x = (bounds.x + ((bounds.width - full_width) / 2.0)).round();
+    let start_x = snap(bounds.x + ((bounds.width - full_width) / 2.0));
 
     let y = bounds.y + f32::from(style.rail_padding);
     let height = bounds.height - (f32::from(style.rail_padding) * 2.0);
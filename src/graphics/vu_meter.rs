@@ -0,0 +1,205 @@
+//! Display a VU meter with standard volume indicator ballistics.
+
+use crate::core::Normal;
+use crate::native::vu_meter;
+use iced_graphics::canvas::{path::Arc, Frame, Path, Stroke};
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Color, Point, Rectangle, Vector};
+
+pub use crate::native::vu_meter::{State, INTEGRATION_TIME};
+pub use crate::style::vu_meter::{BarStyle, NeedleStyle, Style, StyleSheet};
+
+/// A VU meter with a needle or a bar.
+pub type VuMeter<'a, Backend> = vu_meter::VuMeter<'a, Renderer<Backend>>;
+
+impl<B: Backend> vu_meter::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        normal: Normal,
+        zero_position: Normal,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        let primitives = match style_sheet.style() {
+            Style::Needle(style) => {
+                draw_needle_style(bounds, normal, zero_position, &style)
+            }
+            Style::Bar(style) => {
+                draw_bar_style(bounds, normal, zero_position, &style)
+            }
+        };
+
+        (
+            Primitive::Group { primitives },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+fn back_quad(
+    bounds: Rectangle,
+    color: Color,
+    border_width: f32,
+    border_color: Color,
+) -> Primitive {
+    Primitive::Quad {
+        bounds,
+        background: Background::Color(color),
+        border_radius: 0.0,
+        border_width,
+        border_color,
+    }
+}
+
+fn inner_bounds(bounds: Rectangle, border_width: f32) -> Rectangle {
+    Rectangle {
+        x: bounds.x + border_width,
+        y: bounds.y + border_width,
+        width: bounds.width - (border_width * 2.0),
+        height: bounds.height - (border_width * 2.0),
+    }
+}
+
+fn draw_needle_style(
+    bounds: Rectangle,
+    normal: Normal,
+    zero_position: Normal,
+    style: &NeedleStyle,
+) -> Vec<Primitive> {
+    let inner = inner_bounds(bounds, style.back_border_width);
+
+    let half_sweep =
+        (style.sweep_angle / 2.0).clamp(0.01, std::f32::consts::FRAC_PI_2);
+
+    // Keep a small margin at the bottom for the pivot, and make sure the
+    // ends of the sweep stay inside the meter.
+    let pivot = Point::new(
+        inner.width / 2.0,
+        inner.height - (inner.height * 0.1).min(6.0),
+    );
+    let radius = (pivot.y * 0.9)
+        .min(inner.width / 2.0 * 0.9 / half_sweep.sin())
+        .max(0.0);
+
+    // Canvas angles increase clockwise from the positive x axis, so
+    // straight up is `-PI / 2`.
+    let start_angle = -std::f32::consts::FRAC_PI_2 - half_sweep;
+    let angle_at =
+        |normal: Normal| start_angle + normal.as_f32() * half_sweep * 2.0;
+
+    let scale_radius = radius * 0.9;
+    let zero_angle = angle_at(zero_position);
+
+    let mut frame = Frame::new(inner.size());
+
+    let scale_stroke = Stroke {
+        width: style.scale_width,
+        color: style.scale_color,
+        ..Stroke::default()
+    };
+    frame.stroke(
+        &Path::new(|path| {
+            path.arc(Arc {
+                center: pivot,
+                radius: scale_radius,
+                start_angle,
+                end_angle: zero_angle,
+            })
+        }),
+        scale_stroke,
+    );
+
+    let over_stroke = Stroke {
+        width: style.scale_width,
+        color: style.over_color,
+        ..Stroke::default()
+    };
+    frame.stroke(
+        &Path::new(|path| {
+            path.arc(Arc {
+                center: pivot,
+                radius: scale_radius,
+                start_angle: zero_angle,
+                end_angle: start_angle + half_sweep * 2.0,
+            })
+        }),
+        over_stroke,
+    );
+
+    let needle_angle = angle_at(normal);
+    let tip = Point::new(
+        pivot.x + radius * needle_angle.cos(),
+        pivot.y + radius * needle_angle.sin(),
+    );
+
+    let needle_stroke = Stroke {
+        width: style.needle_width,
+        color: style.needle_color,
+        ..Stroke::default()
+    };
+    frame.stroke(&Path::line(pivot, tip), needle_stroke);
+
+    vec![
+        back_quad(
+            bounds,
+            style.back_color,
+            style.back_border_width,
+            style.back_border_color,
+        ),
+        Primitive::Translate {
+            translation: Vector::new(inner.x, inner.y),
+            content: Box::new(frame.into_geometry().into_primitive()),
+        },
+    ]
+}
+
+fn draw_bar_style(
+    bounds: Rectangle,
+    normal: Normal,
+    zero_position: Normal,
+    style: &BarStyle,
+) -> Vec<Primitive> {
+    let inner = inner_bounds(bounds, style.back_border_width);
+
+    let mut primitives = vec![back_quad(
+        bounds,
+        style.back_color,
+        style.back_border_width,
+        style.back_border_color,
+    )];
+
+    let bottom = inner.y + inner.height;
+    let value_y = (bottom - normal.as_f32() * inner.height).round();
+    let zero_y = (bottom - zero_position.as_f32() * inner.height).round();
+
+    let mut push_bar = |top: f32, bottom: f32, color: Color| {
+        if bottom > top {
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: inner.x,
+                    y: top,
+                    width: inner.width,
+                    height: bottom - top,
+                },
+                background: Background::Color(color),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            });
+        }
+    };
+
+    push_bar(value_y.max(zero_y), bottom, style.color);
+    push_bar(value_y, zero_y, style.over_color);
+
+    primitives
+}
@@ -0,0 +1,170 @@
+//! Display a zoomable and scrollable waveform of an audio clip.
+
+use crate::native::waveform_view;
+use iced_graphics::canvas::{Fill, Frame, Path};
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Color, Point, Rectangle, Size, Vector};
+
+pub use crate::native::waveform_view::State;
+pub use crate::style::waveform_view::{Style, StyleSheet};
+
+/// A waveform display of an audio clip.
+pub type WaveformView<'a, Backend> =
+    waveform_view::WaveformView<'a, Renderer<Backend>>;
+
+impl<B: Backend> waveform_view::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        peaks: &[(f32, f32)],
+        visible_range: (f32, f32),
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if is_mouse_over {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        let back = Primitive::Quad {
+            bounds,
+            background: Background::Color(style.back_color),
+            border_radius: 0.0,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        };
+
+        let inner = Rectangle {
+            x: bounds.x + style.back_border_width,
+            y: bounds.y + style.back_border_width,
+            width: bounds.width - (style.back_border_width * 2.0),
+            height: bounds.height - (style.back_border_width * 2.0),
+        };
+
+        let center_line = if let Some(color) = style.center_line_color {
+            Primitive::Quad {
+                bounds: Rectangle {
+                    x: inner.x,
+                    y: (inner.center_y() - (style.center_line_width / 2.0))
+                        .round(),
+                    width: inner.width,
+                    height: style.center_line_width,
+                },
+                background: Background::Color(color),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            }
+        } else {
+            Primitive::None
+        };
+
+        let wave = draw_wave(inner, peaks, visible_range, style.wave_color);
+
+        (
+            Primitive::Group {
+                primitives: vec![back, wave, center_line],
+            },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+/// Returns the `(min, max)` of all peaks that fall into the range
+/// `[start, end)`, where `start` and `end` are indexes into `peaks`.
+fn peak_in_range(peaks: &[(f32, f32)], start: f32, end: f32) -> (f32, f32) {
+    let last = peaks.len() - 1;
+    let first_i = (start.floor().max(0.0) as usize).min(last);
+    let last_i = ((end.ceil() as usize).saturating_sub(1))
+        .max(first_i)
+        .min(last);
+
+    peaks[first_i..=last_i]
+        .iter()
+        .fold((f32::MAX, f32::MIN), |(min, max), peak| {
+            (min.min(peak.0), max.max(peak.1))
+        })
+}
+
+fn draw_wave(
+    bounds: Rectangle,
+    peaks: &[(f32, f32)],
+    visible_range: (f32, f32),
+    color: Color,
+) -> Primitive {
+    let columns = bounds.width.floor() as usize;
+
+    if peaks.is_empty() || columns == 0 || bounds.height <= 0.0 {
+        return Primitive::None;
+    }
+
+    let num_peaks = peaks.len() as f32;
+    let start = visible_range.0 * num_peaks;
+    let peaks_per_column =
+        (visible_range.1 - visible_range.0) * num_peaks / columns as f32;
+
+    let half_height = bounds.height / 2.0;
+
+    let column_peaks: Vec<(f32, f32)> = (0..columns)
+        .map(|column| {
+            let column_start = start + column as f32 * peaks_per_column;
+            let (min, max) = peak_in_range(
+                peaks,
+                column_start,
+                column_start + peaks_per_column,
+            );
+
+            (
+                half_height - (max.clamp(-1.0, 1.0) * half_height),
+                half_height - (min.clamp(-1.0, 1.0) * half_height),
+            )
+        })
+        .collect();
+
+    let path = Path::new(|path| {
+        path.move_to(Point::new(0.0, column_peaks[0].0));
+
+        // Trace the top of the wave from left to right, and then the bottom
+        // from right to left.
+        for (column, (top, _)) in column_peaks.iter().enumerate() {
+            path.line_to(Point::new(column as f32, *top));
+            path.line_to(Point::new(column as f32 + 1.0, *top));
+        }
+        for (column, (_, bottom)) in column_peaks.iter().enumerate().rev() {
+            // Keep the wave at least one pixel thick.
+            let bottom = bottom.max(column_peaks[column].0 + 1.0);
+
+            path.line_to(Point::new(column as f32 + 1.0, bottom));
+            path.line_to(Point::new(column as f32, bottom));
+        }
+
+        path.close();
+    });
+
+    let mut frame = Frame::new(Size::new(bounds.width, bounds.height));
+
+    frame.fill(
+        &path,
+        Fill {
+            color,
+            ..Fill::default()
+        },
+    );
+
+    Primitive::Translate {
+        translation: Vector::new(bounds.x, bounds.y),
+        content: Box::new(frame.into_geometry().into_primitive()),
+    }
+}
@@ -3,14 +3,19 @@
 //!
 //! [`Param`]: ../core/param/trait.Param.html
 
-use crate::core::Normal;
+use crate::graphics::atlas::draw_image;
+use crate::graphics::draw_cache::Revision;
+use crate::graphics::pixel_snap::{snap, snap_floor};
+use crate::graphics::{text_marks, tick_marks};
 use crate::native::xy_pad;
 use iced_graphics::{Backend, Primitive, Renderer};
-use iced_native::{mouse, Background, Color, Point, Rectangle};
+use iced_native::{mouse, Background, Color, Rectangle};
 
-pub use crate::native::xy_pad::State;
+pub use crate::native::xy_pad::{Corner, State, Throw};
 pub use crate::style::xy_pad::{
-    HandleCircle, HandleShape, HandleSquare, Style, StyleSheet,
+    CrosshairStyle, GridStyle, HandleCircle, HandleShape, HandleSquare,
+    SnapshotStyle, Style, StyleSheet, TextMarksStyle, TextureStyle,
+    TickMarksStyle,
 };
 
 /// A 2D XY pad GUI widget that controls two [`Param`] parameters at
@@ -29,173 +34,525 @@ impl<B: Backend> xy_pad::Renderer for Renderer<B> {
 
     fn draw(
         &mut self,
-        bounds: Rectangle,
-        cursor_position: Point,
-        normal_x: Normal,
-        normal_y: Normal,
-        is_dragging: bool,
+        state: &xy_pad::DrawState<'_>,
         style_sheet: &Self::Style,
     ) -> Self::Output {
+        let xy_pad::DrawState {
+            bounds,
+            cursor_position,
+            normal_x,
+            normal_y,
+            is_dragging,
+            is_focused,
+            snapshots,
+            snapshot_size,
+            tick_marks_x,
+            tick_marks_y,
+            text_marks_x,
+            text_marks_y,
+            accent,
+            tick_marks_cache_x,
+            tick_marks_cache_y,
+            text_marks_cache_x,
+            text_marks_cache_y,
+            draw_cache,
+            revision,
+        } = *state;
+
         let is_mouse_over = bounds.contains(cursor_position);
 
-        let style = if is_dragging {
-            style_sheet.dragging()
-        } else if is_mouse_over {
-            style_sheet.hovered()
-        } else {
-            style_sheet.active()
-        };
+        let revision = revision.map(|revision| {
+            Revision::new(revision)
+                .style(&style_sheet.active())
+                .style(&style_sheet.hovered())
+                .style(&style_sheet.dragging())
+                .style(&style_sheet.focused())
+                .style(&style_sheet.tick_marks_style())
+                .style(&style_sheet.text_marks_style())
+                .style(&style_sheet.grid_style())
+                .style(&style_sheet.crosshair_style())
+                .style(&style_sheet.snapshot_style())
+                .finish()
+        });
 
-        let bounds_x = bounds.x.floor();
-        let bounds_y = bounds.y.floor();
+        draw_cache.cached(revision, bounds, is_mouse_over, || {
+            let style = if is_dragging {
+                style_sheet.dragging()
+            } else if is_mouse_over {
+                style_sheet.hovered()
+            } else if is_focused {
+                style_sheet.focused()
+            } else {
+                style_sheet.active()
+            };
 
-        let bounds_size = {
-            if bounds.width <= bounds.height {
-                bounds.width.floor()
+            let style = if let Some(accent) = accent {
+                style.with_accent(accent)
             } else {
-                bounds.height.floor()
-            }
-        };
+                style
+            };
+
+            let bounds_x = snap_floor(bounds.x);
+            let bounds_y = snap_floor(bounds.y);
 
-        let back = Primitive::Quad {
-            bounds: Rectangle {
+            let bounds_size = {
+                if bounds.width <= bounds.height {
+                    snap_floor(bounds.width)
+                } else {
+                    snap_floor(bounds.height)
+                }
+            };
+
+            let back = Primitive::Quad {
+                bounds: Rectangle {
+                    x: bounds_x,
+                    y: bounds_y,
+                    width: bounds_size,
+                    height: bounds_size,
+                },
+                background: Background::Color(style.back_color),
+                border_radius: 0.0,
+                border_width: style.border_width,
+                border_color: style.border_color,
+            };
+
+            let handle_x =
+                snap_floor(bounds_x + (bounds_size * normal_x.as_f32()));
+            let handle_y = snap_floor(
+                bounds_y + (bounds_size * (1.0 - normal_y.as_f32())),
+            );
+
+            let pad_bounds = Rectangle {
                 x: bounds_x,
                 y: bounds_y,
                 width: bounds_size,
                 height: bounds_size,
-            },
-            background: Background::Color(style.back_color),
-            border_radius: 0.0,
-            border_width: style.border_width,
-            border_color: style.border_color,
-        };
+            };
 
-        let handle_x = (bounds_x + (bounds_size * normal_x.as_f32())).floor();
-        let handle_y =
-            (bounds_y + (bounds_size * (1.0 - normal_y.as_f32()))).floor();
+            let (tick_marks_x, tick_marks_y) =
+                if let Some(style) = style_sheet.tick_marks_style() {
+                    draw_tick_marks(
+                        &pad_bounds,
+                        tick_marks_x,
+                        tick_marks_y,
+                        &style,
+                        tick_marks_cache_x,
+                        tick_marks_cache_y,
+                    )
+                } else {
+                    (Primitive::None, Primitive::None)
+                };
 
-        let bounds_center = (bounds_size / 2.0).floor();
+            let (text_marks_x, text_marks_y) =
+                if let Some(style) = style_sheet.text_marks_style() {
+                    draw_text_marks(
+                        &pad_bounds,
+                        text_marks_x,
+                        text_marks_y,
+                        &style,
+                        text_marks_cache_x,
+                        text_marks_cache_y,
+                    )
+                } else {
+                    (Primitive::None, Primitive::None)
+                };
 
-        let (h_center_line, v_center_line) = if style.center_line_color
-            != Color::TRANSPARENT
-        {
-            let center_line_width = style.center_line_width as f32;
-            let half_center_line_width = (center_line_width / 2.0).floor();
+            let back_image = match &style.texture {
+                Some(TextureStyle {
+                    back_image: Some(image),
+                    back_image_region,
+                    ..
+                }) => draw_image(image.clone(), *back_image_region, pad_bounds),
+                _ => Primitive::None,
+            };
 
-            (
-                Primitive::Quad {
-                    bounds: Rectangle {
-                        x: bounds_x,
-                        y: bounds_y + bounds_center - half_center_line_width,
-                        width: bounds_size,
-                        height: center_line_width,
-                    },
-                    background: Background::Color(style.center_line_color),
-                    border_radius: 0.0,
-                    border_width: 0.0,
-                    border_color: Color::TRANSPARENT,
-                },
-                Primitive::Quad {
-                    bounds: Rectangle {
-                        x: bounds_x + bounds_center - half_center_line_width,
-                        y: bounds_y,
-                        width: center_line_width,
-                        height: bounds_size,
-                    },
-                    background: Background::Color(style.center_line_color),
-                    border_radius: 0.0,
-                    border_width: 0.0,
-                    border_color: Color::TRANSPARENT,
-                },
-            )
-        } else {
-            (Primitive::None, Primitive::None)
-        };
+            let grid = if let Some(style) = style_sheet.grid_style() {
+                draw_grid(&pad_bounds, &style)
+            } else {
+                Primitive::None
+            };
 
-        let (h_rail, v_rail) = if style.rail_width != 0.0 {
-            let rail_width = style.rail_width as f32;
-            let half_rail_width = (rail_width / 2.0).floor();
-            (
-                Primitive::Quad {
-                    bounds: Rectangle {
-                        x: bounds_x,
-                        y: handle_y - half_rail_width,
-                        width: bounds_size,
-                        height: style.rail_width as f32,
-                    },
-                    background: Background::Color(style.h_rail_color),
-                    border_radius: 0.0,
-                    border_width: 0.0,
-                    border_color: Color::TRANSPARENT,
-                },
-                Primitive::Quad {
-                    bounds: Rectangle {
-                        x: handle_x - half_rail_width,
-                        y: bounds_y,
-                        width: style.rail_width as f32,
-                        height: bounds_size,
-                    },
-                    background: Background::Color(style.v_rail_color),
-                    border_radius: 0.0,
-                    border_width: 0.0,
-                    border_color: Color::TRANSPARENT,
-                },
-            )
-        } else {
-            (Primitive::None, Primitive::None)
-        };
+            let crosshair = if let Some(style) = style_sheet.crosshair_style() {
+                draw_crosshair(&pad_bounds, handle_x, handle_y, &style)
+            } else {
+                Primitive::None
+            };
+
+            let bounds_center = snap_floor(bounds_size / 2.0);
 
-        let handle = {
-            match style.handle {
-                HandleShape::Circle(circle) => {
-                    let diameter = circle.diameter as f32;
-                    let radius = diameter / 2.0;
+            let (h_center_line, v_center_line) = if style.center_line_color
+                != Color::TRANSPARENT
+            {
+                let center_line_width = style.center_line_width as f32;
+                let half_center_line_width =
+                    snap_floor(center_line_width / 2.0);
 
+                (
                     Primitive::Quad {
                         bounds: Rectangle {
-                            x: handle_x - radius,
-                            y: handle_y - radius,
-                            width: diameter,
-                            height: diameter,
+                            x: bounds_x,
+                            y: bounds_y + bounds_center
+                                - half_center_line_width,
+                            width: bounds_size,
+                            height: center_line_width,
                         },
-                        background: Background::Color(circle.color),
-                        border_radius: radius,
-                        border_width: circle.border_width,
-                        border_color: circle.border_color,
-                    }
-                }
-                HandleShape::Square(square) => {
-                    let size = square.size as f32;
-                    let half_size = (size / 2.0).floor();
+                        background: Background::Color(style.center_line_color),
+                        border_radius: 0.0,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    Primitive::Quad {
+                        bounds: Rectangle {
+                            x: bounds_x + bounds_center
+                                - half_center_line_width,
+                            y: bounds_y,
+                            width: center_line_width,
+                            height: bounds_size,
+                        },
+                        background: Background::Color(style.center_line_color),
+                        border_radius: 0.0,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                )
+            } else {
+                (Primitive::None, Primitive::None)
+            };
 
+            let (h_rail, v_rail) = if style.rail_width != 0.0 {
+                let rail_width = style.rail_width as f32;
+                let half_rail_width = snap_floor(rail_width / 2.0);
+                (
+                    Primitive::Quad {
+                        bounds: Rectangle {
+                            x: bounds_x,
+                            y: handle_y - half_rail_width,
+                            width: bounds_size,
+                            height: style.rail_width as f32,
+                        },
+                        background: Background::Color(style.h_rail_color),
+                        border_radius: 0.0,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
                     Primitive::Quad {
                         bounds: Rectangle {
-                            x: handle_x - half_size,
-                            y: handle_y - half_size,
-                            width: size,
-                            height: size,
+                            x: handle_x - half_rail_width,
+                            y: bounds_y,
+                            width: style.rail_width as f32,
+                            height: bounds_size,
                         },
-                        background: Background::Color(square.color),
-                        border_radius: square.border_radius,
-                        border_width: square.border_width,
-                        border_color: square.border_color,
+                        background: Background::Color(style.v_rail_color),
+                        border_radius: 0.0,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                )
+            } else {
+                (Primitive::None, Primitive::None)
+            };
+
+            let handle = match &style.texture {
+                Some(TextureStyle {
+                    handle_image: Some(image),
+                    handle_image_region,
+                    handle_image_bounds,
+                    ..
+                }) => draw_image(
+                    image.clone(),
+                    *handle_image_region,
+                    Rectangle {
+                        x: snap(handle_x + handle_image_bounds.x),
+                        y: snap(handle_y + handle_image_bounds.y),
+                        width: handle_image_bounds.width,
+                        height: handle_image_bounds.height,
+                    },
+                ),
+                _ => match style.handle {
+                    HandleShape::Circle(circle) => {
+                        let diameter = circle.diameter as f32;
+                        let radius = diameter / 2.0;
+
+                        Primitive::Quad {
+                            bounds: Rectangle {
+                                x: handle_x - radius,
+                                y: handle_y - radius,
+                                width: diameter,
+                                height: diameter,
+                            },
+                            background: Background::Color(circle.color),
+                            border_radius: radius,
+                            border_width: circle.border_width,
+                            border_color: circle.border_color,
+                        }
+                    }
+                    HandleShape::Square(square) => {
+                        let size = square.size;
+                        let half_size = snap_floor(size / 2.0);
+
+                        Primitive::Quad {
+                            bounds: Rectangle {
+                                x: handle_x - half_size,
+                                y: handle_y - half_size,
+                                width: size,
+                                height: size,
+                            },
+                            background: Background::Color(square.color),
+                            border_radius: square.border_radius,
+                            border_width: square.border_width,
+                            border_color: square.border_color,
+                        }
                     }
+                },
+            };
+
+            let corners = if let Some(snapshots) = snapshots {
+                let snapshot_style = style_sheet.snapshot_style();
+                let size = snap_floor(snapshot_size.min(bounds_size / 2.0));
+                let far = bounds_size - size;
+
+                let primitives =
+                    [(0.0, 0.0), (far, 0.0), (0.0, far), (far, far)]
+                        .iter()
+                        .zip(snapshots.iter())
+                        .map(|((x, y), snapshot)| Primitive::Quad {
+                            bounds: Rectangle {
+                                x: bounds_x + x,
+                                y: bounds_y + y,
+                                width: size,
+                                height: size,
+                            },
+                            background: Background::Color(
+                                if snapshot.is_some() {
+                                    snapshot_style.stored_color
+                                } else {
+                                    snapshot_style.empty_color
+                                },
+                            ),
+                            border_radius: 0.0,
+                            border_width: snapshot_style.border_width,
+                            border_color: snapshot_style.border_color,
+                        })
+                        .collect();
+
+                Primitive::Group { primitives }
+            } else {
+                Primitive::None
+            };
+
+            (
+                Primitive::Group {
+                    primitives: vec![
+                        back,
+                        back_image,
+                        grid,
+                        h_center_line,
+                        v_center_line,
+                        tick_marks_x,
+                        tick_marks_y,
+                        text_marks_x,
+                        text_marks_y,
+                        corners,
+                        h_rail,
+                        v_rail,
+                        crosshair,
+                        handle,
+                    ],
+                },
+                mouse::Interaction::default(),
+            )
+        })
+    }
+}
+
+fn line_quad(bounds: Rectangle, color: Color) -> Primitive {
+    Primitive::Quad {
+        bounds,
+        background: Background::Color(color),
+        border_radius: 0.0,
+        border_width: 0.0,
+        border_color: Color::TRANSPARENT,
+    }
+}
+
+fn draw_grid(pad_bounds: &Rectangle, style: &GridStyle) -> Primitive {
+    let half_line_width = snap_floor(style.line_width / 2.0);
+
+    let v_lines = (1..style.x_divisions).map(|i| {
+        let x = pad_bounds.x
+            + (pad_bounds.width * f32::from(i) / f32::from(style.x_divisions));
+
+        line_quad(
+            Rectangle {
+                x: snap_floor(x) - half_line_width,
+                y: pad_bounds.y,
+                width: style.line_width,
+                height: pad_bounds.height,
+            },
+            style.color,
+        )
+    });
+
+    let h_lines = (1..style.y_divisions).map(|i| {
+        let y = pad_bounds.y
+            + (pad_bounds.height * f32::from(i) / f32::from(style.y_divisions));
+
+        line_quad(
+            Rectangle {
+                x: pad_bounds.x,
+                y: snap_floor(y) - half_line_width,
+                width: pad_bounds.width,
+                height: style.line_width,
+            },
+            style.color,
+        )
+    });
+
+    Primitive::Group {
+        primitives: v_lines.chain(h_lines).collect(),
+    }
+}
+
+fn draw_crosshair(
+    pad_bounds: &Rectangle,
+    handle_x: f32,
+    handle_y: f32,
+    style: &CrosshairStyle,
+) -> Primitive {
+    let half_line_width = snap_floor(style.line_width / 2.0);
+    let right = pad_bounds.x + pad_bounds.width;
+    let bottom = pad_bounds.y + pad_bounds.height;
+
+    // The start and end of each line, clamped to the pad.
+    let segment = |from: f32, direction: f32, min: f32, max: f32| {
+        let start = from + (direction * style.gap);
+        let end = match style.length {
+            Some(length) => start + (direction * length),
+            None => {
+                if direction < 0.0 {
+                    min
+                } else {
+                    max
                 }
             }
         };
 
-        (
-            Primitive::Group {
-                primitives: vec![
-                    back,
-                    h_center_line,
-                    v_center_line,
-                    h_rail,
-                    v_rail,
-                    handle,
-                ],
+        let (start, end) = if start <= end {
+            (start.max(min), end.min(max))
+        } else {
+            (end.max(min), start.min(max))
+        };
+
+        if end > start {
+            Some((start, end - start))
+        } else {
+            None
+        }
+    };
+
+    let h_lines = [-1.0, 1.0].iter().filter_map(|direction| {
+        segment(handle_x, *direction, pad_bounds.x, right).map(|(x, width)| {
+            line_quad(
+                Rectangle {
+                    x,
+                    y: handle_y - half_line_width,
+                    width,
+                    height: style.line_width,
+                },
+                style.color,
+            )
+        })
+    });
+
+    let v_lines = [-1.0, 1.0].iter().filter_map(|direction| {
+        segment(handle_y, *direction, pad_bounds.y, bottom).map(
+            |(y, height)| {
+                line_quad(
+                    Rectangle {
+                        x: handle_x - half_line_width,
+                        y,
+                        width: style.line_width,
+                        height,
+                    },
+                    style.color,
+                )
             },
-            mouse::Interaction::default(),
         )
+    });
+
+    Primitive::Group {
+        primitives: h_lines.chain(v_lines).collect(),
     }
 }
+
+fn draw_tick_marks(
+    bounds: &Rectangle,
+    tick_marks_x: Option<&tick_marks::Group>,
+    tick_marks_y: Option<&tick_marks::Group>,
+    style: &TickMarksStyle,
+    tick_marks_cache_x: &tick_marks::PrimitiveCache,
+    tick_marks_cache_y: &tick_marks::PrimitiveCache,
+) -> (Primitive, Primitive) {
+    (
+        if let Some(tick_marks) = tick_marks_x {
+            tick_marks::draw_horizontal_tick_marks(
+                bounds,
+                tick_marks,
+                &style.style,
+                &style.x_placement,
+                false,
+                tick_marks_cache_x,
+            )
+        } else {
+            Primitive::None
+        },
+        if let Some(tick_marks) = tick_marks_y {
+            tick_marks::draw_vertical_tick_marks(
+                bounds,
+                tick_marks,
+                &style.style,
+                &style.y_placement,
+                false,
+                tick_marks_cache_y,
+            )
+        } else {
+            Primitive::None
+        },
+    )
+}
+
+fn draw_text_marks(
+    bounds: &Rectangle,
+    text_marks_x: Option<&text_marks::Group>,
+    text_marks_y: Option<&text_marks::Group>,
+    style: &TextMarksStyle,
+    text_marks_cache_x: &text_marks::PrimitiveCache,
+    text_marks_cache_y: &text_marks::PrimitiveCache,
+) -> (Primitive, Primitive) {
+    (
+        if let Some(text_marks) = text_marks_x {
+            text_marks::draw_horizontal_text_marks(
+                bounds,
+                text_marks,
+                &style.style,
+                &style.x_placement,
+                false,
+                text_marks_cache_x,
+            )
+        } else {
+            Primitive::None
+        },
+        if let Some(text_marks) = text_marks_y {
+            text_marks::draw_vertical_text_marks(
+                bounds,
+                text_marks,
+                &style.style,
+                &style.y_placement,
+                false,
+                text_marks_cache_y,
+            )
+        } else {
+            Primitive::None
+        },
+    )
+}
@@ -55,7 +55,7 @@
 //!     // * LogDBRange - a logarithmic range of decibel values. Values around 0 dB
 //!     // will increment slower than values farther away from 0 dB.
 //!     // * FreqRange - a logarithmic range of frequency values. Each octave in
-//!     // the 10 octave spectrum (from 20 Hz to 20480 Hz) is spaced evenly.
+//!     // the range is spaced evenly.
 //!     //
 //!     float_range: FloatRange,
 //!     int_range: IntRange,
@@ -195,7 +195,7 @@
 #![deny(missing_docs)]
 #![deny(missing_debug_implementations)]
 #![deny(unused_results)]
-#![forbid(rust_2018_idioms)]
+#![deny(rust_2018_idioms)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 //extern crate simdeez;
@@ -212,14 +212,21 @@ pub use crate::core::*;
 mod platform {
     #[doc(no_inline)]
     pub use crate::graphics::{
-        h_slider, knob, mod_range_input, ramp, text_marks, tick_marks,
-        v_slider, xy_pad,
+        clip_indicator, correlation_meter, crossover, db_meter, eq_bands,
+        h_slider, knob, labeled_knob, meter_scale, mod_range_input, pan_knob,
+        ramp, reduction_history, spectrogram, text_marks, tick_marks, v_slider,
+        vu_meter, waveform_view, xy_pad,
     };
 
     #[doc(no_inline)]
     pub use {
-        h_slider::HSlider, knob::Knob, mod_range_input::ModRangeInput,
-        ramp::Ramp, v_slider::VSlider, xy_pad::XYPad,
+        clip_indicator::ClipIndicator, correlation_meter::CorrelationMeter,
+        crossover::Crossover, db_meter::DBMeter, eq_bands::EqBands,
+        h_slider::HSlider, knob::Knob, labeled_knob::LabeledKnob,
+        meter_scale::MeterScale, mod_range_input::ModRangeInput,
+        pan_knob::PanKnob, ramp::Ramp, reduction_history::ReductionHistory,
+        spectrogram::Spectrogram, v_slider::VSlider, vu_meter::VuMeter,
+        waveform_view::WaveformView, xy_pad::XYPad,
     };
 }
 
@@ -0,0 +1,274 @@
+//! Display a clip indicator LED that stays lit until it is clicked.
+
+use std::hash::Hash;
+
+use iced_native::{
+    event, layout, mouse, Clipboard, Element, Event, Hasher, Layout, Length,
+    Point, Rectangle, Size, Widget,
+};
+
+use crate::core::math::sanitize_dt;
+use crate::core::InputConfig;
+
+static DEFAULT_SIZE: u16 = 14;
+
+/// A small LED that lights up when the signal clips, and stays lit until
+/// the user clicks it.
+#[allow(missing_debug_implementations)]
+pub struct ClipIndicator<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    on_reset: Box<dyn Fn() -> Message>,
+    input_config: InputConfig,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer>
+    ClipIndicator<'a, Message, Renderer>
+{
+    /// Creates a new [`ClipIndicator`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`ClipIndicator`]
+    ///   * a function that will be called when the lit [`ClipIndicator`]
+    ///     is clicked and resets
+    ///
+    /// [`State`]: struct.State.html
+    /// [`ClipIndicator`]: struct.ClipIndicator.html
+    pub fn new<F>(state: &'a mut State, on_reset: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        ClipIndicator {
+            state,
+            on_reset: Box::new(on_reset),
+            input_config: InputConfig::global(),
+            width: Length::Units(DEFAULT_SIZE),
+            height: Length::Units(DEFAULT_SIZE),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`ClipIndicator`].
+    /// The default width is `Length::Units(14)`.
+    ///
+    /// [`ClipIndicator`]: struct.ClipIndicator.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`ClipIndicator`].
+    /// The default height is `Length::Units(14)`.
+    ///
+    /// [`ClipIndicator`]: struct.ClipIndicator.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`ClipIndicator`].
+    ///
+    /// [`ClipIndicator`]: struct.ClipIndicator.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the mouse buttons that control the [`ClipIndicator`]. It is
+    /// reset with the `drag_button`.
+    ///
+    /// The default is the global [`InputConfig`].
+    ///
+    /// [`ClipIndicator`]: struct.ClipIndicator.html
+    /// [`InputConfig`]: ../../core/input_config/struct.InputConfig.html
+    pub fn input_config(mut self, input_config: InputConfig) -> Self {
+        self.input_config = input_config;
+        self
+    }
+}
+
+/// The local state of a [`ClipIndicator`].
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::clip_indicator;
+///
+/// let mut state = clip_indicator::State::new();
+///
+/// state.update(true, 0.0);
+/// state.update(false, 1.0);
+///
+/// // The indicator stays lit until it is reset.
+/// assert!(state.is_lit());
+/// assert_eq!(state.since_clip(), 1.0);
+///
+/// state.reset();
+/// assert!(!state.is_lit());
+/// ```
+///
+/// [`ClipIndicator`]: struct.ClipIndicator.html
+#[derive(Debug, Copy, Clone, Default)]
+pub struct State {
+    lit: bool,
+    since_clip: f32,
+}
+
+impl State {
+    /// Creates a new unlit [`ClipIndicator`] state.
+    ///
+    /// [`ClipIndicator`]: struct.ClipIndicator.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feeds the clipped flag of the latest block of audio into the
+    /// indicator.
+    ///
+    /// * `clipped` - whether the signal clipped since the last update
+    /// * `dt` - the time in seconds since the last update
+    pub fn update(&mut self, clipped: bool, dt: f32) {
+        if clipped {
+            self.lit = true;
+            self.since_clip = 0.0;
+        } else {
+            self.since_clip += sanitize_dt(dt);
+        }
+    }
+
+    /// Returns `true` if the signal has clipped since the last reset.
+    pub fn is_lit(&self) -> bool {
+        self.lit
+    }
+
+    /// Returns the time in seconds since the signal last clipped. This
+    /// drives the flash decay of the style.
+    pub fn since_clip(&self) -> f32 {
+        self.since_clip
+    }
+
+    /// Turns the indicator off.
+    pub fn reset(&mut self) {
+        self.lit = false;
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for ClipIndicator<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        if let Event::Mouse(mouse::Event::ButtonPressed(button)) = event {
+            if button == self.input_config.drag_button
+                && self.state.lit
+                && self
+                    .input_config
+                    .hit_bounds(layout.bounds())
+                    .contains(cursor_position)
+            {
+                self.state.reset();
+                messages.push((self.on_reset)());
+
+                return event::Status::Captured;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(
+            layout.bounds(),
+            self.state.lit,
+            self.state.since_clip,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`ClipIndicator`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`ClipIndicator`] in your user interface.
+///
+/// [`ClipIndicator`]: struct.ClipIndicator.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`ClipIndicator`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`ClipIndicator`]
+    ///   * whether the [`ClipIndicator`] is lit
+    ///   * the time in seconds since the signal last clipped
+    ///   * the style of the [`ClipIndicator`]
+    ///
+    /// [`ClipIndicator`]: struct.ClipIndicator.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        lit: bool,
+        since_clip: f32,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<ClipIndicator<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        clip_indicator: ClipIndicator<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(clip_indicator)
+    }
+}
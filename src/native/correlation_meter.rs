@@ -0,0 +1,225 @@
+//! Display a scrolling history of stereo phase correlation values.
+
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+use iced_native::{
+    layout, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use crate::core::Normal;
+
+static DEFAULT_WIDTH: u16 = 200;
+static DEFAULT_HEIGHT: u16 = 40;
+
+/// A meter that displays a scrolling history strip of stereo phase
+/// correlation values.
+///
+/// Correlation values are [`Normal`]s, where `0.0` is a correlation of `-1`
+/// (out of phase), `0.5` is uncorrelated, and `1.0` is a correlation of `+1`
+/// (mono). Values above `0.5` are drawn upwards from the center line and
+/// values below it downwards, with the newest value at the right edge.
+///
+/// [`Normal`]: ../../core/struct.Normal.html
+#[allow(missing_debug_implementations)]
+pub struct CorrelationMeter<'a, Renderer: self::Renderer> {
+    state: &'a State,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Renderer: self::Renderer> CorrelationMeter<'a, Renderer> {
+    /// Creates a new [`CorrelationMeter`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`CorrelationMeter`]
+    ///
+    /// [`State`]: struct.State.html
+    /// [`CorrelationMeter`]: struct.CorrelationMeter.html
+    pub fn new(state: &'a State) -> Self {
+        CorrelationMeter {
+            state,
+            width: Length::Units(DEFAULT_WIDTH),
+            height: Length::Units(DEFAULT_HEIGHT),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`CorrelationMeter`].
+    /// The default width is `Length::Units(200)`.
+    ///
+    /// [`CorrelationMeter`]: struct.CorrelationMeter.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`CorrelationMeter`].
+    /// The default height is `Length::Units(40)`.
+    ///
+    /// [`CorrelationMeter`]: struct.CorrelationMeter.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`CorrelationMeter`].
+    ///
+    /// [`CorrelationMeter`]: struct.CorrelationMeter.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The local state of a [`CorrelationMeter`].
+///
+/// [`CorrelationMeter`]: struct.CorrelationMeter.html
+#[derive(Debug, Clone)]
+pub struct State {
+    history: VecDeque<Normal>,
+    capacity: usize,
+}
+
+impl State {
+    /// Creates a new [`CorrelationMeter`] state.
+    ///
+    /// It expects:
+    ///   * the number of values that fit in the history
+    ///
+    /// [`CorrelationMeter`]: struct.CorrelationMeter.html
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+
+        Self {
+            history: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Records a new correlation value, removing the oldest value if the
+    /// history is full.
+    pub fn push(&mut self, correlation: Normal) {
+        if self.history.len() >= self.capacity {
+            let _ = self.history.pop_front();
+        }
+        self.history.push_back(correlation);
+    }
+
+    /// Returns the most recent correlation value, if any.
+    pub fn latest(&self) -> Option<Normal> {
+        self.history.back().copied()
+    }
+
+    /// Returns the recorded correlation values, from oldest to newest.
+    pub fn history(&self) -> &VecDeque<Normal> {
+        &self.history
+    }
+
+    /// Returns the number of values that fit in the history.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns `true` if no values have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.history.is_empty()
+    }
+
+    /// Removes all recorded values.
+    pub fn clear(&mut self) {
+        self.history.clear();
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for CorrelationMeter<'a, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(
+            layout.bounds(),
+            &self.state.history,
+            self.state.capacity,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`CorrelationMeter`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`CorrelationMeter`] in your user interface.
+///
+/// [`CorrelationMeter`]: struct.CorrelationMeter.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`CorrelationMeter`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`CorrelationMeter`]
+    ///   * the recorded correlation values, from oldest to newest
+    ///   * the number of values that fit in the history
+    ///   * the style of the [`CorrelationMeter`]
+    ///
+    /// [`CorrelationMeter`]: struct.CorrelationMeter.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        history: &VecDeque<Normal>,
+        capacity: usize,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<CorrelationMeter<'a, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        correlation_meter: CorrelationMeter<'a, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(correlation_meter)
+    }
+}
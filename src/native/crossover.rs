@@ -0,0 +1,555 @@
+//! Display a frequency rail with draggable split points, such as the
+//! crossover frequencies of a multiband compressor or EQ.
+
+use std::hash::Hash;
+
+use iced_native::{
+    event, keyboard, layout, mouse, window, Clipboard, Element, Event, Hasher,
+    Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use crate::core::input_config::{DragAnchor, InputAction};
+use crate::core::{InputConfig, Normal, NormalParam, ResetGesture};
+
+static DEFAULT_HEIGHT: u16 = 24;
+static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+static DEFAULT_HIT_RADIUS: f32 = 6.0;
+
+/// A rail with draggable split points that divide it into bands, such as
+/// the crossover frequencies of a multiband compressor or EQ.
+///
+/// Each split point is a [`NormalParam`]. To place the split points on a
+/// logarithmic frequency scale, map them with a [`FreqRange`].
+///
+/// A split point can not be dragged past its neighbors, so the split points
+/// stay in increasing order. The bands between them are drawn in the colors
+/// of the style.
+///
+/// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+/// [`FreqRange`]: ../../core/range/struct.FreqRange.html
+#[allow(missing_debug_implementations)]
+pub struct Crossover<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    on_change: Box<dyn Fn(usize, Normal) -> Message>,
+    on_menu: Option<Box<dyn Fn(usize) -> Message>>,
+    on_reset: Option<Box<dyn Fn(usize, Normal) -> Message>>,
+    modifier_scalar: f32,
+    modifier_keys: keyboard::Modifiers,
+    input_config: InputConfig,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer> Crossover<'a, Message, Renderer> {
+    /// Creates a new [`Crossover`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`Crossover`]
+    ///   * a function that will be called when a split point is moved, with
+    ///     the index and the new value of the split point
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Crossover`]: struct.Crossover.html
+    pub fn new<F>(state: &'a mut State, on_change: F) -> Self
+    where
+        F: 'static + Fn(usize, Normal) -> Message,
+    {
+        Crossover {
+            state,
+            on_change: Box::new(on_change),
+            on_menu: None,
+            on_reset: None,
+            modifier_scalar: DEFAULT_MODIFIER_SCALAR,
+            modifier_keys: keyboard::Modifiers {
+                control: true,
+                ..Default::default()
+            },
+            input_config: InputConfig::global(),
+            width: Length::Fill,
+            height: Length::Units(DEFAULT_HEIGHT),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`Crossover`].
+    /// The default width is `Length::Fill`.
+    ///
+    /// [`Crossover`]: struct.Crossover.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Crossover`].
+    /// The default height is `Length::Units(24)`.
+    ///
+    /// [`Crossover`]: struct.Crossover.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`Crossover`].
+    ///
+    /// [`Crossover`]: struct.Crossover.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the modifier keys to enable finer adjustments when dragging a
+    /// split point. The default modifier key is `Ctrl`.
+    pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
+        self.modifier_keys = modifier_keys;
+        self
+    }
+
+    /// Sets the scalar to use when the user drags a split point while
+    /// holding down the modifier key.
+    ///
+    /// For example, a `modifier_scalar` of `0.5` will cause the split point
+    /// to move half as fast when the modifier key is pressed.
+    ///
+    /// The default `modifier_scalar` is `0.02`, and the default modifier key
+    /// is `Ctrl`.
+    pub fn modifier_scalar(mut self, scalar: f32) -> Self {
+        self.modifier_scalar = scalar;
+        self
+    }
+
+    /// Sets the mouse buttons and drag threshold of the [`Crossover`].
+    ///
+    /// The default is the global [`InputConfig`].
+    ///
+    /// [`Crossover`]: struct.Crossover.html
+    /// [`InputConfig`]: ../../core/input_config/struct.InputConfig.html
+    pub fn input_config(mut self, input_config: InputConfig) -> Self {
+        self.input_config = input_config;
+        self
+    }
+
+    /// Sets a function that will be called with the index of a split point
+    /// when the menu button is pressed over it.
+    pub fn on_menu<F>(mut self, on_menu: F) -> Self
+    where
+        F: 'static + Fn(usize) -> Message,
+    {
+        self.on_menu = Some(Box::new(on_menu));
+        self
+    }
+
+    /// Sets the gesture that resets a split point of the [`Crossover`] to
+    /// its default value.
+    ///
+    /// The default is the gesture of the [`InputConfig`]. Set this after
+    /// [`input_config`], which replaces it.
+    ///
+    /// [`Crossover`]: struct.Crossover.html
+    /// [`InputConfig`]: ../../core/input_config/struct.InputConfig.html
+    /// [`input_config`]: #method.input_config
+    pub fn reset_gesture(mut self, reset_gesture: ResetGesture) -> Self {
+        self.input_config.reset = reset_gesture;
+        self
+    }
+
+    /// Sets a function that will be called with the index of a split point
+    /// and its new value when the split point is reset, instead of
+    /// `on_change`.
+    ///
+    /// This lets the application tell a reset apart from a plain change,
+    /// such as to record it as its own undo step.
+    pub fn on_reset<F>(mut self, on_reset: F) -> Self
+    where
+        F: 'static + Fn(usize, Normal) -> Message,
+    {
+        self.on_reset = Some(Box::new(on_reset));
+        self
+    }
+
+    fn split_at(
+        &self,
+        bounds: Rectangle,
+        cursor_position: Point,
+    ) -> Option<usize> {
+        split_at(
+            &self.state.splits,
+            bounds,
+            cursor_position,
+            self.input_config.hit_radius(DEFAULT_HIT_RADIUS),
+        )
+    }
+}
+
+fn split_at(
+    splits: &[NormalParam],
+    bounds: Rectangle,
+    cursor_position: Point,
+    hit_radius: f32,
+) -> Option<usize> {
+    splits
+        .iter()
+        .enumerate()
+        .map(|(i, split)| {
+            let x = bounds.x + split.value.as_f32() * bounds.width;
+            (i, (x - cursor_position.x).abs())
+        })
+        .filter(|(_, distance)| *distance <= hit_radius)
+        .min_by(|a, b| {
+            a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .map(|(i, _)| i)
+}
+
+/// The local state of a [`Crossover`].
+///
+/// [`Crossover`]: struct.Crossover.html
+#[derive(Debug, Clone)]
+pub struct State {
+    splits: Vec<NormalParam>,
+    dragging: Option<usize>,
+    drag_start: Option<Point>,
+    drag_anchor: DragAnchor,
+    pressed_modifiers: keyboard::Modifiers,
+    last_click: Option<mouse::Click>,
+}
+
+impl State {
+    /// Creates a new [`Crossover`] state.
+    ///
+    /// It expects:
+    ///   * the [`NormalParam`] of each split point, in increasing order
+    ///
+    /// [`Crossover`]: struct.Crossover.html
+    /// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+    pub fn new(splits: Vec<NormalParam>) -> Self {
+        Self {
+            splits,
+            dragging: None,
+            drag_start: None,
+            drag_anchor: DragAnchor::default(),
+            pressed_modifiers: Default::default(),
+            last_click: None,
+        }
+    }
+
+    /// Returns the split points.
+    pub fn splits(&self) -> &[NormalParam] {
+        &self.splits
+    }
+
+    /// Sets the value of the split point at `index`, for example when it
+    /// was changed from somewhere else. The value is kept between the
+    /// neighboring split points.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `index` is out of bounds.
+    pub fn set_split(&mut self, index: usize, value: Normal) {
+        self.splits[index].value = self.clamp_split(index, value);
+    }
+
+    /// Replaces all of the split points, such as when the number of bands
+    /// changes.
+    ///
+    /// The split points must be in increasing order.
+    pub fn set_splits(&mut self, splits: Vec<NormalParam>) {
+        self.splits = splits;
+        self.dragging = None;
+        self.drag_start = None;
+    }
+
+    /// Returns the index of the split point that is being dragged, if any.
+    pub fn dragging(&self) -> Option<usize> {
+        self.dragging
+    }
+
+    /// Returns `value` limited to the range between the neighbors of the
+    /// split point at `index`.
+    fn clamp_split(&self, index: usize, value: Normal) -> Normal {
+        let min = if index > 0 {
+            self.splits[index - 1].value.as_f32()
+        } else {
+            0.0
+        };
+        let max = match self.splits.get(index + 1) {
+            Some(next) => next.value.as_f32(),
+            None => 1.0,
+        };
+
+        value.as_f32().max(min).min(max).into()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Crossover<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse_event) => match mouse_event {
+                mouse::Event::CursorMoved { .. } => {
+                    if let Some(index) = self.state.dragging {
+                        if let Some(drag_start) = self.state.drag_start {
+                            if !self.input_config.drag_threshold_crossed(
+                                drag_start,
+                                cursor_position,
+                            ) {
+                                return event::Status::Captured;
+                            }
+
+                            self.state.drag_start = None;
+                        }
+
+                        if bounds.width <= 0.0 {
+                            return event::Status::Captured;
+                        }
+
+                        let mut scalar = 1.0;
+
+                        if self
+                            .state
+                            .pressed_modifiers
+                            .matches(self.modifier_keys)
+                        {
+                            scalar *= self.modifier_scalar;
+                        }
+
+                        let normal = self
+                            .state
+                            .drag_anchor
+                            .drag(cursor_position.x, scalar / bounds.width);
+                        let value =
+                            self.state.clamp_split(index, normal.into());
+
+                        if value.as_f32() != normal {
+                            // Re-anchor at the neighbor so that reversing
+                            // the drag takes effect immediately.
+                            self.state.drag_anchor.rebase(value.as_f32());
+                        }
+
+                        if value != self.state.splits[index].value {
+                            self.state.splits[index].value = value;
+
+                            messages.push((self.on_change)(index, value));
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+                mouse::Event::ButtonPressed(button) => {
+                    if !self
+                        .input_config
+                        .hit_bounds(bounds)
+                        .contains(cursor_position)
+                    {
+                        return event::Status::Ignored;
+                    }
+
+                    let index = if let Some(index) =
+                        self.split_at(bounds, cursor_position)
+                    {
+                        index
+                    } else {
+                        return event::Status::Ignored;
+                    };
+
+                    let click_kind = if button == self.input_config.drag_button
+                    {
+                        let click = mouse::Click::new(
+                            cursor_position,
+                            self.state.last_click,
+                        );
+
+                        self.state.last_click = Some(click);
+
+                        click.kind()
+                    } else {
+                        mouse::click::Kind::Single
+                    };
+
+                    match self.input_config.action(
+                        button,
+                        click_kind,
+                        self.state.pressed_modifiers,
+                    ) {
+                        InputAction::Drag => {
+                            self.state.dragging = Some(index);
+                            self.state.drag_start = Some(cursor_position);
+                            self.state.drag_anchor = DragAnchor::new(
+                                cursor_position.x,
+                                self.state.splits[index].value.as_f32(),
+                            );
+                        }
+                        InputAction::Reset => {
+                            self.state.dragging = None;
+
+                            let value = self.state.clamp_split(
+                                index,
+                                self.state.splits[index].default,
+                            );
+                            self.state.splits[index].value = value;
+
+                            messages.push(match &self.on_reset {
+                                Some(on_reset) => (on_reset)(index, value),
+                                None => (self.on_change)(index, value),
+                            });
+                        }
+                        InputAction::Menu => {
+                            if let Some(on_menu) = &self.on_menu {
+                                messages.push((on_menu)(index));
+                            }
+                        }
+                        InputAction::None => {
+                            return event::Status::Ignored;
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
+                mouse::Event::ButtonReleased(button)
+                    if button == self.input_config.drag_button
+                        && self.state.dragging.is_some() =>
+                {
+                    self.state.dragging = None;
+                    self.state.drag_start = None;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
+            Event::Keyboard(keyboard_event) => match keyboard_event {
+                keyboard::Event::KeyPressed { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+                }
+                keyboard::Event::KeyReleased { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+                }
+                keyboard::Event::ModifiersChanged(modifiers) => {
+                    self.state.pressed_modifiers = modifiers;
+                }
+                _ => {}
+            },
+            Event::Window(window::Event::Unfocused) => {
+                self.state.pressed_modifiers = Default::default();
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+
+        let hovered = if self
+            .input_config
+            .hit_bounds(bounds)
+            .contains(cursor_position)
+        {
+            self.split_at(bounds, cursor_position)
+        } else {
+            None
+        };
+
+        renderer.draw(
+            bounds,
+            cursor_position,
+            &self.state.splits,
+            hovered,
+            self.state.dragging,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`Crossover`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`Crossover`] in your user interface.
+///
+/// [`Crossover`]: struct.Crossover.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`Crossover`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Crossover`]
+    ///   * the current cursor position
+    ///   * the split points, in increasing order
+    ///   * the index of the split point under the cursor, if any
+    ///   * the index of the split point that is being dragged, if any
+    ///   * the style of the [`Crossover`]
+    ///
+    /// [`Crossover`]: struct.Crossover.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        splits: &[NormalParam],
+        hovered: Option<usize>,
+        dragging: Option<usize>,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Crossover<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        crossover: Crossover<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(crossover)
+    }
+}
@@ -0,0 +1,537 @@
+//! Display a meter of decibel levels with a peak-hold line.
+
+use std::hash::Hash;
+
+use iced_native::{
+    layout, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use crate::core::math::sanitize_dt;
+use crate::core::{LogDBRange, Normal, Orientation};
+
+static DEFAULT_WIDTH: u16 = 14;
+static DEFAULT_PEAK_HOLD: f32 = 1.5;
+static DEFAULT_PEAK_DECAY: f32 = 0.5;
+
+/// A meter that displays decibel levels, with a peak-hold line.
+///
+/// The meter is vertical by default, and can be made horizontal with
+/// [`orientation`]. A stereo [`State`] shows the left and right channels
+/// side by side in one widget, with the left channel on the left of a
+/// vertical meter and on top of a horizontal one.
+///
+/// Levels are [`Normal`]s, usually mapped from decibels with a
+/// [`LogDBRange`] so the meter lines up with sliders and knobs that use the
+/// same range.
+///
+/// [`orientation`]: #method.orientation
+/// [`State`]: struct.State.html
+/// [`Normal`]: ../../core/struct.Normal.html
+/// [`LogDBRange`]: ../../core/range/struct.LogDBRange.html
+#[allow(missing_debug_implementations)]
+pub struct DBMeter<'a, Renderer: self::Renderer> {
+    state: &'a State,
+    orientation: Orientation,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Renderer: self::Renderer> DBMeter<'a, Renderer> {
+    /// Creates a new [`DBMeter`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`DBMeter`]
+    ///
+    /// [`State`]: struct.State.html
+    /// [`DBMeter`]: struct.DBMeter.html
+    pub fn new(state: &'a State) -> Self {
+        DBMeter {
+            state,
+            orientation: Orientation::Vertical,
+            width: Length::Units(DEFAULT_WIDTH),
+            height: Length::Fill,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the [`Orientation`] of the [`DBMeter`]. This also resets its
+    /// width and height to the defaults of that orientation, so set them
+    /// afterwards.
+    ///
+    /// The default is `Orientation::Vertical`, which is `Length::Units(14)`
+    /// wide and fills the available height. `Orientation::Horizontal` fills
+    /// the available width and is `Length::Units(14)` tall.
+    ///
+    /// [`DBMeter`]: struct.DBMeter.html
+    /// [`Orientation`]: ../../core/orientation/enum.Orientation.html
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+
+        match orientation {
+            Orientation::Vertical => {
+                self.width = Length::Units(DEFAULT_WIDTH);
+                self.height = Length::Fill;
+            }
+            Orientation::Horizontal => {
+                self.width = Length::Fill;
+                self.height = Length::Units(DEFAULT_WIDTH);
+            }
+        }
+
+        self
+    }
+
+    /// Sets the width of the [`DBMeter`].
+    /// The default width is `Length::Units(14)`.
+    ///
+    /// [`DBMeter`]: struct.DBMeter.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`DBMeter`].
+    /// The default height is `Length::Fill`.
+    ///
+    /// [`DBMeter`]: struct.DBMeter.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`DBMeter`].
+    ///
+    /// [`DBMeter`]: struct.DBMeter.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The level and peak-hold line of one channel of a [`DBMeter`].
+///
+/// [`DBMeter`]: struct.DBMeter.html
+#[derive(Debug, Copy, Clone)]
+pub struct Channel {
+    normal: Normal,
+    rms: Normal,
+    peak: Normal,
+    hold_remaining: f32,
+}
+
+impl Channel {
+    fn new() -> Self {
+        Self {
+            normal: Normal::min(),
+            rms: Normal::min(),
+            peak: Normal::min(),
+            hold_remaining: 0.0,
+        }
+    }
+
+    fn update(
+        &mut self,
+        normal: Normal,
+        rms: Normal,
+        dt: f32,
+        peak_hold: f32,
+        peak_decay: f32,
+        fall_back: Option<FallBack>,
+    ) {
+        let mut dt = sanitize_dt(dt);
+
+        if let Some(fall_back) = fall_back {
+            self.normal = max_normal(normal, fall_back.fall(self.normal, dt));
+            self.rms = max_normal(rms, fall_back.fall(self.rms, dt));
+        } else {
+            self.normal = normal;
+            self.rms = rms;
+        }
+
+        if self.hold_remaining > 0.0 {
+            let held = dt.min(self.hold_remaining);
+            self.hold_remaining -= held;
+            dt -= held;
+        }
+
+        if dt > 0.0 {
+            self.peak = self.peak.saturating_sub(peak_decay * dt);
+        }
+
+        if normal.as_f32() >= self.peak.as_f32() {
+            self.peak = normal;
+            self.hold_remaining = peak_hold;
+        }
+    }
+
+    fn reset_peak(&mut self) {
+        self.peak = self.normal;
+        self.hold_remaining = 0.0;
+    }
+
+    /// Returns the current level of the channel.
+    pub fn normal(&self) -> Normal {
+        self.normal
+    }
+
+    /// Returns the current RMS level of the channel. This is the same as
+    /// [`normal`] unless the levels are set with [`State::update_with_rms`].
+    ///
+    /// [`normal`]: #method.normal
+    /// [`State::update_with_rms`]: struct.State.html#method.update_with_rms
+    pub fn rms(&self) -> Normal {
+        self.rms
+    }
+
+    /// Returns the position of the peak-hold line of the channel.
+    pub fn peak(&self) -> Normal {
+        self.peak
+    }
+}
+
+/// How fast the levels of a [`DBMeter`] fall back after a peak.
+///
+/// [`DBMeter`]: struct.DBMeter.html
+#[derive(Debug, Copy, Clone)]
+struct FallBack {
+    db_per_second: f32,
+    range: LogDBRange,
+}
+
+impl FallBack {
+    fn fall(&self, normal: Normal, dt: f32) -> Normal {
+        let db = self.range.unmap_to_value(normal);
+
+        self.range.map_to_normal(db - self.db_per_second * dt)
+    }
+}
+
+/// The local state of a [`DBMeter`].
+///
+/// Push levels with [`update`] (or [`update_stereo`]) as they arrive from
+/// the audio thread. The state keeps track of the highest level seen in
+/// each channel, holds it for a while, and then lets it fall back.
+///
+/// The levels themselves follow the audio thread exactly unless a
+/// fall-back rate is set with [`set_fall_back`]. Then a level falls
+/// smoothly after a peak instead of dropping at once, so brief peaks stay
+/// visible even if the GUI only draws a few frames per second.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::db_meter;
+///
+/// let mut state = db_meter::State::new();
+/// state.set_peak_hold(1.0);
+///
+/// state.update(0.8.into(), 0.0);
+/// state.update(0.2.into(), 0.5);
+///
+/// // The peak is still held.
+/// assert_eq!(state.peak().as_f32(), 0.8);
+///
+/// state.update(0.2.into(), 1.0);
+///
+/// // The peak has been falling for 0.5 seconds.
+/// assert!(state.peak().as_f32() < 0.8);
+/// ```
+///
+/// [`DBMeter`]: struct.DBMeter.html
+/// [`update`]: #method.update
+/// [`update_stereo`]: #method.update_stereo
+/// [`set_fall_back`]: #method.set_fall_back
+#[derive(Debug, Copy, Clone)]
+pub struct State {
+    left: Channel,
+    right: Option<Channel>,
+    peak_hold: f32,
+    peak_decay: f32,
+    fall_back: Option<FallBack>,
+}
+
+impl State {
+    /// Creates a new single channel [`DBMeter`] state, which holds peaks
+    /// for 1.5 seconds and then lets them fall by `0.5` per second.
+    ///
+    /// [`DBMeter`]: struct.DBMeter.html
+    pub fn new() -> Self {
+        Self {
+            left: Channel::new(),
+            right: None,
+            peak_hold: DEFAULT_PEAK_HOLD,
+            peak_decay: DEFAULT_PEAK_DECAY,
+            fall_back: None,
+        }
+    }
+
+    /// Creates a new stereo [`DBMeter`] state, which shows a left and a
+    /// right bar side by side.
+    ///
+    /// [`DBMeter`]: struct.DBMeter.html
+    pub fn new_stereo() -> Self {
+        Self {
+            right: Some(Channel::new()),
+            ..Self::new()
+        }
+    }
+
+    /// Returns `true` if the state has a left and a right channel.
+    pub fn is_stereo(&self) -> bool {
+        self.right.is_some()
+    }
+
+    /// Sets the current level of every channel and advances the peak-hold
+    /// lines.
+    ///
+    /// * `normal` - the current level
+    /// * `dt` - the time in seconds since the last update
+    pub fn update(&mut self, normal: Normal, dt: f32) {
+        self.update_stereo(normal, normal, dt);
+    }
+
+    /// Sets the current levels of the left and right channels and advances
+    /// the peak-hold lines. A single channel state shows the louder of
+    /// the two.
+    ///
+    /// * `left` - the current level of the left channel
+    /// * `right` - the current level of the right channel
+    /// * `dt` - the time in seconds since the last update
+    pub fn update_stereo(&mut self, left: Normal, right: Normal, dt: f32) {
+        self.update_stereo_with_rms(left, left, right, right, dt);
+    }
+
+    /// Sets the current peak and RMS levels of every channel and advances
+    /// the peak-hold lines. The RMS level is only drawn separately by a
+    /// style with a `peak_bar_color`.
+    ///
+    /// * `peak` - the current peak level
+    /// * `rms` - the current RMS level
+    /// * `dt` - the time in seconds since the last update
+    pub fn update_with_rms(&mut self, peak: Normal, rms: Normal, dt: f32) {
+        self.update_stereo_with_rms(peak, rms, peak, rms, dt);
+    }
+
+    /// Sets the current peak and RMS levels of the left and right channels
+    /// and advances the peak-hold lines. A single channel state shows the
+    /// louder of the two.
+    ///
+    /// * `left_peak` - the current peak level of the left channel
+    /// * `left_rms` - the current RMS level of the left channel
+    /// * `right_peak` - the current peak level of the right channel
+    /// * `right_rms` - the current RMS level of the right channel
+    /// * `dt` - the time in seconds since the last update
+    pub fn update_stereo_with_rms(
+        &mut self,
+        left_peak: Normal,
+        left_rms: Normal,
+        right_peak: Normal,
+        right_rms: Normal,
+        dt: f32,
+    ) {
+        let (peak_hold, peak_decay, fall_back) =
+            (self.peak_hold, self.peak_decay, self.fall_back);
+
+        if let Some(channel) = &mut self.right {
+            self.left.update(
+                left_peak, left_rms, dt, peak_hold, peak_decay, fall_back,
+            );
+            channel.update(
+                right_peak, right_rms, dt, peak_hold, peak_decay, fall_back,
+            );
+        } else {
+            self.left.update(
+                max_normal(left_peak, right_peak),
+                max_normal(left_rms, right_rms),
+                dt,
+                peak_hold,
+                peak_decay,
+                fall_back,
+            );
+        }
+    }
+
+    /// Returns the left (or only) channel.
+    pub fn left(&self) -> &Channel {
+        &self.left
+    }
+
+    /// Returns the right channel, or `None` for a single channel state.
+    pub fn right(&self) -> Option<&Channel> {
+        self.right.as_ref()
+    }
+
+    /// Returns the current level of the loudest channel.
+    pub fn normal(&self) -> Normal {
+        self.channels()
+            .map(Channel::normal)
+            .fold(Normal::min(), max_normal)
+    }
+
+    /// Returns the highest peak-hold line of all channels.
+    pub fn peak(&self) -> Normal {
+        self.channels()
+            .map(Channel::peak)
+            .fold(Normal::min(), max_normal)
+    }
+
+    /// Sets how long in seconds a peak is held before it starts to fall.
+    pub fn set_peak_hold(&mut self, seconds: f32) {
+        self.peak_hold = seconds.max(0.0);
+
+        let peak_hold = self.peak_hold;
+        self.channels_mut().for_each(|channel| {
+            channel.hold_remaining = channel.hold_remaining.min(peak_hold)
+        });
+    }
+
+    /// Sets how fast a peak falls after it has been held, as the fraction
+    /// of the meter per second.
+    pub fn set_peak_decay(&mut self, per_second: f32) {
+        self.peak_decay = per_second.max(0.0);
+    }
+
+    /// Lets the levels fall back smoothly after a peak at `db_per_second`,
+    /// instead of following the levels pushed with [`update`] exactly.
+    ///
+    /// `range` is the range the levels are mapped from, such as the one
+    /// used to calibrate the meter. A rate of around `20.0` dB per second
+    /// is typical for a peak meter.
+    ///
+    /// [`update`]: #method.update
+    pub fn set_fall_back(&mut self, db_per_second: f32, range: LogDBRange) {
+        self.fall_back = Some(FallBack {
+            db_per_second: db_per_second.max(0.0),
+            range,
+        });
+    }
+
+    /// Makes the levels follow the levels pushed with [`update`] exactly.
+    /// This is the default.
+    ///
+    /// [`update`]: #method.update
+    pub fn clear_fall_back(&mut self) {
+        self.fall_back = None;
+    }
+
+    /// Drops the peak-hold lines down to the current levels.
+    pub fn reset_peak(&mut self) {
+        self.channels_mut().for_each(Channel::reset_peak);
+    }
+
+    fn channels(&self) -> impl Iterator<Item = &Channel> {
+        std::iter::once(&self.left).chain(self.right.iter())
+    }
+
+    fn channels_mut(&mut self) -> impl Iterator<Item = &mut Channel> {
+        std::iter::once(&mut self.left).chain(self.right.iter_mut())
+    }
+}
+
+fn max_normal(a: Normal, b: Normal) -> Normal {
+    if b.as_f32() > a.as_f32() {
+        b
+    } else {
+        a
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State::new()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for DBMeter<'a, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(
+            layout.bounds(),
+            self.orientation,
+            &self.state.left,
+            self.state.right.as_ref(),
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`DBMeter`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`DBMeter`] in your user interface.
+///
+/// [`DBMeter`]: struct.DBMeter.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`DBMeter`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`DBMeter`]
+    ///   * the [`Orientation`] of the [`DBMeter`]
+    ///   * the left (or only) [`Channel`]
+    ///   * the right [`Channel`], if the meter is stereo
+    ///   * the style of the [`DBMeter`]
+    ///
+    /// [`DBMeter`]: struct.DBMeter.html
+    /// [`Orientation`]: ../../core/orientation/enum.Orientation.html
+    /// [`Channel`]: struct.Channel.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        orientation: Orientation,
+        left: &Channel,
+        right: Option<&Channel>,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<DBMeter<'a, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(db_meter: DBMeter<'a, Renderer>) -> Element<'a, Message, Renderer> {
+        Element::new(db_meter)
+    }
+}
@@ -0,0 +1,797 @@
+//! Display draggable EQ band nodes and their response curves on top of a
+//! spectrum.
+
+use std::hash::Hash;
+
+use iced_native::{
+    event, keyboard, layout, mouse, window, Clipboard, Element, Event, Hasher,
+    Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use crate::core::input_config::{DragAnchor, InputAction};
+use crate::core::{
+    FloatRange, FreqRange, InputConfig, Normal, NormalParam, ResetGesture,
+};
+
+static DEFAULT_HEIGHT: u16 = 200;
+static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+static DEFAULT_HIT_RADIUS: f32 = 10.0;
+static DEFAULT_Q_STEP: f32 = 0.05;
+
+/// The filter type of a [`Band`].
+///
+/// [`Band`]: struct.Band.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum BandKind {
+    /// A peaking (bell) filter
+    Bell,
+    /// A low shelf filter
+    LowShelf,
+    /// A high shelf filter
+    HighShelf,
+    /// A low cut (high pass) filter. The gain has no effect.
+    LowCut,
+    /// A high cut (low pass) filter. The gain has no effect.
+    HighCut,
+}
+
+impl BandKind {
+    /// Returns `true` if the gain of this filter type has an effect.
+    pub fn has_gain(&self) -> bool {
+        !matches!(self, BandKind::LowCut | BandKind::HighCut)
+    }
+}
+
+/// The ranges that map the [`Normal`] values of a [`Band`] to frequency,
+/// gain, and Q.
+///
+/// [`Normal`]: ../../core/struct.Normal.html
+/// [`Band`]: struct.Band.html
+#[derive(Debug, Copy, Clone)]
+pub struct Ranges {
+    /// The range of the frequency in Hz, along the x axis.
+    ///
+    /// The default is `20.0` to `20480.0`.
+    pub frequency: FreqRange,
+    /// The range of the gain in decibels, along the y axis.
+    ///
+    /// The default is `-24.0` to `24.0`.
+    pub gain: FloatRange,
+    /// The range of the Q (bandwidth), changed with the mouse wheel.
+    ///
+    /// The default is `0.1` to `10.0`.
+    pub q: FloatRange,
+}
+
+impl Default for Ranges {
+    fn default() -> Self {
+        Self {
+            frequency: FreqRange::new(20.0, 20480.0),
+            gain: FloatRange::new(-24.0, 24.0),
+            q: FloatRange::new(0.1, 10.0),
+        }
+    }
+}
+
+/// A single EQ band controlled by an [`EqBands`] widget.
+///
+/// [`EqBands`]: struct.EqBands.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Band {
+    /// The filter type
+    pub kind: BandKind,
+    /// The frequency, along the x axis
+    pub frequency: NormalParam,
+    /// The gain, along the y axis
+    pub gain: NormalParam,
+    /// The Q (bandwidth), changed with the mouse wheel
+    pub q: NormalParam,
+}
+
+impl Band {
+    /// Creates a new [`Band`].
+    ///
+    /// [`Band`]: struct.Band.html
+    pub fn new(
+        kind: BandKind,
+        frequency: NormalParam,
+        gain: NormalParam,
+        q: NormalParam,
+    ) -> Self {
+        Self {
+            kind,
+            frequency,
+            gain,
+            q,
+        }
+    }
+
+    /// Returns the magnitude response of the [`Band`] in decibels at the
+    /// frequency `hz`.
+    ///
+    /// The response is that of the analog prototype of the filter, so it
+    /// does not depend on the sample rate.
+    ///
+    /// [`Band`]: struct.Band.html
+    pub fn response_db(&self, ranges: &Ranges, hz: f32) -> f32 {
+        let center = ranges.frequency.unmap_to_value(self.frequency.value);
+        let gain_db = ranges.gain.unmap_to_value(self.gain.value);
+        let q = ranges.q.unmap_to_value(self.q.value).max(0.01);
+
+        // The normalized angular frequency `s = jx`.
+        let x = hz / center;
+        let a = 10.0f32.powf(gain_db / 40.0);
+        let sqrt_a = a.sqrt();
+
+        // Each filter is `(num[0]s^2 + num[1]s + num[2]) /
+        // (den[0]s^2 + den[1]s + den[2])`.
+        let (gain, num, den) = match self.kind {
+            BandKind::Bell => {
+                (1.0, [1.0, a / q, 1.0], [1.0, 1.0 / (a * q), 1.0])
+            }
+            BandKind::LowShelf => {
+                (a, [1.0, sqrt_a / q, a], [a, sqrt_a / q, 1.0])
+            }
+            BandKind::HighShelf => {
+                (a, [a, sqrt_a / q, 1.0], [1.0, sqrt_a / q, a])
+            }
+            BandKind::LowCut => (1.0, [1.0, 0.0, 0.0], [1.0, 1.0 / q, 1.0]),
+            BandKind::HighCut => (1.0, [0.0, 0.0, 1.0], [1.0, 1.0 / q, 1.0]),
+        };
+
+        let magnitude_sq = |p: [f32; 3]| {
+            let re = p[2] - p[0] * x * x;
+            let im = p[1] * x;
+            re * re + im * im
+        };
+
+        let magnitude = gain
+            * (magnitude_sq(num) / magnitude_sq(den).max(f32::MIN_POSITIVE))
+                .sqrt();
+
+        20.0 * magnitude.max(1.0e-6).log10()
+    }
+
+    /// Returns the position of the node of the [`Band`], where `(0.0, 0.0)`
+    /// is the bottom left corner and `(1.0, 1.0)` is the top right corner.
+    ///
+    /// [`Band`]: struct.Band.html
+    pub fn node_position(&self, ranges: &Ranges) -> (Normal, Normal) {
+        let y = if self.kind.has_gain() {
+            self.gain.value
+        } else {
+            ranges.gain.map_to_normal(0.0)
+        };
+
+        (self.frequency.value, y)
+    }
+}
+
+/// An EQ display with a draggable node for each [`Band`].
+///
+/// Dragging a node horizontally changes the frequency of its band, and
+/// dragging it vertically changes the gain. Scrolling over a node changes
+/// the Q. The response curve of each band and the combined response are
+/// drawn on top of an optional spectrum.
+///
+/// [`Band`]: struct.Band.html
+#[allow(missing_debug_implementations)]
+pub struct EqBands<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    on_change: Box<dyn Fn(usize, Band) -> Message>,
+    on_menu: Option<Box<dyn Fn(usize) -> Message>>,
+    on_reset: Option<Box<dyn Fn(usize, Band) -> Message>>,
+    ranges: Ranges,
+    spectrum: Option<&'a [f32]>,
+    sample_rate: f32,
+    modifier_scalar: f32,
+    modifier_keys: keyboard::Modifiers,
+    input_config: InputConfig,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer> EqBands<'a, Message, Renderer> {
+    /// Creates a new [`EqBands`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`EqBands`]
+    ///   * a function that will be called when a [`Band`] is changed, with
+    ///     the index and the new value of the [`Band`]
+    ///
+    /// [`State`]: struct.State.html
+    /// [`EqBands`]: struct.EqBands.html
+    /// [`Band`]: struct.Band.html
+    pub fn new<F>(state: &'a mut State, on_change: F) -> Self
+    where
+        F: 'static + Fn(usize, Band) -> Message,
+    {
+        EqBands {
+            state,
+            on_change: Box::new(on_change),
+            on_menu: None,
+            on_reset: None,
+            ranges: Ranges::default(),
+            spectrum: None,
+            sample_rate: 44100.0,
+            modifier_scalar: DEFAULT_MODIFIER_SCALAR,
+            modifier_keys: keyboard::Modifiers {
+                control: true,
+                ..Default::default()
+            },
+            input_config: InputConfig::global(),
+            width: Length::Fill,
+            height: Length::Units(DEFAULT_HEIGHT),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the [`Ranges`] that map the values of the bands.
+    ///
+    /// [`Ranges`]: struct.Ranges.html
+    pub fn ranges(mut self, ranges: Ranges) -> Self {
+        self.ranges = ranges;
+        self
+    }
+
+    /// Sets the spectrum drawn behind the bands.
+    ///
+    /// `magnitudes` are the magnitudes of the linearly spaced bins of a
+    /// spectrum, from 0 Hz up to the Nyquist frequency, each in the range
+    /// `[0.0, 1.0]`.
+    pub fn spectrum(mut self, magnitudes: &'a [f32]) -> Self {
+        self.spectrum = Some(magnitudes);
+        self
+    }
+
+    /// Sets the sample rate of the audio the spectrum was taken from.
+    ///
+    /// The default is `44100.0`.
+    pub fn sample_rate(mut self, sample_rate: f32) -> Self {
+        self.sample_rate = sample_rate;
+        self
+    }
+
+    /// Sets the width of the [`EqBands`].
+    /// The default width is `Length::Fill`.
+    ///
+    /// [`EqBands`]: struct.EqBands.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`EqBands`].
+    /// The default height is `Length::Units(200)`.
+    ///
+    /// [`EqBands`]: struct.EqBands.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`EqBands`].
+    ///
+    /// [`EqBands`]: struct.EqBands.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the modifier keys to enable finer adjustments when dragging a
+    /// node. The default modifier key is `Ctrl`.
+    pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
+        self.modifier_keys = modifier_keys;
+        self
+    }
+
+    /// Sets the scalar to use when the user drags a node while holding down
+    /// the modifier key.
+    ///
+    /// For example, a `modifier_scalar` of `0.5` will cause the node to
+    /// move half as fast when the modifier key is pressed.
+    ///
+    /// The default `modifier_scalar` is `0.02`, and the default modifier key
+    /// is `Ctrl`.
+    pub fn modifier_scalar(mut self, scalar: f32) -> Self {
+        self.modifier_scalar = scalar;
+        self
+    }
+
+    /// Sets the mouse buttons and drag threshold of the [`EqBands`].
+    ///
+    /// The default is the global [`InputConfig`].
+    ///
+    /// [`EqBands`]: struct.EqBands.html
+    /// [`InputConfig`]: ../../core/input_config/struct.InputConfig.html
+    pub fn input_config(mut self, input_config: InputConfig) -> Self {
+        self.input_config = input_config;
+        self
+    }
+
+    /// Sets a function that will be called with the index of a band when
+    /// the menu button is pressed over its node.
+    pub fn on_menu<F>(mut self, on_menu: F) -> Self
+    where
+        F: 'static + Fn(usize) -> Message,
+    {
+        self.on_menu = Some(Box::new(on_menu));
+        self
+    }
+
+    /// Sets the gesture that resets a band of the [`EqBands`] to its default
+    /// values.
+    ///
+    /// The default is the gesture of the [`InputConfig`]. Set this after
+    /// [`input_config`], which replaces it.
+    ///
+    /// [`EqBands`]: struct.EqBands.html
+    /// [`InputConfig`]: ../../core/input_config/struct.InputConfig.html
+    /// [`input_config`]: #method.input_config
+    pub fn reset_gesture(mut self, reset_gesture: ResetGesture) -> Self {
+        self.input_config.reset = reset_gesture;
+        self
+    }
+
+    /// Sets a function that will be called with the index of a band and
+    /// its default values when the band is reset, instead of `on_change`.
+    ///
+    /// This lets the application tell a reset apart from a plain change,
+    /// such as to record it as its own undo step.
+    pub fn on_reset<F>(mut self, on_reset: F) -> Self
+    where
+        F: 'static + Fn(usize, Band) -> Message,
+    {
+        self.on_reset = Some(Box::new(on_reset));
+        self
+    }
+
+    fn node_at(
+        &self,
+        bounds: Rectangle,
+        cursor_position: Point,
+    ) -> Option<usize> {
+        node_at(
+            &self.state.bands,
+            &self.ranges,
+            bounds,
+            cursor_position,
+            self.input_config.hit_radius(DEFAULT_HIT_RADIUS),
+        )
+    }
+}
+
+fn node_point(band: &Band, ranges: &Ranges, bounds: Rectangle) -> Point {
+    let (x, y) = band.node_position(ranges);
+
+    Point::new(
+        bounds.x + x.as_f32() * bounds.width,
+        bounds.y + (1.0 - y.as_f32()) * bounds.height,
+    )
+}
+
+fn node_at(
+    bands: &[Band],
+    ranges: &Ranges,
+    bounds: Rectangle,
+    cursor_position: Point,
+    hit_radius: f32,
+) -> Option<usize> {
+    bands
+        .iter()
+        .enumerate()
+        .map(|(i, band)| {
+            (
+                i,
+                node_point(band, ranges, bounds).distance(cursor_position),
+            )
+        })
+        .filter(|(_, distance)| *distance <= hit_radius)
+        .min_by(|a, b| {
+            a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .map(|(i, _)| i)
+}
+
+/// The local state of an [`EqBands`].
+///
+/// [`EqBands`]: struct.EqBands.html
+#[derive(Debug, Clone)]
+pub struct State {
+    bands: Vec<Band>,
+    dragging: Option<usize>,
+    drag_start: Option<Point>,
+    drag_anchor_x: DragAnchor,
+    drag_anchor_y: DragAnchor,
+    pressed_modifiers: keyboard::Modifiers,
+    last_click: Option<mouse::Click>,
+}
+
+impl State {
+    /// Creates a new [`EqBands`] state.
+    ///
+    /// It expects:
+    ///   * the [`Band`]s to control
+    ///
+    /// [`EqBands`]: struct.EqBands.html
+    /// [`Band`]: struct.Band.html
+    pub fn new(bands: Vec<Band>) -> Self {
+        Self {
+            bands,
+            dragging: None,
+            drag_start: None,
+            drag_anchor_x: DragAnchor::default(),
+            drag_anchor_y: DragAnchor::default(),
+            pressed_modifiers: Default::default(),
+            last_click: None,
+        }
+    }
+
+    /// Returns the bands.
+    pub fn bands(&self) -> &[Band] {
+        &self.bands
+    }
+
+    /// Replaces the band at `index`, for example when it was changed from
+    /// somewhere else.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `index` is out of bounds.
+    pub fn set_band(&mut self, index: usize, band: Band) {
+        self.bands[index] = band;
+    }
+
+    /// Adds a band.
+    pub fn push_band(&mut self, band: Band) {
+        self.bands.push(band);
+    }
+
+    /// Removes and returns the band at `index`.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `index` is out of bounds.
+    pub fn remove_band(&mut self, index: usize) -> Band {
+        self.dragging = None;
+        self.drag_start = None;
+        self.bands.remove(index)
+    }
+
+    /// Returns the index of the band that is being dragged, if any.
+    pub fn dragging(&self) -> Option<usize> {
+        self.dragging
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for EqBands<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse_event) => match mouse_event {
+                mouse::Event::CursorMoved { .. } => {
+                    if let Some(index) = self.state.dragging {
+                        if let Some(drag_start) = self.state.drag_start {
+                            if !self.input_config.drag_threshold_crossed(
+                                drag_start,
+                                cursor_position,
+                            ) {
+                                return event::Status::Captured;
+                            }
+
+                            self.state.drag_start = None;
+                        }
+
+                        if bounds.width <= 0.0 || bounds.height <= 0.0 {
+                            return event::Status::Captured;
+                        }
+
+                        let mut scalar = 1.0;
+
+                        if self
+                            .state
+                            .pressed_modifiers
+                            .matches(self.modifier_keys)
+                        {
+                            scalar *= self.modifier_scalar;
+                        }
+
+                        let normal_x = self.state.drag_anchor_x.drag_unbounded(
+                            cursor_position.x,
+                            scalar / bounds.width,
+                        );
+                        let normal_y = self.state.drag_anchor_y.drag_unbounded(
+                            cursor_position.y,
+                            -scalar / bounds.height,
+                        );
+
+                        let band = &mut self.state.bands[index];
+                        band.frequency.value = normal_x.into();
+                        if band.kind.has_gain() {
+                            band.gain.value = normal_y.into();
+                        }
+
+                        messages.push((self.on_change)(index, *band));
+
+                        return event::Status::Captured;
+                    }
+                }
+                mouse::Event::ButtonPressed(button) => {
+                    if !self
+                        .input_config
+                        .hit_bounds(bounds)
+                        .contains(cursor_position)
+                    {
+                        return event::Status::Ignored;
+                    }
+
+                    let index = if let Some(index) =
+                        self.node_at(bounds, cursor_position)
+                    {
+                        index
+                    } else {
+                        return event::Status::Ignored;
+                    };
+
+                    let click_kind = if button == self.input_config.drag_button
+                    {
+                        let click = mouse::Click::new(
+                            cursor_position,
+                            self.state.last_click,
+                        );
+
+                        self.state.last_click = Some(click);
+
+                        click.kind()
+                    } else {
+                        mouse::click::Kind::Single
+                    };
+
+                    match self.input_config.action(
+                        button,
+                        click_kind,
+                        self.state.pressed_modifiers,
+                    ) {
+                        InputAction::Drag => {
+                            let band = &self.state.bands[index];
+
+                            self.state.dragging = Some(index);
+                            self.state.drag_start = Some(cursor_position);
+                            self.state.drag_anchor_x = DragAnchor::new(
+                                cursor_position.x,
+                                band.frequency.value.as_f32(),
+                            );
+                            self.state.drag_anchor_y = DragAnchor::new(
+                                cursor_position.y,
+                                band.gain.value.as_f32(),
+                            );
+                        }
+                        InputAction::Reset => {
+                            self.state.dragging = None;
+
+                            let band = &mut self.state.bands[index];
+                            band.frequency.value = band.frequency.default;
+                            band.gain.value = band.gain.default;
+                            band.q.value = band.q.default;
+
+                            messages.push(match &self.on_reset {
+                                Some(on_reset) => (on_reset)(index, *band),
+                                None => (self.on_change)(index, *band),
+                            });
+                        }
+                        InputAction::Menu => {
+                            if let Some(on_menu) = &self.on_menu {
+                                messages.push((on_menu)(index));
+                            }
+                        }
+                        InputAction::None => {
+                            return event::Status::Ignored;
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
+                mouse::Event::ButtonReleased(button)
+                    if button == self.input_config.drag_button
+                        && self.state.dragging.is_some() =>
+                {
+                    self.state.dragging = None;
+                    self.state.drag_start = None;
+
+                    return event::Status::Captured;
+                }
+                mouse::Event::WheelScrolled { delta } => {
+                    let index = if let Some(index) = self.state.dragging {
+                        index
+                    } else if let Some(index) =
+                        self.node_at(bounds, cursor_position)
+                    {
+                        index
+                    } else {
+                        return event::Status::Ignored;
+                    };
+
+                    let lines = match delta {
+                        mouse::ScrollDelta::Lines { y, .. } => y,
+                        mouse::ScrollDelta::Pixels { y, .. } => {
+                            if y > 0.0 {
+                                1.0
+                            } else if y < 0.0 {
+                                -1.0
+                            } else {
+                                0.0
+                            }
+                        }
+                    };
+
+                    if lines == 0.0 {
+                        return event::Status::Ignored;
+                    }
+
+                    let band = &mut self.state.bands[index];
+                    band.q.value =
+                        band.q.value.saturating_add(lines * DEFAULT_Q_STEP);
+
+                    messages.push((self.on_change)(index, *band));
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
+            Event::Keyboard(keyboard_event) => match keyboard_event {
+                keyboard::Event::KeyPressed { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+                }
+                keyboard::Event::KeyReleased { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+                }
+                keyboard::Event::ModifiersChanged(modifiers) => {
+                    self.state.pressed_modifiers = modifiers;
+                }
+                _ => {}
+            },
+            Event::Window(window::Event::Unfocused) => {
+                self.state.pressed_modifiers = Default::default();
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+
+        let hovered = if self
+            .input_config
+            .hit_bounds(bounds)
+            .contains(cursor_position)
+        {
+            self.node_at(bounds, cursor_position)
+        } else {
+            None
+        };
+
+        renderer.draw(
+            &DrawState {
+                bounds,
+                cursor_position,
+                bands: &self.state.bands,
+                ranges: &self.ranges,
+                hovered,
+                dragging: self.state.dragging,
+                spectrum: self.spectrum,
+                sample_rate: self.sample_rate,
+            },
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The values an [`EqBands`] is drawn with in a frame.
+///
+/// [`EqBands`]: struct.EqBands.html
+#[derive(Debug, Clone, Copy)]
+pub struct DrawState<'a> {
+    /// The bounds of the equalizer
+    pub bounds: Rectangle,
+    /// The current cursor position
+    pub cursor_position: Point,
+    /// The bands
+    pub bands: &'a [Band],
+    /// The [`Ranges`] of the bands
+    ///
+    /// [`Ranges`]: struct.Ranges.html
+    pub ranges: &'a Ranges,
+    /// The index of the band whose node is under the cursor, if any
+    pub hovered: Option<usize>,
+    /// The index of the band that is being dragged, if any
+    pub dragging: Option<usize>,
+    /// The magnitudes of the spectrum, if any
+    pub spectrum: Option<&'a [f32]>,
+    /// The sample rate of the spectrum
+    pub sample_rate: f32,
+}
+
+/// The renderer of an [`EqBands`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use an [`EqBands`] in your user interface.
+///
+/// [`EqBands`]: struct.EqBands.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws an [`EqBands`] from its [`DrawState`] and its style.
+    ///
+    /// [`EqBands`]: struct.EqBands.html
+    /// [`DrawState`]: struct.DrawState.html
+    fn draw(
+        &mut self,
+        state: &DrawState<'_>,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<EqBands<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        eq_bands: EqBands<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(eq_bands)
+    }
+}
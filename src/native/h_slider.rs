@@ -5,15 +5,21 @@
 use std::fmt::Debug;
 
 use iced_native::{
-    event, keyboard, layout, mouse, Clipboard, Element, Event, Hasher, Layout,
-    Length, Point, Rectangle, Size, Widget,
+    event, keyboard, layout, mouse, window, Clipboard, Color, Element, Event,
+    Hasher, Layout, Length, Point, Rectangle, Size, Widget,
 };
 
 use std::hash::Hash;
+use std::time::{Duration, Instant};
 
+use crate::core::detents;
+use crate::core::input_config::{DragAnchor, InputAction};
 use crate::native::{text_marks, tick_marks};
 use crate::{
-    core::{ModulationRange, Normal, NormalParam},
+    core::{
+        Detents, InputConfig, ModulationRange, Motor, Normal, NormalParam,
+        ResetGesture, Transition,
+    },
     IntRange,
 };
 
@@ -21,6 +27,7 @@ static DEFAULT_HEIGHT: u16 = 14;
 static DEFAULT_SCALAR: f32 = 0.9575;
 static DEFAULT_WHEEL_SCALAR: f32 = 0.01;
 static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+static DEFAULT_PAGE_STEP: f32 = 0.1;
 
 /// A horizontal slider GUI widget that controls a [`NormalParam`]
 ///
@@ -33,16 +40,34 @@ pub struct HSlider<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
     on_change: Box<dyn Fn(Normal) -> Message>,
     scalar: f32,
+    sensitivity: Option<f32>,
+    jump_to_click: bool,
     wheel_scalar: f32,
+    page_step: f32,
+    detents: Option<Detents>,
     modifier_scalar: f32,
     modifier_keys: keyboard::Modifiers,
+    input_config: InputConfig,
+    on_menu: Option<Box<dyn Fn() -> Message>>,
+    on_reset: Option<Box<dyn Fn(Normal) -> Message>>,
+    on_tab: Option<Box<dyn Fn(bool) -> Message>>,
+    on_grab: Option<Box<dyn Fn() -> Message>>,
+    on_release: Option<Box<dyn Fn() -> Message>>,
+    cache_primitives: bool,
+    accent: Option<Color>,
+    warning_threshold: Option<Normal>,
+    center_fill: bool,
     width: Length,
     height: Length,
     style: Renderer::Style,
     tick_marks: Option<&'a tick_marks::Group>,
+    snap_to_tick_marks: bool,
+    soft_takeover: bool,
     text_marks: Option<&'a text_marks::Group>,
     mod_range_1: Option<&'a ModulationRange>,
     mod_range_2: Option<&'a ModulationRange>,
+    modulated_value: Option<Normal>,
+    automation_value: Option<Normal>,
 }
 
 impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
@@ -62,19 +87,37 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
             state,
             on_change: Box::new(on_change),
             scalar: DEFAULT_SCALAR,
+            sensitivity: None,
+            jump_to_click: false,
             wheel_scalar: DEFAULT_WHEEL_SCALAR,
+            page_step: DEFAULT_PAGE_STEP,
+            detents: None,
             modifier_scalar: DEFAULT_MODIFIER_SCALAR,
             modifier_keys: keyboard::Modifiers {
                 control: true,
                 ..Default::default()
             },
+            input_config: InputConfig::global(),
+            on_menu: None,
+            on_reset: None,
+            on_tab: None,
+            on_grab: None,
+            on_release: None,
+            cache_primitives: false,
+            accent: None,
+            warning_threshold: None,
+            center_fill: false,
             width: Length::Fill,
             height: Length::from(Length::Units(DEFAULT_HEIGHT)),
             style: Renderer::Style::default(),
             tick_marks: None,
+            snap_to_tick_marks: false,
+            soft_takeover: false,
             text_marks: None,
             mod_range_1: None,
             mod_range_2: None,
+            modulated_value: None,
+            automation_value: None,
         }
     }
 
@@ -116,6 +159,166 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the mouse buttons that control the [`HSlider`].
+    ///
+    /// The default is the global [`InputConfig`].
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`InputConfig`]: ../../core/input_config/struct.InputConfig.html
+    pub fn input_config(mut self, input_config: InputConfig) -> Self {
+        self.input_config = input_config;
+        self
+    }
+
+    /// Sets the message to send when the menu button of the [`InputConfig`]
+    /// is pressed over the [`HSlider`].
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`InputConfig`]: ../../core/input_config/struct.InputConfig.html
+    pub fn on_menu<F>(mut self, on_menu: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_menu = Some(Box::new(on_menu));
+        self
+    }
+
+    /// Sets the gesture that resets the [`HSlider`] to its default value.
+    ///
+    /// The default is the gesture of the [`InputConfig`]. Set this after
+    /// [`input_config`], which replaces it.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`InputConfig`]: ../../core/input_config/struct.InputConfig.html
+    /// [`input_config`]: #method.input_config
+    pub fn reset_gesture(mut self, reset_gesture: ResetGesture) -> Self {
+        self.input_config.reset = reset_gesture;
+        self
+    }
+
+    /// Sets the message to send with the default value when the
+    /// [`HSlider`] is reset, instead of the message of `on_change`.
+    ///
+    /// This lets the application tell a reset apart from a plain change,
+    /// such as to record it as its own undo step.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn on_reset<F>(mut self, on_reset: F) -> Self
+    where
+        F: 'static + Fn(Normal) -> Message,
+    {
+        self.on_reset = Some(Box::new(on_reset));
+        self
+    }
+
+    /// Sets the message to send when `Tab` is pressed while the [`HSlider`]
+    /// has keyboard focus, with `true` if `Shift` is held.
+    ///
+    /// The [`HSlider`] loses focus, so the application can give it to the
+    /// next (or previous) widget with [`State::focus`].
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`State::focus`]: struct.State.html#method.focus
+    pub fn on_tab<F>(mut self, on_tab: F) -> Self
+    where
+        F: 'static + Fn(bool) -> Message,
+    {
+        self.on_tab = Some(Box::new(on_tab));
+        self
+    }
+
+    /// Sets the message to send when the user grabs the [`HSlider`] to drag
+    /// it, before any change is sent.
+    ///
+    /// Together with [`on_release`], this brackets a gesture, such as to
+    /// tell a host that an automation edit begins and ends, or to group the
+    /// changes of a drag into one undo step.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`on_release`]: #method.on_release
+    pub fn on_grab<F>(mut self, on_grab: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_grab = Some(Box::new(on_grab));
+        self
+    }
+
+    /// Sets the message to send when the user releases the [`HSlider`] after
+    /// grabbing it.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn on_release<F>(mut self, on_release: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_release = Some(Box::new(on_release));
+        self
+    }
+
+    /// Sets whether the [`HSlider`] reuses its primitives from the previous
+    /// frame when nothing that affects its appearance has changed.
+    ///
+    /// Changes to the value, interaction state, size, and any marks or
+    /// modulation ranges are detected automatically. Call
+    /// [`State::invalidate`] after changing the style.
+    ///
+    /// The default is `false`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`State::invalidate`]: struct.State.html#method.invalidate
+    pub fn cache_primitives(mut self, cache_primitives: bool) -> Self {
+        self.cache_primitives = cache_primitives;
+        self
+    }
+
+    /// Sets the accent color of the [`HSlider`]. This replaces the colors of
+    /// the parts of its style that show the value, such as the filled
+    /// portion.
+    ///
+    /// Combined with an [`AccentMap`], this gives whole groups of parameters
+    /// their own color, for example `.accent(accents.get("Filter"))`.
+    ///
+    /// The default is no accent color.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`AccentMap`]: ../../core/accent_map/struct.AccentMap.html
+    pub fn accent(mut self, accent: impl Into<Option<Color>>) -> Self {
+        self.accent = accent.into();
+        self
+    }
+
+    /// Sets a threshold above which the [`HSlider`] is drawn in the warning
+    /// color of its [`StyleSheet`] instead of its accent color, for example
+    /// to flag a gain above 0 dB that may clip.
+    ///
+    /// The default is no threshold.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`StyleSheet`]: ../../style/h_slider/trait.StyleSheet.html
+    pub fn warning_threshold(
+        mut self,
+        threshold: impl Into<Option<Normal>>,
+    ) -> Self {
+        self.warning_threshold = threshold.into();
+        self
+    }
+
+    /// Sets whether the filled portion of a `Rect` style grows from the
+    /// center of the [`HSlider`] out to the handle instead of from its
+    /// minimum. This suits parameters such as EQ gain, where the center is
+    /// the resting value.
+    ///
+    /// Unlike the `RectBipolar` style, both sides use the same filled color.
+    ///
+    /// The default is `false`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn center_fill(mut self, center_fill: bool) -> Self {
+        self.center_fill = center_fill;
+        self
+    }
+
     /// Sets the scalar to use when the user drags the slider per pixel.
     ///
     /// For example, a scalar of `0.5` will cause the slider to move half a
@@ -129,6 +332,43 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
         self
     }
 
+    /// Sets how much the [`Normal`] value will change per pixel the mouse
+    /// moves while dragging, regardless of the length of the [`HSlider`].
+    /// This replaces [`scalar`], so short sliders are not twitchy and long
+    /// ones are not sluggish.
+    ///
+    /// For example, a sensitivity of `0.005` takes 200 pixels to move across
+    /// the whole range.
+    ///
+    /// The default is no sensitivity, where the [`HSlider`] follows the mouse.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    /// [`scalar`]: #method.scalar
+    pub fn sensitivity(mut self, sensitivity: impl Into<Option<f32>>) -> Self {
+        self.sensitivity = sensitivity.into();
+        self
+    }
+
+    /// Sets whether clicking the [`HSlider`] jumps the value to the clicked
+    /// position, with the handle then following the cursor, like the
+    /// faders of many DAWs.
+    ///
+    /// While this is enabled, dragging ignores [`scalar`] and
+    /// [`sensitivity`]. Holding the modifier keys still makes fine
+    /// adjustments.
+    ///
+    /// The default is `false`, where the value only moves relative to
+    /// where the [`HSlider`] was grabbed.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`scalar`]: #method.scalar
+    /// [`sensitivity`]: #method.sensitivity
+    pub fn jump_to_click(mut self, jump_to_click: bool) -> Self {
+        self.jump_to_click = jump_to_click;
+        self
+    }
+
     /// Sets how much the [`Normal`] value will change for the [`HSlider`] per line scrolled
     /// by the mouse wheel.
     ///
@@ -143,6 +383,39 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
         self
     }
 
+    /// Sets how much the [`Normal`] value of the [`HSlider`] changes when
+    /// `PageUp` or `PageDown` is pressed while it has keyboard focus or is
+    /// being dragged.
+    ///
+    /// With detents, this is rounded to a whole number of steps, and is at
+    /// least one step.
+    ///
+    /// The default is `0.1`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn page_step(mut self, page_step: f32) -> Self {
+        self.page_step = page_step;
+        self
+    }
+
+    /// Sets the [`Detents`] of the [`HSlider`] for a discrete parameter, such as
+    /// one mapped by an [`IntRange`].
+    ///
+    /// With detents, each line scrolled by the mouse wheel moves exactly one
+    /// step, and dragging only changes the value once the next detent is
+    /// reached.
+    ///
+    /// The default is no detents.
+    ///
+    /// [`Detents`]: ../../core/detents/struct.Detents.html
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`IntRange`]: ../../core/range/struct.IntRange.html
+    pub fn detents(mut self, detents: impl Into<Detents>) -> Self {
+        self.detents = Some(detents.into());
+        self
+    }
+
     /// Sets the scalar to use when the user drags the slider while holding down
     /// the modifier key.
     ///
@@ -167,6 +440,38 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
         self
     }
 
+    /// Sets whether dragging the [`HSlider`] snaps its value to the nearest
+    /// of its tick marks. Holding down the modifier keys bypasses the
+    /// snapping, along with making the adjustment finer.
+    ///
+    /// This has no effect without tick marks, or with [`detents`].
+    ///
+    /// The default is `false`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`detents`]: #method.detents
+    pub fn snap_to_tick_marks(mut self, snap_to_tick_marks: bool) -> Self {
+        self.snap_to_tick_marks = snap_to_tick_marks;
+        self
+    }
+
+    /// Sets whether the [`HSlider`] uses soft takeover.
+    ///
+    /// With soft takeover, the value does not jump when it differs from
+    /// the drag, such as when it was changed by MIDI or automation while the
+    /// [`HSlider`] is being dragged, or when the [`HSlider`] is clicked with
+    /// [`jump_to_click`]. The value stays put until the drag reaches
+    /// (catches) it, and then follows the drag.
+    ///
+    /// The default is `false`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`jump_to_click`]: #method.jump_to_click
+    pub fn soft_takeover(mut self, soft_takeover: bool) -> Self {
+        self.soft_takeover = soft_takeover;
+        self
+    }
+
     /// Sets the text marks to display. Note your [`StyleSheet`] must
     /// also implement `text_marks_style(&self) -> Option<text_marks::Style>` for
     /// them to display (which the default style does).
@@ -195,7 +500,31 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
     /// [`ModulationRange`]: ../../core/struct.ModulationRange.html
     /// [`StyleSheet`]: ../../style/v_slider/trait.StyleSheet.html
     pub fn mod_range_2(mut self, mod_range: &'a ModulationRange) -> Self {
-        self.mod_range_1 = Some(mod_range);
+        self.mod_range_2 = Some(mod_range);
+        self
+    }
+
+    /// Sets the current modulated value to display as a thin line, while
+    /// the handle still shows the value set by the user. Note your
+    /// [`StyleSheet`] must also implement
+    /// `modulated_value_style(&self) -> Option<ModulatedValueStyle>` for it to
+    /// display.
+    ///
+    /// [`StyleSheet`]: ../../style/h_slider/trait.StyleSheet.html
+    pub fn modulated_value(mut self, normal: Normal) -> Self {
+        self.modulated_value = Some(normal);
+        self
+    }
+
+    /// Sets the value that automation is playing back to display as a
+    /// dimmed line, apart from the value edited by the user. Note your
+    /// [`StyleSheet`] must also implement
+    /// `automation_value_style(&self) -> Option<ModulatedValueStyle>` for it to
+    /// display.
+    ///
+    /// [`StyleSheet`]: ../../style/h_slider/trait.StyleSheet.html
+    pub fn automation_value(mut self, normal: Normal) -> Self {
+        self.automation_value = Some(normal);
         self
     }
 
@@ -216,11 +545,169 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
             normal = 1.0;
         }
 
+        self.state.drag_anchor.rebase(normal);
+
+        self.set_virtual_slider(messages, normal);
+    }
+
+    fn drag_virtual_slider(
+        &mut self,
+        messages: &mut Vec<Message>,
+        position: f32,
+        mut scalar: f32,
+    ) {
+        if self.state.pressed_modifiers.matches(self.modifier_keys) {
+            scalar *= self.modifier_scalar;
+        }
+
+        let normal = self.state.drag_anchor.drag(position, scalar);
+
+        if !self.caught_up(normal) {
+            return;
+        }
+
+        self.set_virtual_slider(messages, normal);
+    }
+
+    fn set_virtual_slider(&mut self, messages: &mut Vec<Message>, normal: f32) {
         self.state.continuous_normal = normal;
 
-        self.state.normal_param.value = normal.into();
+        if let Some(detents) = self.detents {
+            let value = detents.drag(self.state.normal_param.value, normal);
+
+            if value != self.state.normal_param.value {
+                self.state.normal_param.value = value;
+
+                messages.push((self.on_change)(value));
+            }
+        } else if let Some(snapped) = self.snapped_to_tick_marks(normal) {
+            if snapped != self.state.normal_param.value {
+                self.state.normal_param.value = snapped;
+
+                messages.push((self.on_change)(snapped));
+            }
+        } else {
+            self.state.normal_param.value = normal.into();
+
+            messages.push((self.on_change)(self.state.normal_param.value));
+        }
+    }
+
+    /// Returns `false` while soft takeover is waiting for the drag at
+    /// `normal` to reach the current value.
+    fn caught_up(&mut self, normal: f32) -> bool {
+        let last = self.state.drag_normal;
+        self.state.drag_normal = normal;
+
+        if !self.soft_takeover || !self.state.takeover_pending {
+            return true;
+        }
+
+        let value = self.state.normal_param.value.as_f32();
+
+        if (value - last) * (value - normal) > 0.0 {
+            return false;
+        }
+
+        self.state.takeover_pending = false;
+
+        true
+    }
+
+    fn snapped_to_tick_marks(&self, normal: f32) -> Option<Normal> {
+        if !self.snap_to_tick_marks
+            || !self.state.is_dragging
+            || self.state.pressed_modifiers.matches(self.modifier_keys)
+        {
+            return None;
+        }
+
+        self.tick_marks?.nearest(normal.into())
+    }
+
+    fn scroll(&mut self, messages: &mut Vec<Message>, lines: f32) {
+        self.state.motor.stop();
+
+        if let Some(detents) = self.detents {
+            let value = detents.step(
+                self.state.normal_param.value,
+                detents::wheel_steps(lines),
+            );
+
+            self.state.continuous_normal = value.as_f32();
+
+            if value != self.state.normal_param.value {
+                self.state.normal_param.value = value;
+
+                messages.push((self.on_change)(value));
+            }
+        } else {
+            self.move_virtual_slider(messages, -lines * self.wheel_scalar);
+        }
+    }
+
+    fn key_pressed(
+        &mut self,
+        messages: &mut Vec<Message>,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::Modifiers,
+    ) {
+        match key_code {
+            keyboard::KeyCode::Up | keyboard::KeyCode::Right => {
+                self.scroll(messages, 1.0);
+            }
+            keyboard::KeyCode::Down | keyboard::KeyCode::Left => {
+                self.scroll(messages, -1.0);
+            }
+            keyboard::KeyCode::Home => self.jump_to(messages, Normal::min()),
+            keyboard::KeyCode::End => self.jump_to(messages, Normal::max()),
+            keyboard::KeyCode::PageUp => self.page(messages, 1),
+            keyboard::KeyCode::PageDown => self.page(messages, -1),
+            keyboard::KeyCode::Tab => {
+                self.state.is_focused = false;
+
+                if let Some(on_tab) = &self.on_tab {
+                    messages.push((on_tab)(modifiers.shift));
+                }
+            }
+            keyboard::KeyCode::Escape => {
+                self.state.is_focused = false;
+            }
+            _ => {}
+        }
+    }
+
+    fn page(&mut self, messages: &mut Vec<Message>, direction: i32) {
+        let value = match self.detents {
+            Some(detents) => detents.step(
+                self.state.normal_param.value,
+                detents::page_steps(detents, self.page_step) * direction,
+            ),
+            None => Normal::from(
+                self.state.normal_param.value.as_f32()
+                    + direction as f32 * self.page_step,
+            ),
+        };
 
-        messages.push((self.on_change)(self.state.normal_param.value));
+        self.jump_to(messages, value);
+    }
+
+    fn jump_to(&mut self, messages: &mut Vec<Message>, value: Normal) {
+        self.state.motor.stop();
+
+        let value = match self.detents {
+            Some(detents) => detents.snapped(value),
+            None => value,
+        };
+
+        self.state.continuous_normal = value.as_f32();
+        self.state.drag_anchor.rebase(value.as_f32());
+
+        if value != self.state.normal_param.value {
+            self.state.normal_param.value = value;
+
+            messages.push((self.on_change)(value));
+        }
     }
 }
 
@@ -231,12 +718,20 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
 pub struct State {
     normal_param: NormalParam,
     is_dragging: bool,
-    prev_drag_x: f32,
+    is_focused: bool,
+    drag_start: Option<Point>,
+    drag_anchor: DragAnchor,
     continuous_normal: f32,
+    drag_normal: f32,
+    takeover_pending: bool,
     pressed_modifiers: keyboard::Modifiers,
     last_click: Option<mouse::Click>,
     tick_marks_cache: crate::graphics::tick_marks::PrimitiveCache,
     text_marks_cache: crate::graphics::text_marks::PrimitiveCache,
+    motor: Motor,
+    transition: Option<Transition>,
+    revision: u64,
+    draw_cache: crate::graphics::DrawCache,
 }
 
 impl State {
@@ -251,17 +746,29 @@ impl State {
         Self {
             normal_param,
             is_dragging: false,
-            prev_drag_x: 0.0,
+            is_focused: false,
+            drag_start: None,
+            drag_anchor: DragAnchor::default(),
             continuous_normal: normal_param.value.as_f32(),
+            drag_normal: normal_param.value.as_f32(),
+            takeover_pending: false,
             pressed_modifiers: Default::default(),
             last_click: None,
             tick_marks_cache: Default::default(),
             text_marks_cache: Default::default(),
+            motor: Motor::default(),
+            transition: None,
+            revision: 0,
+            draw_cache: Default::default(),
         }
     }
 
     /// Set the normalized value of the [`HSlider`].
     pub fn set_normal(&mut self, normal: Normal) {
+        if self.is_dragging && normal != self.normal_param.value {
+            self.takeover_pending = true;
+        }
+
         self.normal_param.value = normal;
         self.continuous_normal = normal.into();
     }
@@ -271,6 +778,106 @@ impl State {
         self.normal_param.value
     }
 
+    /// Glides the [`HSlider`] toward `normal` like a motorized fader, instead
+    /// of jumping there like [`set_normal`]. Call [`run_motor`]
+    /// periodically (for example from a timer subscription) to move it.
+    ///
+    /// This is ignored while the [`HSlider`] is being dragged, and grabbing
+    /// the [`HSlider`] stops the motor.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`set_normal`]: #method.set_normal
+    /// [`run_motor`]: #method.run_motor
+    pub fn drive_to(&mut self, normal: Normal) {
+        if !self.is_dragging {
+            self.motor.set_target(normal);
+        }
+    }
+
+    /// Moves the [`HSlider`] toward the value set with [`drive_to`].
+    ///
+    /// * `dt` - the time in seconds since the last call
+    ///
+    /// Returns the new normal while the motor is running.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`drive_to`]: #method.drive_to
+    pub fn run_motor(&mut self, dt: f32) -> Option<Normal> {
+        let normal = self.motor.step(self.normal_param.value, dt)?;
+
+        self.set_normal(normal);
+
+        Some(normal)
+    }
+
+    /// Sets how fast the motor of the [`HSlider`] moves, in normals per
+    /// second. The default is `4.0`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn set_motor_speed(&mut self, speed: f32) {
+        self.motor.set_speed(speed);
+    }
+
+    /// Returns `true` while the [`HSlider`] is moving toward the value set
+    /// with [`drive_to`].
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`drive_to`]: #method.drive_to
+    pub fn is_motor_running(&self) -> bool {
+        self.motor.is_running()
+    }
+
+    /// Animates the [`HSlider`] between its active, hovered, and dragging
+    /// styles over `duration`, or switches between them instantly if
+    /// `duration` is `None` (the default).
+    ///
+    /// While animating, call [`tick`] periodically (for example from a
+    /// timer subscription) to advance the animation.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`tick`]: #method.tick
+    pub fn set_transition_duration(&mut self, duration: Option<Duration>) {
+        match (duration, &mut self.transition) {
+            (Some(duration), Some(transition)) => {
+                transition.set_duration(duration)
+            }
+            (Some(duration), None) => {
+                self.transition = Some(Transition::new(duration))
+            }
+            (None, _) => self.transition = None,
+        }
+    }
+
+    /// Advances the style animation of the [`HSlider`] to the time `now`.
+    ///
+    /// Returns `true` while the animation is running. Does nothing and
+    /// returns `false` if no transition duration is set.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn tick(&mut self, now: Instant) -> bool {
+        match &mut self.transition {
+            Some(transition) => {
+                transition.set_pressed(self.is_dragging);
+                transition.tick(now)
+            }
+            None => false,
+        }
+    }
+
+    /// Returns `true` while the style of the [`HSlider`] is animating.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn is_animating(&self) -> bool {
+        match &self.transition {
+            Some(transition) => {
+                let mut transition = *transition;
+                transition.set_pressed(self.is_dragging);
+                transition.is_animating()
+            }
+            None => false,
+        }
+    }
+
     /// Set the normalized default value of the [`HSlider`].
     pub fn set_default(&mut self, normal: Normal) {
         self.normal_param.default = normal;
@@ -305,6 +912,55 @@ impl State {
     pub fn is_dragging(&self) -> bool {
         self.is_dragging
     }
+
+    /// Does the [`HSlider`] have keyboard focus?
+    ///
+    /// While it has focus, the arrow keys, `Home`, `End`, `PageUp`, and
+    /// `PageDown` change its value. Clicking the [`HSlider`] focuses it, and
+    /// clicking anywhere else, `Tab`, or `Escape` removes the focus.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Gives keyboard focus to the [`HSlider`], such as when the user tabs
+    /// to it.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    /// Removes keyboard focus from the [`HSlider`].
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+
+    /// Forces the [`HSlider`] to regenerate its primitives on the next frame.
+    ///
+    /// This only needs to be called after changing the style of a
+    /// [`HSlider`] that caches its primitives.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn invalidate(&mut self) {
+        self.revision = self.revision.wrapping_add(1);
+    }
+
+    /// Returns the bounds of the [`HSlider`] if its primitives were regenerated
+    /// when it was last drawn, or `None` if its cached primitives were
+    /// reused.
+    ///
+    /// This invalidates the whole [`HSlider`]; it does not narrow down which
+    /// part of it changed. It is always `Some` unless the [`HSlider`] caches
+    /// its primitives.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn invalidated_bounds(&self) -> Option<Rectangle> {
+        self.draw_cache.invalidated_bounds()
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -341,21 +997,42 @@ where
         _clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> event::Status {
+        if let Some(transition) = &mut self.state.transition {
+            transition.set_hovered(layout.bounds().contains(cursor_position));
+        }
+
         match event {
             Event::Mouse(mouse_event) => match mouse_event {
                 mouse::Event::CursorMoved { .. } => {
                     if self.state.is_dragging {
+                        if let Some(drag_start) = self.state.drag_start {
+                            if !self.input_config.drag_threshold_crossed(
+                                drag_start,
+                                cursor_position,
+                            ) {
+                                return event::Status::Captured;
+                            }
+
+                            self.state.drag_start = None;
+                        }
+
                         let bounds_width = layout.bounds().width;
 
                         if bounds_width > 0.0 {
-                            let normal_delta = (cursor_position.x
-                                - self.state.prev_drag_x)
-                                / bounds_width
-                                * -self.scalar;
-
-                            self.state.prev_drag_x = cursor_position.x;
+                            let scalar = if self.jump_to_click {
+                                1.0 / bounds_width
+                            } else {
+                                match self.sensitivity {
+                                    Some(sensitivity) => sensitivity,
+                                    None => self.scalar / bounds_width,
+                                }
+                            };
 
-                            self.move_virtual_slider(messages, normal_delta);
+                            self.drag_virtual_slider(
+                                messages,
+                                cursor_position.x,
+                                scalar,
+                            );
 
                             return event::Status::Captured;
                         }
@@ -366,7 +1043,11 @@ where
                         return event::Status::Ignored;
                     }
 
-                    if layout.bounds().contains(cursor_position) {
+                    if self
+                        .input_config
+                        .hit_bounds(layout.bounds())
+                        .contains(cursor_position)
+                    {
                         let lines = match delta {
                             iced_native::mouse::ScrollDelta::Lines {
                                 y,
@@ -387,45 +1068,136 @@ where
                         };
 
                         if lines != 0.0 {
-                            let normal_delta = -lines * self.wheel_scalar;
-
-                            self.move_virtual_slider(messages, normal_delta);
+                            self.scroll(messages, lines);
 
                             return event::Status::Captured;
                         }
                     }
                 }
-                mouse::Event::ButtonPressed(mouse::Button::Left) => {
-                    if layout.bounds().contains(cursor_position) {
-                        let click = mouse::Click::new(
-                            cursor_position,
-                            self.state.last_click,
-                        );
-
-                        match click.kind() {
-                            mouse::click::Kind::Single => {
+                mouse::Event::ButtonPressed(button) => {
+                    self.state.is_focused = self
+                        .input_config
+                        .hit_bounds(layout.bounds())
+                        .contains(cursor_position);
+
+                    if self
+                        .input_config
+                        .hit_bounds(layout.bounds())
+                        .contains(cursor_position)
+                    {
+                        let click_kind =
+                            if button == self.input_config.drag_button {
+                                let click = mouse::Click::new(
+                                    cursor_position,
+                                    self.state.last_click,
+                                );
+
+                                self.state.last_click = Some(click);
+
+                                click.kind()
+                            } else {
+                                mouse::click::Kind::Single
+                            };
+
+                        match self.input_config.action(
+                            button,
+                            click_kind,
+                            self.state.pressed_modifiers,
+                        ) {
+                            InputAction::Drag => {
+                                if let Some(on_grab) = &self.on_grab {
+                                    messages.push((on_grab)());
+                                }
+
+                                self.state.motor.stop();
                                 self.state.is_dragging = true;
-                                self.state.prev_drag_x = cursor_position.x;
+                                self.state.drag_start = Some(cursor_position);
+                                self.state.drag_anchor = DragAnchor::new(
+                                    cursor_position.x,
+                                    self.state.continuous_normal,
+                                );
+                                self.state.drag_normal =
+                                    self.state.continuous_normal;
+                                self.state.takeover_pending = false;
+
+                                let bounds = layout.bounds();
+
+                                if self.jump_to_click && bounds.width > 0.0 {
+                                    let normal = ((cursor_position.x
+                                        - bounds.x)
+                                        / bounds.width)
+                                        .clamp(0.0, 1.0);
+
+                                    self.state.drag_start = None;
+                                    self.state.drag_anchor = DragAnchor::new(
+                                        cursor_position.x,
+                                        normal,
+                                    );
+
+                                    if self.soft_takeover {
+                                        self.state.drag_normal = normal;
+                                        self.state.takeover_pending = normal
+                                            != self
+                                                .state
+                                                .normal_param
+                                                .value
+                                                .as_f32();
+                                    } else {
+                                        self.set_virtual_slider(
+                                            messages, normal,
+                                        );
+                                    }
+                                }
                             }
-                            _ => {
-                                self.state.is_dragging = false;
+                            InputAction::Reset => {
+                                self.state.motor.stop();
+
+                                if self.state.is_dragging {
+                                    self.state.is_dragging = false;
+                                    self.state.drag_start = None;
+
+                                    if let Some(on_release) = &self.on_release {
+                                        messages.push((on_release)());
+                                    }
+                                }
 
                                 self.state.normal_param.value =
                                     self.state.normal_param.default;
 
-                                messages.push((self.on_change)(
-                                    self.state.normal_param.value,
-                                ));
+                                let value = self.state.normal_param.value;
+
+                                self.state.continuous_normal = value.as_f32();
+                                self.state.drag_anchor.rebase(value.as_f32());
+
+                                messages.push(match &self.on_reset {
+                                    Some(on_reset) => (on_reset)(value),
+                                    None => (self.on_change)(value),
+                                });
+                            }
+                            InputAction::Menu => {
+                                if let Some(on_menu) = &self.on_menu {
+                                    messages.push((on_menu)());
+                                }
+                            }
+                            InputAction::None => {
+                                return event::Status::Ignored;
                             }
                         }
 
-                        self.state.last_click = Some(click);
-
                         return event::Status::Captured;
                     }
                 }
-                mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                mouse::Event::ButtonReleased(button)
+                    if button == self.input_config.drag_button =>
+                {
+                    if self.state.is_dragging {
+                        if let Some(on_release) = &self.on_release {
+                            messages.push((on_release)());
+                        }
+                    }
+
                     self.state.is_dragging = false;
+                    self.state.drag_start = None;
                     self.state.continuous_normal =
                         self.state.normal_param.value.as_f32();
 
@@ -434,9 +1206,16 @@ where
                 _ => {}
             },
             Event::Keyboard(keyboard_event) => match keyboard_event {
-                keyboard::Event::KeyPressed { modifiers, .. } => {
+                keyboard::Event::KeyPressed {
+                    key_code,
+                    modifiers,
+                } => {
                     self.state.pressed_modifiers = modifiers;
 
+                    if self.state.is_focused || self.state.is_dragging {
+                        self.key_pressed(messages, key_code, modifiers);
+                    }
+
                     return event::Status::Captured;
                 }
                 keyboard::Event::KeyReleased { modifiers, .. } => {
@@ -444,8 +1223,14 @@ where
 
                     return event::Status::Captured;
                 }
+                keyboard::Event::ModifiersChanged(modifiers) => {
+                    self.state.pressed_modifiers = modifiers;
+                }
                 _ => {}
             },
+            Event::Window(window::Event::Unfocused) => {
+                self.state.pressed_modifiers = Default::default();
+            }
             _ => {}
         }
 
@@ -460,18 +1245,60 @@ where
         cursor_position: Point,
         _viewport: &Rectangle,
     ) -> Renderer::Output {
+        let warning = match self.warning_threshold {
+            Some(threshold) => {
+                self.state.normal_param.value.as_f32() > threshold.as_f32()
+            }
+            None => false,
+        };
+
+        let revision = if self.cache_primitives {
+            Some(
+                crate::graphics::draw_cache::Revision::new(self.state.revision)
+                    .normal(self.state.normal_param.value)
+                    .normal(self.state.normal_param.default)
+                    .flag(self.state.is_dragging)
+                    .transition(self.state.transition.as_ref())
+                    .flag(self.state.is_focused)
+                    .mod_range(self.mod_range_1)
+                    .mod_range(self.mod_range_2)
+                    .optional_normal(self.modulated_value)
+                    .optional_normal(self.automation_value)
+                    .tick_marks(self.tick_marks)
+                    .text_marks(self.text_marks)
+                    .color(self.accent)
+                    .flag(warning)
+                    .flag(self.center_fill)
+                    .finish(),
+            )
+        } else {
+            None
+        };
+
         renderer.draw(
-            layout.bounds(),
-            cursor_position,
-            self.state.normal_param.value,
-            self.state.is_dragging,
-            self.mod_range_1,
-            self.mod_range_2,
-            self.tick_marks,
-            self.text_marks,
+            &DrawState {
+                bounds: layout.bounds(),
+                cursor_position,
+                normal: self.state.normal_param.value,
+                default_normal: self.state.normal_param.default,
+                is_dragging: self.state.is_dragging,
+                is_focused: self.state.is_focused,
+                transition: self.state.transition.as_ref(),
+                mod_range_1: self.mod_range_1,
+                mod_range_2: self.mod_range_2,
+                modulated_value: self.modulated_value,
+                automation_value: self.automation_value,
+                tick_marks: self.tick_marks,
+                text_marks: self.text_marks,
+                accent: self.accent,
+                warning,
+                center_fill: self.center_fill,
+                tick_marks_cache: &self.state.tick_marks_cache,
+                text_marks_cache: &self.state.text_marks_cache,
+                draw_cache: &self.state.draw_cache,
+                revision,
+            },
             &self.style,
-            &self.state.tick_marks_cache,
-            &self.state.text_marks_cache,
         )
     }
 
@@ -484,6 +1311,54 @@ where
     }
 }
 
+/// The values an [`HSlider`] is drawn with in a frame.
+///
+/// [`HSlider`]: struct.HSlider.html
+#[derive(Debug, Clone, Copy)]
+pub struct DrawState<'a> {
+    /// The bounds of the slider
+    pub bounds: Rectangle,
+    /// The current cursor position
+    pub cursor_position: Point,
+    /// The current normal of the slider
+    pub normal: Normal,
+    /// The default normal of the slider
+    pub default_normal: Normal,
+    /// Whether the slider is currently being dragged
+    pub is_dragging: bool,
+    /// Whether the slider has keyboard focus
+    pub is_focused: bool,
+    /// The hover and press transition of the slider, if it is animated
+    pub transition: Option<&'a Transition>,
+    /// The first modulation range to display, if any
+    pub mod_range_1: Option<&'a ModulationRange>,
+    /// The second modulation range to display, if any
+    pub mod_range_2: Option<&'a ModulationRange>,
+    /// The modulated value to display, if any
+    pub modulated_value: Option<Normal>,
+    /// The automation value to display, if any
+    pub automation_value: Option<Normal>,
+    /// Any tick marks to display
+    pub tick_marks: Option<&'a tick_marks::Group>,
+    /// Any text marks to display
+    pub text_marks: Option<&'a text_marks::Group>,
+    /// The accent color of the slider, if any
+    pub accent: Option<Color>,
+    /// Whether the value is above the warning threshold of the slider
+    pub warning: bool,
+    /// Whether a `Rect` style fills from the center out to the
+    /// handle
+    pub center_fill: bool,
+    /// The cache for the primitives of the tick marks
+    pub tick_marks_cache: &'a crate::tick_marks::PrimitiveCache,
+    /// The cache for the primitives of the text marks
+    pub text_marks_cache: &'a crate::text_marks::PrimitiveCache,
+    /// The cache for the primitives of the slider
+    pub draw_cache: &'a crate::graphics::DrawCache,
+    /// The revision of the slider, or `None` if caching is disabled
+    pub revision: Option<u64>,
+}
+
 /// The renderer of an [`HSlider`].
 ///
 /// Your renderer will need to implement this trait before being
@@ -494,32 +1369,14 @@ pub trait Renderer: iced_native::Renderer {
     /// The style supported by this renderer.
     type Style: Default;
 
-    /// Draws an [`HSlider`].
-    ///
-    /// It receives:
-    ///   * the bounds of the [`HSlider`]
-    ///   * the current cursor position
-    ///   * the current normal of the [`HSlider`]
-    ///   * the height of the handle in pixels
-    ///   * whether the slider is currently being dragged
-    ///   * any tick marks to display
-    ///   * any text marks to display
-    ///   * the style of the [`HSlider`]
+    /// Draws an [`HSlider`] from its [`DrawState`] and its style.
     ///
     /// [`HSlider`]: struct.HSlider.html
+    /// [`DrawState`]: struct.DrawState.html
     fn draw(
         &mut self,
-        bounds: Rectangle,
-        cursor_position: Point,
-        normal: Normal,
-        is_dragging: bool,
-        mod_range_1: Option<&ModulationRange>,
-        mod_range_2: Option<&ModulationRange>,
-        tick_marks: Option<&tick_marks::Group>,
-        text_marks: Option<&text_marks::Group>,
+        state: &DrawState<'_>,
         style: &Self::Style,
-        tick_marks_cache: &crate::tick_marks::PrimitiveCache,
-        text_marks_cache: &crate::text_marks::PrimitiveCache,
     ) -> Self::Output;
 }
 
@@ -535,3 +1392,163 @@ where
         Element::new(h_slider)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced_native::{clipboard, renderer};
+
+    impl Renderer for renderer::Null {
+        type Style = ();
+
+        fn draw(&mut self, _state: &DrawState<'_>, _style: &Self::Style) {}
+    }
+
+    /// Records the modulation ranges that reach the renderer.
+    #[derive(Debug, Default)]
+    struct ModRangeRecorder {
+        mod_ranges: Vec<Option<(f32, f32)>>,
+    }
+
+    impl iced_native::Renderer for ModRangeRecorder {
+        type Output = ();
+        type Defaults = ();
+
+        fn overlay(&mut self, _base: (), _overlay: (), _bounds: Rectangle) {}
+    }
+
+    impl Renderer for ModRangeRecorder {
+        type Style = ();
+
+        fn draw(&mut self, state: &DrawState<'_>, _style: &Self::Style) {
+            self.mod_ranges = [state.mod_range_1, state.mod_range_2]
+                .iter()
+                .map(|r| r.map(|r| (r.start.as_f32(), r.end.as_f32())))
+                .collect();
+        }
+    }
+
+    #[test]
+    fn draws_both_mod_ranges() {
+        let mut state = State::new(NormalParam::default());
+        let mod_range_1 = ModulationRange::new(0.2.into(), 0.4.into());
+        let mod_range_2 = ModulationRange::new(0.6.into(), 0.8.into());
+
+        let h_slider = HSlider::<_, ModRangeRecorder>::new(&mut state, |_| ())
+            .mod_range(&mod_range_1)
+            .mod_range_2(&mod_range_2);
+
+        let mut renderer = ModRangeRecorder::default();
+        let node = layout::Node::new(Size::new(100.0, 100.0));
+        Widget::<(), _>::draw(
+            &h_slider,
+            &mut renderer,
+            &(),
+            Layout::new(&node),
+            Point::ORIGIN,
+            &node.bounds(),
+        );
+
+        assert_eq!(
+            renderer.mod_ranges,
+            vec![Some((0.2, 0.4)), Some((0.6, 0.8))]
+        );
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Message {
+        Change(f32),
+        Grab,
+        Release,
+    }
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 10.0,
+        y: 20.0,
+        width: 100.0,
+        height: 14.0,
+    };
+
+    fn h_slider(state: &mut State) -> HSlider<'_, Message, renderer::Null> {
+        HSlider::new(state, |normal| Message::Change(normal.as_f32()))
+            .input_config(InputConfig {
+                drag_threshold: 0.0,
+                ..InputConfig::default()
+            })
+            .reset_gesture(ResetGesture::Button(mouse::Button::Middle))
+            .on_grab(|| Message::Grab)
+            .on_release(|| Message::Release)
+    }
+
+    fn send(state: &mut State, event: mouse::Event) -> Vec<Message> {
+        let mut node = layout::Node::new(BOUNDS.size());
+        node.move_to(BOUNDS.position());
+
+        let mut messages = Vec::new();
+        let _ = h_slider(state).on_event(
+            Event::Mouse(event),
+            Layout::new(&node),
+            BOUNDS.center(),
+            &renderer::Null,
+            &mut clipboard::Null,
+            &mut messages,
+        );
+
+        messages
+    }
+
+    fn scroll_up(state: &mut State) -> Vec<Message> {
+        send(
+            state,
+            mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Lines { x: 0.0, y: 1.0 },
+            },
+        )
+    }
+
+    fn assert_changed_to(messages: &[Message], expected: f32) {
+        match messages {
+            [Message::Change(value)] => {
+                assert!((value - expected).abs() < 0.0001, "{}", value)
+            }
+            _ => panic!("unexpected messages {:?}", messages),
+        }
+    }
+
+    #[test]
+    fn scroll_after_reset_starts_from_default() {
+        let mut state = State::new(NormalParam {
+            value: 0.8.into(),
+            default: 0.2.into(),
+        });
+
+        let messages = send(
+            &mut state,
+            mouse::Event::ButtonPressed(mouse::Button::Middle),
+        );
+        assert_eq!(messages, vec![Message::Change(0.2)]);
+
+        assert_changed_to(&scroll_up(&mut state), 0.21);
+    }
+
+    #[test]
+    fn reset_while_dragging_releases() {
+        let mut state = State::new(NormalParam {
+            value: 0.8.into(),
+            default: 0.2.into(),
+        });
+
+        let messages =
+            send(&mut state, mouse::Event::ButtonPressed(mouse::Button::Left));
+        assert_eq!(messages, vec![Message::Grab]);
+
+        let messages = send(
+            &mut state,
+            mouse::Event::ButtonPressed(mouse::Button::Middle),
+        );
+        assert_eq!(messages, vec![Message::Release, Message::Change(0.2)]);
+        assert!(!state.is_dragging());
+
+        assert_changed_to(&scroll_up(&mut state), 0.21);
+    }
+}
@@ -5,13 +5,19 @@
 use std::fmt::Debug;
 
 use iced_native::{
-    event, keyboard, layout, mouse, Clipboard, Element, Event, Hasher, Layout,
-    Length, Point, Rectangle, Size, Widget,
+    event, keyboard, layout, mouse, window, Clipboard, Color, Element, Event,
+    Hasher, Layout, Length, Point, Rectangle, Size, Widget,
 };
 
 use std::hash::Hash;
+use std::time::{Duration, Instant};
 
-use crate::core::{ModulationRange, Normal, NormalParam};
+use crate::core::detents;
+use crate::core::input_config::{DragAnchor, InputAction};
+use crate::core::{
+    Detents, InputConfig, ModulationRange, Motor, Normal, NormalParam,
+    ResetGesture, Transition,
+};
 use crate::native::{text_marks, tick_marks};
 use crate::IntRange;
 
@@ -19,6 +25,35 @@ static DEFAULT_SIZE: u16 = 30;
 static DEFAULT_SCALAR: f32 = 0.00385;
 static DEFAULT_WHEEL_SCALAR: f32 = 0.01;
 static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+static DEFAULT_PAGE_STEP: f32 = 0.1;
+static DEFAULT_MOD_RING_WIDTH: f32 = 6.0;
+
+/// The mouse movement that turns a [`Knob`].
+///
+/// [`Knob`]: struct.Knob.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum DragDirection {
+    /// Moving the mouse up turns the knob up.
+    #[default]
+    Vertical,
+    /// Moving the mouse right turns the knob up.
+    Horizontal,
+    /// Moving the mouse up or right turns the knob up. Movement along both
+    /// axes is summed.
+    Both,
+}
+
+impl DragDirection {
+    /// Returns the position of the cursor along the drag, increasing in
+    /// the direction that turns the knob up.
+    fn position(self, cursor_position: Point) -> f32 {
+        match self {
+            DragDirection::Vertical => -cursor_position.y,
+            DragDirection::Horizontal => cursor_position.x,
+            DragDirection::Both => cursor_position.x - cursor_position.y,
+        }
+    }
+}
 
 /// A rotating knob GUI widget that controls a [`NormalParam`]
 ///
@@ -29,14 +64,34 @@ pub struct Knob<'a, Message, Renderer: self::Renderer> {
     size: Length,
     on_change: Box<dyn Fn(Normal) -> Message>,
     scalar: f32,
+    drag_direction: DragDirection,
+    wrap: bool,
     wheel_scalar: f32,
+    page_step: f32,
+    detents: Option<Detents>,
     modifier_scalar: f32,
     modifier_keys: keyboard::Modifiers,
+    input_config: InputConfig,
+    on_menu: Option<Box<dyn Fn() -> Message>>,
+    on_reset: Option<Box<dyn Fn(Normal) -> Message>>,
+    on_tab: Option<Box<dyn Fn(bool) -> Message>>,
+    on_grab: Option<Box<dyn Fn() -> Message>>,
+    on_release: Option<Box<dyn Fn() -> Message>>,
+    cache_primitives: bool,
+    accent: Option<Color>,
+    warning_threshold: Option<Normal>,
     style: Renderer::Style,
     tick_marks: Option<&'a tick_marks::Group>,
+    snap_to_tick_marks: bool,
+    soft_detent: Option<(Normal, f32)>,
+    soft_takeover: bool,
     text_marks: Option<&'a text_marks::Group>,
     mod_range_1: Option<&'a ModulationRange>,
     mod_range_2: Option<&'a ModulationRange>,
+    modulated_value: Option<Normal>,
+    automation_value: Option<Normal>,
+    on_mod_depth: Option<Box<dyn Fn(Normal) -> Message>>,
+    mod_ring_width: Option<f32>,
 }
 
 impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
@@ -57,17 +112,37 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
             size: Length::from(Length::Units(DEFAULT_SIZE)),
             on_change: Box::new(on_change),
             scalar: DEFAULT_SCALAR,
+            drag_direction: DragDirection::default(),
+            wrap: false,
             wheel_scalar: DEFAULT_WHEEL_SCALAR,
+            page_step: DEFAULT_PAGE_STEP,
+            detents: None,
             modifier_scalar: DEFAULT_MODIFIER_SCALAR,
             modifier_keys: keyboard::Modifiers {
                 control: true,
                 ..Default::default()
             },
+            input_config: InputConfig::global(),
+            on_menu: None,
+            on_reset: None,
+            on_tab: None,
+            on_grab: None,
+            on_release: None,
+            cache_primitives: false,
+            accent: None,
+            warning_threshold: None,
             style: Renderer::Style::default(),
             tick_marks: None,
+            snap_to_tick_marks: false,
+            soft_detent: None,
+            soft_takeover: false,
             text_marks: None,
             mod_range_1: None,
             mod_range_2: None,
+            modulated_value: None,
+            automation_value: None,
+            on_mod_depth: None,
+            mod_ring_width: None,
         }
     }
 
@@ -88,18 +163,44 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
         self
     }
 
-    /// Sets how much the [`Normal`] value will change for the [`Knob`] per `y`
-    /// pixel movement of the mouse.
+    /// Sets how much the [`Normal`] value will change for the [`Knob`] per
+    /// pixel movement of the mouse along its [`DragDirection`].
     ///
     /// The default value is `0.00385`
     ///
     /// [`Knob`]: struct.Knob.html
     /// [`Normal`]: ../../core/struct.Normal.html
+    /// [`DragDirection`]: enum.DragDirection.html
     pub fn scalar(mut self, scalar: f32) -> Self {
         self.scalar = scalar;
         self
     }
 
+    /// Sets the mouse movement that turns the [`Knob`].
+    ///
+    /// The default is [`DragDirection::Vertical`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`DragDirection::Vertical`]: enum.DragDirection.html#variant.Vertical
+    pub fn drag_direction(mut self, drag_direction: DragDirection) -> Self {
+        self.drag_direction = drag_direction;
+        self
+    }
+
+    /// Sets whether the [`Knob`] wraps around, so turning it past its
+    /// maximum continues from its minimum and the other way around. This
+    /// suits cyclic parameters such as a phase, combined with a style whose
+    /// angle range is a [`KnobAngleRange::full_circle`].
+    ///
+    /// The default is `false`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`KnobAngleRange::full_circle`]: ../../core/knob_angle_range/struct.KnobAngleRange.html#method.full_circle
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
     /// Sets how much the [`Normal`] value will change for the [`Knob`] per line scrolled
     /// by the mouse wheel.
     ///
@@ -114,6 +215,39 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
         self
     }
 
+    /// Sets how much the [`Normal`] value of the [`Knob`] changes when
+    /// `PageUp` or `PageDown` is pressed while it has keyboard focus or is
+    /// being dragged.
+    ///
+    /// With detents, this is rounded to a whole number of steps, and is at
+    /// least one step.
+    ///
+    /// The default is `0.1`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn page_step(mut self, page_step: f32) -> Self {
+        self.page_step = page_step;
+        self
+    }
+
+    /// Sets the [`Detents`] of the [`Knob`] for a discrete parameter, such as
+    /// one mapped by an [`IntRange`].
+    ///
+    /// With detents, each line scrolled by the mouse wheel moves exactly one
+    /// step, and dragging only changes the value once the next detent is
+    /// reached.
+    ///
+    /// The default is no detents.
+    ///
+    /// [`Detents`]: ../../core/detents/struct.Detents.html
+    /// [`Knob`]: struct.Knob.html
+    /// [`IntRange`]: ../../core/range/struct.IntRange.html
+    pub fn detents(mut self, detents: impl Into<Detents>) -> Self {
+        self.detents = Some(detents.into());
+        self
+    }
+
     /// Sets the modifier keys of the [`Knob`].
     ///
     /// The default modifier key is `Ctrl`.
@@ -124,6 +258,151 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the mouse buttons that control the [`Knob`].
+    ///
+    /// The default is the global [`InputConfig`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`InputConfig`]: ../../core/input_config/struct.InputConfig.html
+    pub fn input_config(mut self, input_config: InputConfig) -> Self {
+        self.input_config = input_config;
+        self
+    }
+
+    /// Sets the message to send when the menu button of the [`InputConfig`]
+    /// is pressed over the [`Knob`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`InputConfig`]: ../../core/input_config/struct.InputConfig.html
+    pub fn on_menu<F>(mut self, on_menu: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_menu = Some(Box::new(on_menu));
+        self
+    }
+
+    /// Sets the gesture that resets the [`Knob`] to its default value.
+    ///
+    /// The default is the gesture of the [`InputConfig`]. Set this after
+    /// [`input_config`], which replaces it.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`InputConfig`]: ../../core/input_config/struct.InputConfig.html
+    /// [`input_config`]: #method.input_config
+    pub fn reset_gesture(mut self, reset_gesture: ResetGesture) -> Self {
+        self.input_config.reset = reset_gesture;
+        self
+    }
+
+    /// Sets the message to send with the default value when the
+    /// [`Knob`] is reset, instead of the message of `on_change`.
+    ///
+    /// This lets the application tell a reset apart from a plain change,
+    /// such as to record it as its own undo step.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn on_reset<F>(mut self, on_reset: F) -> Self
+    where
+        F: 'static + Fn(Normal) -> Message,
+    {
+        self.on_reset = Some(Box::new(on_reset));
+        self
+    }
+
+    /// Sets the message to send when `Tab` is pressed while the [`Knob`]
+    /// has keyboard focus, with `true` if `Shift` is held.
+    ///
+    /// The [`Knob`] loses focus, so the application can give it to the
+    /// next (or previous) widget with [`State::focus`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`State::focus`]: struct.State.html#method.focus
+    pub fn on_tab<F>(mut self, on_tab: F) -> Self
+    where
+        F: 'static + Fn(bool) -> Message,
+    {
+        self.on_tab = Some(Box::new(on_tab));
+        self
+    }
+
+    /// Sets the message to send when the user grabs the [`Knob`] to drag
+    /// it, before any change is sent.
+    ///
+    /// Together with [`on_release`], this brackets a gesture, such as to
+    /// tell a host that an automation edit begins and ends, or to group the
+    /// changes of a drag into one undo step.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`on_release`]: #method.on_release
+    pub fn on_grab<F>(mut self, on_grab: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_grab = Some(Box::new(on_grab));
+        self
+    }
+
+    /// Sets the message to send when the user releases the [`Knob`] after
+    /// grabbing it.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn on_release<F>(mut self, on_release: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_release = Some(Box::new(on_release));
+        self
+    }
+
+    /// Sets whether the [`Knob`] reuses its primitives from the previous
+    /// frame when nothing that affects its appearance has changed.
+    ///
+    /// Changes to the value, interaction state, size, and any marks or
+    /// modulation ranges are detected automatically. Call
+    /// [`State::invalidate`] after changing the style.
+    ///
+    /// The default is `false`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`State::invalidate`]: struct.State.html#method.invalidate
+    pub fn cache_primitives(mut self, cache_primitives: bool) -> Self {
+        self.cache_primitives = cache_primitives;
+        self
+    }
+
+    /// Sets the accent color of the [`Knob`]. This replaces the colors of
+    /// the parts of its style that show the value, such as the filled
+    /// portion.
+    ///
+    /// Combined with an [`AccentMap`], this gives whole groups of parameters
+    /// their own color, for example `.accent(accents.get("Filter"))`.
+    ///
+    /// The default is no accent color.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`AccentMap`]: ../../core/accent_map/struct.AccentMap.html
+    pub fn accent(mut self, accent: impl Into<Option<Color>>) -> Self {
+        self.accent = accent.into();
+        self
+    }
+
+    /// Sets a threshold above which the [`Knob`] is drawn in the warning
+    /// color of its [`StyleSheet`] instead of its accent color, for example
+    /// to flag a gain above 0 dB that may clip.
+    ///
+    /// The default is no threshold.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`StyleSheet`]: ../../style/knob/trait.StyleSheet.html
+    pub fn warning_threshold(
+        mut self,
+        threshold: impl Into<Option<Normal>>,
+    ) -> Self {
+        self.warning_threshold = threshold.into();
+        self
+    }
+
     /// Sets the scalar to use when the user drags the knobs while holding down
     /// the modifier key. This is multiplied to the value set by
     /// `Knob::scalar()` (which the default is `0.00385`).
@@ -150,6 +429,69 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
         self
     }
 
+    /// Sets whether dragging the [`Knob`] snaps its value to the nearest
+    /// of its tick marks. Holding down the modifier keys bypasses the
+    /// snapping, along with making the adjustment finer.
+    ///
+    /// This has no effect without tick marks, or with [`detents`].
+    ///
+    /// The default is `false`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`detents`]: #method.detents
+    pub fn snap_to_tick_marks(mut self, snap_to_tick_marks: bool) -> Self {
+        self.snap_to_tick_marks = snap_to_tick_marks;
+        self
+    }
+
+    /// Sets how far (in normalized units) on either side of the center
+    /// the value sticks to the center while the [`Knob`] is dragged, such
+    /// as for a pan or balance control.
+    ///
+    /// This is the same as a [`soft_detent`] at the center.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`soft_detent`]: #method.soft_detent
+    pub fn center_snap(self, center_snap: f32) -> Self {
+        self.soft_detent(Normal::center(), center_snap)
+    }
+
+    /// Sets a value that the [`Knob`] sticks to while it is dragged within
+    /// `width` (in normalized units) on either side of it, such as the mono
+    /// position of a stereo width control. Holding down the modifier keys
+    /// bypasses the snapping, along with making the adjustment finer.
+    ///
+    /// This has no effect with [`detents`] or [`snap_to_tick_marks`].
+    ///
+    /// The default is no soft detent. A `width` of `0.0` removes it.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`detents`]: #method.detents
+    /// [`snap_to_tick_marks`]: #method.snap_to_tick_marks
+    pub fn soft_detent(mut self, normal: Normal, width: f32) -> Self {
+        self.soft_detent = if width > 0.0 {
+            Some((normal, width))
+        } else {
+            None
+        };
+        self
+    }
+
+    /// Sets whether the [`Knob`] uses soft takeover.
+    ///
+    /// With soft takeover, the value does not jump when it differs from
+    /// the drag, such as when it was changed by MIDI or automation while the
+    /// [`Knob`] is being dragged. The value stays put until the drag
+    /// reaches (catches) it, and then follows the drag.
+    ///
+    /// The default is `false`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn soft_takeover(mut self, soft_takeover: bool) -> Self {
+        self.soft_takeover = soft_takeover;
+        self
+    }
+
     /// Sets the text marks to display. Note your [`StyleSheet`] must
     /// also implement `text_marks_style(&self) -> Option<text_marks::Style>` for
     /// them to display (which the default style does).
@@ -178,10 +520,185 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
     /// [`ModulationRange`]: ../../core/struct.ModulationRange.html
     /// [`StyleSheet`]: ../../style/v_slider/trait.StyleSheet.html
     pub fn mod_range_2(mut self, mod_range: &'a ModulationRange) -> Self {
-        self.mod_range_1 = Some(mod_range);
+        self.mod_range_2 = Some(mod_range);
+        self
+    }
+
+    /// Sets the current modulated value to display as a thin ghost notch, while
+    /// the handle still shows the value set by the user. Note your
+    /// [`StyleSheet`] must also implement
+    /// `modulated_value_style(&self) -> Option<NotchShape>` for it to
+    /// display.
+    ///
+    /// [`StyleSheet`]: ../../style/knob/trait.StyleSheet.html
+    pub fn modulated_value(mut self, normal: Normal) -> Self {
+        self.modulated_value = Some(normal);
+        self
+    }
+
+    /// Sets the value that automation is playing back to display as a
+    /// dimmed ghost notch, apart from the value edited by the user. Note your
+    /// [`StyleSheet`] must also implement
+    /// `automation_value_style(&self) -> Option<NotchShape>` for it to
+    /// display.
+    ///
+    /// [`StyleSheet`]: ../../style/knob/trait.StyleSheet.html
+    pub fn automation_value(mut self, normal: Normal) -> Self {
+        self.automation_value = Some(normal);
         self
     }
 
+    /// Lets the user drag the ring around the [`Knob`], where its
+    /// [`ModulationRange`] is drawn, to change the modulation depth.
+    /// `on_mod_depth` is called with the new depth while the ring is
+    /// dragged.
+    ///
+    /// The depth is a [`Normal`] in the same form as the value of a
+    /// [`ModRangeInput`], and is kept in the [`State`] of the [`Knob`]
+    /// (see [`State::set_mod_depth`]).
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`ModulationRange`]: ../../core/struct.ModulationRange.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    /// [`ModRangeInput`]: ../mod_range_input/struct.ModRangeInput.html
+    /// [`State`]: struct.State.html
+    /// [`State::set_mod_depth`]: struct.State.html#method.set_mod_depth
+    pub fn on_mod_depth<F>(mut self, on_mod_depth: F) -> Self
+    where
+        F: 'static + Fn(Normal) -> Message,
+    {
+        self.on_mod_depth = Some(Box::new(on_mod_depth));
+        self
+    }
+
+    /// Sets the width of the ring outside the [`Knob`] that can be dragged
+    /// to change the modulation depth (see [`on_mod_depth`]).
+    ///
+    /// The ring starts at the edge of the face of the [`Knob`], and its
+    /// outer edge is scaled by the `hit_target_scale` of the
+    /// [`InputConfig`]. The default is the width the renderer reports for
+    /// the modulation range arcs of the style, or `6.0` if it draws none.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`on_mod_depth`]: #method.on_mod_depth
+    /// [`InputConfig`]: ../../core/input_config/struct.InputConfig.html
+    pub fn mod_ring_width(mut self, mod_ring_width: f32) -> Self {
+        self.mod_ring_width = Some(mod_ring_width.max(0.0));
+        self
+    }
+
+    /// Returns `true` if a press at `cursor_position` grabs the modulation
+    /// ring around a [`Knob`] with the given `bounds`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    fn is_over_mod_ring(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        cursor_position: Point,
+    ) -> bool {
+        let width = self
+            .mod_ring_width
+            .or_else(|| renderer.mod_ring_width(&self.style))
+            .unwrap_or(DEFAULT_MOD_RING_WIDTH);
+
+        if width <= 0.0 {
+            return false;
+        }
+
+        let radius = bounds.width.min(bounds.height) / 2.0;
+        let distance = cursor_position.distance(bounds.center());
+
+        distance > radius
+            && distance <= self.input_config.hit_radius(radius + width)
+    }
+
+    /// Handles dragging the modulation ring. Returns `None` if the event
+    /// is not part of a drag of the ring.
+    fn on_mod_ring_event(
+        &mut self,
+        renderer: &Renderer,
+        event: &Event,
+        bounds: Rectangle,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) -> Option<event::Status> {
+        let on_mod_depth = self.on_mod_depth.as_ref()?;
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(button))
+                if *button == self.input_config.drag_button =>
+            {
+                if !self.is_over_mod_ring(renderer, bounds, cursor_position) {
+                    return None;
+                }
+
+                if let Some(on_grab) = &self.on_grab {
+                    messages.push((on_grab)());
+                }
+
+                self.state.is_dragging_mod_ring = true;
+                self.state.drag_start = Some(cursor_position);
+                self.state.drag_anchor = DragAnchor::new(
+                    self.drag_direction.position(cursor_position),
+                    self.state.mod_depth.as_f32(),
+                );
+
+                Some(event::Status::Captured)
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if !self.state.is_dragging_mod_ring {
+                    return None;
+                }
+
+                if let Some(drag_start) = self.state.drag_start {
+                    if !self
+                        .input_config
+                        .drag_threshold_crossed(drag_start, cursor_position)
+                    {
+                        return Some(event::Status::Captured);
+                    }
+
+                    self.state.drag_start = None;
+                }
+
+                let mut scalar = self.scalar;
+                if self.state.pressed_modifiers.matches(self.modifier_keys) {
+                    scalar *= self.modifier_scalar;
+                }
+
+                let depth = Normal::from(self.state.drag_anchor.drag(
+                    self.drag_direction.position(cursor_position),
+                    scalar,
+                ));
+
+                if depth != self.state.mod_depth {
+                    self.state.mod_depth = depth;
+                    messages.push((on_mod_depth)(depth));
+                }
+
+                Some(event::Status::Captured)
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(button))
+                if *button == self.input_config.drag_button =>
+            {
+                if !self.state.is_dragging_mod_ring {
+                    return None;
+                }
+
+                self.state.is_dragging_mod_ring = false;
+                self.state.drag_start = None;
+
+                if let Some(on_release) = &self.on_release {
+                    messages.push((on_release)());
+                }
+
+                Some(event::Status::Captured)
+            }
+            _ => None,
+        }
+    }
+
     fn move_virtual_slider(
         &mut self,
         messages: &mut Vec<Message>,
@@ -193,17 +710,212 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
 
         let mut normal = self.state.continuous_normal - normal_delta;
 
-        if normal < 0.0 {
+        if self.wrap {
+            normal = wrap_normal(normal);
+        } else if normal < 0.0 {
             normal = 0.0;
         } else if normal > 1.0 {
             normal = 1.0;
         }
 
+        self.state.drag_anchor.rebase(normal);
+
+        self.set_virtual_slider(messages, normal);
+    }
+
+    fn drag_virtual_slider(
+        &mut self,
+        messages: &mut Vec<Message>,
+        position: f32,
+        mut scalar: f32,
+    ) {
+        if self.state.pressed_modifiers.matches(self.modifier_keys) {
+            scalar *= self.modifier_scalar;
+        }
+
+        let normal = if self.wrap {
+            wrap_normal(self.state.drag_anchor.drag_unbounded(position, scalar))
+        } else {
+            self.state.drag_anchor.drag(position, scalar)
+        };
+
+        if !self.caught_up(normal) {
+            return;
+        }
+
+        self.set_virtual_slider(messages, normal);
+    }
+
+    fn set_virtual_slider(&mut self, messages: &mut Vec<Message>, normal: f32) {
         self.state.continuous_normal = normal;
 
-        self.state.normal_param.value = normal.into();
+        if let Some(detents) = self.detents {
+            let value = detents.drag(self.state.normal_param.value, normal);
+
+            if value != self.state.normal_param.value {
+                self.state.normal_param.value = value;
+
+                messages.push((self.on_change)(value));
+            }
+        } else if let Some(snapped) = self.snapped_to_tick_marks(normal) {
+            if snapped != self.state.normal_param.value {
+                self.state.normal_param.value = snapped;
+
+                messages.push((self.on_change)(snapped));
+            }
+        } else if let Some(snapped) = self.snapped_to_soft_detent(normal) {
+            if snapped != self.state.normal_param.value {
+                self.state.normal_param.value = snapped;
+
+                messages.push((self.on_change)(snapped));
+            }
+        } else {
+            self.state.normal_param.value = normal.into();
+
+            messages.push((self.on_change)(self.state.normal_param.value));
+        }
+    }
+
+    /// Returns `false` while soft takeover is waiting for the drag at
+    /// `normal` to reach the current value.
+    fn caught_up(&mut self, normal: f32) -> bool {
+        let last = self.state.drag_normal;
+        self.state.drag_normal = normal;
+
+        if !self.soft_takeover || !self.state.takeover_pending {
+            return true;
+        }
+
+        let value = self.state.normal_param.value.as_f32();
+
+        if (value - last) * (value - normal) > 0.0 {
+            return false;
+        }
+
+        self.state.takeover_pending = false;
 
-        messages.push((self.on_change)(self.state.normal_param.value));
+        true
+    }
+
+    fn snapped_to_tick_marks(&self, normal: f32) -> Option<Normal> {
+        if !self.snap_to_tick_marks
+            || !self.state.is_dragging
+            || self.state.pressed_modifiers.matches(self.modifier_keys)
+        {
+            return None;
+        }
+
+        self.tick_marks?.nearest(normal.into())
+    }
+
+    fn snapped_to_soft_detent(&self, normal: f32) -> Option<Normal> {
+        let (detent, width) = self.soft_detent?;
+
+        if !self.state.is_dragging
+            || self.state.pressed_modifiers.matches(self.modifier_keys)
+            || (normal - detent.as_f32()).abs() > width
+        {
+            return None;
+        }
+
+        Some(detent)
+    }
+
+    fn scroll(&mut self, messages: &mut Vec<Message>, lines: f32) {
+        self.state.motor.stop();
+
+        if let Some(detents) = self.detents {
+            let value = detents.step(
+                self.state.normal_param.value,
+                detents::wheel_steps(lines),
+            );
+
+            self.state.continuous_normal = value.as_f32();
+
+            if value != self.state.normal_param.value {
+                self.state.normal_param.value = value;
+
+                messages.push((self.on_change)(value));
+            }
+        } else {
+            self.move_virtual_slider(messages, -lines * self.wheel_scalar);
+        }
+    }
+
+    fn key_pressed(
+        &mut self,
+        messages: &mut Vec<Message>,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::Modifiers,
+    ) {
+        match key_code {
+            keyboard::KeyCode::Up | keyboard::KeyCode::Right => {
+                self.scroll(messages, 1.0);
+            }
+            keyboard::KeyCode::Down | keyboard::KeyCode::Left => {
+                self.scroll(messages, -1.0);
+            }
+            keyboard::KeyCode::Home => self.jump_to(messages, Normal::min()),
+            keyboard::KeyCode::End => self.jump_to(messages, Normal::max()),
+            keyboard::KeyCode::PageUp => self.page(messages, 1),
+            keyboard::KeyCode::PageDown => self.page(messages, -1),
+            keyboard::KeyCode::Tab => {
+                self.state.is_focused = false;
+
+                if let Some(on_tab) = &self.on_tab {
+                    messages.push((on_tab)(modifiers.shift));
+                }
+            }
+            keyboard::KeyCode::Escape => {
+                self.state.is_focused = false;
+            }
+            _ => {}
+        }
+    }
+
+    fn page(&mut self, messages: &mut Vec<Message>, direction: i32) {
+        let value = match self.detents {
+            Some(detents) => detents.step(
+                self.state.normal_param.value,
+                detents::page_steps(detents, self.page_step) * direction,
+            ),
+            None => Normal::from(
+                self.state.normal_param.value.as_f32()
+                    + direction as f32 * self.page_step,
+            ),
+        };
+
+        self.jump_to(messages, value);
+    }
+
+    fn jump_to(&mut self, messages: &mut Vec<Message>, value: Normal) {
+        self.state.motor.stop();
+
+        let value = match self.detents {
+            Some(detents) => detents.snapped(value),
+            None => value,
+        };
+
+        self.state.continuous_normal = value.as_f32();
+        self.state.drag_anchor.rebase(value.as_f32());
+
+        if value != self.state.normal_param.value {
+            self.state.normal_param.value = value;
+
+            messages.push((self.on_change)(value));
+        }
+    }
+}
+
+/// Wraps `normal` into the range `[0.0, 1.0)`.
+fn wrap_normal(normal: f32) -> f32 {
+    let normal = normal.rem_euclid(1.0);
+
+    // `rem_euclid` can round up to `1.0` for tiny negative values.
+    if normal >= 1.0 {
+        0.0
+    } else {
+        normal
     }
 }
 
@@ -217,12 +929,22 @@ pub struct State {
     /// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
     pub normal_param: NormalParam,
     is_dragging: bool,
-    prev_drag_y: f32,
+    is_focused: bool,
+    drag_start: Option<Point>,
+    drag_anchor: DragAnchor,
     continuous_normal: f32,
+    drag_normal: f32,
+    takeover_pending: bool,
     pressed_modifiers: keyboard::Modifiers,
     last_click: Option<mouse::Click>,
     tick_marks_cache: crate::graphics::tick_marks::PrimitiveCache,
     text_marks_cache: crate::graphics::text_marks::PrimitiveCache,
+    motor: Motor,
+    transition: Option<Transition>,
+    mod_depth: Normal,
+    is_dragging_mod_ring: bool,
+    revision: u64,
+    draw_cache: crate::graphics::DrawCache,
 }
 
 impl State {
@@ -237,17 +959,31 @@ impl State {
         Self {
             normal_param,
             is_dragging: false,
-            prev_drag_y: 0.0,
+            is_focused: false,
+            drag_start: None,
+            drag_anchor: DragAnchor::default(),
             continuous_normal: normal_param.value.as_f32(),
+            drag_normal: normal_param.value.as_f32(),
+            takeover_pending: false,
             pressed_modifiers: Default::default(),
             last_click: None,
             tick_marks_cache: Default::default(),
             text_marks_cache: Default::default(),
+            motor: Motor::default(),
+            transition: None,
+            mod_depth: Normal::center(),
+            is_dragging_mod_ring: false,
+            revision: 0,
+            draw_cache: Default::default(),
         }
     }
 
     /// Set the normalized value of the [`Knob`].
     pub fn set_normal(&mut self, normal: Normal) {
+        if self.is_dragging && normal != self.normal_param.value {
+            self.takeover_pending = true;
+        }
+
         self.normal_param.value = normal;
         self.continuous_normal = normal.into();
     }
@@ -257,6 +993,106 @@ impl State {
         self.normal_param.value
     }
 
+    /// Glides the [`Knob`] toward `normal` like a motorized fader, instead
+    /// of jumping there like [`set_normal`]. Call [`run_motor`]
+    /// periodically (for example from a timer subscription) to move it.
+    ///
+    /// This is ignored while the [`Knob`] is being dragged, and grabbing
+    /// the [`Knob`] stops the motor.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`set_normal`]: #method.set_normal
+    /// [`run_motor`]: #method.run_motor
+    pub fn drive_to(&mut self, normal: Normal) {
+        if !self.is_dragging {
+            self.motor.set_target(normal);
+        }
+    }
+
+    /// Moves the [`Knob`] toward the value set with [`drive_to`].
+    ///
+    /// * `dt` - the time in seconds since the last call
+    ///
+    /// Returns the new normal while the motor is running.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`drive_to`]: #method.drive_to
+    pub fn run_motor(&mut self, dt: f32) -> Option<Normal> {
+        let normal = self.motor.step(self.normal_param.value, dt)?;
+
+        self.set_normal(normal);
+
+        Some(normal)
+    }
+
+    /// Sets how fast the motor of the [`Knob`] moves, in normals per
+    /// second. The default is `4.0`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn set_motor_speed(&mut self, speed: f32) {
+        self.motor.set_speed(speed);
+    }
+
+    /// Returns `true` while the [`Knob`] is moving toward the value set
+    /// with [`drive_to`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`drive_to`]: #method.drive_to
+    pub fn is_motor_running(&self) -> bool {
+        self.motor.is_running()
+    }
+
+    /// Animates the [`Knob`] between its active, hovered, and dragging
+    /// styles over `duration`, or switches between them instantly if
+    /// `duration` is `None` (the default).
+    ///
+    /// While animating, call [`tick`] periodically (for example from a
+    /// timer subscription) to advance the animation.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`tick`]: #method.tick
+    pub fn set_transition_duration(&mut self, duration: Option<Duration>) {
+        match (duration, &mut self.transition) {
+            (Some(duration), Some(transition)) => {
+                transition.set_duration(duration)
+            }
+            (Some(duration), None) => {
+                self.transition = Some(Transition::new(duration))
+            }
+            (None, _) => self.transition = None,
+        }
+    }
+
+    /// Advances the style animation of the [`Knob`] to the time `now`.
+    ///
+    /// Returns `true` while the animation is running. Does nothing and
+    /// returns `false` if no transition duration is set.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn tick(&mut self, now: Instant) -> bool {
+        match &mut self.transition {
+            Some(transition) => {
+                transition.set_pressed(self.is_dragging);
+                transition.tick(now)
+            }
+            None => false,
+        }
+    }
+
+    /// Returns `true` while the style of the [`Knob`] is animating.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn is_animating(&self) -> bool {
+        match &self.transition {
+            Some(transition) => {
+                let mut transition = *transition;
+                transition.set_pressed(self.is_dragging);
+                transition.is_animating()
+            }
+            None => false,
+        }
+    }
+
     /// Set the normalized default value of the [`Knob`].
     pub fn set_default(&mut self, normal: Normal) {
         self.normal_param.default = normal;
@@ -285,12 +1121,84 @@ impl State {
         self.normal_param.value = range.snapped(self.normal_param.value);
     }
 
+    /// Sets the modulation depth that is changed by dragging the ring
+    /// around the [`Knob`]. The default is `Normal::center()`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn set_mod_depth(&mut self, normal: Normal) {
+        self.mod_depth = normal;
+    }
+
+    /// Returns the modulation depth that is changed by dragging the ring
+    /// around the [`Knob`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn mod_depth(&self) -> Normal {
+        self.mod_depth
+    }
+
+    /// Is the modulation ring around the [`Knob`] being dragged?
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn is_dragging_mod_ring(&self) -> bool {
+        self.is_dragging_mod_ring
+    }
+
     /// Is the [`Knob`] currently in the dragging state?
     ///
     /// [`Knob`]: struct.Knob.html
     pub fn is_dragging(&self) -> bool {
         self.is_dragging
     }
+
+    /// Does the [`Knob`] have keyboard focus?
+    ///
+    /// While it has focus, the arrow keys, `Home`, `End`, `PageUp`, and
+    /// `PageDown` change its value. Clicking the [`Knob`] focuses it, and
+    /// clicking anywhere else, `Tab`, or `Escape` removes the focus.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Gives keyboard focus to the [`Knob`], such as when the user tabs
+    /// to it.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    /// Removes keyboard focus from the [`Knob`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+
+    /// Forces the [`Knob`] to regenerate its primitives on the next frame.
+    ///
+    /// This only needs to be called after changing the style of a
+    /// [`Knob`] that caches its primitives.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn invalidate(&mut self) {
+        self.revision = self.revision.wrapping_add(1);
+    }
+
+    /// Returns the bounds of the [`Knob`] if its primitives were regenerated
+    /// when it was last drawn, or `None` if its cached primitives were
+    /// reused.
+    ///
+    /// This invalidates the whole [`Knob`]; it does not narrow down which
+    /// part of it changed. It is always `Some` unless the [`Knob`] caches
+    /// its primitives.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn invalidated_bounds(&self) -> Option<Rectangle> {
+        self.draw_cache.invalidated_bounds()
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -323,21 +1231,44 @@ where
         event: Event,
         layout: Layout<'_>,
         cursor_position: Point,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> event::Status {
+        if let Some(transition) = &mut self.state.transition {
+            transition.set_hovered(layout.bounds().contains(cursor_position));
+        }
+
+        if let Some(status) = self.on_mod_ring_event(
+            renderer,
+            &event,
+            layout.bounds(),
+            cursor_position,
+            messages,
+        ) {
+            return status;
+        }
+
         match event {
             Event::Mouse(mouse_event) => match mouse_event {
                 mouse::Event::CursorMoved { .. } => {
                     if self.state.is_dragging {
-                        let normal_delta = (cursor_position.y
-                            - self.state.prev_drag_y)
-                            * self.scalar;
+                        if let Some(drag_start) = self.state.drag_start {
+                            if !self.input_config.drag_threshold_crossed(
+                                drag_start,
+                                cursor_position,
+                            ) {
+                                return event::Status::Captured;
+                            }
 
-                        self.state.prev_drag_y = cursor_position.y;
+                            self.state.drag_start = None;
+                        }
 
-                        self.move_virtual_slider(messages, normal_delta);
+                        self.drag_virtual_slider(
+                            messages,
+                            self.drag_direction.position(cursor_position),
+                            self.scalar,
+                        );
 
                         return event::Status::Captured;
                     }
@@ -347,7 +1278,11 @@ where
                         return event::Status::Ignored;
                     }
 
-                    if layout.bounds().contains(cursor_position) {
+                    if self
+                        .input_config
+                        .hit_bounds(layout.bounds())
+                        .contains(cursor_position)
+                    {
                         let lines = match delta {
                             iced_native::mouse::ScrollDelta::Lines {
                                 y,
@@ -368,45 +1303,108 @@ where
                         };
 
                         if lines != 0.0 {
-                            let normal_delta = -lines * self.wheel_scalar;
-
-                            self.move_virtual_slider(messages, normal_delta);
+                            self.scroll(messages, lines);
 
                             return event::Status::Captured;
                         }
                     }
                 }
-                mouse::Event::ButtonPressed(mouse::Button::Left) => {
-                    if layout.bounds().contains(cursor_position) {
-                        let click = mouse::Click::new(
-                            cursor_position,
-                            self.state.last_click,
-                        );
+                mouse::Event::ButtonPressed(button) => {
+                    self.state.is_focused = self
+                        .input_config
+                        .hit_bounds(layout.bounds())
+                        .contains(cursor_position);
+
+                    if self
+                        .input_config
+                        .hit_bounds(layout.bounds())
+                        .contains(cursor_position)
+                    {
+                        let click_kind =
+                            if button == self.input_config.drag_button {
+                                let click = mouse::Click::new(
+                                    cursor_position,
+                                    self.state.last_click,
+                                );
 
-                        match click.kind() {
-                            mouse::click::Kind::Single => {
+                                self.state.last_click = Some(click);
+
+                                click.kind()
+                            } else {
+                                mouse::click::Kind::Single
+                            };
+
+                        match self.input_config.action(
+                            button,
+                            click_kind,
+                            self.state.pressed_modifiers,
+                        ) {
+                            InputAction::Drag => {
+                                if let Some(on_grab) = &self.on_grab {
+                                    messages.push((on_grab)());
+                                }
+
+                                self.state.motor.stop();
                                 self.state.is_dragging = true;
-                                self.state.prev_drag_y = cursor_position.y;
+                                self.state.drag_start = Some(cursor_position);
+                                self.state.drag_anchor = DragAnchor::new(
+                                    self.drag_direction
+                                        .position(cursor_position),
+                                    self.state.continuous_normal,
+                                );
+                                self.state.drag_normal =
+                                    self.state.continuous_normal;
+                                self.state.takeover_pending = false;
                             }
-                            _ => {
-                                self.state.is_dragging = false;
+                            InputAction::Reset => {
+                                self.state.motor.stop();
+
+                                if self.state.is_dragging {
+                                    self.state.is_dragging = false;
+                                    self.state.drag_start = None;
+
+                                    if let Some(on_release) = &self.on_release {
+                                        messages.push((on_release)());
+                                    }
+                                }
 
                                 self.state.normal_param.value =
                                     self.state.normal_param.default;
 
-                                messages.push((self.on_change)(
-                                    self.state.normal_param.value,
-                                ));
+                                let value = self.state.normal_param.value;
+
+                                self.state.continuous_normal = value.as_f32();
+                                self.state.drag_anchor.rebase(value.as_f32());
+
+                                messages.push(match &self.on_reset {
+                                    Some(on_reset) => (on_reset)(value),
+                                    None => (self.on_change)(value),
+                                });
+                            }
+                            InputAction::Menu => {
+                                if let Some(on_menu) = &self.on_menu {
+                                    messages.push((on_menu)());
+                                }
+                            }
+                            InputAction::None => {
+                                return event::Status::Ignored;
                             }
                         }
 
-                        self.state.last_click = Some(click);
-
                         return event::Status::Captured;
                     }
                 }
-                mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                mouse::Event::ButtonReleased(button)
+                    if button == self.input_config.drag_button =>
+                {
+                    if self.state.is_dragging {
+                        if let Some(on_release) = &self.on_release {
+                            messages.push((on_release)());
+                        }
+                    }
+
                     self.state.is_dragging = false;
+                    self.state.drag_start = None;
                     self.state.continuous_normal =
                         self.state.normal_param.value.as_f32();
 
@@ -415,9 +1413,16 @@ where
                 _ => {}
             },
             Event::Keyboard(keyboard_event) => match keyboard_event {
-                keyboard::Event::KeyPressed { modifiers, .. } => {
+                keyboard::Event::KeyPressed {
+                    key_code,
+                    modifiers,
+                } => {
                     self.state.pressed_modifiers = modifiers;
 
+                    if self.state.is_focused || self.state.is_dragging {
+                        self.key_pressed(messages, key_code, modifiers);
+                    }
+
                     return event::Status::Captured;
                 }
                 keyboard::Event::KeyReleased { modifiers, .. } => {
@@ -425,8 +1430,14 @@ where
 
                     return event::Status::Captured;
                 }
+                keyboard::Event::ModifiersChanged(modifiers) => {
+                    self.state.pressed_modifiers = modifiers;
+                }
                 _ => {}
             },
+            Event::Window(window::Event::Unfocused) => {
+                self.state.pressed_modifiers = Default::default();
+            }
             _ => {}
         }
 
@@ -441,18 +1452,58 @@ where
         cursor_position: Point,
         _viewport: &Rectangle,
     ) -> Renderer::Output {
+        let warning = match self.warning_threshold {
+            Some(threshold) => {
+                self.state.normal_param.value.as_f32() > threshold.as_f32()
+            }
+            None => false,
+        };
+
+        let revision = if self.cache_primitives {
+            Some(
+                crate::graphics::draw_cache::Revision::new(self.state.revision)
+                    .normal(self.state.normal_param.value)
+                    .normal(self.state.normal_param.default)
+                    .flag(self.state.is_dragging)
+                    .transition(self.state.transition.as_ref())
+                    .flag(self.state.is_focused)
+                    .mod_range(self.mod_range_1)
+                    .mod_range(self.mod_range_2)
+                    .optional_normal(self.modulated_value)
+                    .optional_normal(self.automation_value)
+                    .tick_marks(self.tick_marks)
+                    .text_marks(self.text_marks)
+                    .color(self.accent)
+                    .flag(warning)
+                    .finish(),
+            )
+        } else {
+            None
+        };
+
         renderer.draw(
-            layout.bounds(),
-            cursor_position,
-            self.state.normal_param.value,
-            self.state.is_dragging,
-            self.mod_range_1,
-            self.mod_range_2,
-            self.tick_marks,
-            self.text_marks,
+            &DrawState {
+                bounds: layout.bounds(),
+                cursor_position,
+                normal: self.state.normal_param.value,
+                default_normal: self.state.normal_param.default,
+                is_dragging: self.state.is_dragging,
+                is_focused: self.state.is_focused,
+                transition: self.state.transition.as_ref(),
+                mod_range_1: self.mod_range_1,
+                mod_range_2: self.mod_range_2,
+                modulated_value: self.modulated_value,
+                automation_value: self.automation_value,
+                tick_marks: self.tick_marks,
+                text_marks: self.text_marks,
+                accent: self.accent,
+                warning,
+                tick_marks_cache: &self.state.tick_marks_cache,
+                text_marks_cache: &self.state.text_marks_cache,
+                draw_cache: &self.state.draw_cache,
+                revision,
+            },
             &self.style,
-            &self.state.tick_marks_cache,
-            &self.state.text_marks_cache,
         )
     }
 
@@ -464,6 +1515,51 @@ where
     }
 }
 
+/// The values a [`Knob`] is drawn with in a frame.
+///
+/// [`Knob`]: struct.Knob.html
+#[derive(Debug, Clone, Copy)]
+pub struct DrawState<'a> {
+    /// The bounds of the knob
+    pub bounds: Rectangle,
+    /// The current cursor position
+    pub cursor_position: Point,
+    /// The current normal of the knob
+    pub normal: Normal,
+    /// The default normal of the knob
+    pub default_normal: Normal,
+    /// Whether the knob is currently being dragged
+    pub is_dragging: bool,
+    /// Whether the knob has keyboard focus
+    pub is_focused: bool,
+    /// The hover and press transition of the knob, if it is animated
+    pub transition: Option<&'a Transition>,
+    /// The first modulation range to display, if any
+    pub mod_range_1: Option<&'a ModulationRange>,
+    /// The second modulation range to display, if any
+    pub mod_range_2: Option<&'a ModulationRange>,
+    /// The modulated value to display, if any
+    pub modulated_value: Option<Normal>,
+    /// The automation value to display, if any
+    pub automation_value: Option<Normal>,
+    /// Any tick marks to display
+    pub tick_marks: Option<&'a tick_marks::Group>,
+    /// Any text marks to display
+    pub text_marks: Option<&'a text_marks::Group>,
+    /// The accent color of the knob, if any
+    pub accent: Option<Color>,
+    /// Whether the value is above the warning threshold of the knob
+    pub warning: bool,
+    /// The cache for the primitives of the tick marks
+    pub tick_marks_cache: &'a crate::tick_marks::PrimitiveCache,
+    /// The cache for the primitives of the text marks
+    pub text_marks_cache: &'a crate::text_marks::PrimitiveCache,
+    /// The cache for the primitives of the knob
+    pub draw_cache: &'a crate::graphics::DrawCache,
+    /// The revision of the knob, or `None` if caching is disabled
+    pub revision: Option<u64>,
+}
+
 /// The renderer of a [`Knob`].
 ///
 /// Your renderer will need to implement this trait before being
@@ -474,32 +1570,27 @@ pub trait Renderer: iced_native::Renderer {
     /// The style supported by this renderer.
     type Style: Default;
 
-    /// Draws a [`Knob`].
-    ///
-    /// It receives:
-    ///   * the bounds of the [`Knob`]
-    ///   * the current cursor position
-    ///   * the current normal of the [`Knob`]
-    ///   * whether the knob is currently being dragged
-    ///   * any tick marks to display
-    ///   * any text marks to display
-    ///   * the style of the [`Knob`]
+    /// Draws a [`Knob`] from its [`DrawState`] and its style.
     ///
     /// [`Knob`]: struct.Knob.html
+    /// [`DrawState`]: struct.DrawState.html
     fn draw(
         &mut self,
-        bounds: Rectangle,
-        cursor_position: Point,
-        normal: Normal,
-        is_dragging: bool,
-        mod_range_1: Option<&ModulationRange>,
-        mod_range_2: Option<&ModulationRange>,
-        tick_marks: Option<&tick_marks::Group>,
-        text_marks: Option<&text_marks::Group>,
+        state: &DrawState<'_>,
         style: &Self::Style,
-        tick_marks_cache: &crate::tick_marks::PrimitiveCache,
-        text_marks_cache: &crate::text_marks::PrimitiveCache,
     ) -> Self::Output;
+
+    /// Returns how far in pixels the modulation range arcs drawn with
+    /// `style` reach out from the edge of a [`Knob`], or `None` if it draws
+    /// none.
+    ///
+    /// This is the default width of the ring that can be dragged to change
+    /// the modulation depth.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    fn mod_ring_width(&self, _style: &Self::Style) -> Option<f32> {
+        None
+    }
 }
 
 impl<'a, Message, Renderer> From<Knob<'a, Message, Renderer>>
@@ -514,3 +1605,308 @@ where
         Element::new(knob)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced_native::{clipboard, renderer};
+
+    impl Renderer for renderer::Null {
+        type Style = ();
+
+        fn draw(&mut self, _state: &DrawState<'_>, _style: &Self::Style) {}
+    }
+
+    #[test]
+    fn keeps_both_mod_ranges() {
+        let mut state = State::new(NormalParam::default());
+        let mod_range_1 = ModulationRange::new(0.2.into(), 0.4.into());
+        let mod_range_2 = ModulationRange::new(0.6.into(), 0.8.into());
+
+        let knob = Knob::<_, renderer::Null>::new(&mut state, |_| ())
+            .mod_range(&mod_range_1)
+            .mod_range_2(&mod_range_2);
+
+        assert!(std::ptr::eq(knob.mod_range_1.unwrap(), &mod_range_1));
+        assert!(std::ptr::eq(knob.mod_range_2.unwrap(), &mod_range_2));
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Message {
+        Change(f32),
+        ModDepth(f32),
+        Grab,
+        Release,
+    }
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 10.0,
+        y: 20.0,
+        width: 30.0,
+        height: 30.0,
+    };
+
+    fn new_state() -> State {
+        State::new(NormalParam::default())
+    }
+
+    fn knob(state: &mut State) -> Knob<'_, Message, renderer::Null> {
+        Knob::new(state, |normal| Message::Change(normal.as_f32()))
+            .input_config(InputConfig {
+                drag_threshold: 0.0,
+                ..InputConfig::default()
+            })
+            .on_mod_depth(|normal| Message::ModDepth(normal.as_f32()))
+            .on_grab(|| Message::Grab)
+            .on_release(|| Message::Release)
+    }
+
+    fn send(
+        mut knob: Knob<'_, Message, renderer::Null>,
+        event: Event,
+        x: f32,
+        y: f32,
+    ) -> (event::Status, Vec<Message>) {
+        let mut node = layout::Node::new(BOUNDS.size());
+        node.move_to(BOUNDS.position());
+
+        let mut messages = Vec::new();
+        let status = knob.on_event(
+            event,
+            Layout::new(&node),
+            Point::new(x, y),
+            &renderer::Null,
+            &mut clipboard::Null,
+            &mut messages,
+        );
+
+        (status, messages)
+    }
+
+    fn pressed() -> Event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+    }
+
+    fn released() -> Event {
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+    }
+
+    fn moved(x: f32, y: f32) -> Event {
+        Event::Mouse(mouse::Event::CursorMoved {
+            position: Point::new(x, y),
+        })
+    }
+
+    #[test]
+    fn ring_drag_changes_mod_depth() {
+        let mut state = new_state();
+        let normal = state.normal();
+
+        // Just above the top edge of the knob.
+        let (status, messages) = send(knob(&mut state), pressed(), 25.0, 17.0);
+        assert_eq!(status, event::Status::Captured);
+        assert_eq!(messages, vec![Message::Grab]);
+        assert!(state.is_dragging_mod_ring());
+        assert!(!state.is_dragging());
+
+        let (status, messages) =
+            send(knob(&mut state), moved(25.0, 7.0), 25.0, 7.0);
+        assert_eq!(status, event::Status::Captured);
+        assert_eq!(
+            messages,
+            vec![Message::ModDepth(0.5 + 10.0 * DEFAULT_SCALAR)]
+        );
+        assert_eq!(state.normal(), normal);
+
+        let (status, messages) = send(knob(&mut state), released(), 25.0, 7.0);
+        assert_eq!(status, event::Status::Captured);
+        assert_eq!(messages, vec![Message::Release]);
+        assert!(!state.is_dragging_mod_ring());
+    }
+
+    #[test]
+    fn press_on_face_turns_knob() {
+        let mut state = new_state();
+
+        let (status, messages) = send(knob(&mut state), pressed(), 33.0, 41.0);
+        assert_eq!(status, event::Status::Captured);
+        assert_eq!(messages, vec![Message::Grab]);
+        assert!(state.is_dragging());
+        assert!(!state.is_dragging_mod_ring());
+    }
+
+    #[test]
+    fn ring_is_grabbed_at_diagonal() {
+        let mut state = new_state();
+
+        // 18 pixels from the center at 45 degrees, inside the bounds.
+        let offset = 18.0 * std::f32::consts::FRAC_1_SQRT_2;
+        let (status, _) =
+            send(knob(&mut state), pressed(), 25.0 + offset, 35.0 - offset);
+        assert_eq!(status, event::Status::Captured);
+        assert!(state.is_dragging_mod_ring());
+    }
+
+    #[test]
+    fn press_just_outside_ring_is_ignored() {
+        let mut state = new_state();
+
+        // 22 pixels from the center at 45 degrees, past the 6 pixel ring.
+        let offset = 22.0 * std::f32::consts::FRAC_1_SQRT_2;
+        let (status, messages) =
+            send(knob(&mut state), pressed(), 25.0 + offset, 35.0 - offset);
+        assert_eq!(status, event::Status::Ignored);
+        assert!(messages.is_empty());
+        assert!(!state.is_dragging_mod_ring());
+        assert!(!state.is_dragging());
+    }
+
+    #[test]
+    fn ring_is_not_grabbed_without_on_mod_depth() {
+        let mut state = new_state();
+
+        let knob =
+            Knob::new(&mut state, |normal| Message::Change(normal.as_f32()));
+        let (status, _) = send(knob, pressed(), 25.0, 17.0);
+        assert_eq!(status, event::Status::Ignored);
+        assert!(!state.is_dragging_mod_ring());
+    }
+
+    #[test]
+    fn ring_drag_waits_for_threshold() {
+        let mut state = new_state();
+        let input_config = InputConfig {
+            drag_threshold: 2.0,
+            ..InputConfig::default()
+        };
+
+        let _ = send(
+            knob(&mut state).input_config(input_config),
+            pressed(),
+            25.0,
+            17.0,
+        );
+
+        let (status, messages) = send(
+            knob(&mut state).input_config(input_config),
+            moved(25.0, 16.0),
+            25.0,
+            16.0,
+        );
+        assert_eq!(status, event::Status::Captured);
+        assert!(messages.is_empty());
+
+        let (_, messages) = send(
+            knob(&mut state).input_config(input_config),
+            moved(25.0, 14.0),
+            25.0,
+            14.0,
+        );
+        assert_eq!(
+            messages,
+            vec![Message::ModDepth(0.5 + 3.0 * DEFAULT_SCALAR)]
+        );
+    }
+
+    #[test]
+    fn ring_drag_follows_drag_direction() {
+        let mut state = new_state();
+
+        let _ = send(
+            knob(&mut state).drag_direction(DragDirection::Horizontal),
+            pressed(),
+            25.0,
+            17.0,
+        );
+
+        let (_, messages) = send(
+            knob(&mut state).drag_direction(DragDirection::Horizontal),
+            moved(35.0, 17.0),
+            35.0,
+            17.0,
+        );
+        assert_eq!(
+            messages,
+            vec![Message::ModDepth(0.5 + 10.0 * DEFAULT_SCALAR)]
+        );
+    }
+
+    #[test]
+    fn ring_drag_uses_modifier_scalar() {
+        let mut state = new_state();
+
+        let _ = send(
+            knob(&mut state),
+            Event::Keyboard(keyboard::Event::ModifiersChanged(
+                keyboard::Modifiers {
+                    control: true,
+                    ..Default::default()
+                },
+            )),
+            0.0,
+            0.0,
+        );
+        let _ = send(knob(&mut state), pressed(), 25.0, 17.0);
+
+        let (_, messages) = send(knob(&mut state), moved(25.0, 7.0), 25.0, 7.0);
+        assert_eq!(
+            messages,
+            vec![Message::ModDepth(
+                0.5 + 10.0 * (DEFAULT_SCALAR * DEFAULT_MODIFIER_SCALAR)
+            )]
+        );
+    }
+
+    #[test]
+    fn ring_grows_with_hit_target_scale() {
+        let mut state = new_state();
+
+        let (status, _) = send(knob(&mut state), pressed(), 25.0, 0.0);
+        assert_eq!(status, event::Status::Ignored);
+
+        let (status, _) = send(
+            knob(&mut state).input_config(InputConfig {
+                drag_threshold: 0.0,
+                hit_target_scale: 2.0,
+                ..InputConfig::default()
+            }),
+            pressed(),
+            25.0,
+            0.0,
+        );
+        assert_eq!(status, event::Status::Captured);
+        assert!(state.is_dragging_mod_ring());
+    }
+
+    #[test]
+    fn reset_resyncs_drag_and_releases() {
+        let mut state = State::new(NormalParam {
+            value: 0.8.into(),
+            default: 0.2.into(),
+        });
+        let middle = mouse::Event::ButtonPressed(mouse::Button::Middle);
+
+        let (_, messages) = send(knob(&mut state), pressed(), 25.0, 35.0);
+        assert_eq!(messages, vec![Message::Grab]);
+
+        let (_, messages) = send(
+            knob(&mut state)
+                .reset_gesture(ResetGesture::Button(mouse::Button::Middle)),
+            Event::Mouse(middle),
+            25.0,
+            35.0,
+        );
+        assert_eq!(messages, vec![Message::Release, Message::Change(0.2)]);
+        assert!(!state.is_dragging());
+
+        let (_, messages) = send(
+            knob(&mut state),
+            Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Lines { x: 0.0, y: 1.0 },
+            }),
+            25.0,
+            35.0,
+        );
+        assert_eq!(messages, vec![Message::Change(0.2 + DEFAULT_WHEEL_SCALAR)]);
+    }
+}
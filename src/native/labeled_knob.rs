@@ -0,0 +1,340 @@
+//! Display a [`Knob`] with a text label and an optional value readout.
+//!
+//! [`Knob`]: ../knob/struct.Knob.html
+
+use std::hash::Hash;
+
+use iced_native::{
+    event, layout, text, Clipboard, Color, Element, Event, Hasher,
+    HorizontalAlignment, Layout, Length, Point, Rectangle, Size,
+    VerticalAlignment, Widget,
+};
+
+use crate::native::knob::{self, Knob};
+use crate::native::text_fit;
+
+static DEFAULT_SPACING: u16 = 2;
+
+/// Where the label of a [`LabeledKnob`] is placed.
+///
+/// [`LabeledKnob`]: struct.LabeledKnob.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum LabelPlacement {
+    /// The label is placed above the knob, and the value readout below it.
+    #[default]
+    Above,
+    /// The label and the value readout are both placed below the knob.
+    Below,
+}
+
+/// A [`Knob`] with a text label and an optional value readout.
+///
+/// The label and value readout never grow wider than the [`Knob`]. Text that
+/// does not fit is truncated with an ellipsis, so long parameter names do
+/// not break grid layouts.
+///
+/// [`Knob`]: ../knob/struct.Knob.html
+#[allow(missing_debug_implementations)]
+pub struct LabeledKnob<'a, Message, Renderer: self::Renderer> {
+    knob: Knob<'a, Message, Renderer>,
+    label: String,
+    value_text: Option<String>,
+    placement: LabelPlacement,
+    spacing: u16,
+    text_size: Option<u16>,
+    text_color: Option<Color>,
+    font: <Renderer as text::Renderer>::Font,
+}
+
+impl<'a, Message, Renderer: self::Renderer> LabeledKnob<'a, Message, Renderer> {
+    /// Creates a new [`LabeledKnob`].
+    ///
+    /// It expects:
+    ///   * the [`Knob`] to display
+    ///   * the text of the label
+    ///
+    /// [`LabeledKnob`]: struct.LabeledKnob.html
+    /// [`Knob`]: ../knob/struct.Knob.html
+    pub fn new(
+        knob: Knob<'a, Message, Renderer>,
+        label: impl Into<String>,
+    ) -> Self {
+        LabeledKnob {
+            knob,
+            label: label.into(),
+            value_text: None,
+            placement: LabelPlacement::default(),
+            spacing: DEFAULT_SPACING,
+            text_size: None,
+            text_color: None,
+            font: Default::default(),
+        }
+    }
+
+    /// Sets the text of the value readout of the [`LabeledKnob`].
+    ///
+    /// The default is no value readout.
+    ///
+    /// [`LabeledKnob`]: struct.LabeledKnob.html
+    pub fn value_text(mut self, value_text: impl Into<String>) -> Self {
+        self.value_text = Some(value_text.into());
+        self
+    }
+
+    /// Sets the [`LabelPlacement`] of the [`LabeledKnob`].
+    ///
+    /// The default is `LabelPlacement::Above`.
+    ///
+    /// [`LabelPlacement`]: enum.LabelPlacement.html
+    /// [`LabeledKnob`]: struct.LabeledKnob.html
+    pub fn placement(mut self, placement: LabelPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets the spacing in pixels between the [`Knob`] and the text.
+    ///
+    /// The default is `2`.
+    ///
+    /// [`Knob`]: ../knob/struct.Knob.html
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the size of the text of the [`LabeledKnob`].
+    ///
+    /// The default is the default text size of the renderer.
+    ///
+    /// [`LabeledKnob`]: struct.LabeledKnob.html
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the color of the text of the [`LabeledKnob`].
+    ///
+    /// [`LabeledKnob`]: struct.LabeledKnob.html
+    pub fn text_color(mut self, color: impl Into<Color>) -> Self {
+        self.text_color = Some(color.into());
+        self
+    }
+
+    /// Sets the font of the text of the [`LabeledKnob`].
+    ///
+    /// [`LabeledKnob`]: struct.LabeledKnob.html
+    pub fn font(mut self, font: <Renderer as text::Renderer>::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    fn resolved_text_size(&self, renderer: &Renderer) -> u16 {
+        self.text_size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    fn draw_text(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        bounds: Rectangle,
+        content: &str,
+    ) -> Renderer::Output {
+        let size = self.resolved_text_size(renderer);
+        let fitted = text_fit::fit_text(
+            renderer,
+            content,
+            size,
+            self.font,
+            bounds.width,
+        );
+
+        text::Renderer::draw(
+            renderer,
+            defaults,
+            bounds,
+            &fitted,
+            size,
+            self.font,
+            self.text_color,
+            HorizontalAlignment::Center,
+            VerticalAlignment::Top,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for LabeledKnob<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        Widget::width(&self.knob)
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_height = f32::from(self.resolved_text_size(renderer));
+        let spacing = f32::from(self.spacing);
+
+        let text_rows = if self.value_text.is_some() { 2.0 } else { 1.0 };
+        let text_space = text_rows * (text_height + spacing);
+
+        let knob_limits = limits.shrink(Size::new(0.0, text_space));
+        let mut knob = self.knob.layout(renderer, &knob_limits);
+        let width = knob.size().width;
+
+        let mut label = layout::Node::new(Size::new(width, text_height));
+        let mut value = layout::Node::new(Size::new(width, text_height));
+
+        let mut y = 0.0;
+        match self.placement {
+            LabelPlacement::Above => {
+                label.move_to(Point::new(0.0, y));
+                y += text_height + spacing;
+
+                knob.move_to(Point::new(0.0, y));
+                y += knob.size().height;
+
+                if self.value_text.is_some() {
+                    y += spacing;
+                    value.move_to(Point::new(0.0, y));
+                    y += text_height;
+                }
+            }
+            LabelPlacement::Below => {
+                knob.move_to(Point::new(0.0, y));
+                y += knob.size().height + spacing;
+
+                label.move_to(Point::new(0.0, y));
+                y += text_height;
+
+                if self.value_text.is_some() {
+                    y += spacing;
+                    value.move_to(Point::new(0.0, y));
+                    y += text_height;
+                }
+            }
+        }
+
+        layout::Node::with_children(
+            Size::new(width, y),
+            vec![knob, label, value],
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let knob_layout = layout.children().next().unwrap();
+
+        self.knob.on_event(
+            event,
+            knob_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            messages,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let mut children = layout.children();
+        let knob_layout = children.next().unwrap();
+        let label_layout = children.next().unwrap();
+        let value_layout = children.next().unwrap();
+
+        let knob = self.knob.draw(
+            renderer,
+            defaults,
+            knob_layout,
+            cursor_position,
+            viewport,
+        );
+
+        let label = self.draw_text(
+            renderer,
+            defaults,
+            label_layout.bounds(),
+            &self.label,
+        );
+
+        let value = self.value_text.as_ref().map(|value_text| {
+            self.draw_text(
+                renderer,
+                defaults,
+                value_layout.bounds(),
+                value_text,
+            )
+        });
+
+        self::Renderer::draw(renderer, knob, label, value)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.knob.hash_layout(state);
+        self.value_text.is_some().hash(state);
+        self.placement.hash(state);
+        self.spacing.hash(state);
+        self.text_size.hash(state);
+    }
+}
+
+/// The renderer of a [`LabeledKnob`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`LabeledKnob`] in your user interface.
+///
+/// [`LabeledKnob`]: struct.LabeledKnob.html
+pub trait Renderer: knob::Renderer + text::Renderer {
+    /// Combines the parts of a [`LabeledKnob`].
+    ///
+    /// It receives:
+    ///   * the output of the [`Knob`]
+    ///   * the output of the label
+    ///   * the output of the value readout, if any
+    ///
+    /// [`LabeledKnob`]: struct.LabeledKnob.html
+    /// [`Knob`]: ../knob/struct.Knob.html
+    fn draw(
+        &mut self,
+        knob: Self::Output,
+        label: Self::Output,
+        value: Option<Self::Output>,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<LabeledKnob<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        labeled_knob: LabeledKnob<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(labeled_knob)
+    }
+}
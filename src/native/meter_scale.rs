@@ -0,0 +1,314 @@
+//! Display a dB scale of tick marks and text labels beside meters.
+
+use std::hash::Hash;
+
+use iced_native::{
+    layout, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use crate::core::{KSystem, LogDBRange, MeterCalibration, Orientation};
+use crate::native::{text_marks, tick_marks};
+
+static DEFAULT_WIDTH: u16 = 30;
+
+/// A ruler of tick marks and dB labels with no meter of its own.
+///
+/// Place it in a `Row` beside one or more meters of the same height, or in a
+/// `Column` under horizontal meters of the same width. As long as its
+/// [`State`] is made from the same [`LogDBRange`] as the levels of the
+/// meters, the labels line up with the meter bars.
+///
+/// [`State`]: struct.State.html
+/// [`LogDBRange`]: ../../core/range/struct.LogDBRange.html
+#[allow(missing_debug_implementations)]
+pub struct MeterScale<'a, Renderer: self::Renderer> {
+    state: &'a State,
+    orientation: Orientation,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Renderer: self::Renderer> MeterScale<'a, Renderer> {
+    /// Creates a new [`MeterScale`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`MeterScale`]
+    ///
+    /// [`State`]: struct.State.html
+    /// [`MeterScale`]: struct.MeterScale.html
+    pub fn new(state: &'a State) -> Self {
+        MeterScale {
+            state,
+            orientation: Orientation::Vertical,
+            width: Length::Units(DEFAULT_WIDTH),
+            height: Length::Fill,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the [`Orientation`] of the [`MeterScale`]. This also resets its
+    /// width and height to the defaults of that orientation, so set them
+    /// afterwards.
+    ///
+    /// The default is `Orientation::Vertical`, which is `Length::Units(30)`
+    /// wide and fills the available height. `Orientation::Horizontal` fills
+    /// the available width and is `Length::Units(30)` tall.
+    ///
+    /// [`MeterScale`]: struct.MeterScale.html
+    /// [`Orientation`]: ../../core/orientation/enum.Orientation.html
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+
+        match orientation {
+            Orientation::Vertical => {
+                self.width = Length::Units(DEFAULT_WIDTH);
+                self.height = Length::Fill;
+            }
+            Orientation::Horizontal => {
+                self.width = Length::Fill;
+                self.height = Length::Units(DEFAULT_WIDTH);
+            }
+        }
+
+        self
+    }
+
+    /// Sets the width of the [`MeterScale`].
+    /// The default width is `Length::Units(30)`.
+    ///
+    /// [`MeterScale`]: struct.MeterScale.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`MeterScale`].
+    /// The default height is `Length::Fill`.
+    ///
+    /// [`MeterScale`]: struct.MeterScale.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`MeterScale`].
+    ///
+    /// [`MeterScale`]: struct.MeterScale.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The local state of a [`MeterScale`].
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{meter_scale, LogDBRange, MeterCalibration};
+///
+/// let range = LogDBRange::new(-60.0, 6.0, 0.0.into());
+///
+/// // A label and tick mark every 6 dB, lined up with meters that map
+/// // their levels with the same range.
+/// let state =
+///     meter_scale::State::from_meter_scale(&range, &MeterCalibration::DBFS, 6.0);
+///
+/// assert_eq!(state.tick_marks().len(), 12);
+/// ```
+///
+/// [`MeterScale`]: struct.MeterScale.html
+#[derive(Debug, Clone)]
+pub struct State {
+    tick_marks: tick_marks::Group,
+    text_marks: text_marks::Group,
+    tick_marks_cache: crate::tick_marks::PrimitiveCache,
+    text_marks_cache: crate::text_marks::PrimitiveCache,
+}
+
+impl State {
+    /// Creates a new [`MeterScale`] state from custom marks.
+    ///
+    /// It expects:
+    ///   * the tick marks of the scale
+    ///   * the text labels of the scale
+    ///
+    /// [`MeterScale`]: struct.MeterScale.html
+    pub fn new(
+        tick_marks: tick_marks::Group,
+        text_marks: text_marks::Group,
+    ) -> Self {
+        Self {
+            tick_marks,
+            text_marks,
+            tick_marks_cache: Default::default(),
+            text_marks_cache: Default::default(),
+        }
+    }
+
+    /// Creates a new [`MeterScale`] state with a tick mark and a label
+    /// every `step` units of a calibrated scale.
+    ///
+    /// * `range` - the range of the meters in dBFS
+    /// * `calibration` - the [`MeterCalibration`] of the scale
+    /// * `step` - the spacing of the marks in scale units
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `step` <= `0.0`
+    ///
+    /// [`MeterScale`]: struct.MeterScale.html
+    /// [`MeterCalibration`]: ../../core/meter_calibration/struct.MeterCalibration.html
+    pub fn from_meter_scale(
+        range: &LogDBRange,
+        calibration: &MeterCalibration,
+        step: f32,
+    ) -> Self {
+        Self::new(
+            tick_marks::Group::from_meter_scale(
+                range,
+                calibration,
+                step,
+                tick_marks::Tier::Two,
+            ),
+            text_marks::Group::from_meter_scale(range, calibration, step),
+        )
+    }
+
+    /// Creates a new [`MeterScale`] state for a [`KSystem`] scale.
+    ///
+    /// * `k_system` - the [`KSystem`] of the scale
+    /// * `range` - the range of the meters in dBFS, such as
+    ///   [`KSystem::range`]
+    ///
+    /// [`MeterScale`]: struct.MeterScale.html
+    /// [`KSystem`]: ../../core/meter_calibration/enum.KSystem.html
+    /// [`KSystem::range`]: ../../core/meter_calibration/enum.KSystem.html#method.range
+    pub fn from_k_system(k_system: KSystem, range: &LogDBRange) -> Self {
+        Self::new(
+            tick_marks::Group::from_k_system(k_system, range),
+            text_marks::Group::from_k_system(k_system, range),
+        )
+    }
+
+    /// Returns the tick marks of the scale.
+    pub fn tick_marks(&self) -> &tick_marks::Group {
+        &self.tick_marks
+    }
+
+    /// Returns the text labels of the scale.
+    pub fn text_marks(&self) -> &text_marks::Group {
+        &self.text_marks
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for MeterScale<'a, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(
+            &DrawState {
+                bounds: layout.bounds(),
+                orientation: self.orientation,
+                tick_marks: &self.state.tick_marks,
+                text_marks: &self.state.text_marks,
+                tick_marks_cache: &self.state.tick_marks_cache,
+                text_marks_cache: &self.state.text_marks_cache,
+            },
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The values a [`MeterScale`] is drawn with in a frame.
+///
+/// [`MeterScale`]: struct.MeterScale.html
+#[derive(Debug, Clone, Copy)]
+pub struct DrawState<'a> {
+    /// The bounds of the scale
+    pub bounds: Rectangle,
+    /// The [`Orientation`] of the scale
+    ///
+    /// [`Orientation`]: ../../core/orientation/enum.Orientation.html
+    pub orientation: Orientation,
+    /// The tick marks of the scale
+    pub tick_marks: &'a tick_marks::Group,
+    /// The text labels of the scale
+    pub text_marks: &'a text_marks::Group,
+    /// The cache for the primitives of the tick marks
+    pub tick_marks_cache: &'a crate::tick_marks::PrimitiveCache,
+    /// The cache for the primitives of the text marks
+    pub text_marks_cache: &'a crate::text_marks::PrimitiveCache,
+}
+
+/// The renderer of a [`MeterScale`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`MeterScale`] in your user interface.
+///
+/// [`MeterScale`]: struct.MeterScale.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`MeterScale`] from its [`DrawState`] and its style.
+    ///
+    /// [`MeterScale`]: struct.MeterScale.html
+    /// [`DrawState`]: struct.DrawState.html
+    fn draw(
+        &mut self,
+        state: &DrawState<'_>,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<MeterScale<'a, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        meter_scale: MeterScale<'a, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(meter_scale)
+    }
+}
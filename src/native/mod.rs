@@ -1,23 +1,60 @@
 //! A renderer-agnostic native GUI runtime for Iced Audio.
 
+pub mod clip_indicator;
+pub mod correlation_meter;
+pub mod crossover;
+pub mod db_meter;
+pub mod eq_bands;
 pub mod h_slider;
 pub mod knob;
+pub mod labeled_knob;
+pub mod meter_scale;
 pub mod mod_range_input;
+pub mod pan_knob;
 pub mod ramp;
+pub mod reduction_history;
+pub mod spectrogram;
+pub mod text_fit;
 pub mod text_marks;
 pub mod tick_marks;
 pub mod v_slider;
+pub mod vu_meter;
+pub mod waveform_view;
 pub mod xy_pad;
 
+#[doc(no_inline)]
+pub use clip_indicator::ClipIndicator;
+#[doc(no_inline)]
+pub use correlation_meter::CorrelationMeter;
+#[doc(no_inline)]
+pub use crossover::Crossover;
+#[doc(no_inline)]
+pub use db_meter::DBMeter;
+#[doc(no_inline)]
+pub use eq_bands::EqBands;
 #[doc(no_inline)]
 pub use h_slider::HSlider;
 #[doc(no_inline)]
 pub use knob::Knob;
 #[doc(no_inline)]
+pub use labeled_knob::LabeledKnob;
+#[doc(no_inline)]
+pub use meter_scale::MeterScale;
+#[doc(no_inline)]
 pub use mod_range_input::ModRangeInput;
 #[doc(no_inline)]
+pub use pan_knob::PanKnob;
+#[doc(no_inline)]
 pub use ramp::Ramp;
 #[doc(no_inline)]
+pub use reduction_history::ReductionHistory;
+#[doc(no_inline)]
+pub use spectrogram::Spectrogram;
+#[doc(no_inline)]
 pub use v_slider::VSlider;
 #[doc(no_inline)]
+pub use vu_meter::VuMeter;
+#[doc(no_inline)]
+pub use waveform_view::WaveformView;
+#[doc(no_inline)]
 pub use xy_pad::XYPad;
@@ -5,19 +5,21 @@
 use std::fmt::Debug;
 
 use iced_native::{
-    event, keyboard, layout, mouse, Clipboard, Element, Event, Hasher, Layout,
-    Length, Point, Rectangle, Size, Widget,
+    event, keyboard, layout, mouse, window, Clipboard, Element, Event, Hasher,
+    Layout, Length, Point, Rectangle, Size, Widget,
 };
 
 use std::hash::Hash;
 
-use crate::core::{Normal, NormalParam};
+use crate::core::input_config::{DragAnchor, InputAction};
+use crate::core::{InputConfig, Normal, NormalParam, ResetGesture};
 use crate::IntRange;
 
 static DEFAULT_SIZE: u16 = 10;
 static DEFAULT_SCALAR: f32 = 0.00385 / 2.0;
 static DEFAULT_WHEEL_SCALAR: f32 = 0.01 / 2.0;
 static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+static DEFAULT_PAGE_STEP: f32 = 0.1;
 
 /// An interactive dot that controls an [`NormalParam`]
 ///
@@ -29,8 +31,14 @@ pub struct ModRangeInput<'a, Message, Renderer: self::Renderer> {
     on_change: Box<dyn Fn(Normal) -> Message>,
     scalar: f32,
     wheel_scalar: f32,
+    page_step: f32,
     modifier_scalar: f32,
     modifier_keys: keyboard::Modifiers,
+    input_config: InputConfig,
+    on_menu: Option<Box<dyn Fn() -> Message>>,
+    on_reset: Option<Box<dyn Fn(Normal) -> Message>>,
+    on_tab: Option<Box<dyn Fn(bool) -> Message>>,
+    cache_primitives: bool,
     style: Renderer::Style,
 }
 
@@ -55,11 +63,17 @@ impl<'a, Message, Renderer: self::Renderer>
             on_change: Box::new(on_change),
             scalar: DEFAULT_SCALAR,
             wheel_scalar: DEFAULT_WHEEL_SCALAR,
+            page_step: DEFAULT_PAGE_STEP,
             modifier_scalar: DEFAULT_MODIFIER_SCALAR,
             modifier_keys: keyboard::Modifiers {
                 control: true,
                 ..Default::default()
             },
+            input_config: InputConfig::global(),
+            on_menu: None,
+            on_reset: None,
+            on_tab: None,
+            cache_primitives: false,
             style: Renderer::Style::default(),
         }
     }
@@ -107,6 +121,19 @@ impl<'a, Message, Renderer: self::Renderer>
         self
     }
 
+    /// Sets how much the [`Normal`] value of the [`ModRangeInput`] changes when
+    /// `PageUp` or `PageDown` is pressed while it has keyboard focus or is
+    /// being dragged.
+    ///
+    /// The default is `0.1`.
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn page_step(mut self, page_step: f32) -> Self {
+        self.page_step = page_step;
+        self
+    }
+
     /// Sets the modifier keys of the [`ModRangeInput`].
     ///
     /// The default modifier key is `Ctrl`.
@@ -117,6 +144,90 @@ impl<'a, Message, Renderer: self::Renderer>
         self
     }
 
+    /// Sets the mouse buttons that control the [`ModRangeInput`].
+    ///
+    /// The default is the global [`InputConfig`].
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    /// [`InputConfig`]: ../../core/input_config/struct.InputConfig.html
+    pub fn input_config(mut self, input_config: InputConfig) -> Self {
+        self.input_config = input_config;
+        self
+    }
+
+    /// Sets the message to send when the menu button of the [`InputConfig`]
+    /// is pressed over the [`ModRangeInput`].
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    /// [`InputConfig`]: ../../core/input_config/struct.InputConfig.html
+    pub fn on_menu<F>(mut self, on_menu: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_menu = Some(Box::new(on_menu));
+        self
+    }
+
+    /// Sets the gesture that resets the [`ModRangeInput`] to its default value.
+    ///
+    /// The default is the gesture of the [`InputConfig`]. Set this after
+    /// [`input_config`], which replaces it.
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    /// [`InputConfig`]: ../../core/input_config/struct.InputConfig.html
+    /// [`input_config`]: #method.input_config
+    pub fn reset_gesture(mut self, reset_gesture: ResetGesture) -> Self {
+        self.input_config.reset = reset_gesture;
+        self
+    }
+
+    /// Sets the message to send with the default value when the
+    /// [`ModRangeInput`] is reset, instead of the message of `on_change`.
+    ///
+    /// This lets the application tell a reset apart from a plain change,
+    /// such as to record it as its own undo step.
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    pub fn on_reset<F>(mut self, on_reset: F) -> Self
+    where
+        F: 'static + Fn(Normal) -> Message,
+    {
+        self.on_reset = Some(Box::new(on_reset));
+        self
+    }
+
+    /// Sets the message to send when `Tab` is pressed while the [`ModRangeInput`]
+    /// has keyboard focus, with `true` if `Shift` is held.
+    ///
+    /// The [`ModRangeInput`] loses focus, so the application can give it to the
+    /// next (or previous) widget with [`State::focus`].
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    /// [`State::focus`]: struct.State.html#method.focus
+    pub fn on_tab<F>(mut self, on_tab: F) -> Self
+    where
+        F: 'static + Fn(bool) -> Message,
+    {
+        self.on_tab = Some(Box::new(on_tab));
+        self
+    }
+
+    /// Sets whether the [`ModRangeInput`] reuses its primitives from the previous
+    /// frame when nothing that affects its appearance has changed.
+    ///
+    /// Changes to the value, interaction state, size, and any marks or
+    /// modulation ranges are detected automatically. Call
+    /// [`State::invalidate`] after changing the style.
+    ///
+    /// The default is `false`.
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    /// [`State::invalidate`]: struct.State.html#method.invalidate
+    pub fn cache_primitives(mut self, cache_primitives: bool) -> Self {
+        self.cache_primitives = cache_primitives;
+        self
+    }
+
     /// Sets the scalar to use when the user drags the ModRangeInputs while holding down
     /// the modifier key. This is multiplied to the value set by
     /// `ModRangeInput::scalar()` (which the default is `0.001925`).
@@ -150,25 +261,101 @@ impl<'a, Message, Renderer: self::Renderer>
             normal = 1.0;
         }
 
+        self.state.drag_anchor.rebase(normal);
+
+        self.set_virtual_slider(messages, normal);
+    }
+
+    fn drag_virtual_slider(
+        &mut self,
+        messages: &mut Vec<Message>,
+        position: f32,
+        mut scalar: f32,
+    ) {
+        if self.state.pressed_modifiers.matches(self.modifier_keys) {
+            scalar *= self.modifier_scalar;
+        }
+
+        let normal = self.state.drag_anchor.drag(position, scalar);
+
+        self.set_virtual_slider(messages, normal);
+    }
+
+    fn set_virtual_slider(&mut self, messages: &mut Vec<Message>, normal: f32) {
         self.state.continuous_normal = normal;
 
         self.state.normal_param.value = normal.into();
 
         messages.push((self.on_change)(self.state.normal_param.value));
     }
+
+    fn key_pressed(
+        &mut self,
+        messages: &mut Vec<Message>,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::Modifiers,
+    ) {
+        match key_code {
+            keyboard::KeyCode::Up | keyboard::KeyCode::Right => {
+                self.move_virtual_slider(messages, -self.wheel_scalar);
+            }
+            keyboard::KeyCode::Down | keyboard::KeyCode::Left => {
+                self.move_virtual_slider(messages, self.wheel_scalar);
+            }
+            keyboard::KeyCode::Home => self.jump_to(messages, Normal::min()),
+            keyboard::KeyCode::End => self.jump_to(messages, Normal::max()),
+            keyboard::KeyCode::PageUp => self.page(messages, 1),
+            keyboard::KeyCode::PageDown => self.page(messages, -1),
+            keyboard::KeyCode::Tab => {
+                self.state.is_focused = false;
+
+                if let Some(on_tab) = &self.on_tab {
+                    messages.push((on_tab)(modifiers.shift));
+                }
+            }
+            keyboard::KeyCode::Escape => {
+                self.state.is_focused = false;
+            }
+            _ => {}
+        }
+    }
+
+    fn page(&mut self, messages: &mut Vec<Message>, direction: i32) {
+        let value = Normal::from(
+            self.state.normal_param.value.as_f32()
+                + direction as f32 * self.page_step,
+        );
+
+        self.jump_to(messages, value);
+    }
+
+    fn jump_to(&mut self, messages: &mut Vec<Message>, value: Normal) {
+        self.state.continuous_normal = value.as_f32();
+        self.state.drag_anchor.rebase(value.as_f32());
+
+        if value != self.state.normal_param.value {
+            self.state.normal_param.value = value;
+
+            messages.push((self.on_change)(value));
+        }
+    }
 }
 
 /// The local state of an [`ModRangeInput`].
 ///
 /// [`ModRangeInput`]: struct.ModRangeInput.html
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 pub struct State {
     normal_param: NormalParam,
     is_dragging: bool,
-    prev_drag_y: f32,
+    is_focused: bool,
+    drag_start: Option<Point>,
+    drag_anchor: DragAnchor,
     continuous_normal: f32,
     pressed_modifiers: keyboard::Modifiers,
     last_click: Option<mouse::Click>,
+    revision: u64,
+    draw_cache: crate::graphics::DrawCache,
 }
 
 impl State {
@@ -183,10 +370,14 @@ impl State {
         Self {
             normal_param,
             is_dragging: false,
-            prev_drag_y: 0.0,
+            is_focused: false,
+            drag_start: None,
+            drag_anchor: DragAnchor::default(),
             continuous_normal: normal_param.value.as_f32(),
             pressed_modifiers: Default::default(),
             last_click: None,
+            revision: 0,
+            draw_cache: Default::default(),
         }
     }
 
@@ -235,6 +426,55 @@ impl State {
     pub fn is_dragging(&self) -> bool {
         self.is_dragging
     }
+
+    /// Does the [`ModRangeInput`] have keyboard focus?
+    ///
+    /// While it has focus, the arrow keys, `Home`, `End`, `PageUp`, and
+    /// `PageDown` change its value. Clicking the [`ModRangeInput`] focuses it, and
+    /// clicking anywhere else, `Tab`, or `Escape` removes the focus.
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Gives keyboard focus to the [`ModRangeInput`], such as when the user tabs
+    /// to it.
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    pub fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    /// Removes keyboard focus from the [`ModRangeInput`].
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    pub fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+
+    /// Forces the [`ModRangeInput`] to regenerate its primitives on the next frame.
+    ///
+    /// This only needs to be called after changing the style of a
+    /// [`ModRangeInput`] that caches its primitives.
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    pub fn invalidate(&mut self) {
+        self.revision = self.revision.wrapping_add(1);
+    }
+
+    /// Returns the bounds of the [`ModRangeInput`] if its primitives were regenerated
+    /// when it was last drawn, or `None` if its cached primitives were
+    /// reused.
+    ///
+    /// This invalidates the whole [`ModRangeInput`]; it does not narrow down which
+    /// part of it changed. It is always `Some` unless the [`ModRangeInput`] caches
+    /// its primitives.
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    pub fn invalidated_bounds(&self) -> Option<Rectangle> {
+        self.draw_cache.invalidated_bounds()
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -275,13 +515,22 @@ where
             Event::Mouse(mouse_event) => match mouse_event {
                 mouse::Event::CursorMoved { .. } => {
                     if self.state.is_dragging {
-                        let normal_delta = (cursor_position.y
-                            - self.state.prev_drag_y)
-                            * self.scalar;
+                        if let Some(drag_start) = self.state.drag_start {
+                            if !self.input_config.drag_threshold_crossed(
+                                drag_start,
+                                cursor_position,
+                            ) {
+                                return event::Status::Captured;
+                            }
 
-                        self.state.prev_drag_y = cursor_position.y;
+                            self.state.drag_start = None;
+                        }
 
-                        self.move_virtual_slider(messages, normal_delta);
+                        self.drag_virtual_slider(
+                            messages,
+                            cursor_position.y,
+                            -self.scalar,
+                        );
 
                         return event::Status::Captured;
                     }
@@ -291,7 +540,11 @@ where
                         return event::Status::Ignored;
                     }
 
-                    if layout.bounds().contains(cursor_position) {
+                    if self
+                        .input_config
+                        .hit_bounds(layout.bounds())
+                        .contains(cursor_position)
+                    {
                         let lines = match delta {
                             iced_native::mouse::ScrollDelta::Lines {
                                 y,
@@ -320,37 +573,79 @@ where
                         }
                     }
                 }
-                mouse::Event::ButtonPressed(mouse::Button::Left) => {
-                    if layout.bounds().contains(cursor_position) {
-                        let click = mouse::Click::new(
-                            cursor_position,
-                            self.state.last_click,
-                        );
-
-                        match click.kind() {
-                            mouse::click::Kind::Single => {
+                mouse::Event::ButtonPressed(button) => {
+                    self.state.is_focused = self
+                        .input_config
+                        .hit_bounds(layout.bounds())
+                        .contains(cursor_position);
+
+                    if self
+                        .input_config
+                        .hit_bounds(layout.bounds())
+                        .contains(cursor_position)
+                    {
+                        let click_kind =
+                            if button == self.input_config.drag_button {
+                                let click = mouse::Click::new(
+                                    cursor_position,
+                                    self.state.last_click,
+                                );
+
+                                self.state.last_click = Some(click);
+
+                                click.kind()
+                            } else {
+                                mouse::click::Kind::Single
+                            };
+
+                        match self.input_config.action(
+                            button,
+                            click_kind,
+                            self.state.pressed_modifiers,
+                        ) {
+                            InputAction::Drag => {
                                 self.state.is_dragging = true;
-                                self.state.prev_drag_y = cursor_position.y;
+                                self.state.drag_start = Some(cursor_position);
+                                self.state.drag_anchor = DragAnchor::new(
+                                    cursor_position.y,
+                                    self.state.continuous_normal,
+                                );
                             }
-                            _ => {
+                            InputAction::Reset => {
                                 self.state.is_dragging = false;
+                                self.state.drag_start = None;
 
                                 self.state.normal_param.value =
                                     self.state.normal_param.default;
 
-                                messages.push((self.on_change)(
-                                    self.state.normal_param.value,
-                                ));
+                                let value = self.state.normal_param.value;
+
+                                self.state.continuous_normal = value.as_f32();
+                                self.state.drag_anchor.rebase(value.as_f32());
+
+                                messages.push(match &self.on_reset {
+                                    Some(on_reset) => (on_reset)(value),
+                                    None => (self.on_change)(value),
+                                });
+                            }
+                            InputAction::Menu => {
+                                if let Some(on_menu) = &self.on_menu {
+                                    messages.push((on_menu)());
+                                }
+                            }
+                            InputAction::None => {
+                                return event::Status::Ignored;
                             }
                         }
 
-                        self.state.last_click = Some(click);
-
                         return event::Status::Captured;
                     }
                 }
-                mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                mouse::Event::ButtonReleased(button)
+                    if button == self.input_config.drag_button =>
+                {
                     self.state.is_dragging = false;
+                    self.state.drag_start = None;
                     self.state.continuous_normal =
                         self.state.normal_param.value.as_f32();
 
@@ -359,9 +654,16 @@ where
                 _ => {}
             },
             Event::Keyboard(keyboard_event) => match keyboard_event {
-                keyboard::Event::KeyPressed { modifiers, .. } => {
+                keyboard::Event::KeyPressed {
+                    key_code,
+                    modifiers,
+                } => {
                     self.state.pressed_modifiers = modifiers;
 
+                    if self.state.is_focused || self.state.is_dragging {
+                        self.key_pressed(messages, key_code, modifiers);
+                    }
+
                     return event::Status::Captured;
                 }
                 keyboard::Event::KeyReleased { modifiers, .. } => {
@@ -369,8 +671,14 @@ where
 
                     return event::Status::Captured;
                 }
+                keyboard::Event::ModifiersChanged(modifiers) => {
+                    self.state.pressed_modifiers = modifiers;
+                }
                 _ => {}
             },
+            Event::Window(window::Event::Unfocused) => {
+                self.state.pressed_modifiers = Default::default();
+            }
             _ => {}
         }
 
@@ -385,10 +693,30 @@ where
         cursor_position: Point,
         _viewport: &Rectangle,
     ) -> Renderer::Output {
+        let revision = if self.cache_primitives {
+            Some(
+                crate::graphics::draw_cache::Revision::new(self.state.revision)
+                    .normal(self.state.normal_param.value)
+                    .normal(self.state.normal_param.default)
+                    .flag(self.state.is_dragging)
+                    .flag(self.state.is_focused)
+                    .finish(),
+            )
+        } else {
+            None
+        };
+
         renderer.draw(
-            layout.bounds(),
-            cursor_position,
-            self.state.is_dragging,
+            &DrawState {
+                bounds: layout.bounds(),
+                cursor_position,
+                normal: self.state.normal_param.value,
+                default_normal: self.state.normal_param.default,
+                is_dragging: self.state.is_dragging,
+                is_focused: self.state.is_focused,
+                draw_cache: &self.state.draw_cache,
+                revision,
+            },
             &self.style,
         )
     }
@@ -401,6 +729,30 @@ where
     }
 }
 
+/// The values a [`ModRangeInput`] is drawn with in a frame.
+///
+/// [`ModRangeInput`]: struct.ModRangeInput.html
+#[derive(Debug, Clone, Copy)]
+pub struct DrawState<'a> {
+    /// The bounds of the input
+    pub bounds: Rectangle,
+    /// The current cursor position
+    pub cursor_position: Point,
+    /// The current normal of the input
+    pub normal: Normal,
+    /// The default normal of the input, where the modulation amount
+    /// is zero
+    pub default_normal: Normal,
+    /// Whether the input is currently being dragged
+    pub is_dragging: bool,
+    /// Whether the input has keyboard focus
+    pub is_focused: bool,
+    /// The cache for the primitives of the input
+    pub draw_cache: &'a crate::graphics::DrawCache,
+    /// The revision of the input, or `None` if caching is disabled
+    pub revision: Option<u64>,
+}
+
 /// The renderer of an [`ModRangeInput`].
 ///
 /// Your renderer will need to implement this trait before being
@@ -411,20 +763,13 @@ pub trait Renderer: iced_native::Renderer {
     /// The style supported by this renderer.
     type Style: Default;
 
-    /// Draws an [`ModRangeInput`].
-    ///
-    /// It receives:
-    ///   * the bounds of the [`ModRangeInput`]
-    ///   * the current cursor position
-    ///   * whether the ModRangeInput is currently being dragged
-    ///   * the style of the [`ModRangeInput`]
+    /// Draws a [`ModRangeInput`] from its [`DrawState`] and its style.
     ///
     /// [`ModRangeInput`]: struct.ModRangeInput.html
+    /// [`DrawState`]: struct.DrawState.html
     fn draw(
         &mut self,
-        bounds: Rectangle,
-        cursor_position: Point,
-        is_dragging: bool,
+        state: &DrawState<'_>,
         style: &Self::Style,
     ) -> Self::Output;
 }
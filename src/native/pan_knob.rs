@@ -0,0 +1,293 @@
+//! Display a [`Knob`] that pans between left and right, with a readout such
+//! as `"L50"`, `"C"` or `"R50"`.
+//!
+//! [`Knob`]: ../knob/struct.Knob.html
+
+use iced_native::{keyboard, text, Color, Element, Length};
+
+use crate::core::{Formatter, InputConfig, Normal, PanFormatter, ResetGesture};
+use crate::native::knob::{self, Knob};
+use crate::native::labeled_knob::{self, LabelPlacement, LabeledKnob};
+
+static DEFAULT_CENTER_SNAP: f32 = 0.02;
+
+/// A [`Knob`] that pans between left and right, with a readout of the pan
+/// position such as `"L50"`, `"C"` or `"R50"`.
+///
+/// The value is a [`Normal`] like that of any other [`Knob`], where `0.0` is
+/// hard left, `0.5` is the center, and `1.0` is hard right. Dragging the
+/// [`Knob`] sticks lightly to the center, and by default it is drawn with a
+/// bipolar arc that fills from the center.
+///
+/// The readout is placed below the [`Knob`], under the label if there is
+/// one.
+///
+/// [`Knob`]: ../knob/struct.Knob.html
+/// [`Normal`]: ../../core/struct.Normal.html
+#[allow(missing_debug_implementations)]
+pub struct PanKnob<'a, Message, Renderer: self::Renderer> {
+    knob: Knob<'a, Message, Renderer>,
+    normal: Normal,
+    formatter: PanFormatter,
+    label: Option<String>,
+    placement: LabelPlacement,
+    spacing: Option<u16>,
+    text_size: Option<u16>,
+    text_color: Option<Color>,
+    font: Option<<Renderer as text::Renderer>::Font>,
+}
+
+impl<'a, Message, Renderer: self::Renderer> PanKnob<'a, Message, Renderer> {
+    /// Creates a new [`PanKnob`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`Knob`]
+    ///   * a function that will be called when the [`PanKnob`] is turned
+    ///
+    /// [`PanKnob`]: struct.PanKnob.html
+    /// [`State`]: ../knob/struct.State.html
+    /// [`Knob`]: ../knob/struct.Knob.html
+    pub fn new<F>(state: &'a mut knob::State, on_change: F) -> Self
+    where
+        F: 'static + Fn(Normal) -> Message,
+    {
+        let normal = state.normal();
+
+        PanKnob {
+            knob: Knob::new(state, on_change)
+                .center_snap(DEFAULT_CENTER_SNAP)
+                .style(Renderer::default_pan_knob_style()),
+            normal,
+            formatter: PanFormatter::default(),
+            label: None,
+            placement: LabelPlacement::Below,
+            spacing: None,
+            text_size: None,
+            text_color: None,
+            font: None,
+        }
+    }
+
+    /// Sets the diameter of the [`Knob`]. The default size is
+    /// `Length::from(Length::Units(31))`.
+    ///
+    /// [`Knob`]: ../knob/struct.Knob.html
+    pub fn size(mut self, size: Length) -> Self {
+        self.knob = self.knob.size(size);
+        self
+    }
+
+    /// Sets the style of the [`Knob`].
+    ///
+    /// The default style draws a bipolar arc that fills from the center.
+    ///
+    /// [`Knob`]: ../knob/struct.Knob.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as knob::Renderer>::Style>,
+    ) -> Self {
+        self.knob = self.knob.style(style);
+        self
+    }
+
+    /// Sets how far (in normalized units) on either side of the center
+    /// the value sticks to the center while the [`PanKnob`] is dragged.
+    ///
+    /// The default is `0.02`.
+    ///
+    /// [`PanKnob`]: struct.PanKnob.html
+    pub fn center_snap(mut self, center_snap: f32) -> Self {
+        self.knob = self.knob.center_snap(center_snap);
+        self
+    }
+
+    /// Sets the modifier keys of the [`PanKnob`], which enable finer
+    /// adjustments and bypass the snapping to the center.
+    ///
+    /// The default modifier key is `Ctrl`.
+    ///
+    /// [`PanKnob`]: struct.PanKnob.html
+    pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
+        self.knob = self.knob.modifier_keys(modifier_keys);
+        self
+    }
+
+    /// Sets the mouse buttons and drag threshold of the [`PanKnob`].
+    ///
+    /// The default is the global [`InputConfig`].
+    ///
+    /// [`PanKnob`]: struct.PanKnob.html
+    /// [`InputConfig`]: ../../core/input_config/struct.InputConfig.html
+    pub fn input_config(mut self, input_config: InputConfig) -> Self {
+        self.knob = self.knob.input_config(input_config);
+        self
+    }
+
+    /// Sets the gesture that resets the [`PanKnob`] to its default value.
+    ///
+    /// The default is the gesture of the [`InputConfig`]. Set this after
+    /// [`input_config`], which replaces it.
+    ///
+    /// [`PanKnob`]: struct.PanKnob.html
+    /// [`InputConfig`]: ../../core/input_config/struct.InputConfig.html
+    /// [`input_config`]: #method.input_config
+    pub fn reset_gesture(mut self, reset_gesture: ResetGesture) -> Self {
+        self.knob = self.knob.reset_gesture(reset_gesture);
+        self
+    }
+
+    /// Sets a function that will be called with the default value when the
+    /// [`PanKnob`] is reset, instead of the function given to [`new`].
+    ///
+    /// [`PanKnob`]: struct.PanKnob.html
+    /// [`new`]: #method.new
+    pub fn on_reset<F>(mut self, on_reset: F) -> Self
+    where
+        F: 'static + Fn(Normal) -> Message,
+    {
+        self.knob = self.knob.on_reset(on_reset);
+        self
+    }
+
+    /// Sets a function that will be called when the menu button is pressed
+    /// over the [`PanKnob`].
+    ///
+    /// [`PanKnob`]: struct.PanKnob.html
+    pub fn on_menu<F>(mut self, on_menu: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.knob = self.knob.on_menu(on_menu);
+        self
+    }
+
+    /// Sets the [`PanFormatter`] of the readout.
+    ///
+    /// The default shows whole percentages.
+    ///
+    /// [`PanFormatter`]: ../../core/formatter/struct.PanFormatter.html
+    pub fn formatter(mut self, formatter: PanFormatter) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the text of a label, such as `"Pan"`.
+    ///
+    /// The default is no label.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the [`LabelPlacement`] of the label. The readout is always
+    /// placed below the [`Knob`].
+    ///
+    /// The default is `LabelPlacement::Below`.
+    ///
+    /// [`LabelPlacement`]: ../labeled_knob/enum.LabelPlacement.html
+    /// [`Knob`]: ../knob/struct.Knob.html
+    pub fn placement(mut self, placement: LabelPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets the spacing in pixels between the [`Knob`] and the text.
+    ///
+    /// The default is `2`.
+    ///
+    /// [`Knob`]: ../knob/struct.Knob.html
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = Some(spacing);
+        self
+    }
+
+    /// Sets the size of the text of the [`PanKnob`].
+    ///
+    /// The default is the default text size of the renderer.
+    ///
+    /// [`PanKnob`]: struct.PanKnob.html
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the color of the text of the [`PanKnob`].
+    ///
+    /// [`PanKnob`]: struct.PanKnob.html
+    pub fn text_color(mut self, color: impl Into<Color>) -> Self {
+        self.text_color = Some(color.into());
+        self
+    }
+
+    /// Sets the font of the text of the [`PanKnob`].
+    ///
+    /// [`PanKnob`]: struct.PanKnob.html
+    pub fn font(mut self, font: <Renderer as text::Renderer>::Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Returns the readout of the pan position, such as `"L50"`, `"C"` or
+    /// `"R50"`.
+    pub fn readout(&self) -> String {
+        self.formatter.to_text(self.normal.as_f32() * 2.0 - 1.0)
+    }
+
+    fn into_labeled_knob(self) -> LabeledKnob<'a, Message, Renderer> {
+        let readout = self.readout();
+
+        let labeled_knob = match self.label {
+            Some(label) => LabeledKnob::new(self.knob, label)
+                .value_text(readout)
+                .placement(self.placement),
+            None => LabeledKnob::new(self.knob, readout)
+                .placement(LabelPlacement::Below),
+        };
+
+        let labeled_knob = match self.spacing {
+            Some(spacing) => labeled_knob.spacing(spacing),
+            None => labeled_knob,
+        };
+        let labeled_knob = match self.text_size {
+            Some(size) => labeled_knob.text_size(size),
+            None => labeled_knob,
+        };
+        let labeled_knob = match self.text_color {
+            Some(color) => labeled_knob.text_color(color),
+            None => labeled_knob,
+        };
+
+        match self.font {
+            Some(font) => labeled_knob.font(font),
+            None => labeled_knob,
+        }
+    }
+}
+
+/// The renderer of a [`PanKnob`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`PanKnob`] in your user interface.
+///
+/// [`PanKnob`]: struct.PanKnob.html
+pub trait Renderer: labeled_knob::Renderer {
+    /// Returns the default style of the [`Knob`] of a [`PanKnob`], which
+    /// should draw a bipolar arc that fills from the center.
+    ///
+    /// [`PanKnob`]: struct.PanKnob.html
+    /// [`Knob`]: ../knob/struct.Knob.html
+    fn default_pan_knob_style() -> <Self as knob::Renderer>::Style;
+}
+
+impl<'a, Message, Renderer> From<PanKnob<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        pan_knob: PanKnob<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(pan_knob.into_labeled_knob())
+    }
+}
@@ -0,0 +1,295 @@
+//! Display a scrolling history of gain reduction.
+
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+use iced_native::{
+    layout, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use crate::core::math::sanitize_dt;
+use crate::core::Normal;
+
+static DEFAULT_WIDTH: u16 = 200;
+static DEFAULT_HEIGHT: u16 = 60;
+
+/// A graph that plots the last few seconds of gain reduction as a scrolling
+/// filled area hanging down from the top, with a bar showing the current
+/// gain reduction at the right edge.
+///
+/// Gain reduction values are [`Normal`]s, where `0.0` is no reduction and
+/// `1.0` is the most reduction the graph can show. The newest values are
+/// on the right.
+///
+/// [`Normal`]: ../../core/struct.Normal.html
+#[allow(missing_debug_implementations)]
+pub struct ReductionHistory<'a, Renderer: self::Renderer> {
+    state: &'a State,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Renderer: self::Renderer> ReductionHistory<'a, Renderer> {
+    /// Creates a new [`ReductionHistory`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`ReductionHistory`]
+    ///
+    /// [`State`]: struct.State.html
+    /// [`ReductionHistory`]: struct.ReductionHistory.html
+    pub fn new(state: &'a State) -> Self {
+        ReductionHistory {
+            state,
+            width: Length::Units(DEFAULT_WIDTH),
+            height: Length::Units(DEFAULT_HEIGHT),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`ReductionHistory`].
+    /// The default width is `Length::Units(200)`.
+    ///
+    /// [`ReductionHistory`]: struct.ReductionHistory.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`ReductionHistory`].
+    /// The default height is `Length::Units(60)`.
+    ///
+    /// [`ReductionHistory`]: struct.ReductionHistory.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`ReductionHistory`].
+    ///
+    /// [`ReductionHistory`]: struct.ReductionHistory.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The local state of a [`ReductionHistory`].
+///
+/// The history is divided into a fixed number of columns, each covering an
+/// equal slice of time. Every column records the most gain reduction seen
+/// during its slice, so short spikes are never lost.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::reduction_history;
+///
+/// // 4 seconds of history in 256 columns.
+/// let mut state = reduction_history::State::new(4.0, 256);
+///
+/// // 1/8 of a second fills 8 columns.
+/// state.update(0.25.into(), 0.125);
+///
+/// assert_eq!(state.history().len(), 8);
+/// assert_eq!(state.current().as_f32(), 0.25);
+/// ```
+///
+/// [`ReductionHistory`]: struct.ReductionHistory.html
+#[derive(Debug, Clone)]
+pub struct State {
+    history: VecDeque<Normal>,
+    columns: usize,
+    column_time: f32,
+    current: Normal,
+    pending: Normal,
+    pending_time: f32,
+}
+
+impl State {
+    /// Creates a new [`ReductionHistory`] state.
+    ///
+    /// It expects:
+    ///   * the length of the history in seconds
+    ///   * the number of columns the history is divided into
+    ///
+    /// [`ReductionHistory`]: struct.ReductionHistory.html
+    pub fn new(duration: f32, columns: usize) -> Self {
+        let columns = columns.max(1);
+
+        Self {
+            history: VecDeque::with_capacity(columns),
+            columns,
+            column_time: duration.max(0.0) / columns as f32,
+            current: Normal::min(),
+            pending: Normal::min(),
+            pending_time: 0.0,
+        }
+    }
+
+    /// Feeds the current gain reduction into the history.
+    ///
+    /// * `reduction` - the current gain reduction
+    /// * `dt` - the time in seconds since the last update
+    pub fn update(&mut self, reduction: Normal, dt: f32) {
+        self.current = reduction;
+
+        if reduction.as_f32() > self.pending.as_f32() {
+            self.pending = reduction;
+        }
+
+        self.pending_time += sanitize_dt(dt);
+
+        let new_columns = if self.column_time <= 0.0 {
+            self.pending_time = 0.0;
+            1
+        } else {
+            let new_columns = (self.pending_time / self.column_time) as usize;
+            self.pending_time %= self.column_time;
+            new_columns
+        };
+
+        if new_columns == 0 {
+            return;
+        }
+
+        if new_columns > self.columns {
+            // The whole history has passed, including the column with the
+            // pending peak.
+            self.history.clear();
+            self.history.resize(self.columns, reduction);
+        } else {
+            self.push_column(self.pending);
+            for _ in 1..new_columns {
+                self.push_column(reduction);
+            }
+        }
+
+        self.pending = reduction;
+    }
+
+    fn push_column(&mut self, reduction: Normal) {
+        if self.history.len() >= self.columns {
+            let _ = self.history.pop_front();
+        }
+        self.history.push_back(reduction);
+    }
+
+    /// Returns the current gain reduction.
+    pub fn current(&self) -> Normal {
+        self.current
+    }
+
+    /// Returns the recorded gain reduction of each column, from oldest to
+    /// newest.
+    pub fn history(&self) -> &VecDeque<Normal> {
+        &self.history
+    }
+
+    /// Returns the number of columns that fit in the history.
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Removes all recorded values.
+    pub fn clear(&mut self) {
+        self.history.clear();
+        self.current = Normal::min();
+        self.pending = Normal::min();
+        self.pending_time = 0.0;
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for ReductionHistory<'a, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(
+            layout.bounds(),
+            &self.state.history,
+            self.state.columns,
+            self.state.current,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`ReductionHistory`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`ReductionHistory`] in your user interface.
+///
+/// [`ReductionHistory`]: struct.ReductionHistory.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`ReductionHistory`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`ReductionHistory`]
+    ///   * the recorded gain reduction of each column, from oldest to newest
+    ///   * the number of columns that fit in the history
+    ///   * the current gain reduction
+    ///   * the style of the [`ReductionHistory`]
+    ///
+    /// [`ReductionHistory`]: struct.ReductionHistory.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        history: &VecDeque<Normal>,
+        columns: usize,
+        current: Normal,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<ReductionHistory<'a, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        reduction_history: ReductionHistory<'a, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(reduction_history)
+    }
+}
@@ -0,0 +1,335 @@
+//! Display a scrolling spectrogram (waterfall) of magnitude spectra.
+
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+use iced_native::{
+    layout, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+static DEFAULT_HEIGHT: u16 = 100;
+
+/// The frequency axis of a [`Spectrogram`].
+///
+/// [`Spectrogram`]: struct.Spectrogram.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FrequencyAxis {
+    /// The frequency in Hz at the bottom edge.
+    ///
+    /// The default is `20.0`.
+    pub min_hz: f32,
+    /// The frequency in Hz at the top edge.
+    ///
+    /// The default is `20000.0`.
+    pub max_hz: f32,
+    /// The sample rate of the analyzed audio, in Hz. The last bin of each
+    /// column is at the Nyquist frequency (half the sample rate).
+    ///
+    /// The default is `44100.0`.
+    pub sample_rate: f32,
+    /// Whether the frequencies are spaced logarithmically (`true`) or
+    /// linearly (`false`).
+    ///
+    /// The default is `true`.
+    pub log_scale: bool,
+}
+
+impl Default for FrequencyAxis {
+    fn default() -> Self {
+        Self {
+            min_hz: 20.0,
+            max_hz: 20000.0,
+            sample_rate: 44100.0,
+            log_scale: true,
+        }
+    }
+}
+
+impl FrequencyAxis {
+    /// Returns the frequency in Hz at `position`, where `0.0` is the bottom
+    /// edge and `1.0` is the top edge.
+    pub fn frequency(&self, position: f32) -> f32 {
+        if self.log_scale && self.min_hz > 0.0 {
+            self.min_hz * (self.max_hz / self.min_hz).powf(position)
+        } else {
+            self.min_hz + (self.max_hz - self.min_hz) * position
+        }
+    }
+
+    /// Returns the (fractional) index of the bin at `position` in a column
+    /// with `num_bins` bins, where `0.0` is the bottom edge and `1.0` is the
+    /// top edge.
+    pub fn bin(&self, position: f32, num_bins: usize) -> f32 {
+        let nyquist = self.sample_rate / 2.0;
+
+        if num_bins < 2 || nyquist <= 0.0 {
+            return 0.0;
+        }
+
+        self.frequency(position) / nyquist * (num_bins - 1) as f32
+    }
+}
+
+/// A scrolling spectrogram (waterfall) display.
+///
+/// Time runs from left to right, with the most recent column at the right
+/// edge. The application pushes new magnitude columns into the [`State`],
+/// and the oldest columns scroll out once the time window is full.
+///
+/// [`State`]: struct.State.html
+#[allow(missing_debug_implementations)]
+pub struct Spectrogram<'a, Renderer: self::Renderer> {
+    state: &'a State,
+    width: Length,
+    height: Length,
+    frequency_axis: FrequencyAxis,
+    style: Renderer::Style,
+}
+
+impl<'a, Renderer: self::Renderer> Spectrogram<'a, Renderer> {
+    /// Creates a new [`Spectrogram`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`Spectrogram`]
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Spectrogram`]: struct.Spectrogram.html
+    pub fn new(state: &'a State) -> Self {
+        Spectrogram {
+            state,
+            width: Length::Fill,
+            height: Length::Units(DEFAULT_HEIGHT),
+            frequency_axis: FrequencyAxis::default(),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`Spectrogram`].
+    /// The default width is `Length::Fill`.
+    ///
+    /// [`Spectrogram`]: struct.Spectrogram.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Spectrogram`].
+    /// The default height is `Length::Units(100)`.
+    ///
+    /// [`Spectrogram`]: struct.Spectrogram.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`FrequencyAxis`] of the [`Spectrogram`].
+    ///
+    /// [`FrequencyAxis`]: struct.FrequencyAxis.html
+    /// [`Spectrogram`]: struct.Spectrogram.html
+    pub fn frequency_axis(mut self, frequency_axis: FrequencyAxis) -> Self {
+        self.frequency_axis = frequency_axis;
+        self
+    }
+
+    /// Sets the style of the [`Spectrogram`].
+    ///
+    /// [`Spectrogram`]: struct.Spectrogram.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The local state of a [`Spectrogram`].
+///
+/// [`Spectrogram`]: struct.Spectrogram.html
+#[derive(Debug)]
+pub struct State {
+    window: usize,
+    columns: VecDeque<Vec<f32>>,
+    pushed: u64,
+    revision: u64,
+    texture: crate::graphics::spectrogram::Texture,
+}
+
+impl State {
+    /// Creates a new [`Spectrogram`] state.
+    ///
+    /// It expects:
+    ///   * the time window, as the number of columns that are visible at
+    ///     once
+    ///
+    /// [`Spectrogram`]: struct.Spectrogram.html
+    pub fn new(window: usize) -> Self {
+        let window = window.max(1);
+
+        Self {
+            window,
+            columns: VecDeque::with_capacity(window),
+            pushed: 0,
+            revision: 0,
+            texture: Default::default(),
+        }
+    }
+
+    /// Pushes a new column to the right edge of the [`Spectrogram`].
+    ///
+    /// `magnitudes` are the magnitudes of the linearly spaced bins of a
+    /// spectrum, from 0 Hz up to the Nyquist frequency, each in the range
+    /// `[0.0, 1.0]`. A [`LogDBRange`] is useful for mapping decibels into
+    /// this range.
+    ///
+    /// [`Spectrogram`]: struct.Spectrogram.html
+    /// [`LogDBRange`]: ../../core/range/struct.LogDBRange.html
+    pub fn push_column(&mut self, magnitudes: &[f32]) {
+        let mut column = if self.columns.len() >= self.window {
+            // Reuse the allocation of the column that scrolls out.
+            let mut column = self.columns.pop_front().unwrap();
+            column.clear();
+            column
+        } else {
+            Vec::with_capacity(magnitudes.len())
+        };
+        column.extend_from_slice(magnitudes);
+
+        self.columns.push_back(column);
+        self.pushed = self.pushed.wrapping_add(1);
+    }
+
+    /// Removes all columns.
+    pub fn clear(&mut self) {
+        self.columns.clear();
+        self.revision = self.revision.wrapping_add(1);
+    }
+
+    /// Returns the time window, as the number of columns that are visible
+    /// at once.
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Sets the time window, as the number of columns that are visible at
+    /// once. The oldest columns are removed if there are more than fit.
+    pub fn set_window(&mut self, window: usize) {
+        self.window = window.max(1);
+
+        while self.columns.len() > self.window {
+            let _ = self.columns.pop_front();
+        }
+        self.revision = self.revision.wrapping_add(1);
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Spectrogram<'a, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(
+            &DrawState {
+                bounds: layout.bounds(),
+                columns: &self.state.columns,
+                window: self.state.window,
+                pushed: self.state.pushed,
+                frequency_axis: &self.frequency_axis,
+                texture: &self.state.texture,
+                revision: self.state.revision,
+            },
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The values a [`Spectrogram`] is drawn with in a frame.
+///
+/// [`Spectrogram`]: struct.Spectrogram.html
+#[derive(Debug, Clone, Copy)]
+pub struct DrawState<'a> {
+    /// The bounds of the spectrogram
+    pub bounds: Rectangle,
+    /// The magnitude columns, from oldest to newest
+    pub columns: &'a VecDeque<Vec<f32>>,
+    /// The time window, as the number of visible columns
+    pub window: usize,
+    /// The total number of columns pushed so far
+    pub pushed: u64,
+    /// The [`FrequencyAxis`] of the spectrogram
+    ///
+    /// [`FrequencyAxis`]: struct.FrequencyAxis.html
+    pub frequency_axis: &'a FrequencyAxis,
+    /// The texture cache of the spectrogram
+    pub texture: &'a crate::graphics::spectrogram::Texture,
+    /// The revision of the state, which changes when the columns were
+    /// cleared or the time window was changed
+    pub revision: u64,
+}
+
+/// The renderer of a [`Spectrogram`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`Spectrogram`] in your user interface.
+///
+/// [`Spectrogram`]: struct.Spectrogram.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`Spectrogram`] from its [`DrawState`] and its style.
+    ///
+    /// [`Spectrogram`]: struct.Spectrogram.html
+    /// [`DrawState`]: struct.DrawState.html
+    fn draw(
+        &mut self,
+        state: &DrawState<'_>,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Spectrogram<'a, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        spectrogram: Spectrogram<'a, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(spectrogram)
+    }
+}
@@ -0,0 +1,66 @@
+//! Helpers for fitting text into a limited space.
+
+use std::borrow::Cow;
+
+use iced_native::{text, Size};
+
+/// The string appended to text that has been truncated.
+pub const ELLIPSIS: &str = "…";
+
+/// Returns the width of `content` when drawn with the given `size` and `font`.
+pub fn text_width<Renderer: text::Renderer>(
+    renderer: &Renderer,
+    content: &str,
+    size: u16,
+    font: Renderer::Font,
+) -> f32 {
+    renderer
+        .measure(content, size, font, Size::new(f32::INFINITY, f32::INFINITY))
+        .0
+}
+
+/// Truncates `content` with an ellipsis so that it fits into `max_width`.
+///
+/// If `content` already fits, then it is returned unchanged. If not even a
+/// single character fits next to the ellipsis, then only the ellipsis is
+/// returned.
+pub fn fit_text<'a, Renderer: text::Renderer>(
+    renderer: &Renderer,
+    content: &'a str,
+    size: u16,
+    font: Renderer::Font,
+    max_width: f32,
+) -> Cow<'a, str> {
+    if content.is_empty()
+        || text_width(renderer, content, size, font) <= max_width
+    {
+        return Cow::Borrowed(content);
+    }
+
+    let char_starts: Vec<usize> =
+        content.char_indices().map(|(i, _)| i).collect();
+
+    let truncated = |num_chars: usize| {
+        let mut text =
+            String::from(content[..char_starts[num_chars]].trim_end());
+        text.push_str(ELLIPSIS);
+        text
+    };
+
+    // The full text does not fit, so search for the longest prefix that
+    // still fits next to the ellipsis.
+    let mut lo = 0;
+    let mut hi = char_starts.len();
+
+    while hi - lo > 1 {
+        let mid = (lo + hi) / 2;
+
+        if text_width(renderer, &truncated(mid), size, font) <= max_width {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    Cow::Owned(truncated(lo))
+}
@@ -2,7 +2,9 @@
 
 use std::fmt::Debug;
 
-use crate::core::Normal;
+use crate::core::{
+    meter_layout, KSystem, LogDBRange, MeterCalibration, Normal,
+};
 
 /// A group of text marks.
 #[derive(Debug, Clone)]
@@ -152,6 +154,55 @@ impl Group {
         vec.into()
     }
 
+    /// Creates a group of text marks labeling every multiple of `step` on
+    /// the calibrated scale of a meter, lined up with a meter that uses the
+    /// same [`LogDBRange`]. Positive readings are prefixed with `+`.
+    ///
+    /// * `range` - the range of the meter in dBFS
+    /// * `calibration` - the [`MeterCalibration`] of the scale
+    /// * `step` - the spacing of the labels in scale units
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `step` <= `0.0`
+    ///
+    /// [`LogDBRange`]: ../../core/range/struct.LogDBRange.html
+    /// [`MeterCalibration`]: ../../core/meter_calibration/struct.MeterCalibration.html
+    pub fn from_meter_scale(
+        range: &LogDBRange,
+        calibration: &MeterCalibration,
+        step: f32,
+    ) -> Self {
+        meter_layout::scale_marks(range, calibration, step)
+            .into_iter()
+            .map(|(position, value)| {
+                let text = if value > 0.0 {
+                    format!("+{}", value)
+                } else if value < 0.0 {
+                    format!("{}", value)
+                } else {
+                    String::from("0")
+                };
+
+                (position, text)
+            })
+            .collect::<Vec<(Normal, String)>>()
+            .into()
+    }
+
+    /// Creates a group of text marks for a [`KSystem`] scale, labeling the
+    /// `0` point and every 4 dB above and below it.
+    ///
+    /// * `k_system` - the [`KSystem`] of the scale
+    /// * `range` - the range of the meter in dBFS, such as
+    ///   [`KSystem::range`]
+    ///
+    /// [`KSystem`]: ../../core/meter_calibration/enum.KSystem.html
+    /// [`KSystem::range`]: ../../core/meter_calibration/enum.KSystem.html#method.range
+    pub fn from_k_system(k_system: KSystem, range: &LogDBRange) -> Self {
+        Self::from_meter_scale(range, &k_system.calibration(), 4.0)
+    }
+
     /// Returns the hashed value of the internal data.
     pub(crate) fn hashed(&self) -> u64 {
         self.hashed
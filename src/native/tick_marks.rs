@@ -2,7 +2,9 @@
 
 use std::fmt::Debug;
 
-use crate::core::Normal;
+use crate::core::{
+    meter_layout, KSystem, LogDBRange, MeterCalibration, Normal,
+};
 
 /// A group of tick marks.
 ///
@@ -27,41 +29,20 @@ impl Group {
     ///
     /// [`Group`]: struct.Group.html
     pub fn from_normalized(tick_marks: &[(Normal, Tier)]) -> Self {
-        use std::hash::{Hash, Hasher};
-        let mut hasher = iced_native::Hasher::default();
-        tick_marks.len().hash(&mut hasher);
-
-        let len = tick_marks.len();
-
-        let mut tier_1_positions: Vec<Normal> = Vec::new();
-        let mut tier_2_positions: Vec<Normal> = Vec::new();
-        let mut tier_3_positions: Vec<Normal> = Vec::new();
+        let mut group = Self {
+            tier_1_positions: Vec::new(),
+            tier_2_positions: Vec::new(),
+            tier_3_positions: Vec::new(),
+            len: 0,
+            hashed: 0,
+        };
 
         for tick_mark in tick_marks.iter() {
-            tick_mark.1.hash(&mut hasher);
-            // Rust can't hash an f32 value.
-            ((tick_mark.0.as_f32() * 10000000.0) as u64).hash(&mut hasher);
-
-            match tick_mark.1 {
-                Tier::One => {
-                    tier_1_positions.push(tick_mark.0);
-                }
-                Tier::Two => {
-                    tier_2_positions.push(tick_mark.0);
-                }
-                Tier::Three => {
-                    tier_3_positions.push(tick_mark.0);
-                }
-            }
+            group.positions_mut(tick_mark.1).push(tick_mark.0);
         }
 
-        Self {
-            tier_1_positions,
-            tier_2_positions,
-            tier_3_positions,
-            len,
-            hashed: hasher.finish(),
-        }
+        group.update();
+        group
     }
 
     /// Returns a new [`Group`] with a single tick mark in
@@ -194,6 +175,66 @@ impl Group {
         Self::from_normalized(&tick_marks)
     }
 
+    /// Creates a [`Group`] with a tick mark at every multiple of `step` on
+    /// the calibrated scale of a meter, lined up with a meter that uses the
+    /// same [`LogDBRange`].
+    ///
+    /// * `range` - the range of the meter in dBFS
+    /// * `calibration` - the [`MeterCalibration`] of the scale
+    /// * `step` - the spacing of the tick marks in scale units
+    /// * `tier` - the [`Tier`] of the tick marks
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `step` <= `0.0`
+    ///
+    /// [`Group`]: struct.Group.html
+    /// [`LogDBRange`]: ../../core/range/struct.LogDBRange.html
+    /// [`MeterCalibration`]: ../../core/meter_calibration/struct.MeterCalibration.html
+    /// [`Tier`]: enum.Tier.html
+    pub fn from_meter_scale(
+        range: &LogDBRange,
+        calibration: &MeterCalibration,
+        step: f32,
+        tier: Tier,
+    ) -> Self {
+        let tick_marks: Vec<(Normal, Tier)> =
+            meter_layout::scale_marks(range, calibration, step)
+                .into_iter()
+                .map(|(position, _)| (position, tier))
+                .collect();
+
+        Self::from_normalized(&tick_marks)
+    }
+
+    /// Creates a [`Group`] for a [`KSystem`] scale, with a [`Tier::One`]
+    /// tick mark at the `0` point and [`Tier::Two`] tick marks every 4 dB.
+    ///
+    /// * `k_system` - the [`KSystem`] of the scale
+    /// * `range` - the range of the meter in dBFS, such as
+    ///   [`KSystem::range`]
+    ///
+    /// [`Group`]: struct.Group.html
+    /// [`KSystem`]: ../../core/meter_calibration/enum.KSystem.html
+    /// [`KSystem::range`]: ../../core/meter_calibration/enum.KSystem.html#method.range
+    /// [`Tier::One`]: enum.Tier.html#variant.One
+    /// [`Tier::Two`]: enum.Tier.html#variant.Two
+    pub fn from_k_system(k_system: KSystem, range: &LogDBRange) -> Self {
+        let tick_marks: Vec<(Normal, Tier)> =
+            meter_layout::scale_marks(range, &k_system.calibration(), 4.0)
+                .into_iter()
+                .map(|(position, value)| {
+                    if value == 0.0 {
+                        (position, Tier::One)
+                    } else {
+                        (position, Tier::Two)
+                    }
+                })
+                .collect();
+
+        Self::from_normalized(&tick_marks)
+    }
+
     /// Returns the positions of the tier 1 tick marks.
     /// Returns `None` if there are no tier 1 tick marks.
     pub fn tier_1(&self) -> Option<&Vec<Normal>> {
@@ -229,6 +270,176 @@ impl Group {
         self.len
     }
 
+    /// Returns `true` if there are no tick marks.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns an iterator over the position and [`Tier`] of every tick mark.
+    ///
+    /// The tier 1 tick marks come first, followed by the tier 2 and tier 3
+    /// tick marks.
+    ///
+    /// [`Tier`]: enum.Tier.html
+    pub fn iter(&self) -> impl Iterator<Item = (Normal, Tier)> + '_ {
+        fn tier(
+            positions: &[Normal],
+            tier: Tier,
+        ) -> impl Iterator<Item = (Normal, Tier)> + '_ {
+            positions.iter().map(move |position| (*position, tier))
+        }
+
+        tier(&self.tier_1_positions, Tier::One)
+            .chain(tier(&self.tier_2_positions, Tier::Two))
+            .chain(tier(&self.tier_3_positions, Tier::Three))
+    }
+
+    /// Returns the position of the tick mark closest to `normal`, or `None`
+    /// if there are no tick marks.
+    pub fn nearest(&self, normal: Normal) -> Option<Normal> {
+        let mut nearest: Option<Normal> = None;
+
+        for (position, _) in self.iter() {
+            let closer = match nearest {
+                Some(n) => {
+                    (position.as_f32() - normal.as_f32()).abs()
+                        < (n.as_f32() - normal.as_f32()).abs()
+                }
+                None => true,
+            };
+
+            if closer {
+                nearest = Some(position);
+            }
+        }
+
+        nearest
+    }
+
+    /// Adds a tick mark.
+    ///
+    /// * `position` - the position of the tick mark
+    /// * `tier` - the [`Tier`] of the tick mark
+    ///
+    /// [`Tier`]: enum.Tier.html
+    pub fn push(&mut self, position: Normal, tier: Tier) {
+        self.positions_mut(tier).push(position);
+        self.update();
+    }
+
+    /// Removes the first tick mark with the given position and [`Tier`].
+    ///
+    /// Returns `true` if a tick mark was removed.
+    ///
+    /// [`Tier`]: enum.Tier.html
+    pub fn remove(&mut self, position: Normal, tier: Tier) -> bool {
+        let positions = self.positions_mut(tier);
+
+        if let Some(i) = positions.iter().position(|p| *p == position) {
+            let _ = positions.remove(i);
+            self.update();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Retains only the tick marks for which `f` returns `true`.
+    pub fn retain<F: FnMut(Normal, Tier) -> bool>(&mut self, mut f: F) {
+        self.tier_1_positions.retain(|p| f(*p, Tier::One));
+        self.tier_2_positions.retain(|p| f(*p, Tier::Two));
+        self.tier_3_positions.retain(|p| f(*p, Tier::Three));
+        self.update();
+    }
+
+    /// Removes all tick marks.
+    pub fn clear(&mut self) {
+        self.tier_1_positions.clear();
+        self.tier_2_positions.clear();
+        self.tier_3_positions.clear();
+        self.update();
+    }
+
+    /// Sorts the tick marks of each [`Tier`] by position, from `0.0` to `1.0`.
+    ///
+    /// [`Tier`]: enum.Tier.html
+    pub fn sort(&mut self) {
+        let sort = |positions: &mut Vec<Normal>| {
+            positions.sort_by(|a, b| {
+                a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal)
+            })
+        };
+
+        sort(&mut self.tier_1_positions);
+        sort(&mut self.tier_2_positions);
+        sort(&mut self.tier_3_positions);
+        self.update();
+    }
+
+    /// Sorts the tick marks and removes the ones that share a position with
+    /// another tick mark.
+    ///
+    /// When tick marks of different tiers share a position, the one with the
+    /// largest size is kept.
+    ///
+    /// ```
+    /// use iced_audio::tick_marks::{Group, Tier};
+    /// use iced_audio::Normal;
+    ///
+    /// let mut group = Group::min_max(Tier::Two);
+    /// group.push(Normal::max(), Tier::One);
+    /// group.push(Normal::min(), Tier::Two);
+    /// group.dedup();
+    ///
+    /// assert_eq!(
+    ///     group.iter().collect::<Vec<_>>(),
+    ///     vec![(Normal::max(), Tier::One), (Normal::min(), Tier::Two)]
+    /// );
+    /// ```
+    pub fn dedup(&mut self) {
+        self.sort();
+
+        self.tier_1_positions.dedup();
+        self.tier_2_positions.dedup();
+        self.tier_3_positions.dedup();
+
+        let tier_1 = &self.tier_1_positions;
+        self.tier_2_positions.retain(|p| !tier_1.contains(p));
+
+        let tier_2 = &self.tier_2_positions;
+        self.tier_3_positions
+            .retain(|p| !tier_1.contains(p) && !tier_2.contains(p));
+
+        self.update();
+    }
+
+    fn positions_mut(&mut self, tier: Tier) -> &mut Vec<Normal> {
+        match tier {
+            Tier::One => &mut self.tier_1_positions,
+            Tier::Two => &mut self.tier_2_positions,
+            Tier::Three => &mut self.tier_3_positions,
+        }
+    }
+
+    /// Updates the length and the hash after the tick marks have changed.
+    fn update(&mut self) {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = iced_native::Hasher::default();
+
+        self.len = self.tier_1_positions.len()
+            + self.tier_2_positions.len()
+            + self.tier_3_positions.len();
+        self.len.hash(&mut hasher);
+
+        for (position, tier) in self.iter() {
+            tier.hash(&mut hasher);
+            // Rust can't hash an f32 value.
+            ((position.as_f32() * 10000000.0) as u64).hash(&mut hasher);
+        }
+
+        self.hashed = hasher.finish();
+    }
+
     /// Returns the hashed value of the internal data.
     pub(crate) fn hashed(&self) -> u64 {
         self.hashed
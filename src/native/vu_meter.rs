@@ -0,0 +1,358 @@
+//! Display a VU meter with standard volume indicator ballistics.
+
+use std::hash::Hash;
+
+use iced_native::{
+    layout, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use crate::core::math::sanitize_dt;
+use crate::core::{MeterCalibration, Normal};
+
+static DEFAULT_WIDTH: u16 = 120;
+static DEFAULT_HEIGHT: u16 = 80;
+
+/// The time in seconds a [`VuMeter`] takes to reach 99% of a steady input
+/// level.
+///
+/// [`VuMeter`]: struct.VuMeter.html
+pub const INTEGRATION_TIME: f32 = 0.3;
+
+/// The damping ratio of the needle, which gives the standard overshoot of
+/// about 1.3%.
+const DAMPING: f32 = 0.81;
+/// The natural frequency of a needle with [`DAMPING`] that first reaches 99%
+/// of a step after one unit of time.
+const RISE_99: f32 = 4.0266;
+/// The longest time step of the simulation, so that long or irregular
+/// frames stay stable.
+const MAX_STEP: f32 = 0.001;
+/// The time after which the needle has settled on a steady level. Longer
+/// updates (such as after the computer wakes from sleep) jump straight to
+/// the level instead of simulating every step.
+const SETTLE_TIME: f32 = 4.0 * INTEGRATION_TIME;
+
+/// A VU meter with a needle or a bar.
+///
+/// Unlike an instantaneous level meter, the levels pushed into the
+/// [`State`] are smoothed with the ballistics of a standard volume
+/// indicator: the needle rises to 99% of a steady level in 300 ms with a
+/// slight overshoot, and falls back just as fast.
+///
+/// The scale is linear in amplitude, like an analog VU meter, so `0 VU`
+/// sits at about 70% of the default `-20` to `+3` VU range.
+///
+/// [`State`]: struct.State.html
+#[allow(missing_debug_implementations)]
+pub struct VuMeter<'a, Renderer: self::Renderer> {
+    state: &'a State,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Renderer: self::Renderer> VuMeter<'a, Renderer> {
+    /// Creates a new [`VuMeter`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`VuMeter`]
+    ///
+    /// [`State`]: struct.State.html
+    /// [`VuMeter`]: struct.VuMeter.html
+    pub fn new(state: &'a State) -> Self {
+        VuMeter {
+            state,
+            width: Length::Units(DEFAULT_WIDTH),
+            height: Length::Units(DEFAULT_HEIGHT),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`VuMeter`].
+    /// The default width is `Length::Units(120)`.
+    ///
+    /// [`VuMeter`]: struct.VuMeter.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`VuMeter`].
+    /// The default height is `Length::Units(80)`.
+    ///
+    /// [`VuMeter`]: struct.VuMeter.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`VuMeter`].
+    ///
+    /// [`VuMeter`]: struct.VuMeter.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The local state of a [`VuMeter`].
+///
+/// Push raw levels with [`update`] as they arrive from the audio thread,
+/// and the state applies the ballistics. Raw levels are converted to VU
+/// with the [`MeterCalibration`] of the state, which shows them unchanged
+/// by default. Use [`set_calibration`] to pick a reference level such as
+/// `0 VU = -18 dBFS`.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{vu_meter, MeterCalibration};
+///
+/// let mut state = vu_meter::State::default();
+/// state.set_calibration(MeterCalibration::new(-18.0));
+///
+/// // A steady -18 dBFS tone, pushed once per 60 Hz frame.
+/// for _ in 0..18 {
+///     state.update(-18.0, 1.0 / 60.0);
+/// }
+///
+/// assert!(state.db() > -0.1);
+/// ```
+///
+/// [`VuMeter`]: struct.VuMeter.html
+/// [`update`]: #method.update
+/// [`set_calibration`]: #method.set_calibration
+/// [`MeterCalibration`]: ../../core/meter_calibration/struct.MeterCalibration.html
+#[derive(Debug, Copy, Clone)]
+pub struct State {
+    calibration: MeterCalibration,
+    min_db: f32,
+    max_db: f32,
+    min_amp: f32,
+    max_amp: f32,
+    target: f32,
+    amplitude: f32,
+    velocity: f32,
+}
+
+impl State {
+    /// Creates a new [`VuMeter`] state.
+    ///
+    /// It expects:
+    ///   * the level in VU at the left end of the scale
+    ///   * the level in VU at the right end of the scale
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `max_db` <= `min_db`
+    ///
+    /// [`VuMeter`]: struct.VuMeter.html
+    pub fn new(min_db: f32, max_db: f32) -> Self {
+        assert!(max_db > min_db, "max_db must be greater than min_db");
+
+        Self {
+            calibration: MeterCalibration::DBFS,
+            min_db,
+            max_db,
+            min_amp: db_to_amplitude(min_db),
+            max_amp: db_to_amplitude(max_db),
+            target: 0.0,
+            amplitude: 0.0,
+            velocity: 0.0,
+        }
+    }
+
+    /// Feeds a raw level into the meter and advances the ballistics.
+    ///
+    /// * `db` - the raw level in dBFS. `NaN` and positive infinity are
+    ///   ignored, and the needle keeps moving towards the previous level.
+    /// * `dt` - the time in seconds since the last update. A `dt` of four
+    ///   times the [`INTEGRATION_TIME`] or more moves the needle straight to
+    ///   the level.
+    ///
+    /// [`INTEGRATION_TIME`]: constant.INTEGRATION_TIME.html
+    pub fn update(&mut self, db: f32, dt: f32) {
+        if db.is_nan() || db == f32::INFINITY {
+            log::debug!(
+                "VuMeter received {} dB, keeping the previous level",
+                db
+            );
+        } else {
+            self.target = db_to_amplitude(self.calibration.to_scale(db));
+        }
+
+        let target = self.target;
+        let omega = RISE_99 / INTEGRATION_TIME;
+
+        let dt = sanitize_dt(dt);
+
+        if dt >= SETTLE_TIME {
+            self.amplitude = target;
+            self.velocity = 0.0;
+            return;
+        }
+
+        let steps = (dt / MAX_STEP).ceil() as u32;
+        let step = if steps > 0 { dt / steps as f32 } else { 0.0 };
+
+        for _ in 0..steps {
+            let accel = omega * omega * (target - self.amplitude)
+                - 2.0 * DAMPING * omega * self.velocity;
+
+            self.velocity += accel * step;
+            self.amplitude = (self.amplitude + self.velocity * step).max(0.0);
+        }
+    }
+
+    /// Returns the current level of the needle in VU.
+    pub fn db(&self) -> f32 {
+        if self.amplitude > 0.0 {
+            20.0 * self.amplitude.log10()
+        } else {
+            f32::NEG_INFINITY
+        }
+    }
+
+    /// Returns the current position of the needle on the scale.
+    pub fn normal(&self) -> Normal {
+        self.amplitude_to_normal(self.amplitude)
+    }
+
+    /// Returns the position of `0 VU` on the scale.
+    pub fn zero_position(&self) -> Normal {
+        self.amplitude_to_normal(1.0)
+    }
+
+    /// Returns the level in VU at the left end of the scale.
+    pub fn min_db(&self) -> f32 {
+        self.min_db
+    }
+
+    /// Returns the level in VU at the right end of the scale.
+    pub fn max_db(&self) -> f32 {
+        self.max_db
+    }
+
+    /// Sets the [`MeterCalibration`] that converts raw levels to VU.
+    ///
+    /// [`MeterCalibration`]: ../../core/meter_calibration/struct.MeterCalibration.html
+    pub fn set_calibration(&mut self, calibration: MeterCalibration) {
+        self.calibration = calibration;
+    }
+
+    /// Returns the [`MeterCalibration`] that converts raw levels to VU.
+    ///
+    /// [`MeterCalibration`]: ../../core/meter_calibration/struct.MeterCalibration.html
+    pub fn calibration(&self) -> MeterCalibration {
+        self.calibration
+    }
+
+    /// Drops the needle back to rest immediately.
+    pub fn reset(&mut self) {
+        self.target = 0.0;
+        self.amplitude = 0.0;
+        self.velocity = 0.0;
+    }
+
+    fn amplitude_to_normal(&self, amplitude: f32) -> Normal {
+        ((amplitude - self.min_amp) / (self.max_amp - self.min_amp)).into()
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State::new(-20.0, 3.0)
+    }
+}
+
+fn db_to_amplitude(db: f32) -> f32 {
+    10.0_f32.powf(db / 20.0)
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for VuMeter<'a, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(
+            layout.bounds(),
+            self.state.normal(),
+            self.state.zero_position(),
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`VuMeter`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`VuMeter`] in your user interface.
+///
+/// [`VuMeter`]: struct.VuMeter.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`VuMeter`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`VuMeter`]
+    ///   * the position of the needle on the scale
+    ///   * the position of `0 VU` on the scale
+    ///   * the style of the [`VuMeter`]
+    ///
+    /// [`VuMeter`]: struct.VuMeter.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        normal: Normal,
+        zero_position: Normal,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<VuMeter<'a, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(vu_meter: VuMeter<'a, Renderer>) -> Element<'a, Message, Renderer> {
+        Element::new(vu_meter)
+    }
+}
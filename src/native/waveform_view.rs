@@ -0,0 +1,335 @@
+//! Display a zoomable and scrollable waveform of an audio clip.
+
+use std::hash::Hash;
+
+use iced_native::{
+    event, keyboard, layout, window, Clipboard, Element, Event, Hasher, Layout,
+    Length, Point, Rectangle, Size, Widget,
+};
+
+use crate::core::{Viewport, ViewportGestures};
+
+static DEFAULT_HEIGHT: u16 = 100;
+
+/// A waveform display of an audio clip.
+///
+/// The waveform is drawn from min/max peak data, where each peak is the
+/// `(min, max)` sample value in the range `[-1.0, 1.0]` of a consecutive
+/// block of samples of the clip.
+///
+/// The mouse wheel scrolls the view horizontally, and zooms in and out around
+/// the cursor while the zoom modifier keys are held down. Dragging with the
+/// middle mouse button pans the view. See [`ViewportGestures`] for the
+/// details.
+///
+/// [`ViewportGestures`]: ../../core/viewport/struct.ViewportGestures.html
+#[allow(missing_debug_implementations)]
+pub struct WaveformView<'a, Renderer: self::Renderer> {
+    state: &'a mut State,
+    peaks: &'a [(f32, f32)],
+    width: Length,
+    height: Length,
+    gestures: ViewportGestures,
+    style: Renderer::Style,
+}
+
+impl<'a, Renderer: self::Renderer> WaveformView<'a, Renderer> {
+    /// Creates a new [`WaveformView`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`WaveformView`]
+    ///   * the `(min, max)` peak data of the whole clip
+    ///
+    /// [`State`]: struct.State.html
+    /// [`WaveformView`]: struct.WaveformView.html
+    pub fn new(state: &'a mut State, peaks: &'a [(f32, f32)]) -> Self {
+        WaveformView {
+            state,
+            peaks,
+            width: Length::Fill,
+            height: Length::Units(DEFAULT_HEIGHT),
+            gestures: ViewportGestures::default(),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`WaveformView`].
+    /// The default width is `Length::Fill`.
+    ///
+    /// [`WaveformView`]: struct.WaveformView.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`WaveformView`].
+    /// The default height is `Length::Units(100)`.
+    ///
+    /// [`WaveformView`]: struct.WaveformView.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`WaveformView`].
+    ///
+    /// [`WaveformView`]: struct.WaveformView.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the maximum zoom of the [`WaveformView`], where `1.0` shows the
+    /// whole clip and `2.0` shows half of it.
+    ///
+    /// The default maximum zoom is `64.0`.
+    ///
+    /// [`WaveformView`]: struct.WaveformView.html
+    pub fn max_zoom(mut self, max_zoom: f32) -> Self {
+        self.gestures.max_zoom = max_zoom.max(1.0);
+        self
+    }
+
+    /// Sets the modifier keys that make the mouse wheel zoom the
+    /// [`WaveformView`] instead of scrolling it.
+    ///
+    /// The default modifier key is `Ctrl`.
+    ///
+    /// [`WaveformView`]: struct.WaveformView.html
+    pub fn zoom_keys(mut self, zoom_keys: keyboard::Modifiers) -> Self {
+        self.gestures.zoom_keys = zoom_keys;
+        self
+    }
+
+    /// Sets the mouse gestures that zoom and scroll the [`WaveformView`],
+    /// including their sensitivity.
+    ///
+    /// The default is `ViewportGestures::default()`.
+    ///
+    /// [`WaveformView`]: struct.WaveformView.html
+    pub fn gestures(mut self, gestures: ViewportGestures) -> Self {
+        self.gestures = gestures;
+        self
+    }
+}
+
+/// The local state of a [`WaveformView`].
+///
+/// [`WaveformView`]: struct.WaveformView.html
+#[derive(Debug, Copy, Clone)]
+pub struct State {
+    viewport: Viewport,
+    pressed_modifiers: keyboard::Modifiers,
+}
+
+impl State {
+    /// Creates a new [`WaveformView`] state that shows the whole clip.
+    ///
+    /// [`WaveformView`]: struct.WaveformView.html
+    pub fn new() -> Self {
+        Self {
+            viewport: Viewport::new(),
+            pressed_modifiers: Default::default(),
+        }
+    }
+
+    /// Returns the [`Viewport`] of the [`WaveformView`].
+    ///
+    /// [`Viewport`]: ../../core/viewport/struct.Viewport.html
+    /// [`WaveformView`]: struct.WaveformView.html
+    pub fn viewport(&self) -> &Viewport {
+        &self.viewport
+    }
+
+    /// Returns the [`Viewport`] of the [`WaveformView`] for editing, for
+    /// example to keep it in sync with another timeline.
+    ///
+    /// [`Viewport`]: ../../core/viewport/struct.Viewport.html
+    /// [`WaveformView`]: struct.WaveformView.html
+    pub fn viewport_mut(&mut self) -> &mut Viewport {
+        &mut self.viewport
+    }
+
+    /// Returns the zoom of the [`WaveformView`], where `1.0` shows the whole
+    /// clip and `2.0` shows half of it.
+    ///
+    /// [`WaveformView`]: struct.WaveformView.html
+    pub fn zoom(&self) -> f32 {
+        self.viewport.zoom()
+    }
+
+    /// Sets the zoom of the [`WaveformView`], keeping the start of the
+    /// visible range in place. The zoom is clamped to be at least `1.0`.
+    ///
+    /// [`WaveformView`]: struct.WaveformView.html
+    pub fn set_zoom(&mut self, zoom: f32) {
+        self.viewport.set_zoom(zoom);
+    }
+
+    /// Returns the start of the visible range, as a fraction of the whole
+    /// clip.
+    pub fn offset(&self) -> f32 {
+        self.viewport.offset()
+    }
+
+    /// Scrolls the [`WaveformView`] so that the visible range starts at
+    /// `offset`, as a fraction of the whole clip. The offset is clamped so
+    /// the visible range stays inside the clip.
+    ///
+    /// [`WaveformView`]: struct.WaveformView.html
+    pub fn set_offset(&mut self, offset: f32) {
+        self.viewport.set_offset(offset);
+    }
+
+    /// Returns the `(start, end)` of the visible range, as fractions of the
+    /// whole clip.
+    pub fn visible_range(&self) -> (f32, f32) {
+        self.viewport.visible_range()
+    }
+
+    /// Zooms by `factor` while keeping the point at `anchor` in place, where
+    /// `anchor` is a position in the view from `0.0` (left) to `1.0` (right).
+    pub fn zoom_at(&mut self, factor: f32, anchor: f32) {
+        self.viewport.zoom_at(factor, anchor);
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for WaveformView<'a, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _messages: &mut Vec<Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse_event) => {
+                return self.state.viewport.on_mouse_event(
+                    mouse_event,
+                    layout.bounds(),
+                    cursor_position,
+                    &self.gestures,
+                    self.state.pressed_modifiers,
+                );
+            }
+            Event::Keyboard(keyboard_event) => match keyboard_event {
+                keyboard::Event::KeyPressed { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+                }
+                keyboard::Event::KeyReleased { modifiers, .. } => {
+                    self.state.pressed_modifiers = modifiers;
+                }
+                keyboard::Event::ModifiersChanged(modifiers) => {
+                    self.state.pressed_modifiers = modifiers;
+                }
+                _ => {}
+            },
+            Event::Window(window::Event::Unfocused) => {
+                self.state.pressed_modifiers = Default::default();
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(
+            layout.bounds(),
+            cursor_position,
+            self.peaks,
+            self.state.visible_range(),
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`WaveformView`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`WaveformView`] in your user interface.
+///
+/// [`WaveformView`]: struct.WaveformView.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`WaveformView`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`WaveformView`]
+    ///   * the current cursor position
+    ///   * the `(min, max)` peak data of the whole clip
+    ///   * the `(start, end)` of the visible range, as fractions of the clip
+    ///   * the style of the [`WaveformView`]
+    ///
+    /// [`WaveformView`]: struct.WaveformView.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        peaks: &[(f32, f32)],
+        visible_range: (f32, f32),
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<WaveformView<'a, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        waveform_view: WaveformView<'a, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(waveform_view)
+    }
+}
@@ -4,18 +4,141 @@
 //! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
 
 use std::fmt::Debug;
+use std::time::{Duration, Instant};
 
 use iced_native::{
-    event, keyboard, layout, mouse, Clipboard, Element, Event, Hasher, Layout,
-    Length, Point, Rectangle, Size, Widget,
+    event, keyboard, layout, mouse, window, Clipboard, Color, Element, Event,
+    Hasher, Layout, Length, Point, Rectangle, Size, Widget,
 };
 
 use std::hash::Hash;
 
-use crate::core::{Normal, NormalParam};
+use crate::core::input_config::{DragAnchor, InputAction};
+use crate::core::{InputConfig, Normal, NormalParam, ResetGesture};
+use crate::native::{text_marks, tick_marks};
 use crate::IntRange;
 
 static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+static DEFAULT_KEY_STEP: f32 = 0.01;
+static DEFAULT_SNAPSHOT_SIZE: u16 = 14;
+
+/// How long a snapshot corner must be held down to store the current values
+/// instead of recalling them.
+const DEFAULT_STORE_HOLD: Duration = Duration::from_millis(600);
+
+/// If the cursor rests for longer than this before the drag button is
+/// released, the [`XYPad`] will not be thrown.
+///
+/// [`XYPad`]: struct.XYPad.html
+const THROW_TIMEOUT: Duration = Duration::from_millis(60);
+
+/// A glide slower than this (in normals per second) comes to a stop.
+const MIN_GLIDE_SPEED: f32 = 0.01;
+
+/// The physics of a thrown [`XYPad`] handle.
+///
+/// When set, releasing a drag while the cursor is still moving lets the
+/// handle glide on and decelerate. Call [`State::glide`] periodically (for
+/// example from a timer subscription) to advance the glide.
+///
+/// [`XYPad`]: struct.XYPad.html
+/// [`State::glide`]: struct.State.html#method.glide
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Throw {
+    /// How quickly the handle decelerates. The velocity is multiplied by
+    /// `e^(-friction * seconds)`, so higher values stop the handle sooner.
+    ///
+    /// The default is `4.0`.
+    pub friction: f32,
+    /// The fraction of velocity kept when the handle bounces off an edge.
+    /// `0.0` stops the handle at the edge, and `1.0` is a perfectly
+    /// elastic bounce.
+    ///
+    /// The default is `0.5`.
+    pub bounce: f32,
+}
+
+impl Default for Throw {
+    fn default() -> Self {
+        Self {
+            friction: 4.0,
+            bounce: 0.5,
+        }
+    }
+}
+
+/// A corner of an [`XYPad`] that holds a snapshot of its values.
+///
+/// [`XYPad`]: struct.XYPad.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Corner {
+    /// The top left corner
+    TopLeft,
+    /// The top right corner
+    TopRight,
+    /// The bottom left corner
+    BottomLeft,
+    /// The bottom right corner
+    BottomRight,
+}
+
+impl Corner {
+    /// All corners, in the order of the snapshots passed to the
+    /// [`Renderer`].
+    ///
+    /// [`Renderer`]: trait.Renderer.html
+    pub const ALL: [Corner; 4] = [
+        Corner::TopLeft,
+        Corner::TopRight,
+        Corner::BottomLeft,
+        Corner::BottomRight,
+    ];
+
+    fn index(self) -> usize {
+        match self {
+            Corner::TopLeft => 0,
+            Corner::TopRight => 1,
+            Corner::BottomLeft => 2,
+            Corner::BottomRight => 3,
+        }
+    }
+
+    /// Returns the corner of the square `bounds` whose snapshot area of
+    /// `size` pixels contains `point`, if any.
+    fn at(bounds: Rectangle, size: f32, point: Point) -> Option<Self> {
+        if !bounds.contains(point) {
+            return None;
+        }
+
+        let left = point.x < bounds.x + size;
+        let right = point.x >= bounds.x + bounds.width - size;
+        let top = point.y < bounds.y + size;
+        let bottom = point.y >= bounds.y + bounds.height - size;
+
+        match (left, right, top, bottom) {
+            (true, _, true, _) => Some(Corner::TopLeft),
+            (_, true, true, _) => Some(Corner::TopRight),
+            (true, _, _, true) => Some(Corner::BottomLeft),
+            (_, true, _, true) => Some(Corner::BottomRight),
+            _ => None,
+        }
+    }
+}
+
+struct Snapshots<Message> {
+    on_recall: Box<dyn Fn(Corner, Normal, Normal) -> Message>,
+    on_store: Box<dyn Fn(Corner, Normal, Normal) -> Message>,
+    size: u16,
+    store_hold: Duration,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Glide {
+    throw: Throw,
+    velocity_x: f32,
+    velocity_y: f32,
+    last_tick: Instant,
+}
 
 /// A 2D XY pad GUI widget that controls two [`NormalParam`] parameters at
 /// once. One in the `x` coordinate and one in the `y` coordinate.
@@ -23,6 +146,11 @@ static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
 /// an [`XYPad`] will try to fill the space of its container while keeping a
 /// square aspect ratio.
 ///
+/// While dragging, the [`XYPad`] keeps tracking the cursor outside of its
+/// bounds until the drag button is released. The values are clamped at the
+/// edges, and the handle only moves away from an edge again once the cursor
+/// has come back to it.
+///
 /// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
 /// [`XYPad`]: struct.XYPad.html
 #[allow(missing_debug_implementations)]
@@ -31,6 +159,21 @@ pub struct XYPad<'a, Message, Renderer: self::Renderer> {
     on_change: Box<dyn Fn(Normal, Normal) -> Message>,
     modifier_scalar: f32,
     modifier_keys: keyboard::Modifiers,
+    axis_lock_keys: Option<keyboard::Modifiers>,
+    input_config: InputConfig,
+    on_menu: Option<Box<dyn Fn() -> Message>>,
+    on_reset: Option<Box<dyn Fn(Normal, Normal) -> Message>>,
+    on_tab: Option<Box<dyn Fn(bool) -> Message>>,
+    on_grab: Option<Box<dyn Fn() -> Message>>,
+    on_release: Option<Box<dyn Fn() -> Message>>,
+    cache_primitives: bool,
+    accent: Option<Color>,
+    throw: Option<Throw>,
+    snapshots: Option<Snapshots<Message>>,
+    tick_marks_x: Option<&'a tick_marks::Group>,
+    tick_marks_y: Option<&'a tick_marks::Group>,
+    text_marks_x: Option<&'a text_marks::Group>,
+    text_marks_y: Option<&'a text_marks::Group>,
     size: Length,
     style: Renderer::Style,
 }
@@ -56,6 +199,24 @@ impl<'a, Message, Renderer: self::Renderer> XYPad<'a, Message, Renderer> {
                 control: true,
                 ..Default::default()
             },
+            axis_lock_keys: Some(keyboard::Modifiers {
+                shift: true,
+                ..Default::default()
+            }),
+            input_config: InputConfig::global(),
+            on_menu: None,
+            on_reset: None,
+            on_tab: None,
+            on_grab: None,
+            on_release: None,
+            cache_primitives: false,
+            accent: None,
+            throw: None,
+            snapshots: None,
+            tick_marks_x: None,
+            tick_marks_y: None,
+            text_marks_x: None,
+            text_marks_y: None,
             size: Length::Fill,
             style: Renderer::Style::default(),
         }
@@ -87,6 +248,163 @@ impl<'a, Message, Renderer: self::Renderer> XYPad<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the modifier keys that lock a drag of the [`XYPad`] to the axis
+    /// it has moved the most along since the keys were pressed, so one value
+    /// can be changed without disturbing the other. Set this to `None` to
+    /// disable axis locking.
+    ///
+    /// The default axis lock key is `Shift`.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn axis_lock_keys(
+        mut self,
+        axis_lock_keys: impl Into<Option<keyboard::Modifiers>>,
+    ) -> Self {
+        self.axis_lock_keys = axis_lock_keys.into();
+        self
+    }
+
+    /// Sets the mouse buttons that control the [`XYPad`].
+    ///
+    /// The default is the global [`InputConfig`].
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`InputConfig`]: ../../core/input_config/struct.InputConfig.html
+    pub fn input_config(mut self, input_config: InputConfig) -> Self {
+        self.input_config = input_config;
+        self
+    }
+
+    /// Sets the message to send when the menu button of the [`InputConfig`]
+    /// is pressed over the [`XYPad`].
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`InputConfig`]: ../../core/input_config/struct.InputConfig.html
+    pub fn on_menu<F>(mut self, on_menu: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_menu = Some(Box::new(on_menu));
+        self
+    }
+
+    /// Sets the gesture that resets the [`XYPad`] to its default value.
+    ///
+    /// The default is the gesture of the [`InputConfig`]. Set this after
+    /// [`input_config`], which replaces it.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`InputConfig`]: ../../core/input_config/struct.InputConfig.html
+    /// [`input_config`]: #method.input_config
+    pub fn reset_gesture(mut self, reset_gesture: ResetGesture) -> Self {
+        self.input_config.reset = reset_gesture;
+        self
+    }
+
+    /// Sets the message to send with the default values when the
+    /// [`XYPad`] is reset, instead of the message of `on_change`.
+    ///
+    /// This lets the application tell a reset apart from a plain change,
+    /// such as to record it as its own undo step.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn on_reset<F>(mut self, on_reset: F) -> Self
+    where
+        F: 'static + Fn(Normal, Normal) -> Message,
+    {
+        self.on_reset = Some(Box::new(on_reset));
+        self
+    }
+
+    /// Sets the message to send when `Tab` is pressed while the [`XYPad`]
+    /// has keyboard focus, with `true` if `Shift` is held.
+    ///
+    /// The [`XYPad`] loses focus, so the application can give it to the
+    /// next (or previous) widget with [`State::focus`].
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`State::focus`]: struct.State.html#method.focus
+    pub fn on_tab<F>(mut self, on_tab: F) -> Self
+    where
+        F: 'static + Fn(bool) -> Message,
+    {
+        self.on_tab = Some(Box::new(on_tab));
+        self
+    }
+
+    /// Sets the message to send when the user grabs the [`XYPad`] to drag
+    /// it, before any change is sent.
+    ///
+    /// Together with [`on_release`], this brackets a gesture, such as to
+    /// tell a host that an automation edit begins and ends, or to group the
+    /// changes of a drag into one undo step.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`on_release`]: #method.on_release
+    pub fn on_grab<F>(mut self, on_grab: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_grab = Some(Box::new(on_grab));
+        self
+    }
+
+    /// Sets the message to send when the user releases the [`XYPad`] after
+    /// grabbing it.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn on_release<F>(mut self, on_release: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_release = Some(Box::new(on_release));
+        self
+    }
+
+    /// Sets whether the [`XYPad`] reuses its primitives from the previous
+    /// frame when nothing that affects its appearance has changed.
+    ///
+    /// Changes to the value, interaction state, size, and any marks or
+    /// modulation ranges are detected automatically. Call
+    /// [`State::invalidate`] after changing the style.
+    ///
+    /// The default is `false`.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`State::invalidate`]: struct.State.html#method.invalidate
+    pub fn cache_primitives(mut self, cache_primitives: bool) -> Self {
+        self.cache_primitives = cache_primitives;
+        self
+    }
+
+    /// Sets the accent color of the [`XYPad`]. This replaces the colors of
+    /// the parts of its style that show the value, such as the filled
+    /// portion.
+    ///
+    /// Combined with an [`AccentMap`], this gives whole groups of parameters
+    /// their own color, for example `.accent(accents.get("Filter"))`.
+    ///
+    /// The default is no accent color.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`AccentMap`]: ../../core/accent_map/struct.AccentMap.html
+    pub fn accent(mut self, accent: impl Into<Option<Color>>) -> Self {
+        self.accent = accent.into();
+        self
+    }
+
+    /// Lets the handle of the [`XYPad`] be thrown with the given [`Throw`]
+    /// physics.
+    ///
+    /// The default is no throw physics.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`Throw`]: struct.Throw.html
+    pub fn throw(mut self, throw: Throw) -> Self {
+        self.throw = Some(throw);
+        self
+    }
+
     /// Sets the scalar to use when the user drags the slider while holding down
     /// the modifier key.
     ///
@@ -100,22 +418,197 @@ impl<'a, Message, Renderer: self::Renderer> XYPad<'a, Message, Renderer> {
         self.modifier_scalar = scalar;
         self
     }
+
+    /// Turns the corners of the [`XYPad`] into snapshot slots for quickly
+    /// morphing between stored positions.
+    ///
+    /// Clicking a corner recalls the `(x, y)` values stored in it, and
+    /// holding a corner down stores the current values in it. The snapshots
+    /// are kept in the [`State`].
+    ///
+    /// It expects:
+    ///   * a function that will be called when a snapshot is recalled, with
+    ///     the recalled values. The function passed to [`new`] is called
+    ///     with the same values afterwards.
+    ///   * a function that will be called when the current values are
+    ///     stored in a corner
+    ///
+    /// The default is no snapshots.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`State`]: struct.State.html
+    /// [`new`]: #method.new
+    pub fn snapshots<R, S>(mut self, on_recall: R, on_store: S) -> Self
+    where
+        R: 'static + Fn(Corner, Normal, Normal) -> Message,
+        S: 'static + Fn(Corner, Normal, Normal) -> Message,
+    {
+        self.snapshots = Some(Snapshots {
+            on_recall: Box::new(on_recall),
+            on_store: Box::new(on_store),
+            size: DEFAULT_SNAPSHOT_SIZE,
+            store_hold: DEFAULT_STORE_HOLD,
+        });
+        self
+    }
+
+    /// Sets the size in pixels of the snapshot corners of the [`XYPad`].
+    /// This has no effect unless [`snapshots`] is set.
+    ///
+    /// The default size is `14`.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`snapshots`]: #method.snapshots
+    pub fn snapshot_size(mut self, size: u16) -> Self {
+        if let Some(snapshots) = &mut self.snapshots {
+            snapshots.size = size;
+        }
+        self
+    }
+
+    /// Sets how long a snapshot corner of the [`XYPad`] must be held down
+    /// to store the current values instead of recalling them. This has no
+    /// effect unless [`snapshots`] is set.
+    ///
+    /// The default is 600 milliseconds.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`snapshots`]: #method.snapshots
+    pub fn store_hold(mut self, store_hold: Duration) -> Self {
+        if let Some(snapshots) = &mut self.snapshots {
+            snapshots.store_hold = store_hold;
+        }
+        self
+    }
+
+    /// Sets the tick marks to display along the bottom edge for the x axis.
+    /// Note your [`StyleSheet`] must also implement
+    /// `tick_marks_style(&self) -> Option<TickMarksStyle>` for them to
+    /// display (which the default style does).
+    ///
+    /// [`StyleSheet`]: ../../style/xy_pad/trait.StyleSheet.html
+    pub fn tick_marks_x(mut self, tick_marks: &'a tick_marks::Group) -> Self {
+        self.tick_marks_x = Some(tick_marks);
+        self
+    }
+
+    /// Sets the tick marks to display along the left edge for the y axis.
+    /// Note your [`StyleSheet`] must also implement
+    /// `tick_marks_style(&self) -> Option<TickMarksStyle>` for them to
+    /// display (which the default style does).
+    ///
+    /// [`StyleSheet`]: ../../style/xy_pad/trait.StyleSheet.html
+    pub fn tick_marks_y(mut self, tick_marks: &'a tick_marks::Group) -> Self {
+        self.tick_marks_y = Some(tick_marks);
+        self
+    }
+
+    /// Sets the text marks to display along the bottom edge for the x axis.
+    /// Note your [`StyleSheet`] must also implement
+    /// `text_marks_style(&self) -> Option<TextMarksStyle>` for them to
+    /// display (which the default style does).
+    ///
+    /// [`StyleSheet`]: ../../style/xy_pad/trait.StyleSheet.html
+    pub fn text_marks_x(mut self, text_marks: &'a text_marks::Group) -> Self {
+        self.text_marks_x = Some(text_marks);
+        self
+    }
+
+    /// Sets the text marks to display along the left edge for the y axis.
+    /// Note your [`StyleSheet`] must also implement
+    /// `text_marks_style(&self) -> Option<TextMarksStyle>` for them to
+    /// display (which the default style does).
+    ///
+    /// [`StyleSheet`]: ../../style/xy_pad/trait.StyleSheet.html
+    pub fn text_marks_y(mut self, text_marks: &'a text_marks::Group) -> Self {
+        self.text_marks_y = Some(text_marks);
+        self
+    }
+
+    fn key_pressed(
+        &mut self,
+        messages: &mut Vec<Message>,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::Modifiers,
+    ) {
+        match key_code {
+            keyboard::KeyCode::Up => self.nudge(messages, 0.0, 1.0),
+            keyboard::KeyCode::Down => self.nudge(messages, 0.0, -1.0),
+            keyboard::KeyCode::Right => self.nudge(messages, 1.0, 0.0),
+            keyboard::KeyCode::Left => self.nudge(messages, -1.0, 0.0),
+            keyboard::KeyCode::Tab => {
+                self.state.is_focused = false;
+
+                if let Some(on_tab) = &self.on_tab {
+                    messages.push((on_tab)(modifiers.shift));
+                }
+            }
+            keyboard::KeyCode::Escape => {
+                self.state.is_focused = false;
+            }
+            _ => {}
+        }
+    }
+
+    fn nudge(&mut self, messages: &mut Vec<Message>, x: f32, y: f32) {
+        let mut step = DEFAULT_KEY_STEP;
+
+        if self.state.pressed_modifiers.matches(self.modifier_keys) {
+            step *= self.modifier_scalar;
+        }
+
+        let normal_x = (self.state.normal_param_x.value.as_f32() + x * step)
+            .clamp(0.0, 1.0);
+        let normal_y = (self.state.normal_param_y.value.as_f32() + y * step)
+            .clamp(0.0, 1.0);
+
+        self.state.glide = None;
+        self.state.continuous_normal_x = normal_x;
+        self.state.continuous_normal_y = normal_y;
+
+        let value_x = Normal::from(normal_x);
+        let value_y = Normal::from(normal_y);
+
+        if value_x != self.state.normal_param_x.value
+            || value_y != self.state.normal_param_y.value
+        {
+            self.state.normal_param_x.value = value_x;
+            self.state.normal_param_y.value = value_y;
+
+            messages.push((self.on_change)(value_x, value_y));
+        }
+    }
 }
 
 /// The local state of a [`XYPad`].
 ///
 /// [`XYPad`]: struct.XYPad.html
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 pub struct State {
     normal_param_x: NormalParam,
     normal_param_y: NormalParam,
     is_dragging: bool,
-    prev_drag_x: f32,
-    prev_drag_y: f32,
+    is_focused: bool,
+    drag_start: Option<Point>,
+    drag_anchor_x: DragAnchor,
+    drag_anchor_y: DragAnchor,
     continuous_normal_x: f32,
     continuous_normal_y: f32,
+    axis_lock_origin: Option<(f32, f32)>,
     pressed_modifiers: keyboard::Modifiers,
     last_click: Option<mouse::Click>,
+    velocity_x: f32,
+    velocity_y: f32,
+    last_move: Option<Instant>,
+    glide: Option<Glide>,
+    snapshots: [Option<(Normal, Normal)>; 4],
+    corner_press: Option<(Corner, Instant)>,
+    revision: u64,
+    tick_marks_cache_x: crate::graphics::tick_marks::PrimitiveCache,
+    tick_marks_cache_y: crate::graphics::tick_marks::PrimitiveCache,
+    text_marks_cache_x: crate::graphics::text_marks::PrimitiveCache,
+    text_marks_cache_y: crate::graphics::text_marks::PrimitiveCache,
+    draw_cache: crate::graphics::DrawCache,
 }
 
 impl State {
@@ -135,12 +628,27 @@ impl State {
             normal_param_x,
             normal_param_y,
             is_dragging: false,
-            prev_drag_x: 0.0,
-            prev_drag_y: 0.0,
+            is_focused: false,
+            drag_start: None,
+            drag_anchor_x: DragAnchor::default(),
+            drag_anchor_y: DragAnchor::default(),
             continuous_normal_x: normal_param_x.value.as_f32(),
             continuous_normal_y: normal_param_y.value.as_f32(),
+            axis_lock_origin: None,
             pressed_modifiers: Default::default(),
             last_click: None,
+            velocity_x: 0.0,
+            velocity_y: 0.0,
+            last_move: None,
+            glide: None,
+            snapshots: [None; 4],
+            corner_press: None,
+            revision: 0,
+            tick_marks_cache_x: Default::default(),
+            tick_marks_cache_y: Default::default(),
+            text_marks_cache_x: Default::default(),
+            text_marks_cache_y: Default::default(),
+            draw_cache: Default::default(),
         }
     }
 
@@ -228,6 +736,197 @@ impl State {
     pub fn is_dragging(&self) -> bool {
         self.is_dragging
     }
+
+    /// Does the [`XYPad`] have keyboard focus?
+    ///
+    /// While it has focus, the arrow keys change its value. Clicking the
+    /// [`XYPad`] focuses it, and clicking anywhere else, `Tab`, or
+    /// `Escape` removes the focus.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Gives keyboard focus to the [`XYPad`], such as when the user tabs
+    /// to it.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    /// Removes keyboard focus from the [`XYPad`].
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+
+    /// Forces the [`XYPad`] to regenerate its primitives on the next frame.
+    ///
+    /// This only needs to be called after changing the style of a
+    /// [`XYPad`] that caches its primitives.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn invalidate(&mut self) {
+        self.revision = self.revision.wrapping_add(1);
+    }
+
+    /// Returns the bounds of the [`XYPad`] if its primitives were regenerated
+    /// when it was last drawn, or `None` if its cached primitives were
+    /// reused.
+    ///
+    /// This invalidates the whole [`XYPad`]; it does not narrow down which
+    /// part of it changed. It is always `Some` unless the [`XYPad`] caches
+    /// its primitives.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn invalidated_bounds(&self) -> Option<Rectangle> {
+        self.draw_cache.invalidated_bounds()
+    }
+
+    /// Returns the `(x, y)` values stored in a snapshot corner of the
+    /// [`XYPad`], if any.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn snapshot(&self, corner: Corner) -> Option<(Normal, Normal)> {
+        self.snapshots[corner.index()]
+    }
+
+    /// Stores `(x, y)` values in a snapshot corner of the [`XYPad`], or
+    /// empties it with `None`. Use this to restore snapshots, for example
+    /// when loading a preset.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::xy_pad::{self, Corner};
+    ///
+    /// let mut state = xy_pad::State::new(Default::default(), Default::default());
+    ///
+    /// state.set_snapshot(Corner::TopRight, Some((1.0.into(), 1.0.into())));
+    ///
+    /// assert!(state.snapshot(Corner::TopRight).is_some());
+    /// assert!(state.snapshot(Corner::BottomLeft).is_none());
+    /// ```
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn set_snapshot(
+        &mut self,
+        corner: Corner,
+        values: Option<(Normal, Normal)>,
+    ) {
+        self.snapshots[corner.index()] = values;
+    }
+
+    /// Empties all snapshot corners of the [`XYPad`].
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn clear_snapshots(&mut self) {
+        self.snapshots = [None; 4];
+    }
+
+    /// Is the handle of the [`XYPad`] currently gliding after being thrown?
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn is_gliding(&self) -> bool {
+        self.glide.is_some()
+    }
+
+    /// Stops the handle of the [`XYPad`] if it is gliding.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn stop_glide(&mut self) {
+        self.glide = None;
+    }
+
+    /// Advances the glide of a thrown [`XYPad`] handle to the time `now`.
+    ///
+    /// Returns the new `(x, y)` values if the handle is gliding, or `None`
+    /// if it is not. Call this periodically (for example from a timer
+    /// subscription) while [`is_gliding`] returns `true`, and treat the
+    /// returned values the same way as a message from the [`XYPad`].
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`is_gliding`]: #method.is_gliding
+    pub fn glide(&mut self, now: Instant) -> Option<(Normal, Normal)> {
+        let mut glide = self.glide?;
+
+        let seconds =
+            now.saturating_duration_since(glide.last_tick).as_secs_f32();
+        glide.last_tick = now;
+
+        let (normal_x, velocity_x) = glide_axis(
+            self.continuous_normal_x,
+            glide.velocity_x,
+            seconds,
+            &glide.throw,
+        );
+        let (normal_y, velocity_y) = glide_axis(
+            self.continuous_normal_y,
+            glide.velocity_y,
+            seconds,
+            &glide.throw,
+        );
+
+        glide.velocity_x = velocity_x;
+        glide.velocity_y = velocity_y;
+
+        self.continuous_normal_x = normal_x;
+        self.normal_param_x.value = normal_x.into();
+        self.continuous_normal_y = normal_y;
+        self.normal_param_y.value = normal_y.into();
+
+        if velocity_x.hypot(velocity_y) < MIN_GLIDE_SPEED {
+            self.glide = None;
+        } else {
+            self.glide = Some(glide);
+        }
+
+        Some((self.normal_param_x.value, self.normal_param_y.value))
+    }
+}
+
+/// Returns the square area of `bounds` that the [`XYPad`] is drawn in.
+///
+/// [`XYPad`]: struct.XYPad.html
+fn square_bounds(bounds: Rectangle) -> Rectangle {
+    let size = bounds.width.min(bounds.height);
+
+    Rectangle {
+        width: size,
+        height: size,
+        ..bounds
+    }
+}
+
+/// Moves one axis of a gliding handle, bouncing it off the edges.
+///
+/// Returns the new position and velocity.
+fn glide_axis(
+    normal: f32,
+    velocity: f32,
+    seconds: f32,
+    throw: &Throw,
+) -> (f32, f32) {
+    let velocity = velocity * (-throw.friction * seconds).exp();
+    let normal = normal + velocity * seconds;
+
+    let (normal, velocity) = if normal < 0.0 {
+        (-normal * throw.bounce, -velocity * throw.bounce)
+    } else if normal > 1.0 {
+        (
+            1.0 - (normal - 1.0) * throw.bounce,
+            -velocity * throw.bounce,
+        )
+    } else {
+        (normal, velocity)
+    };
+
+    // A long step can overshoot by more than the whole range, which would
+    // reflect the handle past the opposite edge.
+    (normal.clamp(0.0, 1.0), velocity)
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -274,6 +973,17 @@ where
             Event::Mouse(mouse_event) => match mouse_event {
                 mouse::Event::CursorMoved { .. } => {
                     if self.state.is_dragging {
+                        if let Some(drag_start) = self.state.drag_start {
+                            if !self.input_config.drag_threshold_crossed(
+                                drag_start,
+                                cursor_position,
+                            ) {
+                                return event::Status::Captured;
+                            }
+
+                            self.state.drag_start = None;
+                        }
+
                         let bounds_size = {
                             if layout.bounds().width <= layout.bounds().height {
                                 layout.bounds().width
@@ -282,58 +992,157 @@ where
                             }
                         };
                         if bounds_size != 0.0 {
-                            let mut movement_x = (cursor_position.x
-                                - self.state.prev_drag_x)
-                                / bounds_size;
-
-                            let mut movement_y = (cursor_position.y
-                                - self.state.prev_drag_y)
-                                / bounds_size;
+                            let mut scalar = bounds_size.recip();
 
                             if self
                                 .state
                                 .pressed_modifiers
                                 .matches(self.modifier_keys)
                             {
-                                movement_x *= self.modifier_scalar;
-                                movement_y *= self.modifier_scalar;
+                                scalar *= self.modifier_scalar;
                             }
 
-                            let normal_x =
-                                self.state.continuous_normal_x + movement_x;
-                            let normal_y =
-                                self.state.continuous_normal_y - movement_y;
+                            let mut normal_x = self
+                                .state
+                                .drag_anchor_x
+                                .drag_unbounded(cursor_position.x, scalar);
+                            let mut normal_y = self
+                                .state
+                                .drag_anchor_y
+                                .drag_unbounded(cursor_position.y, -scalar);
 
-                            self.state.prev_drag_x = cursor_position.x;
-                            self.state.prev_drag_y = cursor_position.y;
+                            let axis_locked = match self.axis_lock_keys {
+                                Some(keys) => {
+                                    self.state.pressed_modifiers.matches(keys)
+                                }
+                                None => false,
+                            };
 
-                            self.state.continuous_normal_x = normal_x;
-                            self.state.normal_param_x.value = normal_x.into();
+                            if axis_locked {
+                                let (origin_x, origin_y) = *self
+                                    .state
+                                    .axis_lock_origin
+                                    .get_or_insert((
+                                        self.state.continuous_normal_x,
+                                        self.state.continuous_normal_y,
+                                    ));
 
+                                if (normal_x - origin_x).abs()
+                                    >= (normal_y - origin_y).abs()
+                                {
+                                    normal_y = origin_y;
+                                } else {
+                                    normal_x = origin_x;
+                                }
+                            } else {
+                                self.state.axis_lock_origin = None;
+                            }
+
+                            let now = Instant::now();
+                            if let Some(last_move) = self.state.last_move {
+                                let seconds =
+                                    now.duration_since(last_move).as_secs_f32();
+
+                                if seconds > 0.0 {
+                                    // Smooth out jitter in the cursor events.
+                                    self.state.velocity_x = (self
+                                        .state
+                                        .velocity_x
+                                        + (normal_x
+                                            - self.state.continuous_normal_x)
+                                            / seconds)
+                                        * 0.5;
+                                    self.state.velocity_y = (self
+                                        .state
+                                        .velocity_y
+                                        + (normal_y
+                                            - self.state.continuous_normal_y)
+                                            / seconds)
+                                        * 0.5;
+                                }
+                            }
+                            self.state.last_move = Some(now);
+
+                            // The continuous normals keep tracking the cursor
+                            // outside of the bounds, so the handle stays pinned
+                            // to the edge until the cursor comes back.
+                            self.state.continuous_normal_x = normal_x;
                             self.state.continuous_normal_y = normal_y;
-                            self.state.normal_param_y.value = normal_y.into();
 
-                            messages.push((self.on_change)(
-                                self.state.normal_param_x.value,
-                                self.state.normal_param_y.value,
-                            ));
+                            let value_x = Normal::from(normal_x);
+                            let value_y = Normal::from(normal_y);
+
+                            if value_x != self.state.normal_param_x.value
+                                || value_y != self.state.normal_param_y.value
+                            {
+                                self.state.normal_param_x.value = value_x;
+                                self.state.normal_param_y.value = value_y;
+
+                                messages
+                                    .push((self.on_change)(value_x, value_y));
+                            }
 
                             return event::Status::Captured;
                         }
                     }
                 }
-                mouse::Event::ButtonPressed(mouse::Button::Left) => {
-                    if layout.bounds().contains(cursor_position) {
-                        let click = mouse::Click::new(
-                            cursor_position,
-                            self.state.last_click,
-                        );
-
-                        match click.kind() {
-                            mouse::click::Kind::Single => {
+                mouse::Event::ButtonPressed(button) => {
+                    self.state.is_focused = self
+                        .input_config
+                        .hit_bounds(layout.bounds())
+                        .contains(cursor_position);
+
+                    if let Some(snapshots) = &self.snapshots {
+                        if button == self.input_config.drag_button {
+                            if let Some(corner) = Corner::at(
+                                square_bounds(layout.bounds()),
+                                f32::from(snapshots.size),
+                                cursor_position,
+                            ) {
+                                self.state.corner_press =
+                                    Some((corner, Instant::now()));
+
+                                return event::Status::Captured;
+                            }
+                        }
+                    }
+
+                    if self
+                        .input_config
+                        .hit_bounds(layout.bounds())
+                        .contains(cursor_position)
+                    {
+                        let click_kind =
+                            if button == self.input_config.drag_button {
+                                let click = mouse::Click::new(
+                                    cursor_position,
+                                    self.state.last_click,
+                                );
+
+                                self.state.last_click = Some(click);
+
+                                click.kind()
+                            } else {
+                                mouse::click::Kind::Single
+                            };
+
+                        match self.input_config.action(
+                            button,
+                            click_kind,
+                            self.state.pressed_modifiers,
+                        ) {
+                            InputAction::Drag => {
+                                if let Some(on_grab) = &self.on_grab {
+                                    messages.push((on_grab)());
+                                }
+
                                 self.state.is_dragging = true;
-                                self.state.prev_drag_x = cursor_position.x;
-                                self.state.prev_drag_y = cursor_position.y;
+                                self.state.drag_start = Some(cursor_position);
+                                self.state.velocity_x = 0.0;
+                                self.state.velocity_y = 0.0;
+                                self.state.last_move = None;
+                                self.state.glide = None;
+                                self.state.axis_lock_origin = None;
 
                                 let bounds_size = {
                                     if layout.bounds().width
@@ -345,13 +1154,27 @@ where
                                     }
                                 };
 
-                                let normal_x = (cursor_position.x
+                                // The press may land in the enlarged hit
+                                // area just outside the bounds.
+                                let normal_x = ((cursor_position.x
                                     - layout.bounds().x)
-                                    / bounds_size;
+                                    / bounds_size)
+                                    .clamp(0.0, 1.0);
 
-                                let normal_y = 1.0
-                                    - ((cursor_position.y - layout.bounds().y)
-                                        / bounds_size);
+                                let normal_y = (1.0
+                                    - ((cursor_position.y
+                                        - layout.bounds().y)
+                                        / bounds_size))
+                                    .clamp(0.0, 1.0);
+
+                                self.state.drag_anchor_x = DragAnchor::new(
+                                    cursor_position.x,
+                                    normal_x,
+                                );
+                                self.state.drag_anchor_y = DragAnchor::new(
+                                    cursor_position.y,
+                                    normal_y,
+                                );
 
                                 self.state.continuous_normal_x = normal_x;
                                 self.state.normal_param_x.value =
@@ -366,41 +1189,144 @@ where
                                     self.state.normal_param_y.value,
                                 ));
                             }
-                            _ => {
-                                self.state.is_dragging = false;
+                            InputAction::Reset => {
+                                if self.state.is_dragging {
+                                    self.state.is_dragging = false;
+                                    self.state.drag_start = None;
+                                    self.state.axis_lock_origin = None;
+
+                                    if let Some(on_release) = &self.on_release {
+                                        messages.push((on_release)());
+                                    }
+                                }
+
+                                self.state.glide = None;
 
                                 self.state.normal_param_x.value =
                                     self.state.normal_param_x.default;
                                 self.state.normal_param_y.value =
                                     self.state.normal_param_y.default;
 
-                                messages.push((self.on_change)(
-                                    self.state.normal_param_x.value,
-                                    self.state.normal_param_y.value,
-                                ));
+                                let x = self.state.normal_param_x.value;
+                                let y = self.state.normal_param_y.value;
+
+                                self.state.continuous_normal_x = x.as_f32();
+                                self.state.continuous_normal_y = y.as_f32();
+                                self.state.drag_anchor_x.rebase(x.as_f32());
+                                self.state.drag_anchor_y.rebase(y.as_f32());
+
+                                messages.push(match &self.on_reset {
+                                    Some(on_reset) => (on_reset)(x, y),
+                                    None => (self.on_change)(x, y),
+                                });
+                            }
+                            InputAction::Menu => {
+                                if let Some(on_menu) = &self.on_menu {
+                                    messages.push((on_menu)());
+                                }
+                            }
+                            InputAction::None => {
+                                return event::Status::Ignored;
                             }
                         }
 
-                        self.state.last_click = Some(click);
+                        return event::Status::Captured;
+                    }
+                }
+                mouse::Event::ButtonReleased(button)
+                    if button == self.input_config.drag_button
+                        && self.state.corner_press.is_some() =>
+                {
+                    let (corner, pressed_at) =
+                        self.state.corner_press.take().unwrap();
+
+                    let snapshots = match &self.snapshots {
+                        Some(snapshots) => snapshots,
+                        None => return event::Status::Captured,
+                    };
 
+                    // Releasing the button away from the corner cancels.
+                    if Corner::at(
+                        square_bounds(layout.bounds()),
+                        f32::from(snapshots.size),
+                        cursor_position,
+                    ) != Some(corner)
+                    {
                         return event::Status::Captured;
                     }
+
+                    if pressed_at.elapsed() >= snapshots.store_hold {
+                        let x = self.state.normal_param_x.value;
+                        let y = self.state.normal_param_y.value;
+
+                        self.state.set_snapshot(corner, Some((x, y)));
+
+                        messages.push((snapshots.on_store)(corner, x, y));
+                    } else if let Some((x, y)) = self.state.snapshot(corner) {
+                        self.state.glide = None;
+                        self.state.set_normal_x(x);
+                        self.state.set_normal_y(y);
+
+                        messages.push((snapshots.on_recall)(corner, x, y));
+                        messages.push((self.on_change)(x, y));
+                    }
+
+                    return event::Status::Captured;
                 }
-                mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                mouse::Event::ButtonReleased(button)
+                    if button == self.input_config.drag_button
+                        && self.state.is_dragging =>
+                {
                     self.state.is_dragging = false;
+                    self.state.drag_start = None;
+                    self.state.axis_lock_origin = None;
+
+                    if let Some(on_release) = &self.on_release {
+                        messages.push((on_release)());
+                    }
+
                     self.state.continuous_normal_x =
                         self.state.normal_param_x.value.as_f32();
                     self.state.continuous_normal_y =
                         self.state.normal_param_y.value.as_f32();
 
+                    if let (Some(throw), Some(last_move)) =
+                        (self.throw, self.state.last_move)
+                    {
+                        let now = Instant::now();
+
+                        if now.duration_since(last_move) < THROW_TIMEOUT
+                            && self
+                                .state
+                                .velocity_x
+                                .hypot(self.state.velocity_y)
+                                >= MIN_GLIDE_SPEED
+                        {
+                            self.state.glide = Some(Glide {
+                                throw,
+                                velocity_x: self.state.velocity_x,
+                                velocity_y: self.state.velocity_y,
+                                last_tick: now,
+                            });
+                        }
+                    }
+                    self.state.last_move = None;
+
                     return event::Status::Captured;
                 }
                 _ => {}
             },
             Event::Keyboard(keyboard_event) => match keyboard_event {
-                keyboard::Event::KeyPressed { modifiers, .. } => {
+                keyboard::Event::KeyPressed {
+                    key_code,
+                    modifiers,
+                } => {
                     self.state.pressed_modifiers = modifiers;
 
+                    if self.state.is_focused {
+                        self.key_pressed(messages, key_code, modifiers);
+                    }
+
                     return event::Status::Captured;
                 }
                 keyboard::Event::KeyReleased { modifiers, .. } => {
@@ -408,8 +1334,14 @@ where
 
                     return event::Status::Captured;
                 }
+                keyboard::Event::ModifiersChanged(modifiers) => {
+                    self.state.pressed_modifiers = modifiers;
+                }
                 _ => {}
             },
+            Event::Window(window::Event::Unfocused) => {
+                self.state.pressed_modifiers = Default::default();
+            }
             _ => {}
         }
 
@@ -424,12 +1356,61 @@ where
         cursor_position: Point,
         _viewport: &Rectangle,
     ) -> Renderer::Output {
+        let revision = if self.cache_primitives {
+            let revision =
+                crate::graphics::draw_cache::Revision::new(self.state.revision)
+                    .normal(self.state.normal_param_x.value)
+                    .normal(self.state.normal_param_y.value)
+                    .flag(self.state.is_dragging)
+                    .flag(self.state.is_focused)
+                    .color(self.accent)
+                    .flag(self.snapshots.is_some())
+                    .tick_marks(self.tick_marks_x)
+                    .tick_marks(self.tick_marks_y)
+                    .text_marks(self.text_marks_x)
+                    .text_marks(self.text_marks_y);
+
+            let revision = self.state.snapshots.iter().fold(
+                revision,
+                |revision, snapshot| match snapshot {
+                    Some((x, y)) => revision.flag(true).normal(*x).normal(*y),
+                    None => revision.flag(false),
+                },
+            );
+
+            Some(revision.finish())
+        } else {
+            None
+        };
+
         renderer.draw(
-            layout.bounds(),
-            cursor_position,
-            self.state.normal_param_x.value,
-            self.state.normal_param_y.value,
-            self.state.is_dragging,
+            &DrawState {
+                bounds: layout.bounds(),
+                cursor_position,
+                normal_x: self.state.normal_param_x.value,
+                normal_y: self.state.normal_param_y.value,
+                is_dragging: self.state.is_dragging,
+                is_focused: self.state.is_focused,
+                snapshots: self
+                    .snapshots
+                    .as_ref()
+                    .map(|_| &self.state.snapshots),
+                snapshot_size: self
+                    .snapshots
+                    .as_ref()
+                    .map_or(0.0, |snapshots| f32::from(snapshots.size)),
+                tick_marks_x: self.tick_marks_x,
+                tick_marks_y: self.tick_marks_y,
+                text_marks_x: self.text_marks_x,
+                text_marks_y: self.text_marks_y,
+                accent: self.accent,
+                tick_marks_cache_x: &self.state.tick_marks_cache_x,
+                tick_marks_cache_y: &self.state.tick_marks_cache_y,
+                text_marks_cache_x: &self.state.text_marks_cache_x,
+                text_marks_cache_y: &self.state.text_marks_cache_y,
+                draw_cache: &self.state.draw_cache,
+                revision,
+            },
             &self.style,
         )
     }
@@ -442,6 +1423,54 @@ where
     }
 }
 
+/// The values an [`XYPad`] is drawn with in a frame.
+///
+/// [`XYPad`]: struct.XYPad.html
+#[derive(Debug, Clone, Copy)]
+pub struct DrawState<'a> {
+    /// The bounds of the pad
+    pub bounds: Rectangle,
+    /// The current cursor position
+    pub cursor_position: Point,
+    /// The current normal of the x coordinate of the pad
+    pub normal_x: Normal,
+    /// The current normal of the y coordinate of the pad
+    pub normal_y: Normal,
+    /// Whether the pad is currently being dragged
+    pub is_dragging: bool,
+    /// Whether the pad has keyboard focus
+    pub is_focused: bool,
+    /// The values stored in each snapshot corner, in the order of
+    /// [`Corner::ALL`], or `None` if snapshots are disabled
+    ///
+    /// [`Corner::ALL`]: enum.Corner.html#associatedconstant.ALL
+    pub snapshots: Option<&'a [Option<(Normal, Normal)>; 4]>,
+    /// The size of the snapshot corners in pixels
+    pub snapshot_size: f32,
+    /// The tick marks of the x axis, if any
+    pub tick_marks_x: Option<&'a tick_marks::Group>,
+    /// The tick marks of the y axis, if any
+    pub tick_marks_y: Option<&'a tick_marks::Group>,
+    /// The text marks of the x axis, if any
+    pub text_marks_x: Option<&'a text_marks::Group>,
+    /// The text marks of the y axis, if any
+    pub text_marks_y: Option<&'a text_marks::Group>,
+    /// The accent color of the pad, if any
+    pub accent: Option<Color>,
+    /// The cache for the primitives of the tick marks of the x axis
+    pub tick_marks_cache_x: &'a crate::tick_marks::PrimitiveCache,
+    /// The cache for the primitives of the tick marks of the y axis
+    pub tick_marks_cache_y: &'a crate::tick_marks::PrimitiveCache,
+    /// The cache for the primitives of the text marks of the x axis
+    pub text_marks_cache_x: &'a crate::text_marks::PrimitiveCache,
+    /// The cache for the primitives of the text marks of the y axis
+    pub text_marks_cache_y: &'a crate::text_marks::PrimitiveCache,
+    /// The cache for the primitives of the pad
+    pub draw_cache: &'a crate::graphics::DrawCache,
+    /// The revision of the pad, or `None` if caching is disabled
+    pub revision: Option<u64>,
+}
+
 /// The renderer of an [`XYPad`].
 ///
 /// Your renderer will need to implement this trait before being
@@ -452,24 +1481,13 @@ pub trait Renderer: iced_native::Renderer {
     /// The style supported by this renderer.
     type Style: Default;
 
-    /// Draws an [`XYPad`].
-    ///
-    /// It receives:
-    ///   * the bounds of the [`XYPad`]
-    ///   * the current cursor position
-    ///   * the current normal of the x coordinate of the [`XYPad`]
-    ///   * the current normal of the y coordinate of the [`XYPad`]
-    ///   * whether the xy_pad is currently being dragged
-    ///   * the style of the [`XYPad`]
+    /// Draws an [`XYPad`] from its [`DrawState`] and its style.
     ///
     /// [`XYPad`]: struct.XYPad.html
+    /// [`DrawState`]: struct.DrawState.html
     fn draw(
         &mut self,
-        bounds: Rectangle,
-        cursor_position: Point,
-        normal_x: Normal,
-        normal_y: Normal,
-        is_dragging: bool,
+        state: &DrawState<'_>,
         style: &Self::Style,
     ) -> Self::Output;
 }
@@ -486,3 +1504,399 @@ where
         Element::new(xy_pad)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced_native::{clipboard, renderer};
+
+    impl Renderer for renderer::Null {
+        type Style = ();
+
+        fn draw(&mut self, _state: &DrawState<'_>, _style: &Self::Style) {}
+    }
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 10.0,
+        y: 20.0,
+        width: 128.0,
+        height: 128.0,
+    };
+
+    fn new_state() -> State {
+        State::new(NormalParam::default(), NormalParam::default())
+    }
+
+    fn send(
+        state: &mut State,
+        event: mouse::Event,
+        x: f32,
+        y: f32,
+    ) -> (event::Status, Vec<(f32, f32)>) {
+        send_event(state, Event::Mouse(event), x, y)
+    }
+
+    fn send_event(
+        state: &mut State,
+        event: Event,
+        x: f32,
+        y: f32,
+    ) -> (event::Status, Vec<(f32, f32)>) {
+        let mut node = layout::Node::new(BOUNDS.size());
+        node.move_to(BOUNDS.position());
+
+        let mut xy_pad = XYPad::<_, renderer::Null>::new(state, |x, y| {
+            (x.as_f32(), y.as_f32())
+        })
+        .input_config(InputConfig {
+            drag_threshold: 0.0,
+            ..InputConfig::default()
+        });
+
+        let mut messages = Vec::new();
+        let status = xy_pad.on_event(
+            event,
+            Layout::new(&node),
+            Point::new(x, y),
+            &renderer::Null,
+            &mut clipboard::Null,
+            &mut messages,
+        );
+
+        (status, messages)
+    }
+
+    fn press(state: &mut State, x: f32, y: f32) -> event::Status {
+        send(
+            state,
+            mouse::Event::ButtonPressed(mouse::Button::Left),
+            x,
+            y,
+        )
+        .0
+    }
+
+    fn release(state: &mut State, x: f32, y: f32) -> event::Status {
+        send(
+            state,
+            mouse::Event::ButtonReleased(mouse::Button::Left),
+            x,
+            y,
+        )
+        .0
+    }
+
+    fn move_to(state: &mut State, x: f32, y: f32) -> Vec<(f32, f32)> {
+        let (status, messages) = send(
+            state,
+            mouse::Event::CursorMoved {
+                position: Point::new(x, y),
+            },
+            x,
+            y,
+        );
+        assert_eq!(status, event::Status::Captured);
+        messages
+    }
+
+    fn key(state: &mut State, key_code: keyboard::KeyCode) -> Vec<(f32, f32)> {
+        send_event(
+            state,
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers: Default::default(),
+            }),
+            0.0,
+            0.0,
+        )
+        .1
+    }
+
+    fn values(state: &State) -> (f32, f32) {
+        (
+            state.normal_param_x.value.as_f32(),
+            state.normal_param_y.value.as_f32(),
+        )
+    }
+
+    #[test]
+    fn press_outside_bounds_is_ignored() {
+        let mut state = new_state();
+
+        assert_eq!(press(&mut state, 5.0, 52.0), event::Status::Ignored);
+        assert!(!state.is_dragging());
+    }
+
+    #[test]
+    fn press_sets_values_under_cursor() {
+        let mut state = new_state();
+
+        assert_eq!(press(&mut state, 42.0, 52.0), event::Status::Captured);
+        assert!(state.is_dragging());
+        assert_eq!(values(&state), (0.25, 0.75));
+    }
+
+    #[test]
+    fn drag_past_edges_clamps_values() {
+        let mut state = new_state();
+        let _ = press(&mut state, 74.0, 84.0);
+
+        let messages = move_to(&mut state, 266.0, 340.0);
+        assert_eq!(messages, vec![(1.0, 0.0)]);
+        assert_eq!(values(&state), (1.0, 0.0));
+
+        let messages = move_to(&mut state, -118.0, -108.0);
+        assert_eq!(messages, vec![(0.0, 1.0)]);
+        assert_eq!(values(&state), (0.0, 1.0));
+    }
+
+    #[test]
+    fn drag_outside_bounds_does_not_repeat_messages() {
+        let mut state = new_state();
+        let _ = press(&mut state, 74.0, 84.0);
+
+        let _ = move_to(&mut state, 266.0, 84.0);
+        assert!(move_to(&mut state, 298.0, 84.0).is_empty());
+        assert!(move_to(&mut state, 202.0, 84.0).is_empty());
+        assert_eq!(values(&state), (1.0, 0.5));
+    }
+
+    #[test]
+    fn drag_keeps_tracking_outside_bounds() {
+        let mut state = new_state();
+        let _ = press(&mut state, 74.0, 84.0);
+
+        // The handle stays at the edge until the cursor has come back.
+        let _ = move_to(&mut state, 394.0, 84.0);
+        let _ = move_to(&mut state, 138.0, 84.0);
+        assert_eq!(values(&state), (1.0, 0.5));
+
+        let messages = move_to(&mut state, 106.0, 84.0);
+        assert_eq!(messages, vec![(0.75, 0.5)]);
+    }
+
+    #[test]
+    fn release_outside_bounds_ends_drag() {
+        let mut state = new_state();
+        let _ = press(&mut state, 74.0, 84.0);
+        let _ = move_to(&mut state, 330.0, 340.0);
+
+        assert_eq!(release(&mut state, 330.0, 340.0), event::Status::Captured);
+        assert!(!state.is_dragging());
+
+        let (status, messages) = send(
+            &mut state,
+            mouse::Event::CursorMoved {
+                position: Point::new(74.0, 84.0),
+            },
+            74.0,
+            84.0,
+        );
+        assert_eq!(status, event::Status::Ignored);
+        assert!(messages.is_empty());
+        assert_eq!(values(&state), (1.0, 0.0));
+    }
+
+    #[test]
+    fn release_without_drag_is_ignored() {
+        let mut state = new_state();
+
+        assert_eq!(release(&mut state, 74.0, 84.0), event::Status::Ignored);
+    }
+
+    fn click_corner(
+        state: &mut State,
+        store_hold: Duration,
+        x: f32,
+        y: f32,
+    ) -> Vec<(f32, f32)> {
+        let mut node = layout::Node::new(BOUNDS.size());
+        node.move_to(BOUNDS.position());
+
+        let mut messages = Vec::new();
+
+        for event in [
+            mouse::Event::ButtonPressed(mouse::Button::Left),
+            mouse::Event::ButtonReleased(mouse::Button::Left),
+        ]
+        .iter()
+        {
+            let mut xy_pad = XYPad::<_, renderer::Null>::new(state, |x, y| {
+                (x.as_f32(), y.as_f32())
+            })
+            .snapshots(
+                |_, x, y| (-x.as_f32(), -y.as_f32()),
+                |_, x, y| (x.as_f32() + 10.0, y.as_f32() + 10.0),
+            )
+            .store_hold(store_hold);
+
+            let status = xy_pad.on_event(
+                Event::Mouse(*event),
+                Layout::new(&node),
+                Point::new(x, y),
+                &renderer::Null,
+                &mut clipboard::Null,
+                &mut messages,
+            );
+            assert_eq!(status, event::Status::Captured);
+        }
+
+        messages
+    }
+
+    #[test]
+    fn long_press_on_corner_stores_snapshot() {
+        let mut state = new_state();
+        state.set_normal_x(0.25.into());
+        state.set_normal_y(0.75.into());
+
+        let messages =
+            click_corner(&mut state, Duration::from_secs(0), 12.0, 22.0);
+
+        assert_eq!(messages, vec![(10.25, 10.75)]);
+        assert_eq!(
+            state.snapshot(Corner::TopLeft),
+            Some((0.25.into(), 0.75.into()))
+        );
+        assert!(!state.is_dragging());
+    }
+
+    #[test]
+    fn click_on_corner_recalls_snapshot() {
+        let mut state = new_state();
+        state.set_snapshot(Corner::BottomRight, Some((1.0.into(), 0.0.into())));
+
+        let messages =
+            click_corner(&mut state, Duration::from_secs(60), 136.0, 146.0);
+
+        assert_eq!(messages, vec![(-1.0, -0.0), (1.0, 0.0)]);
+        assert_eq!(values(&state), (1.0, 0.0));
+    }
+
+    #[test]
+    fn click_on_empty_corner_does_nothing() {
+        let mut state = new_state();
+
+        let messages =
+            click_corner(&mut state, Duration::from_secs(60), 136.0, 22.0);
+
+        assert!(messages.is_empty());
+        assert_eq!(values(&state), (0.0, 0.0));
+    }
+
+    #[test]
+    fn grab_and_release_bracket_changes() {
+        let mut state = new_state();
+        let mut node = layout::Node::new(BOUNDS.size());
+        node.move_to(BOUNDS.position());
+
+        let mut messages = Vec::new();
+
+        for (event, x, y) in [
+            (mouse::Event::ButtonPressed(mouse::Button::Left), 42.0, 52.0),
+            (
+                mouse::Event::CursorMoved {
+                    position: Point::new(74.0, 84.0),
+                },
+                74.0,
+                84.0,
+            ),
+            (
+                mouse::Event::ButtonReleased(mouse::Button::Left),
+                74.0,
+                84.0,
+            ),
+        ]
+        .iter()
+        {
+            let mut xy_pad =
+                XYPad::<_, renderer::Null>::new(&mut state, |x, y| {
+                    (x.as_f32(), y.as_f32())
+                })
+                .input_config(InputConfig {
+                    drag_threshold: 0.0,
+                    ..InputConfig::default()
+                })
+                .on_grab(|| (-1.0, -1.0))
+                .on_release(|| (-2.0, -2.0));
+
+            let _ = xy_pad.on_event(
+                Event::Mouse(*event),
+                Layout::new(&node),
+                Point::new(*x, *y),
+                &renderer::Null,
+                &mut clipboard::Null,
+                &mut messages,
+            );
+        }
+
+        assert_eq!(
+            messages,
+            vec![(-1.0, -1.0), (0.25, 0.75), (0.5, 0.5), (-2.0, -2.0)]
+        );
+    }
+
+    #[test]
+    fn arrow_keys_only_move_focused_pad() {
+        let mut state = new_state();
+        assert!(key(&mut state, keyboard::KeyCode::Right).is_empty());
+
+        let _ = press(&mut state, 74.0, 84.0);
+        let _ = release(&mut state, 74.0, 84.0);
+        assert!(state.is_focused());
+
+        let messages = key(&mut state, keyboard::KeyCode::Right);
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].0 > 0.5);
+        assert_eq!(messages[0].1, 0.5);
+
+        assert!(key(&mut state, keyboard::KeyCode::Escape).is_empty());
+        assert!(!state.is_focused());
+        assert!(key(&mut state, keyboard::KeyCode::Up).is_empty());
+    }
+
+    #[test]
+    fn press_outside_bounds_removes_focus() {
+        let mut state = new_state();
+        state.focus();
+
+        let _ = press(&mut state, 0.0, 0.0);
+        assert!(!state.is_focused());
+    }
+
+    #[test]
+    fn axis_lock_keeps_the_minor_axis() {
+        let mut state = new_state();
+        let _ = press(&mut state, 74.0, 84.0);
+
+        let _ = send_event(
+            &mut state,
+            Event::Keyboard(keyboard::Event::ModifiersChanged(
+                keyboard::Modifiers {
+                    shift: true,
+                    ..Default::default()
+                },
+            )),
+            74.0,
+            84.0,
+        );
+
+        assert_eq!(move_to(&mut state, 106.0, 78.0), vec![(0.75, 0.5)]);
+        assert_eq!(move_to(&mut state, 74.0, 116.0), vec![(0.5, 0.25)]);
+    }
+
+    #[test]
+    fn long_glide_step_stays_in_range() {
+        let throw = Throw {
+            friction: 0.0,
+            bounce: 0.5,
+        };
+
+        let (normal, velocity) = glide_axis(0.5, 100.0, 1.0, &throw);
+        assert_eq!(normal, 0.0);
+        assert_eq!(velocity, -50.0);
+
+        let (normal, _) = glide_axis(0.5, -100.0, 1.0, &throw);
+        assert_eq!(normal, 1.0);
+    }
+}
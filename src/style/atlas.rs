@@ -0,0 +1,42 @@
+//! Select part of a texture atlas image
+
+use iced_native::{Rectangle, Size};
+
+/// A region of a texture atlas, which is a single image that holds many
+/// graphics, such as all the handles and knobs of a skin.
+///
+/// Both `atlas_size` and `rect` are in the same units, usually the pixels
+/// of the image file.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct AtlasRegion {
+    /// The size of the whole atlas image
+    pub atlas_size: Size,
+    /// The region of the atlas image to use
+    pub rect: Rectangle,
+}
+
+impl AtlasRegion {
+    /// Creates a new `AtlasRegion` of `rect` within an atlas image of size
+    /// `atlas_size`.
+    pub fn new(atlas_size: Size, rect: Rectangle) -> Self {
+        Self { atlas_size, rect }
+    }
+
+    /// Returns the region of frame `frame` when this region is split into
+    /// `frames` frames of the same size stacked vertically, such as a
+    /// filmstrip.
+    pub fn frame(&self, frame: u16, frames: u16) -> Self {
+        let frames = frames.max(1);
+        let height = self.rect.height / f32::from(frames);
+
+        Self {
+            atlas_size: self.atlas_size,
+            rect: Rectangle {
+                x: self.rect.x,
+                y: self.rect.y + (f32::from(frame.min(frames - 1)) * height),
+                width: self.rect.width,
+                height,
+            },
+        }
+    }
+}
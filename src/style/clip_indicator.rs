@@ -0,0 +1,79 @@
+//! Various styles for the [`ClipIndicator`] widget
+//!
+//! [`ClipIndicator`]: ../native/clip_indicator/struct.ClipIndicator.html
+
+use iced_native::Color;
+
+use crate::style::default_colors;
+
+/// The appearance of a [`ClipIndicator`].
+///
+/// [`ClipIndicator`]: ../../native/clip_indicator/struct.ClipIndicator.html
+#[derive(Debug, Copy, Clone)]
+pub struct Style {
+    /// The color of the LED while it is lit
+    pub on_color: Color,
+    /// The color of the LED while it is off
+    pub off_color: Color,
+    /// The width of the border
+    pub border_width: f32,
+    /// The color of the border
+    pub border_color: Color,
+    /// The radius of the corners
+    pub border_radius: f32,
+    /// The color the LED flashes to when the signal clips, which then
+    /// fades to `on_color` over `flash_decay` seconds. Set this to `None`
+    /// for no flash.
+    pub flash_color: Option<Color>,
+    /// The time in seconds the flash takes to fade to `on_color`
+    pub flash_decay: f32,
+}
+
+/// A set of rules that dictate the style of a [`ClipIndicator`].
+///
+/// [`ClipIndicator`]: ../../native/clip_indicator/struct.ClipIndicator.html
+pub trait StyleSheet {
+    /// Produces the style of a [`ClipIndicator`].
+    ///
+    /// [`ClipIndicator`]: ../../native/clip_indicator/struct.ClipIndicator.html
+    fn style(&self) -> Style;
+}
+
+struct Default;
+impl Default {
+    const STYLE: Style = Style {
+        on_color: default_colors::CLIP_INDICATOR_ON,
+        off_color: default_colors::CLIP_INDICATOR_OFF,
+        border_width: 1.0,
+        border_color: default_colors::BORDER,
+        border_radius: 2.0,
+        flash_color: Some(default_colors::CLIP_INDICATOR_FLASH),
+        flash_decay: 0.5,
+    };
+}
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Self::STYLE
+    }
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Default::STYLE
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -0,0 +1,77 @@
+//! Various styles for the [`CorrelationMeter`] widget
+//!
+//! [`CorrelationMeter`]: ../native/correlation_meter/struct.CorrelationMeter.html
+
+use iced_native::Color;
+
+use crate::style::default_colors;
+
+/// The appearance of a [`CorrelationMeter`].
+///
+/// [`CorrelationMeter`]: ../../native/correlation_meter/struct.CorrelationMeter.html
+#[derive(Debug, Copy, Clone)]
+pub struct Style {
+    /// The color of the background rectangle
+    pub back_color: Color,
+    /// The width of the border of the background rectangle
+    pub back_border_width: f32,
+    /// The color of the border of the background rectangle
+    pub back_border_color: Color,
+    /// The color of positive correlation values (above the center line)
+    pub positive_color: Color,
+    /// The color of negative correlation values (below the center line)
+    pub negative_color: Color,
+    /// The width of the center line
+    pub center_line_width: f32,
+    /// The color of the center line. Set this to `None` for no center line.
+    pub center_line_color: Option<Color>,
+}
+
+/// A set of rules that dictate the style of a [`CorrelationMeter`].
+///
+/// [`CorrelationMeter`]: ../../native/correlation_meter/struct.CorrelationMeter.html
+pub trait StyleSheet {
+    /// Produces the style of a [`CorrelationMeter`].
+    ///
+    /// [`CorrelationMeter`]: ../../native/correlation_meter/struct.CorrelationMeter.html
+    fn style(&self) -> Style;
+}
+
+struct Default;
+impl Default {
+    const STYLE: Style = Style {
+        back_color: default_colors::CORRELATION_METER_BACK,
+        back_border_width: 1.0,
+        back_border_color: default_colors::BORDER,
+        positive_color: default_colors::CORRELATION_METER_POSITIVE,
+        negative_color: default_colors::CORRELATION_METER_NEGATIVE,
+        center_line_width: 1.0,
+        center_line_color: Some(default_colors::CORRELATION_METER_CENTER_LINE),
+    };
+}
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Self::STYLE
+    }
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Default::STYLE
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -0,0 +1,146 @@
+//! Various styles for the [`Crossover`] widget
+//!
+//! [`Crossover`]: ../native/crossover/struct.Crossover.html
+
+use iced_native::Color;
+
+use crate::style::default_colors;
+
+/// The appearance of a [`Crossover`].
+///
+/// [`Crossover`]: ../../native/crossover/struct.Crossover.html
+#[derive(Debug, Clone)]
+pub struct Style {
+    /// The color of the background rectangle
+    pub back_color: Color,
+    /// The width of the border of the background rectangle
+    pub back_border_width: f32,
+    /// The color of the border of the background rectangle
+    pub back_border_color: Color,
+    /// The height of the rail the bands are drawn on. The rail is centered
+    /// vertically, and a split point handle spans the whole height of the
+    /// widget.
+    pub rail_height: f32,
+    /// The colors of the bands between the split points, from left to
+    /// right. Band `n` uses color `n % 8`.
+    pub band_colors: [Color; 8],
+}
+
+impl Style {
+    /// Returns the color of the band at `index`.
+    pub fn band_color(&self, index: usize) -> Color {
+        self.band_colors[index % self.band_colors.len()]
+    }
+}
+
+/// The appearance of the handle of a split point in a [`Crossover`].
+///
+/// [`Crossover`]: ../../native/crossover/struct.Crossover.html
+#[derive(Debug, Copy, Clone)]
+pub struct HandleStyle {
+    /// The width of the handle
+    pub width: f32,
+    /// The color of the handle
+    pub color: Color,
+    /// The radius of the corners of the handle
+    pub border_radius: f32,
+    /// The width of the border of the handle
+    pub border_width: f32,
+    /// The color of the border of the handle
+    pub border_color: Color,
+}
+
+/// A set of rules that dictate the style of a [`Crossover`].
+///
+/// [`Crossover`]: ../../native/crossover/struct.Crossover.html
+pub trait StyleSheet {
+    /// Produces the style of a [`Crossover`].
+    ///
+    /// [`Crossover`]: ../../native/crossover/struct.Crossover.html
+    fn style(&self) -> Style;
+
+    /// Produces the style of the handle of a split point.
+    fn handle(&self) -> HandleStyle;
+
+    /// Produces the style of the handle of a split point under the cursor.
+    fn hovered_handle(&self) -> HandleStyle {
+        self.handle()
+    }
+
+    /// Produces the style of the handle of a split point that is being
+    /// dragged.
+    fn dragging_handle(&self) -> HandleStyle {
+        self.hovered_handle()
+    }
+}
+
+struct Default;
+impl Default {
+    const STYLE: Style = Style {
+        back_color: default_colors::EQ_BACK,
+        back_border_width: 1.0,
+        back_border_color: default_colors::BORDER,
+        rail_height: 8.0,
+        band_colors: default_colors::EQ_BANDS,
+    };
+    const HANDLE_STYLE: HandleStyle = HandleStyle {
+        width: 4.0,
+        color: default_colors::EQ_SUM_CURVE,
+        border_radius: 2.0,
+        border_width: 1.0,
+        border_color: default_colors::BORDER,
+    };
+}
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Self::STYLE
+    }
+
+    fn handle(&self) -> HandleStyle {
+        Self::HANDLE_STYLE
+    }
+
+    fn hovered_handle(&self) -> HandleStyle {
+        HandleStyle {
+            width: 6.0,
+            border_radius: 3.0,
+            ..Self::HANDLE_STYLE
+        }
+    }
+
+    fn dragging_handle(&self) -> HandleStyle {
+        HandleStyle {
+            width: 6.0,
+            color: Color::WHITE,
+            border_radius: 3.0,
+            ..Self::HANDLE_STYLE
+        }
+    }
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Default::STYLE
+    }
+}
+
+impl std::default::Default for HandleStyle {
+    fn default() -> Self {
+        Default::HANDLE_STYLE
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -0,0 +1,163 @@
+//! Various styles for the [`DBMeter`] widget
+//!
+//! [`DBMeter`]: ../native/db_meter/struct.DBMeter.html
+
+use iced_native::Color;
+
+use crate::core::{KSystem, LogDBRange};
+use crate::style::default_colors;
+
+/// The appearance of a [`DBMeter`].
+///
+/// The meter bar is split into up to four colored zones. Zone boundaries
+/// are positions on the meter from `0.0` (bottom or left) to `1.0` (top or
+/// right), and can
+/// be taken from [`meter_layout::zones`] to line them up with decibel
+/// values.
+///
+/// With `gradient` set, the zone boundaries become the stops of a smooth
+/// color ramp instead.
+///
+/// [`DBMeter`]: ../../native/db_meter/struct.DBMeter.html
+/// [`meter_layout::zones`]: ../../core/meter_layout/fn.zones.html
+#[derive(Debug, Copy, Clone)]
+pub struct Style {
+    /// The color of the background rectangle
+    pub back_color: Color,
+    /// The width of the border of the background rectangle
+    pub back_border_width: f32,
+    /// The color of the border of the background rectangle
+    pub back_border_color: Color,
+    /// The color of the bar below `med_start`
+    pub low_color: Color,
+    /// The color of the bar from `med_start` to `high_start`
+    pub med_color: Color,
+    /// The color of the bar from `high_start` to `clip_start`
+    pub high_color: Color,
+    /// The color of the bar above `clip_start`
+    pub clip_color: Color,
+    /// The position where the `med_color` zone starts
+    pub med_start: f32,
+    /// The position where the `high_color` zone starts
+    pub high_start: f32,
+    /// The position where the `clip_color` zone starts
+    pub clip_start: f32,
+    /// If set, the zone colored bar shows the RMS level of each channel,
+    /// and a bar of this color behind it shows the peak level. Use a
+    /// lighter or translucent color so the RMS bar stands out.
+    ///
+    /// If `None`, only the peak level is shown.
+    pub peak_bar_color: Option<Color>,
+    /// If `true`, the bar blends smoothly from `low_color` at the start
+    /// to `med_color` at `med_start`, `high_color` at `high_start`, and
+    /// `clip_color` at `clip_start`, instead of using flat zones
+    pub gradient: bool,
+    /// The color of the peak-hold line
+    pub peak_line_color: Color,
+    /// The thickness of the peak-hold line. Set this to `0.0` to hide it.
+    pub peak_line_width: f32,
+    /// The gap between the left and right bars of a stereo meter
+    pub channel_gap: f32,
+    /// If `true`, a stereo meter draws a single peak-hold line across
+    /// both bars at the higher of the two peaks
+    pub shared_peak_line: bool,
+}
+
+impl Style {
+    /// Returns the default style with a solid RMS bar in front of a lighter
+    /// peak bar, the usual look of DAW channel meters.
+    ///
+    /// Feed the levels with [`State::update_with_rms`].
+    ///
+    /// [`State::update_with_rms`]: ../../native/db_meter/struct.State.html#method.update_with_rms
+    pub fn rms_peak() -> Self {
+        Style {
+            peak_bar_color: Some(default_colors::DB_METER_PEAK_BAR),
+            ..Default::STYLE
+        }
+    }
+
+    /// Returns the default style with the green, yellow, and red zones of a
+    /// [`KSystem`] scale, positioned for a meter that uses `range`.
+    ///
+    /// The bar is green below the `0` point, yellow up to `+4` on the scale,
+    /// and red above that.
+    ///
+    /// * `k_system` - the [`KSystem`] of the scale
+    /// * `range` - the range of the meter in dBFS, such as
+    ///   [`KSystem::range`]
+    ///
+    /// [`KSystem`]: ../../core/meter_calibration/enum.KSystem.html
+    /// [`KSystem::range`]: ../../core/meter_calibration/enum.KSystem.html#method.range
+    pub fn k_system(k_system: KSystem, range: &LogDBRange) -> Self {
+        let [zero, plus_four] = k_system.zone_starts();
+
+        Style {
+            low_color: default_colors::DB_METER_LOW,
+            med_color: default_colors::DB_METER_HIGH,
+            high_color: default_colors::DB_METER_CLIP,
+            med_start: range.map_to_normal(zero).as_f32(),
+            high_start: range.map_to_normal(plus_four).as_f32(),
+            clip_start: 1.0,
+            ..Default::STYLE
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a [`DBMeter`].
+///
+/// [`DBMeter`]: ../../native/db_meter/struct.DBMeter.html
+pub trait StyleSheet {
+    /// Produces the style of a [`DBMeter`].
+    ///
+    /// [`DBMeter`]: ../../native/db_meter/struct.DBMeter.html
+    fn style(&self) -> Style;
+}
+
+struct Default;
+impl Default {
+    const STYLE: Style = Style {
+        back_color: default_colors::DB_METER_BACK,
+        back_border_width: 1.0,
+        back_border_color: default_colors::DB_METER_BORDER,
+        low_color: default_colors::DB_METER_LOW,
+        med_color: default_colors::DB_METER_MED,
+        high_color: default_colors::DB_METER_HIGH,
+        clip_color: default_colors::DB_METER_CLIP,
+        med_start: 0.6,
+        high_start: 0.85,
+        clip_start: 0.97,
+        peak_bar_color: None,
+        gradient: false,
+        peak_line_color: default_colors::DB_METER_PEAK_LINE,
+        peak_line_width: 2.0,
+        channel_gap: 2.0,
+        shared_peak_line: false,
+    };
+}
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Self::STYLE
+    }
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Default::STYLE
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -6,6 +6,15 @@ pub const BORDER: Color = Color::from_rgb(0.315, 0.315, 0.315);
 pub const LIGHT_BACK: Color = Color::from_rgb(0.97, 0.97, 0.97);
 pub const LIGHT_BACK_HOVER: Color = Color::from_rgb(0.93, 0.93, 0.93);
 pub const LIGHT_BACK_DRAG: Color = Color::from_rgb(0.92, 0.92, 0.92);
+pub const PARAM_WARNING: Color = Color::from_rgb(0.95, 0.3, 0.1);
+pub const ACCENT: Color = Color::from_rgb(0.44, 0.65, 0.85);
+pub const ACCENT_INVERSE: Color = Color::from_rgb(0.85, 0.62, 0.44);
+pub const EMPTY: Color = Color {
+    r: 0.56,
+    g: 0.56,
+    b: 0.56,
+    a: 0.35,
+};
 
 pub const SLIDER_RAIL: (Color, Color) = (
     Color {
@@ -64,14 +73,93 @@ pub const XY_PAD_CENTER_LINE: Color = Color {
     b: 0.56,
     a: 0.5,
 };
+pub const XY_PAD_SNAPSHOT_EMPTY: Color = Color {
+    r: 0.56,
+    g: 0.56,
+    b: 0.56,
+    a: 0.25,
+};
+pub const XY_PAD_SNAPSHOT_STORED: Color = Color::from_rgb(0.44, 0.65, 0.85);
+
+pub const WAVEFORM_BACK: Color = Color::from_rgb(0.2, 0.2, 0.2);
+pub const WAVEFORM: Color = Color::from_rgb(0.435, 0.886, 0.11);
+pub const WAVEFORM_CENTER_LINE: Color = Color {
+    r: 0.56,
+    g: 0.56,
+    b: 0.56,
+    a: 0.5,
+};
+
+pub const CORRELATION_METER_BACK: Color = Color::from_rgb(0.2, 0.2, 0.2);
+pub const CORRELATION_METER_POSITIVE: Color =
+    Color::from_rgb(0.435, 0.886, 0.11);
+pub const CORRELATION_METER_NEGATIVE: Color =
+    Color::from_rgb(1.0, 0.071, 0.071);
+pub const CORRELATION_METER_CENTER_LINE: Color = Color {
+    r: 0.56,
+    g: 0.56,
+    b: 0.56,
+    a: 0.5,
+};
+
+pub const EQ_BACK: Color = Color::from_rgb(0.2, 0.2, 0.2);
+pub const EQ_SPECTRUM: Color = Color {
+    r: 0.56,
+    g: 0.56,
+    b: 0.56,
+    a: 0.35,
+};
+pub const EQ_ZERO_LINE: Color = Color {
+    r: 0.56,
+    g: 0.56,
+    b: 0.56,
+    a: 0.5,
+};
+pub const EQ_SUM_CURVE: Color = Color::from_rgb(0.92, 0.92, 0.92);
+pub const EQ_BANDS: [Color; 8] = [
+    Color::from_rgb(0.95, 0.33, 0.31),
+    Color::from_rgb(0.98, 0.62, 0.2),
+    Color::from_rgb(0.96, 0.85, 0.25),
+    Color::from_rgb(0.435, 0.886, 0.11),
+    Color::from_rgb(0.2, 0.8, 0.7),
+    Color::from_rgb(0.3, 0.6, 0.98),
+    Color::from_rgb(0.6, 0.45, 0.95),
+    Color::from_rgb(0.93, 0.4, 0.75),
+];
+
+pub const VU_METER_BACK: Color = Color::from_rgb(0.93, 0.88, 0.72);
+pub const VU_METER_SCALE: Color = Color::from_rgb(0.15, 0.15, 0.15);
+pub const VU_METER_OVER: Color = Color::from_rgb(0.85, 0.1, 0.1);
+pub const VU_METER_NEEDLE: Color = Color::from_rgb(0.1, 0.1, 0.1);
+
+pub const CLIP_INDICATOR_ON: Color = Color::from_rgb(0.85, 0.071, 0.071);
+pub const CLIP_INDICATOR_OFF: Color = Color::from_rgb(0.3, 0.15, 0.15);
+pub const CLIP_INDICATOR_FLASH: Color = Color::from_rgb(1.0, 0.6, 0.6);
 
-/*
 pub const DB_METER_BACK: Color = Color::from_rgb(0.45, 0.45, 0.45);
 pub const DB_METER_BORDER: Color = Color::from_rgb(0.2, 0.2, 0.2);
 pub const DB_METER_LOW: Color = Color::from_rgb(0.435, 0.886, 0.11);
 pub const DB_METER_MED: Color = Color::from_rgb(0.737, 1.0, 0.145);
 pub const DB_METER_HIGH: Color = Color::from_rgb(1.0, 0.945, 0.0);
 pub const DB_METER_CLIP: Color = Color::from_rgb(1.0, 0.071, 0.071);
+pub const DB_METER_PEAK_LINE: Color = Color::from_rgb(0.92, 0.92, 0.92);
+pub const DB_METER_PEAK_BAR: Color = Color {
+    r: 0.92,
+    g: 0.92,
+    b: 0.92,
+    a: 0.35,
+};
+
+pub const REDUCTION_HISTORY_BACK: Color = Color::from_rgb(0.2, 0.2, 0.2);
+pub const REDUCTION_HISTORY_FILL: Color = Color {
+    r: 0.95,
+    g: 0.55,
+    b: 0.2,
+    a: 0.6,
+};
+pub const REDUCTION_HISTORY_BAR: Color = Color::from_rgb(0.95, 0.55, 0.2);
+
+/*
 pub const DB_METER_CLIP_MARKER: Color = Color {
     r: 0.78,
     g: 0.78,
@@ -0,0 +1,153 @@
+//! Various styles for the [`EqBands`] widget
+//!
+//! [`EqBands`]: ../native/eq_bands/struct.EqBands.html
+
+use iced_native::Color;
+
+use crate::style::default_colors;
+
+/// The appearance of an [`EqBands`].
+///
+/// [`EqBands`]: ../../native/eq_bands/struct.EqBands.html
+#[derive(Debug, Clone)]
+pub struct Style {
+    /// The color of the background rectangle
+    pub back_color: Color,
+    /// The width of the border of the background rectangle
+    pub back_border_width: f32,
+    /// The color of the border of the background rectangle
+    pub back_border_color: Color,
+    /// The fill color of the spectrum. Set this to `None` to hide the
+    /// spectrum.
+    pub spectrum_color: Option<Color>,
+    /// The width of the 0 dB line
+    pub zero_line_width: f32,
+    /// The color of the 0 dB line. Set this to `None` for no 0 dB line.
+    pub zero_line_color: Option<Color>,
+    /// The colors of the bands. Band `n` uses color `n % 8`.
+    pub band_colors: [Color; 8],
+    /// The width of the response curve of each band. Set this to `0.0` to
+    /// only draw the combined response curve.
+    pub band_curve_width: f32,
+    /// The color of the combined response curve of all bands
+    pub sum_curve_color: Color,
+    /// The width of the combined response curve of all bands
+    pub sum_curve_width: f32,
+}
+
+impl Style {
+    /// Returns the color of the band at `index`.
+    pub fn band_color(&self, index: usize) -> Color {
+        self.band_colors[index % self.band_colors.len()]
+    }
+}
+
+/// The appearance of the node of a band in an [`EqBands`]. The node is
+/// filled with the color of its band.
+///
+/// [`EqBands`]: ../../native/eq_bands/struct.EqBands.html
+#[derive(Debug, Copy, Clone)]
+pub struct NodeStyle {
+    /// The diameter of the node
+    pub diameter: f32,
+    /// The width of the border of the node
+    pub border_width: f32,
+    /// The color of the border of the node
+    pub border_color: Color,
+}
+
+/// A set of rules that dictate the style of an [`EqBands`].
+///
+/// [`EqBands`]: ../../native/eq_bands/struct.EqBands.html
+pub trait StyleSheet {
+    /// Produces the style of an [`EqBands`].
+    ///
+    /// [`EqBands`]: ../../native/eq_bands/struct.EqBands.html
+    fn style(&self) -> Style;
+
+    /// Produces the style of a node.
+    fn node(&self) -> NodeStyle;
+
+    /// Produces the style of a node under the cursor.
+    fn hovered_node(&self) -> NodeStyle {
+        self.node()
+    }
+
+    /// Produces the style of a node that is being dragged.
+    fn dragging_node(&self) -> NodeStyle {
+        self.hovered_node()
+    }
+}
+
+struct Default;
+impl Default {
+    const STYLE: Style = Style {
+        back_color: default_colors::EQ_BACK,
+        back_border_width: 1.0,
+        back_border_color: default_colors::BORDER,
+        spectrum_color: Some(default_colors::EQ_SPECTRUM),
+        zero_line_width: 1.0,
+        zero_line_color: Some(default_colors::EQ_ZERO_LINE),
+        band_colors: default_colors::EQ_BANDS,
+        band_curve_width: 1.0,
+        sum_curve_color: default_colors::EQ_SUM_CURVE,
+        sum_curve_width: 2.0,
+    };
+    const NODE_STYLE: NodeStyle = NodeStyle {
+        diameter: 12.0,
+        border_width: 1.0,
+        border_color: default_colors::BORDER,
+    };
+}
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Self::STYLE
+    }
+
+    fn node(&self) -> NodeStyle {
+        Self::NODE_STYLE
+    }
+
+    fn hovered_node(&self) -> NodeStyle {
+        NodeStyle {
+            diameter: 14.0,
+            border_color: default_colors::EQ_SUM_CURVE,
+            ..Self::NODE_STYLE
+        }
+    }
+
+    fn dragging_node(&self) -> NodeStyle {
+        NodeStyle {
+            diameter: 14.0,
+            border_width: 2.0,
+            border_color: default_colors::EQ_SUM_CURVE,
+        }
+    }
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Default::STYLE
+    }
+}
+
+impl std::default::Default for NodeStyle {
+    fn default() -> Self {
+        Default::NODE_STYLE
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
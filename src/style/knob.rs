@@ -2,20 +2,22 @@
 //!
 //! [`Knob`]: ../native/knob/struct.Knob.html
 
-use iced_native::Color;
-//use iced_native::image;
+use iced_native::{image, Color};
 
 pub use iced_graphics::canvas::LineCap;
 
+use crate::style::atlas::AtlasRegion;
+use crate::style::transition::{switch, Interpolate};
 use crate::style::{default_colors, text_marks, tick_marks};
-use crate::KnobAngleRange;
+use crate::{KnobAngleRange, Normal};
 
 /// The appearance of a [`Knob`],
 ///
 /// [`Knob`]: ../../native/knob/struct.Knob.html
 #[derive(Debug, Clone)]
 pub enum Style {
-    //Texture(TextureStyle),
+    /// A filmstrip of image frames, one of which is shown for the value
+    Texture(TextureStyle),
     /// A classic circular style
     Circle(CircleStyle),
     /// A modern arc style
@@ -23,6 +25,38 @@ pub enum Style {
     /// A modern arc style with. It can display different colors
     /// for left, right, and center positions.
     ArcBipolar(ArcBipolarStyle),
+    /// An arc style for stereo width, with two notches that spread apart
+    /// from the center as the value increases.
+    StereoWidth(StereoWidthStyle),
+}
+
+impl Style {
+    /// Returns this style with its value colors replaced by `accent`.
+    ///
+    /// This recolors the notch of a `Circle` style and the filled portions
+    /// of the `Arc`, `ArcBipolar` and `StereoWidth` styles.
+    pub fn with_accent(self, accent: Color) -> Self {
+        match self {
+            Style::Circle(style) => Style::Circle(CircleStyle {
+                notch: style.notch.with_color(accent),
+                ..style
+            }),
+            Style::Arc(style) => Style::Arc(ArcStyle {
+                filled_color: accent,
+                ..style
+            }),
+            Style::ArcBipolar(style) => Style::ArcBipolar(ArcBipolarStyle {
+                left_filled_color: accent,
+                right_filled_color: accent,
+                ..style
+            }),
+            Style::StereoWidth(style) => Style::StereoWidth(StereoWidthStyle {
+                filled_color: accent,
+                ..style
+            }),
+            Style::Texture(style) => Style::Texture(style),
+        }
+    }
 }
 
 /*
@@ -68,6 +102,28 @@ impl StyleLength {
     }
 }
 
+/// A [`Style`] for a [`Knob`] that uses a filmstrip (spritesheet) image,
+/// such as one rendered with KnobMan.
+///
+/// The image contains `frames` frames of the same size, stacked vertically
+/// from the minimum value at the top to the maximum value at the bottom.
+/// The frame nearest to the value is drawn over the whole [`Knob`].
+///
+/// [`Style`]: enum.Style.html
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+#[derive(Debug, Clone)]
+pub struct TextureStyle {
+    /// The [`Handle`] to the filmstrip image
+    ///
+    /// [`Handle`]: https://docs.rs/iced/0.2.0/iced/widget/image/struct.Handle.html
+    pub image_handle: image::Handle,
+    /// The number of frames in the image
+    pub frames: u16,
+    /// The region of the image that holds the frames if it is a texture
+    /// atlas, or `None` if the frames fill the whole image.
+    pub image_region: Option<AtlasRegion>,
+}
+
 /// Circle notch
 #[derive(Debug, Clone)]
 pub struct CircleNotch {
@@ -109,6 +165,21 @@ pub enum NotchShape {
     Line(LineNotch),
 }
 
+impl NotchShape {
+    /// Returns this notch with its color replaced by `color`.
+    pub fn with_color(self, color: Color) -> Self {
+        match self {
+            NotchShape::None => NotchShape::None,
+            NotchShape::Circle(notch) => {
+                NotchShape::Circle(CircleNotch { color, ..notch })
+            }
+            NotchShape::Line(notch) => {
+                NotchShape::Line(LineNotch { color, ..notch })
+            }
+        }
+    }
+}
+
 /// A classic circular [`Style`] of a [`Knob`]
 ///
 /// [`Style`]: enum.Style.html
@@ -145,7 +216,7 @@ pub struct ArcStyle {
 
 /// A modern arc [`Style`] of a [`Knob`].
 /// It can display different colors for left, right, and center positions. The filled arc
-/// color draws from the center position.
+/// color draws from the `center` position.
 ///
 /// [`Style`]: enum.Style.html
 /// [`Knob`]: ../../native/knob/struct.Knob.html
@@ -159,6 +230,9 @@ pub struct ArcBipolarStyle {
     pub left_filled_color: Color,
     /// The color of the filled portion to the right of the center
     pub right_filled_color: Color,
+    /// The value the filled arc grows from, such as `Normal::center()` for
+    /// the 12 o'clock position of a symmetrical angle range
+    pub center: Normal,
     /// The shape of the notch when in the center position
     pub notch_center: NotchShape,
     /// The shape of the notch when it is to the left and right of the
@@ -168,6 +242,30 @@ pub struct ArcBipolarStyle {
     pub cap: LineCap,
 }
 
+/// A stereo width [`Style`] of a [`Knob`].
+///
+/// Two mirrored notches spread apart from the center as the value
+/// increases, and the arc between them is filled. At the minimum value
+/// (mono) both notches meet at the center, and at the maximum value they
+/// reach the ends of the arc. Map the value with a [`WidthRange`].
+///
+/// [`Style`]: enum.Style.html
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+/// [`WidthRange`]: ../../core/range/struct.WidthRange.html
+#[derive(Debug, Clone)]
+pub struct StereoWidthStyle {
+    /// The width (thickness) of the arc
+    pub width: StyleLength,
+    /// The color of the empty background portion of the arc
+    pub empty_color: Color,
+    /// The color of the filled portion of the arc between the notches
+    pub filled_color: Color,
+    /// The shape of both notches
+    pub notch: NotchShape,
+    /// The cap at the ends of the arc
+    pub cap: LineCap,
+}
+
 /// A style for a value arc around a [`Knob`]
 ///
 /// [`Knob`]: ../../native/knob/struct.Knob.html
@@ -190,6 +288,17 @@ pub struct ValueArcStyle {
     pub cap: LineCap,
 }
 
+impl ValueArcStyle {
+    /// Returns this style with its filled colors replaced by `accent`.
+    pub fn with_accent(self, accent: Color) -> Self {
+        Self {
+            left_filled_color: accent,
+            right_filled_color: self.right_filled_color.map(|_| accent),
+            ..self
+        }
+    }
+}
+
 /// A style for a [`ModulationRange`] arc around a [`Knob`]
 ///
 /// [`ModulationRange`]: ../../core/struct.ModulationRange.html
@@ -274,6 +383,16 @@ pub trait StyleSheet {
     /// [`Knob`]: ../../native/knob/struct.Knob.html
     fn dragging(&self) -> Style;
 
+    /// Produces the style of a [`Knob`] that has keyboard focus and is
+    /// not hovered or dragged.
+    ///
+    /// By default, this is the same as the hovered style.
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn focused(&self) -> Style {
+        self.hovered()
+    }
+
     /// a [`KnobAngleRange`] that defines the minimum and maximum angle that the
     /// knob rotates
     ///
@@ -321,6 +440,38 @@ pub trait StyleSheet {
         None
     }
 
+    /// The shape of the ghost notch that shows the modulated value of a
+    /// [`Knob`]
+    ///
+    /// For no ghost notch, don't override this or set this to return `None`.
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn modulated_value_style(&self) -> Option<NotchShape> {
+        None
+    }
+
+    /// The style of the dimmed ghost notch that shows the automation playback
+    /// value of a [`Knob`]
+    ///
+    /// For no automation ghost notch, don't override this or set this to return `None`.
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn automation_value_style(&self) -> Option<NotchShape> {
+        None
+    }
+
+    /// The shape of the ghost notch that shows the default value of a
+    /// [`Knob`], where a reset gesture will move it to. This is usually a
+    /// faint version of the notch of the [`Style`].
+    ///
+    /// For no default notch, don't override this or set this to return `None`.
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    /// [`Style`]: enum.Style.html
+    fn default_notch_style(&self) -> Option<NotchShape> {
+        None
+    }
+
     /// The style of text marks around a [`Knob`]
     ///
     /// For no text marks, don't override this or set this to return `None`.
@@ -330,6 +481,14 @@ pub trait StyleSheet {
     fn text_marks_style(&self) -> Option<TextMarksStyle> {
         None
     }
+
+    /// The color that replaces the accent color of a [`Knob`] while its
+    /// value is above its warning threshold.
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn warning_color(&self) -> Color {
+        default_colors::PARAM_WARNING
+    }
 }
 
 struct Default;
@@ -365,7 +524,132 @@ impl StyleSheet for Default {
     }
 
     fn tick_marks_style(&self) -> Option<TickMarksStyle> {
-        Some(TickMarksStyle {
+        Some(TickMarksStyle::default())
+    }
+
+    fn text_marks_style(&self) -> Option<TextMarksStyle> {
+        Some(TextMarksStyle {
+            style: text_marks::Style::default(),
+            offset: 14.0,
+            h_char_offset: 3.0,
+            v_offset: -0.75,
+        })
+    }
+}
+
+impl std::default::Default for CircleStyle {
+    fn default() -> Self {
+        Default::ACTIVE_CIRCLE_STYLE
+    }
+}
+
+impl std::default::Default for CircleNotch {
+    fn default() -> Self {
+        Self {
+            color: default_colors::BORDER,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            diameter: StyleLength::Scaled(0.17),
+            offset: StyleLength::Scaled(0.15),
+        }
+    }
+}
+
+impl std::default::Default for LineNotch {
+    fn default() -> Self {
+        Self {
+            color: default_colors::BORDER,
+            width: StyleLength::Units(3.0),
+            length: StyleLength::Scaled(0.25),
+            cap: LineCap::Round,
+            offset: StyleLength::Units(2.5),
+        }
+    }
+}
+
+impl std::default::Default for ArcStyle {
+    fn default() -> Self {
+        Self {
+            width: StyleLength::Units(3.0),
+            empty_color: default_colors::EMPTY,
+            filled_color: default_colors::ACCENT,
+            notch: NotchShape::Line(LineNotch {
+                color: default_colors::ACCENT,
+                ..LineNotch::default()
+            }),
+            cap: LineCap::Round,
+        }
+    }
+}
+
+impl std::default::Default for ArcBipolarStyle {
+    fn default() -> Self {
+        Self {
+            width: StyleLength::Units(3.0),
+            empty_color: default_colors::EMPTY,
+            left_filled_color: default_colors::ACCENT,
+            right_filled_color: default_colors::ACCENT_INVERSE,
+            center: Normal::center(),
+            notch_center: NotchShape::Line(LineNotch::default()),
+            notch_left_right: Some((
+                NotchShape::Line(LineNotch {
+                    color: default_colors::ACCENT,
+                    ..LineNotch::default()
+                }),
+                NotchShape::Line(LineNotch {
+                    color: default_colors::ACCENT_INVERSE,
+                    ..LineNotch::default()
+                }),
+            )),
+            cap: LineCap::Round,
+        }
+    }
+}
+
+impl std::default::Default for StereoWidthStyle {
+    fn default() -> Self {
+        Self {
+            width: StyleLength::Units(3.0),
+            empty_color: default_colors::EMPTY,
+            filled_color: default_colors::ACCENT,
+            notch: NotchShape::Line(LineNotch {
+                color: default_colors::ACCENT,
+                ..LineNotch::default()
+            }),
+            cap: LineCap::Round,
+        }
+    }
+}
+
+impl std::default::Default for ValueArcStyle {
+    fn default() -> Self {
+        Self {
+            width: 3.0,
+            offset: 1.5,
+            empty_color: Some(default_colors::EMPTY),
+            left_filled_color: default_colors::ACCENT,
+            right_filled_color: None,
+            cap: LineCap::Butt,
+        }
+    }
+}
+
+impl std::default::Default for ModRangeArcStyle {
+    fn default() -> Self {
+        Self {
+            width: 3.0,
+            offset: 6.0,
+            empty_color: Some(default_colors::EMPTY),
+            filled_color: default_colors::ACCENT,
+            filled_inverse_color: default_colors::ACCENT_INVERSE,
+            cap: LineCap::Butt,
+        }
+    }
+}
+
+impl std::default::Default for TickMarksStyle {
+    fn default() -> Self {
+        TickMarksStyle {
             style: tick_marks::Style {
                 tier_1: tick_marks::Shape::Circle {
                     diameter: 4.0,
@@ -381,19 +665,151 @@ impl StyleSheet for Default {
                 },
             },
             offset: 3.5,
-        })
+        }
     }
+}
 
-    fn text_marks_style(&self) -> Option<TextMarksStyle> {
-        Some(TextMarksStyle {
-            style: text_marks::Style::default(),
-            offset: 14.0,
-            h_char_offset: 3.0,
-            v_offset: -0.75,
-        })
+impl Interpolate for Style {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        match (self, other) {
+            (Style::Circle(a), Style::Circle(b)) => {
+                Style::Circle(a.interpolate(b, amount))
+            }
+            (Style::Arc(a), Style::Arc(b)) => {
+                Style::Arc(a.interpolate(b, amount))
+            }
+            (Style::ArcBipolar(a), Style::ArcBipolar(b)) => {
+                Style::ArcBipolar(a.interpolate(b, amount))
+            }
+            (Style::StereoWidth(a), Style::StereoWidth(b)) => {
+                Style::StereoWidth(a.interpolate(b, amount))
+            }
+            _ => switch(self, other, amount),
+        }
+    }
+}
+impl Interpolate for StyleLength {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        match (self, other) {
+            (StyleLength::Scaled(a), StyleLength::Scaled(b)) => {
+                StyleLength::Scaled(a.interpolate(b, amount))
+            }
+            (StyleLength::Units(a), StyleLength::Units(b)) => {
+                StyleLength::Units(a.interpolate(b, amount))
+            }
+            _ => switch(self, other, amount),
+        }
+    }
+}
+
+impl Interpolate for NotchShape {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        match (self, other) {
+            (NotchShape::Circle(a), NotchShape::Circle(b)) => {
+                NotchShape::Circle(a.interpolate(b, amount))
+            }
+            (NotchShape::Line(a), NotchShape::Line(b)) => {
+                NotchShape::Line(a.interpolate(b, amount))
+            }
+            _ => switch(self, other, amount),
+        }
     }
 }
 
+impl Interpolate for CircleNotch {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        CircleNotch {
+            color: self.color.interpolate(&other.color, amount),
+            border_width: self
+                .border_width
+                .interpolate(&other.border_width, amount),
+            border_color: self
+                .border_color
+                .interpolate(&other.border_color, amount),
+            diameter: self.diameter.interpolate(&other.diameter, amount),
+            offset: self.offset.interpolate(&other.offset, amount),
+        }
+    }
+}
+impl Interpolate for LineNotch {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        LineNotch {
+            color: self.color.interpolate(&other.color, amount),
+            width: self.width.interpolate(&other.width, amount),
+            length: self.length.interpolate(&other.length, amount),
+            cap: switch(&self.cap, &other.cap, amount),
+            offset: self.offset.interpolate(&other.offset, amount),
+        }
+    }
+}
+impl Interpolate for CircleStyle {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        CircleStyle {
+            color: self.color.interpolate(&other.color, amount),
+            border_width: self
+                .border_width
+                .interpolate(&other.border_width, amount),
+            border_color: self
+                .border_color
+                .interpolate(&other.border_color, amount),
+            notch: self.notch.interpolate(&other.notch, amount),
+        }
+    }
+}
+impl Interpolate for ArcStyle {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        ArcStyle {
+            width: self.width.interpolate(&other.width, amount),
+            empty_color: self
+                .empty_color
+                .interpolate(&other.empty_color, amount),
+            filled_color: self
+                .filled_color
+                .interpolate(&other.filled_color, amount),
+            notch: self.notch.interpolate(&other.notch, amount),
+            cap: switch(&self.cap, &other.cap, amount),
+        }
+    }
+}
+impl Interpolate for ArcBipolarStyle {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        ArcBipolarStyle {
+            width: self.width.interpolate(&other.width, amount),
+            empty_color: self
+                .empty_color
+                .interpolate(&other.empty_color, amount),
+            left_filled_color: self
+                .left_filled_color
+                .interpolate(&other.left_filled_color, amount),
+            right_filled_color: self
+                .right_filled_color
+                .interpolate(&other.right_filled_color, amount),
+            center: switch(&self.center, &other.center, amount),
+            notch_center: self
+                .notch_center
+                .interpolate(&other.notch_center, amount),
+            notch_left_right: self
+                .notch_left_right
+                .interpolate(&other.notch_left_right, amount),
+            cap: switch(&self.cap, &other.cap, amount),
+        }
+    }
+}
+impl Interpolate for StereoWidthStyle {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        StereoWidthStyle {
+            width: self.width.interpolate(&other.width, amount),
+            empty_color: self
+                .empty_color
+                .interpolate(&other.empty_color, amount),
+            filled_color: self
+                .filled_color
+                .interpolate(&other.filled_color, amount),
+            notch: self.notch.interpolate(&other.notch, amount),
+            cap: switch(&self.cap, &other.cap, amount),
+        }
+    }
+}
 impl std::default::Default for Box<dyn StyleSheet> {
     fn default() -> Self {
         Box::new(Default)
@@ -0,0 +1,95 @@
+//! Various styles for the [`MeterScale`] widget
+//!
+//! [`MeterScale`]: ../native/meter_scale/struct.MeterScale.html
+
+use crate::core::Offset;
+use crate::style::{text_marks, tick_marks};
+
+/// The appearance of a [`MeterScale`].
+///
+/// [`MeterScale`]: ../../native/meter_scale/struct.MeterScale.html
+#[derive(Debug, Copy, Clone)]
+pub struct Style {
+    /// The style of the tick marks
+    pub tick_marks: tick_marks::Style,
+    /// The placement of the tick marks
+    pub tick_marks_placement: tick_marks::Placement,
+    /// The style of the text labels
+    pub text_marks: text_marks::Style,
+    /// The placement of the text labels
+    pub text_marks_placement: text_marks::Placement,
+    /// The space in pixels between the ends of the [`MeterScale`] and its
+    /// `1.0` and `0.0` marks. Set this to the border
+    /// width of the meters beside it so the marks line up with their bars.
+    ///
+    /// [`MeterScale`]: ../../native/meter_scale/struct.MeterScale.html
+    pub inset: f32,
+}
+
+/// A set of rules that dictate the style of a [`MeterScale`].
+///
+/// [`MeterScale`]: ../../native/meter_scale/struct.MeterScale.html
+pub trait StyleSheet {
+    /// Produces the style of a [`MeterScale`].
+    ///
+    /// [`MeterScale`]: ../../native/meter_scale/struct.MeterScale.html
+    fn style(&self) -> Style;
+
+    /// Produces the style of a horizontal [`MeterScale`]. This defaults to
+    /// the vertical style.
+    ///
+    /// [`MeterScale`]: ../../native/meter_scale/struct.MeterScale.html
+    fn horizontal_style(&self) -> Style {
+        self.style()
+    }
+}
+
+struct Default;
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style {
+            tick_marks: tick_marks::Style::default(),
+            tick_marks_placement: tick_marks::Placement::LeftOrTop {
+                offset: Offset::ZERO,
+                inside: true,
+            },
+            text_marks: text_marks::Style::default(),
+            text_marks_placement: text_marks::Placement::LeftOrTop {
+                inside: true,
+                offset: Offset { x: 6.0, y: 0.0 },
+            },
+            inset: 1.0,
+        }
+    }
+
+    fn horizontal_style(&self) -> Style {
+        Style {
+            text_marks_placement: text_marks::Placement::LeftOrTop {
+                inside: true,
+                offset: Offset { x: 0.0, y: 6.0 },
+            },
+            ..self.style()
+        }
+    }
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Default.style()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -2,16 +2,29 @@
 
 mod default_colors;
 
+pub mod clip_indicator;
+pub mod correlation_meter;
+pub mod crossover;
+pub mod db_meter;
+pub mod eq_bands;
 pub mod h_slider;
 pub mod knob;
+pub mod meter_scale;
 pub mod mod_range_input;
+pub mod pan_knob;
 pub mod ramp;
+pub mod reduction_history;
+pub mod spectrogram;
 pub mod v_slider;
+pub mod vu_meter;
+pub mod waveform_view;
 pub mod xy_pad;
 
+pub mod atlas;
 pub mod text_marks;
+pub mod theme;
 pub mod tick_marks;
+pub mod transition;
 
-//pub mod db_meter;
 //pub mod phase_meter;
 //pub mod reduction_meter;
@@ -15,6 +15,13 @@ pub enum Style {
     Circle(CircleStyle),
     /// A square style
     Square(SquareStyle),
+    /// A circle style that is colored by the sign of the modulation
+    /// amount, with an optional arrow pointing in its direction
+    Bipolar(BipolarStyle),
+    /// A hollow square style that fills up from its center as the
+    /// modulation amount grows, upward for positive amounts and downward
+    /// for negative amounts
+    InvertedFill(InvertedFillStyle),
     /// Appearance is invisible, but still interactable. Useful if placed right
     /// on top of a [`Knob`] with an [`ModRangeRingStyle`].
     ///
@@ -53,6 +60,53 @@ pub struct SquareStyle {
     pub border_color: Color,
 }
 
+/// A bipolar circle [`Style`] for an [`ModRangeInput`]
+///
+/// Values above the default value of the [`ModRangeInput`] are positive
+/// modulation amounts, and values below it are negative.
+///
+/// [`Style`]: enum.Style.html
+/// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
+#[derive(Debug, Clone)]
+pub struct BipolarStyle {
+    /// Color of the circle when the amount is positive
+    pub positive_color: Color,
+    /// Color of the circle when the amount is negative
+    pub negative_color: Color,
+    /// Color of the circle when the amount is zero
+    pub center_color: Color,
+    /// Width of the border
+    pub border_width: f32,
+    /// Color of the border
+    pub border_color: Color,
+    /// Color of an arrow inside the circle that points up for positive
+    /// amounts and down for negative amounts, or `None` for no arrow
+    pub arrow_color: Option<Color>,
+}
+
+/// An inverted fill [`Style`] for an [`ModRangeInput`]
+///
+/// Values above the default value of the [`ModRangeInput`] are positive
+/// modulation amounts, and values below it are negative.
+///
+/// [`Style`]: enum.Style.html
+/// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
+#[derive(Debug, Clone)]
+pub struct InvertedFillStyle {
+    /// Color of the empty square
+    pub back_color: Color,
+    /// Color of the fill when the amount is positive
+    pub positive_color: Color,
+    /// Color of the fill when the amount is negative
+    pub negative_color: Color,
+    /// Width of the border
+    pub border_width: f32,
+    /// Radius of the border
+    pub border_radius: f32,
+    /// Color of the border
+    pub border_color: Color,
+}
+
 /// A set of rules that dictate the style of a [`ModRangeInput`].
 ///
 /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
@@ -71,6 +125,16 @@ pub trait StyleSheet {
     ///
     /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
     fn dragging(&self) -> Style;
+
+    /// Produces the style of a [`ModRangeInput`] that has keyboard focus and is
+    /// not hovered or dragged.
+    ///
+    /// By default, this is the same as the hovered style.
+    ///
+    /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
+    fn focused(&self) -> Style {
+        self.hovered()
+    }
 }
 
 struct Default;
@@ -124,6 +188,49 @@ impl StyleSheet for DefaultInvisible {
     }
 }
 
+impl std::default::Default for CircleStyle {
+    fn default() -> Self {
+        Default::ACTIVE_STYLE
+    }
+}
+
+impl std::default::Default for SquareStyle {
+    fn default() -> Self {
+        Self {
+            color: default_colors::LIGHT_BACK,
+            border_width: 1.0,
+            border_radius: 2.0,
+            border_color: default_colors::BORDER,
+        }
+    }
+}
+
+impl std::default::Default for BipolarStyle {
+    fn default() -> Self {
+        Self {
+            positive_color: default_colors::ACCENT,
+            negative_color: default_colors::ACCENT_INVERSE,
+            center_color: default_colors::LIGHT_BACK,
+            border_width: 1.0,
+            border_color: default_colors::BORDER,
+            arrow_color: None,
+        }
+    }
+}
+
+impl std::default::Default for InvertedFillStyle {
+    fn default() -> Self {
+        Self {
+            back_color: default_colors::LIGHT_BACK,
+            positive_color: default_colors::ACCENT,
+            negative_color: default_colors::ACCENT_INVERSE,
+            border_width: 1.0,
+            border_radius: 2.0,
+            border_color: default_colors::BORDER,
+        }
+    }
+}
+
 impl std::default::Default for Box<dyn StyleSheet> {
     fn default() -> Self {
         Box::new(Default)
@@ -0,0 +1,53 @@
+//! Style for the [`PanKnob`] widget
+//!
+//! A [`PanKnob`] is styled with a [`knob::StyleSheet`]. The default draws a
+//! bipolar arc that fills from the center.
+//!
+//! [`PanKnob`]: ../native/pan_knob/struct.PanKnob.html
+//! [`knob::StyleSheet`]: ../knob/trait.StyleSheet.html
+
+use crate::style::knob::{
+    ArcBipolarStyle, Style, StyleLength, StyleSheet, TextMarksStyle,
+    TickMarksStyle,
+};
+use crate::style::text_marks;
+
+/// Returns the default [`knob::StyleSheet`] of a [`PanKnob`], which draws
+/// a bipolar arc that fills from the center.
+///
+/// [`PanKnob`]: ../../native/pan_knob/struct.PanKnob.html
+/// [`knob::StyleSheet`]: ../knob/trait.StyleSheet.html
+pub fn default_style_sheet() -> Box<dyn StyleSheet> {
+    Box::new(Default)
+}
+
+struct Default;
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style::ArcBipolar(ArcBipolarStyle::default())
+    }
+
+    fn hovered(&self) -> Style {
+        Style::ArcBipolar(ArcBipolarStyle {
+            width: StyleLength::Units(4.0),
+            ..ArcBipolarStyle::default()
+        })
+    }
+
+    fn dragging(&self) -> Style {
+        self.hovered()
+    }
+
+    fn tick_marks_style(&self) -> Option<TickMarksStyle> {
+        Some(TickMarksStyle::default())
+    }
+
+    fn text_marks_style(&self) -> Option<TextMarksStyle> {
+        Some(TextMarksStyle {
+            style: text_marks::Style::default(),
+            offset: 14.0,
+            h_char_offset: 3.0,
+            v_offset: -0.75,
+        })
+    }
+}
@@ -27,6 +27,18 @@ pub struct Style {
     pub line_down_color: Color,
 }
 
+impl Style {
+    /// Returns this style with the colors of the ramp line in the up and
+    /// down positions replaced by `accent`.
+    pub fn with_accent(self, accent: Color) -> Self {
+        Self {
+            line_up_color: accent,
+            line_down_color: accent,
+            ..self
+        }
+    }
+}
+
 /// A set of rules that dictate the style of a [`Ramp`].
 ///
 /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
@@ -45,6 +57,16 @@ pub trait StyleSheet {
     ///
     /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
     fn dragging(&self) -> Style;
+
+    /// Produces the style of a [`Ramp`] that has keyboard focus and is
+    /// not hovered or dragged.
+    ///
+    /// By default, this is the same as the hovered style.
+    ///
+    /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+    fn focused(&self) -> Style {
+        self.hovered()
+    }
 }
 
 struct Default;
@@ -76,6 +98,12 @@ impl StyleSheet for Default {
     }
 }
 
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Default::ACTIVE_STYLE
+    }
+}
+
 impl std::default::Default for Box<dyn StyleSheet> {
     fn default() -> Self {
         Box::new(Default)
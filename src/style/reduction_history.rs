@@ -0,0 +1,75 @@
+//! Various styles for the [`ReductionHistory`] widget
+//!
+//! [`ReductionHistory`]: ../native/reduction_history/struct.ReductionHistory.html
+
+use iced_native::Color;
+
+use crate::style::default_colors;
+
+/// The appearance of a [`ReductionHistory`].
+///
+/// [`ReductionHistory`]: ../../native/reduction_history/struct.ReductionHistory.html
+#[derive(Debug, Copy, Clone)]
+pub struct Style {
+    /// The color of the background rectangle
+    pub back_color: Color,
+    /// The width of the border of the background rectangle
+    pub back_border_width: f32,
+    /// The color of the border of the background rectangle
+    pub back_border_color: Color,
+    /// The color of the filled gain reduction graph
+    pub fill_color: Color,
+    /// The color of the bar showing the current gain reduction
+    pub bar_color: Color,
+    /// The width of the bar showing the current gain reduction. Set this
+    /// to `0.0` to hide the bar.
+    pub bar_width: f32,
+}
+
+/// A set of rules that dictate the style of a [`ReductionHistory`].
+///
+/// [`ReductionHistory`]: ../../native/reduction_history/struct.ReductionHistory.html
+pub trait StyleSheet {
+    /// Produces the style of a [`ReductionHistory`].
+    ///
+    /// [`ReductionHistory`]: ../../native/reduction_history/struct.ReductionHistory.html
+    fn style(&self) -> Style;
+}
+
+struct Default;
+impl Default {
+    const STYLE: Style = Style {
+        back_color: default_colors::REDUCTION_HISTORY_BACK,
+        back_border_width: 1.0,
+        back_border_color: default_colors::BORDER,
+        fill_color: default_colors::REDUCTION_HISTORY_FILL,
+        bar_color: default_colors::REDUCTION_HISTORY_BAR,
+        bar_width: 6.0,
+    };
+}
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Self::STYLE
+    }
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Default::STYLE
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -0,0 +1,141 @@
+//! Various styles for the [`Spectrogram`] widget
+//!
+//! [`Spectrogram`]: ../native/spectrogram/struct.Spectrogram.html
+
+use iced_native::Color;
+
+use crate::style::default_colors;
+
+const VIRIDIS: [Color; 9] = [
+    Color::from_rgb(0.267, 0.005, 0.329),
+    Color::from_rgb(0.283, 0.141, 0.458),
+    Color::from_rgb(0.254, 0.265, 0.530),
+    Color::from_rgb(0.207, 0.372, 0.553),
+    Color::from_rgb(0.164, 0.471, 0.558),
+    Color::from_rgb(0.128, 0.567, 0.551),
+    Color::from_rgb(0.135, 0.659, 0.518),
+    Color::from_rgb(0.478, 0.821, 0.317),
+    Color::from_rgb(0.993, 0.906, 0.144),
+];
+
+const INFERNO: [Color; 9] = [
+    Color::from_rgb(0.001, 0.000, 0.014),
+    Color::from_rgb(0.106, 0.047, 0.255),
+    Color::from_rgb(0.290, 0.047, 0.420),
+    Color::from_rgb(0.471, 0.110, 0.427),
+    Color::from_rgb(0.647, 0.173, 0.376),
+    Color::from_rgb(0.812, 0.267, 0.275),
+    Color::from_rgb(0.929, 0.412, 0.145),
+    Color::from_rgb(0.984, 0.608, 0.024),
+    Color::from_rgb(0.988, 1.000, 0.643),
+];
+
+/// A map from a magnitude in the range `[0.0, 1.0]` to a color.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Colormap {
+    /// The perceptually uniform "viridis" colormap, from dark blue to yellow
+    #[default]
+    Viridis,
+    /// The perceptually uniform "inferno" colormap, from black to pale yellow
+    Inferno,
+    /// A custom colormap made of evenly spaced color stops, where the first
+    /// color is used for `0.0` and the last color for `1.0`
+    Custom(Vec<Color>),
+}
+
+impl Colormap {
+    /// Returns the color of the given magnitude in the range `[0.0, 1.0]`.
+    pub fn color(&self, magnitude: f32) -> Color {
+        let stops: &[Color] = match self {
+            Colormap::Viridis => &VIRIDIS,
+            Colormap::Inferno => &INFERNO,
+            Colormap::Custom(stops) => stops,
+        };
+
+        match stops.len() {
+            0 => Color::BLACK,
+            1 => stops[0],
+            len => {
+                let magnitude = if magnitude.is_nan() {
+                    0.0
+                } else {
+                    magnitude.clamp(0.0, 1.0)
+                };
+
+                let pos = magnitude * (len - 1) as f32;
+                let i = (pos.floor() as usize).min(len - 2);
+                let t = pos - i as f32;
+
+                let a = stops[i];
+                let b = stops[i + 1];
+
+                Color {
+                    r: a.r + (b.r - a.r) * t,
+                    g: a.g + (b.g - a.g) * t,
+                    b: a.b + (b.b - a.b) * t,
+                    a: a.a + (b.a - a.a) * t,
+                }
+            }
+        }
+    }
+}
+
+/// The appearance of a [`Spectrogram`].
+///
+/// [`Spectrogram`]: ../../native/spectrogram/struct.Spectrogram.html
+#[derive(Debug, Clone)]
+pub struct Style {
+    /// The [`Colormap`] used to color the magnitudes
+    ///
+    /// [`Colormap`]: enum.Colormap.html
+    pub colormap: Colormap,
+    /// The width of the border around the spectrogram
+    pub back_border_width: f32,
+    /// The color of the border around the spectrogram
+    pub back_border_color: Color,
+}
+
+/// A set of rules that dictate the style of a [`Spectrogram`].
+///
+/// [`Spectrogram`]: ../../native/spectrogram/struct.Spectrogram.html
+pub trait StyleSheet {
+    /// Produces the style of a [`Spectrogram`].
+    ///
+    /// [`Spectrogram`]: ../../native/spectrogram/struct.Spectrogram.html
+    fn style(&self) -> Style;
+}
+
+struct Default;
+impl Default {
+    const STYLE: Style = Style {
+        colormap: Colormap::Viridis,
+        back_border_width: 1.0,
+        back_border_color: default_colors::BORDER,
+    };
+}
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Self::STYLE
+    }
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Default::STYLE
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -0,0 +1,506 @@
+//! Coherent styles for every widget from a shared palette
+//!
+//! A [`Theme`] builds the `StyleSheet` of each widget from one [`Palette`],
+//! so the widgets of an app match without a stylesheet being written for
+//! every one of them.
+//!
+//! [`Theme`]: struct.Theme.html
+//! [`Palette`]: struct.Palette.html
+
+use iced_native::Color;
+
+use crate::core::Offset;
+use crate::style::{
+    db_meter, default_colors, h_slider, knob, text_marks, tick_marks, v_slider,
+    xy_pad,
+};
+
+/// The colors a [`Theme`] builds its styles from.
+///
+/// [`Theme`]: struct.Theme.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Palette {
+    /// The color of the background of rails, pads, and meters
+    pub background: Color,
+    /// The color of handles and knobs
+    pub surface: Color,
+    /// The color of handles and knobs while the mouse is over them
+    pub surface_hovered: Color,
+    /// The color of handles and knobs while they are dragged
+    pub surface_dragging: Color,
+    /// The color of borders and notches
+    pub border: Color,
+    /// The color of empty arcs, center lines, and the rails of an `XYPad`
+    pub rail: Color,
+    /// The color of tick marks and text marks
+    pub marks: Color,
+    /// The color of the filled portions of widgets
+    pub accent: Color,
+    /// The color of a widget whose value is above its warning threshold
+    pub warning: Color,
+}
+
+/// A set of styles for every widget, built from a [`Palette`].
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::style::theme::Theme;
+/// use iced_native::Color;
+///
+/// let theme = Theme::dark().with_accent(Color::from_rgb(0.9, 0.5, 0.1));
+///
+/// assert_eq!(theme.palette().accent, Color::from_rgb(0.9, 0.5, 0.1));
+///
+/// // Pass these to the `style()` method of each widget.
+/// let _knob_style = theme.knob();
+/// let _slider_style = theme.h_slider();
+/// ```
+///
+/// [`Palette`]: struct.Palette.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Theme {
+    palette: Palette,
+}
+
+impl Theme {
+    /// Creates a new `Theme` from `palette`.
+    pub fn new(palette: Palette) -> Self {
+        Self { palette }
+    }
+
+    /// A theme of light widgets, matching the default styles.
+    pub fn light() -> Self {
+        Self::new(Palette {
+            background: default_colors::LIGHT_BACK,
+            surface: default_colors::LIGHT_BACK,
+            surface_hovered: default_colors::LIGHT_BACK_HOVER,
+            surface_dragging: default_colors::LIGHT_BACK_DRAG,
+            border: default_colors::BORDER,
+            rail: default_colors::XY_PAD_RAIL,
+            marks: default_colors::TEXT_MARK,
+            accent: default_colors::XY_PAD_SNAPSHOT_STORED,
+            warning: default_colors::PARAM_WARNING,
+        })
+    }
+
+    /// A theme of dark widgets.
+    pub fn dark() -> Self {
+        Self::new(Palette {
+            background: Color::from_rgb(0.16, 0.16, 0.16),
+            surface: Color::from_rgb(0.3, 0.3, 0.3),
+            surface_hovered: Color::from_rgb(0.34, 0.34, 0.34),
+            surface_dragging: Color::from_rgb(0.36, 0.36, 0.36),
+            border: Color::from_rgb(0.08, 0.08, 0.08),
+            rail: Color {
+                r: 0.5,
+                g: 0.5,
+                b: 0.5,
+                a: 0.6,
+            },
+            marks: Color {
+                r: 0.85,
+                g: 0.85,
+                b: 0.85,
+                a: 0.9,
+            },
+            accent: Color::from_rgb(0.3, 0.66, 0.98),
+            warning: default_colors::PARAM_WARNING,
+        })
+    }
+
+    /// Derives a theme from a background color, a foreground color (used
+    /// for borders, notches, and marks), and an accent color.
+    pub fn from_colors(
+        background: Color,
+        foreground: Color,
+        accent: Color,
+    ) -> Self {
+        Self::new(Palette {
+            background,
+            surface: mix(background, foreground, 0.12),
+            surface_hovered: mix(background, foreground, 0.18),
+            surface_dragging: mix(background, foreground, 0.22),
+            border: mix(background, foreground, 0.7),
+            rail: Color {
+                a: 0.6,
+                ..mix(background, foreground, 0.5)
+            },
+            marks: Color {
+                a: 0.9,
+                ..foreground
+            },
+            accent,
+            warning: default_colors::PARAM_WARNING,
+        })
+    }
+
+    /// Returns this theme with a different accent color.
+    pub fn with_accent(mut self, accent: Color) -> Self {
+        self.palette.accent = accent;
+        self
+    }
+
+    /// Returns this theme with a different warning color.
+    pub fn with_warning(mut self, warning: Color) -> Self {
+        self.palette.warning = warning;
+        self
+    }
+
+    /// Returns the palette of this theme.
+    pub fn palette(&self) -> &Palette {
+        &self.palette
+    }
+
+    /// Returns the style of an `HSlider`.
+    pub fn h_slider(&self) -> Box<dyn h_slider::StyleSheet> {
+        Box::new(HSliderTheme(self.palette))
+    }
+
+    /// Returns the style of a `VSlider`.
+    pub fn v_slider(&self) -> Box<dyn v_slider::StyleSheet> {
+        Box::new(VSliderTheme(self.palette))
+    }
+
+    /// Returns the style of a `Knob`.
+    pub fn knob(&self) -> Box<dyn knob::StyleSheet> {
+        Box::new(KnobTheme(self.palette))
+    }
+
+    /// Returns the style of an `XYPad`.
+    pub fn xy_pad(&self) -> Box<dyn xy_pad::StyleSheet> {
+        Box::new(XYPadTheme(self.palette))
+    }
+
+    /// Returns the style of a `DBMeter`.
+    pub fn db_meter(&self) -> Box<dyn db_meter::StyleSheet> {
+        Box::new(DBMeterTheme(self.palette))
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::light()
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        *Theme::light().palette()
+    }
+}
+
+fn mix(a: Color, b: Color, amount: f32) -> Color {
+    Color {
+        r: a.r + (b.r - a.r) * amount,
+        g: a.g + (b.g - a.g) * amount,
+        b: a.b + (b.b - a.b) * amount,
+        a: a.a + (b.a - a.a) * amount,
+    }
+}
+
+fn tick_marks_style(palette: &Palette, scale: f32) -> tick_marks::Style {
+    tick_marks::Style {
+        tier_1: tick_marks::Shape::Line {
+            length: 4.0 * scale,
+            width: 2.0,
+            color: Color {
+                a: 0.93,
+                ..palette.marks
+            },
+        },
+        tier_2: tick_marks::Shape::Line {
+            length: 3.0 * scale,
+            width: 1.0,
+            color: Color {
+                a: 0.83,
+                ..palette.marks
+            },
+        },
+        tier_3: tick_marks::Shape::Line {
+            length: 2.0 * scale,
+            width: 1.0,
+            color: Color {
+                a: 0.65,
+                ..palette.marks
+            },
+        },
+    }
+}
+
+fn text_marks_style(palette: &Palette) -> text_marks::Style {
+    text_marks::Style {
+        color: palette.marks,
+        ..text_marks::Style::default()
+    }
+}
+
+struct HSliderTheme(Palette);
+
+impl HSliderTheme {
+    fn style(&self, handle_color: Color) -> h_slider::Style {
+        h_slider::Style::Rect(h_slider::RectStyle {
+            back_color: self.0.background,
+            back_border_width: 1.0,
+            back_border_radius: 2.0,
+            back_border_color: self.0.border,
+            filled_color: self.0.accent,
+            handle_color,
+            handle_width: 4.0,
+            handle_filled_gap: 1.0,
+        })
+    }
+}
+
+impl h_slider::StyleSheet for HSliderTheme {
+    fn active(&self) -> h_slider::Style {
+        self.style(self.0.surface)
+    }
+
+    fn hovered(&self) -> h_slider::Style {
+        self.style(self.0.surface_hovered)
+    }
+
+    fn dragging(&self) -> h_slider::Style {
+        self.style(self.0.surface_dragging)
+    }
+
+    fn tick_marks_style(&self) -> Option<h_slider::TickMarksStyle> {
+        Some(h_slider::TickMarksStyle {
+            style: tick_marks_style(&self.0, 1.0),
+            placement: tick_marks::Placement::BothSides {
+                offset: Offset::ZERO,
+                inside: false,
+            },
+        })
+    }
+
+    fn text_marks_style(&self) -> Option<h_slider::TextMarksStyle> {
+        Some(h_slider::TextMarksStyle {
+            style: text_marks_style(&self.0),
+            placement: text_marks::Placement::RightOrBottom {
+                inside: false,
+                offset: Offset { x: 0.0, y: 7.0 },
+            },
+        })
+    }
+
+    fn warning_color(&self) -> Color {
+        self.0.warning
+    }
+}
+
+struct VSliderTheme(Palette);
+
+impl VSliderTheme {
+    fn style(&self, handle_color: Color) -> v_slider::Style {
+        v_slider::Style::Rect(v_slider::RectStyle {
+            back_color: self.0.background,
+            back_border_width: 1.0,
+            back_border_radius: 2.0,
+            back_border_color: self.0.border,
+            filled_color: self.0.accent,
+            handle_color,
+            handle_height: 4.0,
+            handle_filled_gap: 1.0,
+        })
+    }
+}
+
+impl v_slider::StyleSheet for VSliderTheme {
+    fn active(&self) -> v_slider::Style {
+        self.style(self.0.surface)
+    }
+
+    fn hovered(&self) -> v_slider::Style {
+        self.style(self.0.surface_hovered)
+    }
+
+    fn dragging(&self) -> v_slider::Style {
+        self.style(self.0.surface_dragging)
+    }
+
+    fn tick_marks_style(&self) -> Option<v_slider::TickMarksStyle> {
+        Some(v_slider::TickMarksStyle {
+            style: tick_marks_style(&self.0, 1.0),
+            placement: tick_marks::Placement::BothSides {
+                offset: Offset::ZERO,
+                inside: false,
+            },
+        })
+    }
+
+    fn text_marks_style(&self) -> Option<v_slider::TextMarksStyle> {
+        Some(v_slider::TextMarksStyle {
+            style: text_marks_style(&self.0),
+            placement: text_marks::Placement::LeftOrTop {
+                inside: false,
+                offset: Offset { x: -7.0, y: 0.0 },
+            },
+        })
+    }
+
+    fn warning_color(&self) -> Color {
+        self.0.warning
+    }
+}
+
+struct KnobTheme(Palette);
+
+impl KnobTheme {
+    fn style(&self, color: Color) -> knob::Style {
+        knob::Style::Circle(knob::CircleStyle {
+            color,
+            border_width: 1.0,
+            border_color: self.0.border,
+            notch: knob::NotchShape::Circle(knob::CircleNotch {
+                color: self.0.border,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+                diameter: knob::StyleLength::Scaled(0.17),
+                offset: knob::StyleLength::Scaled(0.15),
+            }),
+        })
+    }
+}
+
+impl knob::StyleSheet for KnobTheme {
+    fn active(&self) -> knob::Style {
+        self.style(self.0.surface)
+    }
+
+    fn hovered(&self) -> knob::Style {
+        self.style(self.0.surface_hovered)
+    }
+
+    fn dragging(&self) -> knob::Style {
+        self.style(self.0.surface_dragging)
+    }
+
+    fn value_arc_style(&self) -> Option<knob::ValueArcStyle> {
+        Some(knob::ValueArcStyle {
+            width: 3.0,
+            offset: 1.5,
+            empty_color: Some(self.0.rail),
+            left_filled_color: self.0.accent,
+            right_filled_color: None,
+            cap: knob::LineCap::Butt,
+        })
+    }
+
+    fn text_marks_style(&self) -> Option<knob::TextMarksStyle> {
+        Some(knob::TextMarksStyle {
+            style: text_marks_style(&self.0),
+            offset: 14.0,
+            h_char_offset: 3.0,
+            v_offset: -0.75,
+        })
+    }
+
+    fn warning_color(&self) -> Color {
+        self.0.warning
+    }
+}
+
+struct XYPadTheme(Palette);
+
+impl XYPadTheme {
+    fn style(&self, handle_color: Color, diameter: f32) -> xy_pad::Style {
+        xy_pad::Style {
+            rail_width: 2.0,
+            h_rail_color: self.0.accent,
+            v_rail_color: self.0.accent,
+            handle: xy_pad::HandleShape::Circle(xy_pad::HandleCircle {
+                color: handle_color,
+                diameter,
+                border_width: 2.0,
+                border_color: self.0.border,
+            }),
+            back_color: self.0.background,
+            border_width: 1.0,
+            border_color: self.0.border,
+            center_line_width: 1.0,
+            center_line_color: self.0.rail,
+            texture: None,
+        }
+    }
+}
+
+impl xy_pad::StyleSheet for XYPadTheme {
+    fn active(&self) -> xy_pad::Style {
+        self.style(self.0.surface, 11.0)
+    }
+
+    fn hovered(&self) -> xy_pad::Style {
+        self.style(self.0.surface_hovered, 11.0)
+    }
+
+    fn dragging(&self) -> xy_pad::Style {
+        self.style(self.0.surface_dragging, 9.0)
+    }
+
+    fn snapshot_style(&self) -> xy_pad::SnapshotStyle {
+        xy_pad::SnapshotStyle {
+            empty_color: Color {
+                a: 0.25,
+                ..self.0.rail
+            },
+            stored_color: self.0.accent,
+            border_width: 1.0,
+            border_color: self.0.border,
+        }
+    }
+
+    fn tick_marks_style(&self) -> Option<xy_pad::TickMarksStyle> {
+        Some(xy_pad::TickMarksStyle {
+            style: tick_marks_style(&self.0, 2.0),
+            x_placement: tick_marks::Placement::RightOrBottom {
+                offset: Offset::ZERO,
+                inside: true,
+            },
+            y_placement: tick_marks::Placement::LeftOrTop {
+                offset: Offset::ZERO,
+                inside: true,
+            },
+        })
+    }
+
+    fn text_marks_style(&self) -> Option<xy_pad::TextMarksStyle> {
+        Some(xy_pad::TextMarksStyle {
+            style: text_marks_style(&self.0),
+            x_placement: text_marks::Placement::RightOrBottom {
+                inside: false,
+                offset: Offset { x: 0.0, y: 7.0 },
+            },
+            y_placement: text_marks::Placement::LeftOrTop {
+                inside: false,
+                offset: Offset { x: -7.0, y: 0.0 },
+            },
+        })
+    }
+}
+
+struct DBMeterTheme(Palette);
+
+impl db_meter::StyleSheet for DBMeterTheme {
+    fn style(&self) -> db_meter::Style {
+        db_meter::Style {
+            back_color: self.0.background,
+            back_border_width: 1.0,
+            back_border_color: self.0.border,
+            low_color: default_colors::DB_METER_LOW,
+            med_color: default_colors::DB_METER_MED,
+            high_color: default_colors::DB_METER_HIGH,
+            clip_color: default_colors::DB_METER_CLIP,
+            med_start: 0.6,
+            high_start: 0.85,
+            clip_start: 0.97,
+            peak_bar_color: None,
+            gradient: false,
+            peak_line_color: self.0.marks,
+            peak_line_width: 2.0,
+            channel_gap: 2.0,
+            shared_peak_line: false,
+        }
+    }
+}
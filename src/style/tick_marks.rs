@@ -92,6 +92,36 @@ pub enum Shape {
         /// The color of the tick mark.
         color: Color,
     },
+    /// Line shape with rounded corners
+    RoundedLine {
+        /// The length of the tick mark.
+        length: f32,
+
+        /// The width (thickness) of the tick mark.
+        width: f32,
+
+        /// The radius of the corners. This is limited to half of the
+        /// width and length.
+        border_radius: f32,
+
+        /// The color of the tick mark.
+        color: Color,
+    },
+    /// Triangle shape
+    ///
+    /// The base of the triangle sits on the edge the tick mark is placed
+    /// against, and the tip points away from it. Tick marks placed in the
+    /// center point to the bottom/right.
+    Triangle {
+        /// The length of the tick mark from the base to the tip.
+        length: f32,
+
+        /// The width of the base of the tick mark.
+        width: f32,
+
+        /// The color of the tick mark.
+        color: Color,
+    },
     /// Circle shape
     Circle {
         /// The diameter of the tick mark.
@@ -0,0 +1,70 @@
+//! Blend between styles for animated transitions
+//!
+//! Widgets with a [`Transition`] blend their active, hovered, and dragging
+//! styles with [`Interpolate`] instead of switching between them.
+//!
+//! [`Transition`]: ../../core/transition/struct.Transition.html
+//! [`Interpolate`]: trait.Interpolate.html
+
+use iced_native::Color;
+
+/// A style that can be blended with another style of the same type.
+pub trait Interpolate: Sized {
+    /// Returns the style that is `amount` of the way from `self` to
+    /// `other`, where `amount` is from `0.0` to `1.0`.
+    ///
+    /// Colors and lengths are blended linearly. Values that can't be
+    /// blended, such as images or two different variants of a style,
+    /// switch halfway through.
+    fn interpolate(&self, other: &Self, amount: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    #[inline]
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        self + (other - self) * amount
+    }
+}
+
+impl Interpolate for Color {
+    #[inline]
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        Color {
+            r: self.r.interpolate(&other.r, amount),
+            g: self.g.interpolate(&other.g, amount),
+            b: self.b.interpolate(&other.b, amount),
+            a: self.a.interpolate(&other.a, amount),
+        }
+    }
+}
+
+impl<A: Interpolate, B: Interpolate> Interpolate for (A, B) {
+    #[inline]
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        (
+            self.0.interpolate(&other.0, amount),
+            self.1.interpolate(&other.1, amount),
+        )
+    }
+}
+
+impl<T: Interpolate + Clone> Interpolate for Option<T> {
+    #[inline]
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.interpolate(b, amount)),
+            _ => switch(self, other, amount),
+        }
+    }
+}
+
+/// Returns `a` for the first half of a transition and `b` for the second,
+/// for values that can't be blended.
+#[inline]
+pub(crate) fn switch<T: Clone>(a: &T, b: &T, amount: f32) -> T {
+    if amount < 0.5 {
+        a.clone()
+    } else {
+        b.clone()
+    }
+}
@@ -5,6 +5,8 @@
 use iced_native::{image, Color, Rectangle};
 
 use crate::core::Offset;
+use crate::style::atlas::AtlasRegion;
+use crate::style::transition::{switch, Interpolate};
 use crate::style::{default_colors, text_marks, tick_marks};
 
 /// The appearance of a [`VSlider`].
@@ -23,6 +25,32 @@ pub enum Style {
     RectBipolar(RectBipolarStyle),
 }
 
+impl Style {
+    /// Returns this style with its value colors replaced by `accent`.
+    ///
+    /// This recolors the notch of a `Classic` handle and the filled portions
+    /// of the `Rect` and `RectBipolar` styles. A `Texture` style is returned
+    /// unchanged.
+    pub fn with_accent(self, accent: Color) -> Self {
+        match self {
+            Style::Texture(style) => Style::Texture(style),
+            Style::Classic(mut style) => {
+                style.handle.notch_color = accent;
+                Style::Classic(style)
+            }
+            Style::Rect(style) => Style::Rect(RectStyle {
+                filled_color: accent,
+                ..style
+            }),
+            Style::RectBipolar(style) => Style::RectBipolar(RectBipolarStyle {
+                bottom_filled_color: accent,
+                top_filled_color: accent,
+                ..style
+            }),
+        }
+    }
+}
+
 /// A classic line rail style
 #[derive(Debug, Clone)]
 pub struct ClassicRail {
@@ -32,6 +60,42 @@ pub struct ClassicRail {
     pub rail_widths: (f32, f32),
     /// The padding from the rail to the top and bottom edges of the widget
     pub rail_padding: f32,
+    /// An image to draw in place of the colored lines, or `None` to draw
+    /// the lines
+    pub rail_image: Option<RailImage>,
+}
+
+/// An image texture for the groove of a [`ClassicRail`]
+///
+/// [`ClassicRail`]: struct.ClassicRail.html
+#[derive(Debug, Clone)]
+pub struct RailImage {
+    /// The [`Handle`] to the image texture
+    ///
+    /// [`Handle`]: https://docs.rs/iced/0.2.0/iced/widget/image/struct.Handle.html
+    pub image_handle: image::Handle,
+    /// The region of the image to draw if it is a texture atlas, or `None`
+    /// to draw the whole image.
+    pub image_region: Option<AtlasRegion>,
+    /// The thickness of the image. It is centered in the widget.
+    pub thickness: f32,
+    /// How the image fills the length of the rail
+    pub fill: RailImageFill,
+}
+
+/// How a [`RailImage`] fills the length of the rail
+///
+/// [`RailImage`]: struct.RailImage.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum RailImageFill {
+    /// The image is stretched over the whole length of the rail.
+    Stretch,
+    /// The image is repeated from top to bottom, with each copy
+    /// being `length` long. The last copy is cut off at the end of the rail.
+    Tile {
+        /// The length of each copy of the image
+        length: f32,
+    },
 }
 
 /// A [`Style`] for a [`VSlider`] that uses an image texture for the handle
@@ -46,10 +110,34 @@ pub struct TextureStyle {
     /// The [`Handle`] to the image texture
     pub image_handle: image::Handle,
     /// The effective height of the handle (not including any padding on the texture)
-    pub handle_height: u16,
+    pub handle_height: f32,
     /// The bounds of the image texture, where the origin is in the
     /// center of the handle.
     pub image_bounds: Rectangle,
+    /// The region of the image to draw if it is a texture atlas, or `None`
+    /// to draw the whole image.
+    pub image_region: Option<AtlasRegion>,
+    /// The image of the handle while the mouse is over the slider, or
+    /// `None` to use `image_handle`
+    pub hovered_image: Option<HandleImage>,
+    /// The image of the handle while it is dragged, or `None` to use
+    /// `image_handle`
+    pub dragging_image: Option<HandleImage>,
+}
+
+/// An image for the handle of a [`TextureStyle`] in one state, such as a
+/// lit handle while it is dragged
+///
+/// [`TextureStyle`]: struct.TextureStyle.html
+#[derive(Debug, Clone)]
+pub struct HandleImage {
+    /// The [`Handle`] to the image texture
+    ///
+    /// [`Handle`]: https://docs.rs/iced/0.2.0/iced/widget/image/struct.Handle.html
+    pub image_handle: image::Handle,
+    /// The region of the image to draw if it is a texture atlas, or `None`
+    /// to draw the whole image.
+    pub image_region: Option<AtlasRegion>,
 }
 
 /// A classic [`Style`] for a [`VSlider`], modeled after hardware sliders
@@ -74,7 +162,7 @@ pub struct ClassicHandle {
     /// background color
     pub color: Color,
     /// height of the handle
-    pub height: u16,
+    pub height: f32,
     /// the width (thickness) of the middle notch
     pub notch_width: f32,
     /// color of the middle notch
@@ -107,7 +195,7 @@ pub struct RectStyle {
     /// color of the handle rectangle
     pub handle_color: Color,
     /// height of the handle rectangle
-    pub handle_height: u16,
+    pub handle_height: f32,
     /// height of the gap between the handle and the filled
     /// portion of the background rectangle
     pub handle_filled_gap: f32,
@@ -144,7 +232,7 @@ pub struct RectBipolarStyle {
     /// color of the handle rectangle when it is in the center
     pub handle_center_color: Color,
     /// height of the handle rectangle
-    pub handle_height: u16,
+    pub handle_height: f32,
     /// height of the gap between the handle and the filled
     /// portion of the background rectangle
     pub handle_filled_gap: f32,
@@ -209,6 +297,42 @@ pub struct ModRangeStyle {
     pub filled_inverse_color: Color,
 }
 
+/// Style of a line that shows the modulated or automation value of a
+/// [`VSlider`].
+///
+/// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+#[derive(Debug, Clone)]
+pub struct ModulatedValueStyle {
+    /// The color of the line
+    pub color: Color,
+    /// The height (thickness) of the line
+    pub height: f32,
+    /// The padding from the left and right edges of the widget to the
+    /// ends of the line
+    pub padding: f32,
+}
+
+/// Style of the marker that shows the default value of a [`VSlider`],
+/// where a reset gesture will move it to.
+///
+/// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+#[derive(Debug, Clone)]
+pub struct DefaultMarkerStyle {
+    /// The color of the marker
+    pub color: Color,
+    /// The width of the marker
+    pub width: f32,
+    /// The height of the marker
+    pub height: f32,
+    /// The radius of the corners of the marker
+    pub border_radius: f32,
+    /// The horizontal offset of the center of the marker from the center of
+    /// the [`VSlider`]
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    pub offset: f32,
+}
+
 /// Style of tick marks for a [`VSlider`].
 ///
 /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
@@ -250,6 +374,16 @@ pub trait StyleSheet {
     /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
     fn dragging(&self) -> Style;
 
+    /// Produces the style of a [`VSlider`] that has keyboard focus and is
+    /// not hovered or dragged.
+    ///
+    /// By default, this is the same as the hovered style.
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    fn focused(&self) -> Style {
+        self.hovered()
+    }
+
     /// The style of tick marks for a [`VSlider`]
     ///
     /// For no tick marks, don't override this or set this to return `None`.
@@ -279,6 +413,36 @@ pub trait StyleSheet {
         None
     }
 
+    /// The style of the line that shows the modulated value of a
+    /// [`VSlider`]
+    ///
+    /// For no modulated value line, don't override this or set this to return `None`.
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    fn modulated_value_style(&self) -> Option<ModulatedValueStyle> {
+        None
+    }
+
+    /// The style of the dimmed line that shows the automation playback
+    /// value of a [`VSlider`]
+    ///
+    /// For no automation line, don't override this or set this to return `None`.
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    fn automation_value_style(&self) -> Option<ModulatedValueStyle> {
+        None
+    }
+
+    /// The style of the marker that shows the default value of a
+    /// [`VSlider`]
+    ///
+    /// For no default marker, don't override this or set this to return `None`.
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    fn default_marker_style(&self) -> Option<DefaultMarkerStyle> {
+        None
+    }
+
     /// The style of text marks for a [`VSlider`]
     ///
     /// For no text marks, don't override this or set this to return `None`.
@@ -287,6 +451,14 @@ pub trait StyleSheet {
     fn text_marks_style(&self) -> Option<TextMarksStyle> {
         None
     }
+
+    /// The color that replaces the accent color of a [`VSlider`] while its
+    /// value is above its warning threshold.
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    fn warning_color(&self) -> Color {
+        default_colors::PARAM_WARNING
+    }
 }
 
 struct Default;
@@ -296,10 +468,11 @@ impl Default {
             rail_colors: default_colors::SLIDER_RAIL,
             rail_widths: (1.0, 1.0),
             rail_padding: 12.0,
+            rail_image: None,
         },
         handle: ClassicHandle {
             color: default_colors::LIGHT_BACK,
-            height: 34,
+            height: 34.0,
             notch_width: 4.0,
             notch_color: default_colors::BORDER,
             border_radius: 2.0,
@@ -334,7 +507,85 @@ impl StyleSheet for Default {
     }
 
     fn tick_marks_style(&self) -> Option<TickMarksStyle> {
-        Some(TickMarksStyle {
+        Some(TickMarksStyle::default())
+    }
+
+    fn text_marks_style(&self) -> Option<TextMarksStyle> {
+        Some(TextMarksStyle::default())
+    }
+}
+
+impl std::default::Default for ClassicStyle {
+    fn default() -> Self {
+        Default::ACTIVE_STYLE
+    }
+}
+
+impl std::default::Default for ClassicRail {
+    fn default() -> Self {
+        Default::ACTIVE_STYLE.rail
+    }
+}
+
+impl std::default::Default for ClassicHandle {
+    fn default() -> Self {
+        Default::ACTIVE_STYLE.handle
+    }
+}
+
+impl std::default::Default for RectStyle {
+    fn default() -> Self {
+        Self {
+            back_color: default_colors::LIGHT_BACK,
+            back_border_width: 1.0,
+            back_border_radius: 2.0,
+            back_border_color: default_colors::BORDER,
+            filled_color: default_colors::ACCENT,
+            handle_color: default_colors::BORDER,
+            handle_height: 4.0,
+            handle_filled_gap: 1.0,
+        }
+    }
+}
+
+impl std::default::Default for RectBipolarStyle {
+    fn default() -> Self {
+        Self {
+            back_color: default_colors::LIGHT_BACK,
+            back_border_width: 1.0,
+            back_border_radius: 2.0,
+            back_border_color: default_colors::BORDER,
+            top_filled_color: default_colors::ACCENT_INVERSE,
+            bottom_filled_color: default_colors::ACCENT,
+            handle_top_color: default_colors::BORDER,
+            handle_bottom_color: default_colors::BORDER,
+            handle_center_color: default_colors::BORDER,
+            handle_height: 4.0,
+            handle_filled_gap: 1.0,
+        }
+    }
+}
+
+impl std::default::Default for ModRangeStyle {
+    fn default() -> Self {
+        Self {
+            placement: ModRangePlacement::Right {
+                width: 3.0,
+                offset: 2.0,
+            },
+            back_border_width: 0.0,
+            back_border_radius: 2.0,
+            back_border_color: Color::TRANSPARENT,
+            back_color: Some(default_colors::EMPTY),
+            filled_color: default_colors::ACCENT,
+            filled_inverse_color: default_colors::ACCENT_INVERSE,
+        }
+    }
+}
+
+impl std::default::Default for TickMarksStyle {
+    fn default() -> Self {
+        TickMarksStyle {
             style: tick_marks::Style {
                 tier_1: tick_marks::Shape::Line {
                     length: 24.0,
@@ -356,20 +607,188 @@ impl StyleSheet for Default {
                 offset: Offset::ZERO,
                 fill_length: false,
             },
-        })
+        }
     }
+}
 
-    fn text_marks_style(&self) -> Option<TextMarksStyle> {
-        Some(TextMarksStyle {
+impl std::default::Default for TextMarksStyle {
+    fn default() -> Self {
+        TextMarksStyle {
             style: text_marks::Style::default(),
             placement: text_marks::Placement::LeftOrTop {
                 inside: false,
                 offset: Offset { x: -7.0, y: 0.0 },
             },
-        })
+        }
     }
 }
 
+impl Interpolate for Style {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        match (self, other) {
+            (Style::Texture(a), Style::Texture(b)) => {
+                Style::Texture(a.interpolate(b, amount))
+            }
+            (Style::Classic(a), Style::Classic(b)) => {
+                Style::Classic(a.interpolate(b, amount))
+            }
+            (Style::Rect(a), Style::Rect(b)) => {
+                Style::Rect(a.interpolate(b, amount))
+            }
+            (Style::RectBipolar(a), Style::RectBipolar(b)) => {
+                Style::RectBipolar(a.interpolate(b, amount))
+            }
+            _ => switch(self, other, amount),
+        }
+    }
+}
+impl Interpolate for ClassicRail {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        ClassicRail {
+            rail_colors: self
+                .rail_colors
+                .interpolate(&other.rail_colors, amount),
+            rail_widths: self
+                .rail_widths
+                .interpolate(&other.rail_widths, amount),
+            rail_padding: self
+                .rail_padding
+                .interpolate(&other.rail_padding, amount),
+            rail_image: switch(&self.rail_image, &other.rail_image, amount),
+        }
+    }
+}
+impl Interpolate for TextureStyle {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        TextureStyle {
+            rail: self.rail.interpolate(&other.rail, amount),
+            image_handle: switch(
+                &self.image_handle,
+                &other.image_handle,
+                amount,
+            ),
+            handle_height: self
+                .handle_height
+                .interpolate(&other.handle_height, amount),
+            image_bounds: switch(
+                &self.image_bounds,
+                &other.image_bounds,
+                amount,
+            ),
+            image_region: switch(
+                &self.image_region,
+                &other.image_region,
+                amount,
+            ),
+            hovered_image: switch(
+                &self.hovered_image,
+                &other.hovered_image,
+                amount,
+            ),
+            dragging_image: switch(
+                &self.dragging_image,
+                &other.dragging_image,
+                amount,
+            ),
+        }
+    }
+}
+impl Interpolate for ClassicStyle {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        ClassicStyle {
+            rail: self.rail.interpolate(&other.rail, amount),
+            handle: self.handle.interpolate(&other.handle, amount),
+        }
+    }
+}
+impl Interpolate for ClassicHandle {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        ClassicHandle {
+            color: self.color.interpolate(&other.color, amount),
+            height: self.height.interpolate(&other.height, amount),
+            notch_width: self
+                .notch_width
+                .interpolate(&other.notch_width, amount),
+            notch_color: self
+                .notch_color
+                .interpolate(&other.notch_color, amount),
+            border_radius: self
+                .border_radius
+                .interpolate(&other.border_radius, amount),
+            border_width: self
+                .border_width
+                .interpolate(&other.border_width, amount),
+            border_color: self
+                .border_color
+                .interpolate(&other.border_color, amount),
+        }
+    }
+}
+impl Interpolate for RectStyle {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        RectStyle {
+            back_color: self.back_color.interpolate(&other.back_color, amount),
+            back_border_width: self
+                .back_border_width
+                .interpolate(&other.back_border_width, amount),
+            back_border_radius: self
+                .back_border_radius
+                .interpolate(&other.back_border_radius, amount),
+            back_border_color: self
+                .back_border_color
+                .interpolate(&other.back_border_color, amount),
+            filled_color: self
+                .filled_color
+                .interpolate(&other.filled_color, amount),
+            handle_color: self
+                .handle_color
+                .interpolate(&other.handle_color, amount),
+            handle_height: self
+                .handle_height
+                .interpolate(&other.handle_height, amount),
+            handle_filled_gap: self
+                .handle_filled_gap
+                .interpolate(&other.handle_filled_gap, amount),
+        }
+    }
+}
+impl Interpolate for RectBipolarStyle {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        RectBipolarStyle {
+            back_color: self.back_color.interpolate(&other.back_color, amount),
+            back_border_width: self
+                .back_border_width
+                .interpolate(&other.back_border_width, amount),
+            back_border_radius: self
+                .back_border_radius
+                .interpolate(&other.back_border_radius, amount),
+            back_border_color: self
+                .back_border_color
+                .interpolate(&other.back_border_color, amount),
+            top_filled_color: self
+                .top_filled_color
+                .interpolate(&other.top_filled_color, amount),
+            bottom_filled_color: self
+                .bottom_filled_color
+                .interpolate(&other.bottom_filled_color, amount),
+            handle_top_color: self
+                .handle_top_color
+                .interpolate(&other.handle_top_color, amount),
+            handle_bottom_color: self
+                .handle_bottom_color
+                .interpolate(&other.handle_bottom_color, amount),
+            handle_center_color: self
+                .handle_center_color
+                .interpolate(&other.handle_center_color, amount),
+            handle_height: self
+                .handle_height
+                .interpolate(&other.handle_height, amount),
+            handle_filled_gap: self
+                .handle_filled_gap
+                .interpolate(&other.handle_filled_gap, amount),
+        }
+    }
+}
 impl std::default::Default for Box<dyn StyleSheet> {
     fn default() -> Self {
         Box::new(Default)
@@ -0,0 +1,122 @@
+//! Various styles for the [`VuMeter`] widget
+//!
+//! [`VuMeter`]: ../native/vu_meter/struct.VuMeter.html
+
+use iced_native::Color;
+
+use crate::style::default_colors;
+
+/// The appearance of a [`VuMeter`].
+///
+/// [`VuMeter`]: ../../native/vu_meter/struct.VuMeter.html
+#[derive(Debug, Clone)]
+pub enum Style {
+    /// A classic analog style with a needle swinging over an arc
+    Needle(NeedleStyle),
+    /// A bar that fills from the bottom
+    Bar(BarStyle),
+}
+
+/// A classic analog style with a needle swinging over an arc.
+///
+/// The pivot of the needle sits at the bottom center of the meter.
+#[derive(Debug, Copy, Clone)]
+pub struct NeedleStyle {
+    /// The color of the background rectangle
+    pub back_color: Color,
+    /// The width of the border of the background rectangle
+    pub back_border_width: f32,
+    /// The color of the border of the background rectangle
+    pub back_border_color: Color,
+    /// The angle in radians the needle sweeps from the left end of the
+    /// scale to the right end
+    pub sweep_angle: f32,
+    /// The width of the scale arc
+    pub scale_width: f32,
+    /// The color of the scale arc below `0 VU`
+    pub scale_color: Color,
+    /// The color of the scale arc above `0 VU`
+    pub over_color: Color,
+    /// The width of the needle
+    pub needle_width: f32,
+    /// The color of the needle
+    pub needle_color: Color,
+}
+
+/// A bar that fills from the bottom.
+#[derive(Debug, Copy, Clone)]
+pub struct BarStyle {
+    /// The color of the background rectangle
+    pub back_color: Color,
+    /// The width of the border of the background rectangle
+    pub back_border_width: f32,
+    /// The color of the border of the background rectangle
+    pub back_border_color: Color,
+    /// The color of the bar below `0 VU`
+    pub color: Color,
+    /// The color of the bar above `0 VU`
+    pub over_color: Color,
+}
+
+/// A set of rules that dictate the style of a [`VuMeter`].
+///
+/// [`VuMeter`]: ../../native/vu_meter/struct.VuMeter.html
+pub trait StyleSheet {
+    /// Produces the style of a [`VuMeter`].
+    ///
+    /// [`VuMeter`]: ../../native/vu_meter/struct.VuMeter.html
+    fn style(&self) -> Style;
+}
+
+struct Default;
+impl Default {
+    const NEEDLE_STYLE: NeedleStyle = NeedleStyle {
+        back_color: default_colors::VU_METER_BACK,
+        back_border_width: 1.0,
+        back_border_color: default_colors::BORDER,
+        sweep_angle: std::f32::consts::FRAC_PI_2,
+        scale_width: 2.0,
+        scale_color: default_colors::VU_METER_SCALE,
+        over_color: default_colors::VU_METER_OVER,
+        needle_width: 1.5,
+        needle_color: default_colors::VU_METER_NEEDLE,
+    };
+}
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style::Needle(Self::NEEDLE_STYLE)
+    }
+}
+
+impl std::default::Default for NeedleStyle {
+    fn default() -> Self {
+        Default::NEEDLE_STYLE
+    }
+}
+
+impl std::default::Default for BarStyle {
+    fn default() -> Self {
+        Self {
+            back_color: default_colors::VU_METER_BACK,
+            back_border_width: 1.0,
+            back_border_color: default_colors::BORDER,
+            color: default_colors::VU_METER_SCALE,
+            over_color: default_colors::VU_METER_OVER,
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
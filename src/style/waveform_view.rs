@@ -0,0 +1,82 @@
+//! Various styles for the [`WaveformView`] widget
+//!
+//! [`WaveformView`]: ../native/waveform_view/struct.WaveformView.html
+
+use iced_native::Color;
+
+use crate::style::default_colors;
+
+/// The appearance of a [`WaveformView`].
+///
+/// [`WaveformView`]: ../../native/waveform_view/struct.WaveformView.html
+#[derive(Debug, Clone)]
+pub struct Style {
+    /// The color of the background rectangle
+    pub back_color: Color,
+    /// The width of the border of the background rectangle
+    pub back_border_width: f32,
+    /// The color of the border of the background rectangle
+    pub back_border_color: Color,
+    /// The color of the waveform
+    pub wave_color: Color,
+    /// The width of the center (zero) line
+    pub center_line_width: f32,
+    /// The color of the center (zero) line. Set this to `None` for no
+    /// center line.
+    pub center_line_color: Option<Color>,
+}
+
+/// A set of rules that dictate the style of a [`WaveformView`].
+///
+/// [`WaveformView`]: ../../native/waveform_view/struct.WaveformView.html
+pub trait StyleSheet {
+    /// Produces the style of an active [`WaveformView`].
+    ///
+    /// [`WaveformView`]: ../../native/waveform_view/struct.WaveformView.html
+    fn active(&self) -> Style;
+
+    /// Produces the style of a hovered [`WaveformView`].
+    ///
+    /// [`WaveformView`]: ../../native/waveform_view/struct.WaveformView.html
+    fn hovered(&self) -> Style {
+        self.active()
+    }
+}
+
+struct Default;
+impl Default {
+    const ACTIVE_STYLE: Style = Style {
+        back_color: default_colors::WAVEFORM_BACK,
+        back_border_width: 1.0,
+        back_border_color: default_colors::BORDER,
+        wave_color: default_colors::WAVEFORM,
+        center_line_width: 1.0,
+        center_line_color: Some(default_colors::WAVEFORM_CENTER_LINE),
+    };
+}
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Self::ACTIVE_STYLE
+    }
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Default::ACTIVE_STYLE
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -2,9 +2,11 @@
 //!
 //! [`XYPad`]: ../native/xy_pad/struct.XYPad.html
 
-use iced_native::Color;
+use iced_native::{image, Color, Rectangle};
 
-use crate::style::default_colors;
+use crate::core::Offset;
+use crate::style::atlas::AtlasRegion;
+use crate::style::{default_colors, text_marks, tick_marks};
 
 /// The appearance of an [`XYPad`].
 ///
@@ -32,6 +34,27 @@ pub struct Style {
     pub center_line_width: f32,
     /// the color of the center line markings
     pub center_line_color: Color,
+    /// the images to draw for the background and the handle, or `None`
+    /// to only draw shapes
+    pub texture: Option<TextureStyle>,
+}
+
+impl Style {
+    /// Returns this style with the color of the handle replaced by `accent`.
+    pub fn with_accent(self, accent: Color) -> Self {
+        let handle = match self.handle {
+            HandleShape::Circle(circle) => HandleShape::Circle(HandleCircle {
+                color: accent,
+                ..circle
+            }),
+            HandleShape::Square(square) => HandleShape::Square(HandleSquare {
+                color: accent,
+                ..square
+            }),
+        };
+
+        Self { handle, ..self }
+    }
 }
 
 /// The shape of the handle for the [`Style`] of an [`XYPad`]
@@ -71,7 +94,7 @@ pub struct HandleSquare {
     /// the color of the square
     pub color: Color,
     /// the size of the square
-    pub size: u16,
+    pub size: f32,
     /// the width of the border of the square
     pub border_width: f32,
     /// the radius of the corners of the square
@@ -80,6 +103,106 @@ pub struct HandleSquare {
     pub border_color: Color,
 }
 
+/// The images of the [`Style`] of an [`XYPad`]
+///
+/// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+/// [`Style`]: struct.Style.html
+/// [`Handle`]: https://docs.rs/iced/0.2.0/iced/widget/image/struct.Handle.html
+#[derive(Debug, Clone)]
+pub struct TextureStyle {
+    /// the [`Handle`] to the image stretched over the background square,
+    /// or `None` to only draw the background square
+    pub back_image: Option<image::Handle>,
+    /// the region of the background image to draw when it is a texture
+    /// atlas, or `None` to draw the whole image
+    pub back_image_region: Option<AtlasRegion>,
+    /// the [`Handle`] to the image drawn in place of the [`HandleShape`],
+    /// or `None` to draw the [`HandleShape`]
+    ///
+    /// [`HandleShape`]: enum.HandleShape.html
+    pub handle_image: Option<image::Handle>,
+    /// the region of the handle image to draw when it is a texture atlas,
+    /// or `None` to draw the whole image
+    pub handle_image_region: Option<AtlasRegion>,
+    /// the bounds of the handle image, where the origin is in the center
+    /// of the handle. These can be larger than the handle to leave room
+    /// for padding, such as a glow or shadow.
+    pub handle_image_bounds: Rectangle,
+}
+
+/// The style of the snapshot corners of an [`XYPad`]
+///
+/// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+#[derive(Debug, Copy, Clone)]
+pub struct SnapshotStyle {
+    /// the color of a corner with no stored snapshot
+    pub empty_color: Color,
+    /// the color of a corner with a stored snapshot
+    pub stored_color: Color,
+    /// the width of the border of a corner
+    pub border_width: f32,
+    /// the color of the border of a corner
+    pub border_color: Color,
+}
+
+/// The style of the grid lines drawn across an [`XYPad`]
+///
+/// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+#[derive(Debug, Copy, Clone)]
+pub struct GridStyle {
+    /// the number of cells the x axis is divided into
+    pub x_divisions: u16,
+    /// the number of cells the y axis is divided into
+    pub y_divisions: u16,
+    /// the width of the grid lines
+    pub line_width: f32,
+    /// the color of the grid lines
+    pub color: Color,
+}
+
+/// The style of a crosshair that follows the handle of an [`XYPad`]
+///
+/// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+#[derive(Debug, Copy, Clone)]
+pub struct CrosshairStyle {
+    /// the width of the lines
+    pub line_width: f32,
+    /// the color of the lines
+    pub color: Color,
+    /// the gap between the center of the handle and the start of each
+    /// line
+    pub gap: f32,
+    /// the length of each line, or `None` for lines that reach the edges
+    /// of the pad
+    pub length: Option<f32>,
+}
+
+/// Style of the tick marks along the axes of an [`XYPad`].
+///
+/// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+#[derive(Debug, Clone)]
+pub struct TickMarksStyle {
+    /// The style of the tick marks
+    pub style: tick_marks::Style,
+    /// The placement of the tick marks of the x axis, relative to the pad
+    pub x_placement: tick_marks::Placement,
+    /// The placement of the tick marks of the y axis, relative to the pad
+    pub y_placement: tick_marks::Placement,
+}
+
+/// Style of the text marks along the axes of an [`XYPad`].
+///
+/// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+#[derive(Debug, Clone)]
+pub struct TextMarksStyle {
+    /// The style of the text marks
+    pub style: text_marks::Style,
+    /// The placement of the text marks of the x axis, relative to the pad
+    pub x_placement: text_marks::Placement,
+    /// The placement of the text marks of the y axis, relative to the pad
+    pub y_placement: text_marks::Placement,
+}
+
 /// A set of rules that dictate the style of an [`XYPad`].
 ///
 /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
@@ -98,6 +221,60 @@ pub trait StyleSheet {
     ///
     /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
     fn dragging(&self) -> Style;
+
+    /// Produces the style of an [`XYPad`] that has keyboard focus and is
+    /// not hovered or dragged.
+    ///
+    /// By default, this is the same as the hovered style.
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    fn focused(&self) -> Style {
+        self.hovered()
+    }
+
+    /// The style of the snapshot corners of an [`XYPad`]. This is only
+    /// used when the snapshots are enabled.
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    fn snapshot_style(&self) -> SnapshotStyle {
+        Default::SNAPSHOT_STYLE
+    }
+
+    /// The style of the tick marks along the axes of an [`XYPad`]
+    ///
+    /// For no tick marks, don't override this or set this to return `None`.
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    fn tick_marks_style(&self) -> Option<TickMarksStyle> {
+        None
+    }
+
+    /// The style of the text marks along the axes of an [`XYPad`]
+    ///
+    /// For no text marks, don't override this or set this to return `None`.
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    fn text_marks_style(&self) -> Option<TextMarksStyle> {
+        None
+    }
+
+    /// The style of the grid lines drawn across an [`XYPad`]
+    ///
+    /// For no grid, don't override this or set this to return `None`.
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    fn grid_style(&self) -> Option<GridStyle> {
+        None
+    }
+
+    /// The style of a crosshair that follows the handle of an [`XYPad`]
+    ///
+    /// For no crosshair, don't override this or set this to return `None`.
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    fn crosshair_style(&self) -> Option<CrosshairStyle> {
+        None
+    }
 }
 
 struct Default;
@@ -118,6 +295,13 @@ impl Default {
         border_color: default_colors::BORDER,
         center_line_width: 1.0,
         center_line_color: default_colors::XY_PAD_CENTER_LINE,
+        texture: None,
+    };
+    const SNAPSHOT_STYLE: SnapshotStyle = SnapshotStyle {
+        empty_color: default_colors::XY_PAD_SNAPSHOT_EMPTY,
+        stored_color: default_colors::XY_PAD_SNAPSHOT_STORED,
+        border_width: 1.0,
+        border_color: default_colors::BORDER,
     };
 }
 impl StyleSheet for Default {
@@ -145,6 +329,114 @@ impl StyleSheet for Default {
             ..Self::ACTIVE_STYLE
         }
     }
+
+    fn tick_marks_style(&self) -> Option<TickMarksStyle> {
+        Some(TickMarksStyle::default())
+    }
+
+    fn text_marks_style(&self) -> Option<TextMarksStyle> {
+        Some(TextMarksStyle::default())
+    }
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Default::ACTIVE_STYLE
+    }
+}
+
+impl std::default::Default for HandleCircle {
+    fn default() -> Self {
+        Default::ACTIVE_HANDLE
+    }
+}
+
+impl std::default::Default for HandleSquare {
+    fn default() -> Self {
+        Self {
+            color: default_colors::LIGHT_BACK,
+            size: 10.0,
+            border_width: 2.0,
+            border_radius: 0.0,
+            border_color: default_colors::BORDER,
+        }
+    }
+}
+
+impl std::default::Default for SnapshotStyle {
+    fn default() -> Self {
+        Default::SNAPSHOT_STYLE
+    }
+}
+
+impl std::default::Default for GridStyle {
+    fn default() -> Self {
+        Self {
+            x_divisions: 4,
+            y_divisions: 4,
+            line_width: 1.0,
+            color: default_colors::XY_PAD_CENTER_LINE,
+        }
+    }
+}
+
+impl std::default::Default for CrosshairStyle {
+    fn default() -> Self {
+        Self {
+            line_width: 1.0,
+            color: default_colors::XY_PAD_RAIL,
+            gap: 8.0,
+            length: Some(10.0),
+        }
+    }
+}
+
+impl std::default::Default for TickMarksStyle {
+    fn default() -> Self {
+        TickMarksStyle {
+            style: tick_marks::Style {
+                tier_1: tick_marks::Shape::Line {
+                    length: 8.0,
+                    width: 2.0,
+                    color: default_colors::TICK_TIER_1,
+                },
+                tier_2: tick_marks::Shape::Line {
+                    length: 6.0,
+                    width: 1.0,
+                    color: default_colors::TICK_TIER_2,
+                },
+                tier_3: tick_marks::Shape::Line {
+                    length: 4.0,
+                    width: 1.0,
+                    color: default_colors::TICK_TIER_3,
+                },
+            },
+            x_placement: tick_marks::Placement::RightOrBottom {
+                offset: Offset::ZERO,
+                inside: true,
+            },
+            y_placement: tick_marks::Placement::LeftOrTop {
+                offset: Offset::ZERO,
+                inside: true,
+            },
+        }
+    }
+}
+
+impl std::default::Default for TextMarksStyle {
+    fn default() -> Self {
+        TextMarksStyle {
+            style: text_marks::Style::default(),
+            x_placement: text_marks::Placement::RightOrBottom {
+                inside: false,
+                offset: Offset { x: 0.0, y: 7.0 },
+            },
+            y_placement: text_marks::Placement::LeftOrTop {
+                inside: false,
+                offset: Offset { x: -7.0, y: 0.0 },
+            },
+        }
+    }
 }
 
 impl std::default::Default for Box<dyn StyleSheet> {